@@ -1,5 +1,5 @@
 use criterion::{criterion_group, criterion_main, Criterion};
-use nice_colors::Color;
+use nice_colors::{html, Color};
 
 fn criterion_benchmark(c: &mut Criterion) {
     c.bench_function("parses FF0000", |b| b.iter(||
@@ -10,9 +10,26 @@ fn criterion_benchmark(c: &mut Criterion) {
         Color::from_hex_str("#FF0000")
     ));
     
+    c.bench_function("parses LightGoldenrodYellow", |b| b.iter(||
+        html::from_html_color_name("LightGoldenrodYellow")
+    ));
+    
     c.bench_function("parses rgb(255, 0, 0)", |b| b.iter(||
         Color::from_rgb_str("rgb(255, 0, 0)")
     ));
+    
+    let colors = (0..10_000)
+        .map(|i| Color::from_decimal(i * 1667))
+        .collect::<Vec<_>>();
+    let hex = Color::to_hex_bulk(&colors);
+    
+    c.bench_function("formats 10000 colors in bulk", |b| b.iter(||
+        Color::to_hex_bulk(&colors)
+    ));
+    
+    c.bench_function("parses 10000 colors in bulk", |b| b.iter(||
+        Color::parse_hex_bulk(&hex)
+    ));
 }
 
 criterion_group!{
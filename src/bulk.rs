@@ -0,0 +1,127 @@
+//! Operations over slices of colors, for applying adjustments to whole frames of pixels.
+//! 
+//! These are written as simple loops without branches so that the compiler can auto-vectorize 
+//! them.
+
+use crate::Color;
+use crate::color::Value;
+
+/// Blends each color in `colors` with the color at the same position in `others` in place. If 
+/// the slices differ in length, only colors up to the length of the shorter slice are blended. 
+/// The result is the same as [`Color::blend`].
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::Color;
+/// use nice_colors::bulk;
+/// 
+/// let mut colors = [Color { red: 255, green: 0, blue: 0 }; 4];
+/// let others = [Color { red: 0, green: 0, blue: 255 }; 4];
+/// 
+/// bulk::blend_slices(&mut colors, &others, 0.5);
+/// 
+/// assert_eq!(colors, [Color { red: 128, green: 0, blue: 128 }; 4]);
+/// ```
+pub fn blend_slices(colors: &mut [Color], others: &[Color], amount: f32) {
+    let amount = amount.clamp(0.0, 1.0);
+    let blend = |a: Value, b: Value| {
+        (a as f32 * (1.0 - amount) + b as f32 * amount).round() as Value
+    };
+    
+    map_slices(colors, others, blend);
+}
+
+/// Maps each value of each color in `colors` with the corresponding value of the color at the 
+/// same position in `others` in place. If the slices differ in length, only colors up to the 
+/// length of the shorter slice are mapped.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::Color;
+/// use nice_colors::bulk;
+/// 
+/// let mut colors = [Color { red: 255, green: 0, blue: 0 }; 4];
+/// let others = [Color { red: 0, green: 0, blue: 255 }; 4];
+/// 
+/// bulk::map_slices(&mut colors, &others, std::cmp::max);
+/// 
+/// assert_eq!(colors, [Color { red: 255, green: 0, blue: 255 }; 4]);
+/// ```
+pub fn map_slices<F>(colors: &mut [Color], others: &[Color], f: F)
+where
+    F: Fn(Value, Value) -> Value,
+{
+    for (color, other) in colors.iter_mut().zip(others) {
+        color.red = f(color.red, other.red);
+        color.green = f(color.green, other.green);
+        color.blue = f(color.blue, other.blue);
+    }
+}
+
+/// Converts each color in `colors` to grayscale in place. The result is the same as 
+/// [`Color::grayscale`].
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::Color;
+/// use nice_colors::bulk;
+/// 
+/// let mut colors = [Color { red: 255, green: 0, blue: 0 }; 4];
+/// 
+/// bulk::grayscale_in_place(&mut colors);
+/// 
+/// assert_eq!(colors, [Color { red: 54, green: 54, blue: 54 }; 4]);
+/// ```
+pub fn grayscale_in_place(colors: &mut [Color]) {
+    for color in colors.iter_mut() {
+        *color = color.grayscale();
+    }
+}
+
+/// Swaps the red and blue values of each color in `colors` in place, converting between RGB and 
+/// BGR. The result is the same as [`Color::swap_rb`].
+pub fn swap_rb_in_place(colors: &mut [Color]) {
+    for color in colors.iter_mut() {
+        std::mem::swap(&mut color.red, &mut color.blue);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn matches_single_color_operations() {
+        let colors = (0..1000)
+            .map(|i| Color::from_decimal(i * 16769))
+            .collect::<Vec<_>>();
+        let others = colors.iter().rev().copied().collect::<Vec<_>>();
+        let mut blended = colors.clone();
+        let mut grayscale = colors.clone();
+        let mut swapped = colors.clone();
+        
+        blend_slices(&mut blended, &others, 0.3);
+        grayscale_in_place(&mut grayscale);
+        swap_rb_in_place(&mut swapped);
+        
+        for (i, color) in colors.iter().enumerate() {
+            assert_eq!(blended[i], color.blend(others[i], 0.3));
+            assert_eq!(grayscale[i], color.grayscale());
+            assert_eq!(swapped[i], color.swap_rb());
+        }
+    }
+    
+    #[test]
+    fn maps_up_to_shorter_slice() {
+        let mut colors = [Color { red: 10, green: 10, blue: 10 }; 3];
+        let others = [Color { red: 5, green: 5, blue: 5 }; 2];
+        
+        map_slices(&mut colors, &others, Value::saturating_sub);
+        
+        assert_eq!(colors, [
+            Color { red: 5, green: 5, blue: 5 },
+            Color { red: 5, green: 5, blue: 5 },
+            Color { red: 10, green: 10, blue: 10 },
+        ]);
+    }
+}
\ No newline at end of file
@@ -1,8 +1,13 @@
-use crate::{parse, html};
+use crate::parse;
 use crate::helpers::{self, conversions};
-use crate::HSLColor;
+use crate::{
+    format, Deficiency, Easing, FormatOptions, GamutMapping, HSLColor, Illuminant, ParseColorError,
+    Rgba,
+};
+use crate::format::{DisplayHex, DisplayRgb, DisplayRgba};
 use std::fmt;
 use std::hash::Hash;
+use std::ops::Range;
 use std::fmt::Write;
 
 pub type Value = u8;
@@ -10,11 +15,34 @@ pub type Alpha = f32;
 pub type DecimalValue = u32;
 pub const SLICE_LENGTH: usize = 3;
 
+/// The maximum of a 10-bit value.
+const MAX_10_BIT: u32 = 0x3FF;
+
+/// The correlated color temperature of the D65 white point of sRGB in kelvin.
+const D65_KELVIN: f32 = 6504.0;
+
+/// The shift in mireds of the white point for each unit of temperature in 
+/// [`Color::white_balance`].
+const WHITE_BALANCE_MIREDS: f32 = 90.0;
+
+/// The shift in the y chromaticity of the white point for each unit of tint in 
+/// [`Color::white_balance`].
+const WHITE_BALANCE_TINT: f32 = 0.02;
+
 /// A color containing values for red, green, blue, and alpha.
 pub type ColorWithAlpha = (Color, Alpha);
 
 /// A color containing values for red, green, and blue.
+/// 
+/// The layout is guaranteed to be the same as `[u8; 3]`, see [`Color::as_bytes`].
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(zerocopy::AsBytes, zerocopy::FromBytes, zerocopy::FromZeroes),
+)]
+#[repr(C)]
 pub struct Color {
     /// The red value.
     pub red: Value,
@@ -24,23 +52,129 @@ pub struct Color {
     pub blue: Value,
 }
 
+/// A channel of a [`Color`], for indexing a color by channel.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Channel, Color};
+/// 
+/// let mut color = Color { red: 255, green: 0, blue: 0 };
+/// 
+/// color[Channel::Blue] = 128;
+/// 
+/// assert_eq!(color[Channel::Red], 255);
+/// assert_eq!(color, Color { red: 255, green: 0, blue: 128 });
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Channel {
+    /// The red channel, at index 0.
+    Red,
+    /// The green channel, at index 1.
+    Green,
+    /// The blue channel, at index 2.
+    Blue,
+}
+
+impl Channel {
+    /// Every channel, in order.
+    pub const ALL: [Self; SLICE_LENGTH] = [Self::Red, Self::Green, Self::Blue];
+}
+
+/// The order of channels when packing a color into a `u32`, from the most significant byte to the 
+/// least significant byte. Used by [`Color::to_u32`] and [`Rgba::to_u32`].
+/// 
+/// [`ChannelOrder::Argb`] matches CSS hexadecimal integers such as `0xFFFF0000`, and 
+/// [`ChannelOrder::Abgr`] matches Win32 `COLORREF` values once the alpha byte is masked off.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ChannelOrder {
+    /// Alpha, red, green, blue.
+    Argb,
+    /// Red, green, blue, alpha.
+    Rgba,
+    /// Alpha, blue, green, red.
+    Abgr,
+    /// Blue, green, red, alpha.
+    Bgra,
+}
+
+impl ChannelOrder {
+    /// Packs color values and an alpha value into a `u32` in this order.
+    pub(crate) fn pack(self, [red, green, blue]: [Value; SLICE_LENGTH], alpha: Value) -> u32 {
+        u32::from_be_bytes(match self {
+            Self::Argb => [alpha, red, green, blue],
+            Self::Rgba => [red, green, blue, alpha],
+            Self::Abgr => [alpha, blue, green, red],
+            Self::Bgra => [blue, green, red, alpha],
+        })
+    }
+    
+    /// Unpacks color values and an alpha value from a `u32` in this order.
+    pub(crate) fn unpack(self, value: u32) -> ([Value; SLICE_LENGTH], Value) {
+        let [a, b, c, d] = value.to_be_bytes();
+        
+        match self {
+            Self::Argb => ([b, c, d], a),
+            Self::Rgba => ([a, b, c], d),
+            Self::Abgr => ([d, c, b], a),
+            Self::Bgra => ([c, b, a], d),
+        }
+    }
+}
+
+/// Serializes to a hexadecimal color string for human-readable formats such as JSON, and to 
+/// the three bytes of the color for binary formats such as bincode.
 #[cfg(feature = "serde")]
 impl serde::Serialize for Color {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.collect_str(&self.to_hex_string())
+        if serializer.is_human_readable() {
+            serializer.collect_str(&self.to_hex_string())
+        } else {
+            self.to_array().serialize(serializer)
+        }
     }
 }
 
+/// Deserializes from any supported color string for human-readable formats such as JSON, and 
+/// from the three bytes of the color for binary formats such as bincode.
 #[cfg(feature = "serde")]
 impl<'de> serde::Deserialize<'de> for Color {
     fn deserialize<D>(deserializer: D) -> Result<Color, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        deserializer.deserialize_any(crate::serializers::ColorVisitor)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(crate::serializers::ColorVisitor)
+        } else {
+            <[Value; SLICE_LENGTH]>::deserialize(deserializer).map(Self::from)
+        }
+    }
+}
+
+/// Samples a uniformly random color, where each value is equally likely.
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Color> for rand::distributions::Standard {
+    fn sample<R>(&self, rng: &mut R) -> Color
+    where
+        R: rand::Rng + ?Sized,
+    {
+        let [red, green, blue] = rng.gen::<[Value; SLICE_LENGTH]>();
+        
+        Color { red, green, blue }
+    }
+}
+
+/// Generates any color, shrinking each value towards 0 so that failures shrink towards black.
+#[cfg(feature = "quickcheck")]
+impl quickcheck::Arbitrary for Color {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Self::from(<[Value; SLICE_LENGTH]>::arbitrary(g))
+    }
+    
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.to_array().shrink().map(Self::from))
     }
 }
 
@@ -102,12 +236,11 @@ impl Color {
     where
         F: Fn(Value) -> Value,
     {
-        let mut mapped = Color::default();
-        
-        mapped.red = f(self.red);
-        mapped.green = f(self.green);
-        mapped.blue = f(self.blue);
-        mapped
+        Self {
+            red: f(self.red),
+            green: f(self.green),
+            blue: f(self.blue),
+        }
     }
     
     /// Maps each value in this color with another color.
@@ -126,12 +259,11 @@ impl Color {
     where
         F: Fn(Value, Value) -> Value,
     {
-        let mut mapped = Color::default();
-        
-        mapped.red = f(self.red, other.red);
-        mapped.green = f(self.green, other.green);
-        mapped.blue = f(self.blue, other.blue);
-        mapped
+        Self {
+            red: f(self.red, other.red),
+            green: f(self.green, other.green),
+            blue: f(self.blue, other.blue),
+        }
     }
     
     /// Lightens this color by a given amount.
@@ -182,7 +314,7 @@ impl Color {
     /// assert_eq!(darkened, Color { red: 128, green: 0, blue: 0 });
     /// ```
     pub fn darken(&self, mut amount: f32) -> Self {
-        amount = amount.max(0.0).min(1.0);
+        amount = amount.clamp(0.0, 1.0);
         
         self.map_each(|c| (c as f32 * (1.0 - amount)).round() as Value)
     }
@@ -217,6 +349,441 @@ impl Color {
         })
     }
     
+    /// Blends this color with another color, warping `amount` with an easing function first. See 
+    /// [`Color::blend`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Easing};
+    /// 
+    /// let black = Color { red: 0, green: 0, blue: 0 };
+    /// let white = Color { red: 255, green: 255, blue: 255 };
+    /// 
+    /// assert_eq!(black.blend_eased(white, 0.5, Easing::EaseInOut), black.blend(white, 0.5));
+    /// assert!(black.blend_eased(white, 0.25, Easing::EaseIn).red < black.blend(white, 0.25).red);
+    /// ```
+    pub fn blend_eased(&self, other: Color, amount: f32, easing: Easing) -> Self {
+        self.blend(other, easing.apply(amount))
+    }
+    
+    /// Converts this color to grayscale using the Rec. 709 luma coefficients, the same weights 
+    /// used by the CSS `grayscale()` filter.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color { red: 255, green: 0, blue: 0 };
+    /// 
+    /// assert_eq!(color.grayscale(), Color { red: 54, green: 54, blue: 54 });
+    /// ```
+    pub fn grayscale(&self) -> Self {
+        let luma = 0.2126 * self.red as f32
+            + 0.7152 * self.green as f32
+            + 0.0722 * self.blue as f32;
+        let value = luma.round() as Value;
+        
+        Self { red: value, green: value, blue: value }
+    }
+    
+    /// Swaps the red and blue values of this color, converting between RGB and BGR.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color { red: 255, green: 128, blue: 0 };
+    /// 
+    /// assert_eq!(color.swap_rb(), Color { red: 0, green: 128, blue: 255 });
+    /// ```
+    pub fn swap_rb(&self) -> Self {
+        Self {
+            red: self.blue,
+            green: self.green,
+            blue: self.red,
+        }
+    }
+    
+    /// Rearranges the values of this color, where each channel in `order` gives the channel to 
+    /// read the red, green, and blue values from respectively.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Channel, Color};
+    /// 
+    /// let color = Color { red: 255, green: 128, blue: 0 };
+    /// 
+    /// assert_eq!(
+    ///     color.swizzle([Channel::Green, Channel::Green, Channel::Red]),
+    ///     Color { red: 128, green: 128, blue: 255 },
+    /// );
+    /// ```
+    pub fn swizzle(&self, order: [Channel; SLICE_LENGTH]) -> Self {
+        let [red, green, blue] = order.map(|channel| self[channel]);
+        
+        Self { red, green, blue }
+    }
+    
+    /// Gets the relative luminance of this color as defined by WCAG 2, from 0.0 for black to 1.0 
+    /// for white.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// assert_eq!(Color { red: 255, green: 255, blue: 255 }.relative_luminance(), 1.0);
+    /// assert_eq!(Color { red: 0, green: 0, blue: 0 }.relative_luminance(), 0.0);
+    /// ```
+    pub fn relative_luminance(&self) -> f32 {
+        let [red, green, blue] = self
+            .to_array()
+            .map(|value| conversions::srgb_to_linear(value as f32 / Value::MAX as f32));
+        
+        0.2126 * red + 0.7152 * green + 0.0722 * blue
+    }
+    
+    /// Gets the WCAG 2 contrast ratio between this color and another color, from 1.0 for 
+    /// identical colors to 21.0 for black and white. WCAG AA requires a ratio of at least 4.5 for 
+    /// normal text.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let black = Color { red: 0, green: 0, blue: 0 };
+    /// let white = Color { red: 255, green: 255, blue: 255 };
+    /// 
+    /// assert_eq!(black.contrast_ratio(white).round(), 21.0);
+    /// assert_eq!(white.contrast_ratio(black), black.contrast_ratio(white));
+    /// ```
+    pub fn contrast_ratio(&self, other: Color) -> f32 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+    
+    /// Gets the perceptual difference between this color and another color as ΔEOK, the 
+    /// Euclidean distance between them in Oklab used by CSS Color 4. A difference of about 0.02 is 
+    /// just noticeable, and identical colors have a difference of 0.0.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let black = Color { red: 0, green: 0, blue: 0 };
+    /// let white = Color { red: 255, green: 255, blue: 255 };
+    /// 
+    /// assert!((black.delta_e_ok(white) - 1.0).abs() < 0.001);
+    /// assert!(white.delta_e_ok(Color { red: 254, green: 255, blue: 255 }) < 0.02);
+    /// assert_eq!(white.delta_e_ok(white), 0.0);
+    /// ```
+    pub fn delta_e_ok(&self, other: Color) -> f32 {
+        conversions::delta_e_ok(self.to_oklab(), other.to_oklab())
+    }
+    
+    /// Adjusts the lightness of this color in Oklch until its WCAG 2 contrast ratio with 
+    /// `background` is at least `target_ratio`, keeping hue and chroma where the sRGB gamut 
+    /// allows. The color is lightened or darkened, whichever reaches the target with the smallest 
+    /// change. Returns this color if it already has enough contrast, or black or white, whichever 
+    /// has more contrast, if the target cannot be reached.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let white = Color { red: 255, green: 255, blue: 255 };
+    /// let orange = Color { red: 255, green: 165, blue: 0 };
+    /// let adjusted = orange.adjust_for_contrast(white, 4.5);
+    /// 
+    /// assert!(orange.contrast_ratio(white) < 4.5);
+    /// assert!(adjusted.contrast_ratio(white) >= 4.5);
+    /// assert!(adjusted.relative_luminance() < orange.relative_luminance());
+    /// ```
+    pub fn adjust_for_contrast(&self, background: Color, target_ratio: f32) -> Self {
+        const ITERATIONS: usize = 24;
+        
+        if self.contrast_ratio(background) >= target_ratio {
+            return *self;
+        }
+        
+        let [lightness, a, b] = self.to_oklab();
+        let with_lightness = |lightness: f32| {
+            let linear = conversions::oklab_to_linear([lightness, a, b]);
+            
+            Self::from(conversions::linear_to_rgb(conversions::gamut_map_linear(linear)))
+        };
+        let passes = |color: Self| color.contrast_ratio(background) >= target_ratio;
+        // Finds the lightness closest to the current lightness that passes between the current 
+        // lightness and `end`, if `end` passes.
+        let search = |end: f32| {
+            let mut failing = lightness;
+            let mut passing = end;
+            
+            if !passes(with_lightness(passing)) {
+                return None;
+            }
+            
+            for _ in 0..ITERATIONS {
+                let middle = (failing + passing) / 2.0;
+                
+                if passes(with_lightness(middle)) {
+                    passing = middle;
+                } else {
+                    failing = middle;
+                }
+            }
+            
+            Some(passing)
+        };
+        let best = [search(0.0), search(1.0)]
+            .into_iter()
+            .flatten()
+            .min_by(|x, y| (x - lightness).abs().total_cmp(&(y - lightness).abs()));
+        
+        match best {
+            Some(lightness) => with_lightness(lightness),
+            None => {
+                let black = Self { red: 0, green: 0, blue: 0 };
+                let white = Self { red: 255, green: 255, blue: 255 };
+                
+                if black.contrast_ratio(background) >= white.contrast_ratio(background) {
+                    black
+                } else {
+                    white
+                }
+            },
+        }
+    }
+    
+    /// Inverts this color by subtracting each value from 255. This is also available as the `!` 
+    /// operator.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color { red: 255, green: 100, blue: 0 };
+    /// 
+    /// assert_eq!(color.invert(), Color { red: 0, green: 155, blue: 255 });
+    /// assert_eq!(!color, color.invert());
+    /// ```
+    pub fn invert(&self) -> Self {
+        self.map_each(|value| Value::MAX - value)
+    }
+    
+    /// Applies a gamma adjustment in linear light, raising each linear value to the power of 
+    /// `1.0 / gamma`. Values of `gamma` above 1.0 brighten midtones and values below 1.0 darken 
+    /// them, while black and white are unchanged. The color is returned unchanged if `gamma` is 
+    /// not positive.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color { red: 128, green: 64, blue: 255 };
+    /// 
+    /// assert_eq!(color.gamma(2.0), Color { red: 181, green: 131, blue: 255 });
+    /// assert_eq!(color.gamma(1.0), color);
+    /// ```
+    pub fn gamma(&self, gamma: f32) -> Self {
+        if gamma <= 0.0 {
+            return *self;
+        }
+        
+        let linear = conversions::rgb_to_linear(self.to_array());
+        
+        Self::from(conversions::linear_to_rgb(linear.map(|value| value.powf(1.0 / gamma))))
+    }
+    
+    /// Adjusts exposure by a number of stops in linear light, where each stop doubles or halves 
+    /// the amount of light. Values that exceed white are clipped.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color { red: 128, green: 64, blue: 200 };
+    /// 
+    /// assert_eq!(color.exposure(1.0), Color { red: 176, green: 90, blue: 255 });
+    /// assert_eq!(color.exposure(-1.0), Color { red: 92, green: 44, blue: 146 });
+    /// ```
+    pub fn exposure(&self, stops: f32) -> Self {
+        let linear = conversions::rgb_to_linear(self.to_array());
+        let scale = stops.exp2();
+        
+        Self::from(conversions::linear_to_rgb(linear.map(|value| value * scale)))
+    }
+    
+    /// Adjusts white balance by adapting this color from the D65 white point of sRGB to a warmer 
+    /// or cooler white using the Bradford transform, like the temperature and tint sliders of 
+    /// photo editors.
+    /// 
+    /// `temperature` ranges from -1.0 (cooler, about 15700 K) to 1.0 (warmer, about 4100 K), and 
+    /// `tint` ranges from -1.0 (greener) to 1.0 (more magenta). Both are clamped to that range, 
+    /// and 0.0 for both leaves the color unchanged apart from rounding. Values that leave the 
+    /// sRGB gamut are clipped.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let white = Color { red: 255, green: 255, blue: 255 };
+    /// let warm = white.white_balance(0.5, 0.0);
+    /// let cool = white.white_balance(-0.5, 0.0);
+    /// 
+    /// assert!(warm.red > warm.blue);
+    /// assert!(cool.blue > cool.red);
+    /// assert_eq!(white.white_balance(0.0, 0.0), white);
+    /// ```
+    pub fn white_balance(&self, temperature: f32, tint: f32) -> Self {
+        let (x, y) = conversions::daylight_chromaticity(D65_KELVIN);
+        let from_white = conversions::xy_to_xyz(x, y);
+        let mireds = 1_000_000.0 / D65_KELVIN + temperature.clamp(-1.0, 1.0) * WHITE_BALANCE_MIREDS;
+        let (x, y) = conversions::daylight_chromaticity(1_000_000.0 / mireds);
+        let to_white = conversions::xy_to_xyz(x, y - tint.clamp(-1.0, 1.0) * WHITE_BALANCE_TINT);
+        let xyz = conversions::linear_to_xyz_d65(conversions::rgb_to_linear(self.to_array()));
+        let adapted = conversions::bradford_adapt(xyz, from_white, to_white);
+        
+        Self::from(conversions::linear_to_rgb(conversions::xyz_d65_to_linear(adapted)))
+    }
+    
+    /// Approximates how this color, as a surface seen under the D65 white point of sRGB, shifts 
+    /// under another light source. The color is adapted from D65 to the white of the illuminant 
+    /// using the Bradford transform, so white takes on the color of the light and other colors 
+    /// shift with it. Values that leave the sRGB gamut are clipped.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Illuminant};
+    /// 
+    /// let white = Color { red: 255, green: 255, blue: 255 };
+    /// let incandescent = white.under_illuminant(Illuminant::A);
+    /// let print_booth = white.under_illuminant(Illuminant::D50);
+    /// 
+    /// assert!(incandescent.red > incandescent.blue);
+    /// assert!(incandescent.blue < print_booth.blue);
+    /// assert_eq!(white.under_illuminant(Illuminant::D65), white);
+    /// ```
+    pub fn under_illuminant(&self, illuminant: Illuminant) -> Self {
+        let (x, y) = Illuminant::D65.chromaticity();
+        let from_white = conversions::xy_to_xyz(x, y);
+        let (x, y) = illuminant.chromaticity();
+        let to_white = conversions::xy_to_xyz(x, y);
+        let xyz = conversions::linear_to_xyz_d65(conversions::rgb_to_linear(self.to_array()));
+        let adapted = conversions::bradford_adapt(xyz, from_white, to_white);
+        
+        Self::from(conversions::linear_to_rgb(conversions::xyz_d65_to_linear(adapted)))
+    }
+    
+    /// Simulates how this color appears to someone with a color vision deficiency. Values that 
+    /// leave the sRGB gamut are clipped.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Deficiency};
+    /// 
+    /// let red = Color { red: 255, green: 0, blue: 0 };
+    /// let green = Color { red: 0, green: 128, blue: 0 };
+    /// let simulated_red = red.simulate_deficiency(Deficiency::Deuteranopia);
+    /// let simulated_green = green.simulate_deficiency(Deficiency::Deuteranopia);
+    /// 
+    /// assert!(simulated_red.delta_e_ok(simulated_green) < red.delta_e_ok(green) / 2.0);
+    /// assert_eq!(
+    ///     red.simulate_deficiency(Deficiency::Achromatopsia),
+    ///     Color { red: 127, green: 127, blue: 127 },
+    /// );
+    /// ```
+    pub fn simulate_deficiency(&self, deficiency: Deficiency) -> Self {
+        let linear = conversions::rgb_to_linear(self.to_array());
+        
+        Self::from(conversions::linear_to_rgb(conversions::multiply_matrix(
+            &deficiency.matrix(),
+            linear,
+        )))
+    }
+    
+    /// Describes this color in plain words, e.g. "dark desaturated blue" or "vivid orange", for 
+    /// alt text, logs, and voice interfaces. The description is made from buckets of hue, 
+    /// saturation, and lightness, so it is approximate.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// assert_eq!(Color { red: 255, green: 165, blue: 0 }.describe(), "vivid orange");
+    /// assert_eq!(Color { red: 40, green: 50, blue: 80 }.describe(), "dark desaturated blue");
+    /// assert_eq!(Color { red: 128, green: 128, blue: 128 }.describe(), "gray");
+    /// ```
+    pub fn describe(&self) -> String {
+        let HSLColor { hue, saturation, lightness } = HSLColor::from(self);
+        
+        if saturation < 0.1 || !(0.06..=0.96).contains(&lightness) {
+            return match lightness {
+                l if l < 0.1 => "black",
+                l if l < 0.35 => "dark gray",
+                l if l < 0.7 => "gray",
+                l if l < 0.95 => "light gray",
+                _ => "white",
+            }.to_string();
+        }
+        
+        let hue_name = match hue {
+            h if h < 15.0 && lightness > 0.7 => "pink",
+            h if h < 15.0 => "red",
+            h if h < 45.0 && lightness < 0.35 => "brown",
+            h if h < 45.0 => "orange",
+            h if h < 70.0 => "yellow",
+            h if h < 150.0 => "green",
+            h if h < 195.0 => "cyan",
+            h if h < 255.0 => "blue",
+            h if h < 285.0 => "purple",
+            h if h < 320.0 => "magenta",
+            h if h < 345.0 => "pink",
+            _ if lightness > 0.7 => "pink",
+            _ => "red",
+        };
+        let lightness_name = match lightness {
+            _ if hue_name == "brown" => None,
+            l if l < 0.3 => Some("dark"),
+            l if l > 0.7 => Some("light"),
+            _ => None,
+        };
+        let saturation_name = match saturation {
+            s if s < 0.35 => Some("desaturated"),
+            s if s > 0.8 && (0.3..=0.7).contains(&lightness) => Some("vivid"),
+            _ => None,
+        };
+        
+        [lightness_name, saturation_name, Some(hue_name)]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+    
+    /// Reduces each value of this color to the nearest of `levels` evenly spaced levels between 0 
+    /// and 255, e.g. 0, 85, 170, and 255 for 4 levels. `levels` less than 2 is treated as 2.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color { red: 200, green: 100, blue: 20 };
+    /// 
+    /// assert_eq!(color.posterize(4), Color { red: 170, green: 85, blue: 0 });
+    /// assert_eq!(color.posterize(2), Color { red: 255, green: 0, blue: 0 });
+    /// ```
+    pub fn posterize(&self, levels: u8) -> Self {
+        let steps = levels.max(2) as f32 - 1.0;
+        
+        self.map_each(|value| {
+            let level = (value as f32 / Value::MAX as f32 * steps).round();
+            
+            helpers::float_to_value(level / steps * Value::MAX as f32)
+        })
+    }
+    
     /// Converts this color into a decimal color value.
     /// 
     /// # Examples
@@ -228,158 +795,945 @@ impl Color {
     /// assert_eq!(color.to_decimal(), 6579300);
     /// ```
     pub fn to_decimal(&self) -> DecimalValue {
-        DecimalValue::from_le_bytes([self.red, self.green, self.blue, 0])
+        DecimalValue::from_be_bytes([0, self.red, self.green, self.blue])
     }
     
-    /// Converts this color into a hexadecimal color string.
+    /// Packs this color into a `u32` with the given channel order. The alpha byte is `0xFF`.
     /// 
     /// # Examples
     /// ```
-    /// use nice_colors::Color;
+    /// use nice_colors::{ChannelOrder, Color};
     /// 
-    /// assert_eq!(Color { red: 255, green: 0, blue: 0 }.to_hex_string(), "#FF0000");
+    /// let color = Color { red: 0x11, green: 0x22, blue: 0x33 };
+    /// 
+    /// assert_eq!(color.to_u32(ChannelOrder::Argb), 0xFF112233);
+    /// assert_eq!(color.to_u32(ChannelOrder::Rgba), 0x112233FF);
+    /// assert_eq!(color.to_u32(ChannelOrder::Abgr), 0xFF332211);
+    /// assert_eq!(color.to_u32(ChannelOrder::Bgra), 0x332211FF);
     /// ```
-    pub fn to_hex_string(&self) -> String {
-        self
-            .into_iter()
-            .fold(String::from('#'),|mut output, b| {
-                let _ = write!(output, "{b:02X}");
-                output
-            })
+    pub fn to_u32(&self, order: ChannelOrder) -> u32 {
+        order.pack(self.to_array(), Value::MAX)
     }
     
-    /// Converts this color into an rgb color string.
+    /// Packs this color into 16 bits with 5 bits for red, 6 bits for green, and 5 bits for blue, 
+    /// the format used by many small embedded displays. The lowest bits of each value are lost.
     /// 
     /// # Examples
     /// ```
     /// use nice_colors::Color;
     /// 
-    /// assert_eq!(Color { red: 255, green: 0, blue: 0 }.to_rgb_string(), "rgb(255 0 0)");
+    /// let color = Color { red: 255, green: 128, blue: 0 };
+    /// 
+    /// assert_eq!(color.to_rgb565(), 0xFC00);
+    /// assert_eq!(Color::from_rgb565(0xFC00), Color { red: 255, green: 130, blue: 0 });
     /// ```
-    pub fn to_rgb_string(&self) -> String {
-        format!("rgb({} {} {})", self.red, self.green, self.blue)
+    pub fn to_rgb565(&self) -> u16 {
+        pack_16(self.red, self.green, self.blue, 6)
     }
     
-    /// Converts this color into an rgba color string.
+    /// Packs this color into 16 bits with 5 bits for blue, 6 bits for green, and 5 bits for red. 
+    /// See [`Color::to_rgb565`].
+    pub fn to_bgr565(&self) -> u16 {
+        pack_16(self.blue, self.green, self.red, 6)
+    }
+    
+    /// Packs this color into 16 bits with 5 bits for each value. The highest bit is unused.
+    pub fn to_rgb555(&self) -> u16 {
+        pack_16(self.red, self.green, self.blue, 5)
+    }
+    
+    /// Packs this color into a `u32` with 10 bits for each value in A2R10G10B10 order, the format 
+    /// used by 10-bit video and HDR swap chains. Each value is scaled so that 255 becomes 1023, 
+    /// and the 2 alpha bits are set to fully opaque.
     /// 
     /// # Examples
     /// ```
     /// use nice_colors::Color;
     /// 
-    /// assert_eq!(Color { red: 255, green: 0, blue: 0 }.to_rgba_string(0.5), "rgba(255 0 0 0.5)");
+    /// let color = Color { red: 255, green: 128, blue: 0 };
+    /// 
+    /// assert_eq!(color.to_a2r10g10b10(), 0xFFF80800);
+    /// assert_eq!(Color::from_a2r10g10b10(0xFFF80800), color);
     /// ```
-    pub fn to_rgba_string(&self, mut alpha: Alpha) -> String {
-        alpha = alpha.max(0.0).min(1.0);
+    pub fn to_a2r10g10b10(&self) -> u32 {
+        let [red, green, blue] = self.to_array().map(|value| {
+            (u32::from(value) * MAX_10_BIT + Value::MAX as u32 / 2) / Value::MAX as u32
+        });
         
-        format!("rgba({} {} {} {})", self.red, self.green, self.blue, alpha)
+        0b11 << 30 | red << 20 | green << 10 | blue
     }
     
-    /// Converts this color into an hsl color string.
+    /// Converts this color into gamma-encoded Display P3 values from 0.0 to 1.0, the color space 
+    /// of most modern phone and laptop displays. Every sRGB color is within the Display P3 gamut.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, GamutMapping};
+    /// 
+    /// let color = Color { red: 255, green: 0, blue: 0 };
+    /// let [red, green, blue] = color.to_display_p3();
+    /// 
+    /// assert!((red - 0.917).abs() < 0.001);
+    /// assert!((green - 0.200).abs() < 0.001);
+    /// assert!((blue - 0.139).abs() < 0.001);
+    /// assert_eq!(Color::from_display_p3([red, green, blue], GamutMapping::Clip), color);
+    /// ```
+    pub fn to_display_p3(&self) -> [f32; 3] {
+        conversions::linear_to_display_p3(conversions::rgb_to_linear(self.to_array()))
+    }
+    
+    /// Converts this color into gamma-encoded Rec. 709 values from 0.0 to 1.0, the color space of 
+    /// HD video. Rec. 709 uses the same primaries as sRGB with a different transfer function.
+    /// 
+    /// Values are full range. Scale them to 16 to 235 for limited range 8-bit video.
     /// 
     /// # Examples
     /// ```
     /// use nice_colors::Color;
     /// 
-    /// assert_eq!(Color { red: 255, green: 0, blue: 0 }.to_hsl_string(), "hsl(0 100% 50%)");
+    /// let color = Color { red: 255, green: 128, blue: 0 };
+    /// let rec709 = color.to_rec709();
+    /// 
+    /// assert!((rec709[1] - 0.452).abs() < 0.001);
+    /// assert_eq!(Color::from_rec709(rec709), color);
     /// ```
-    pub fn to_hsl_string(&self) -> String {
-        let hsl: HSLColor = self.into();
-        let hue = hsl.hue.round() as i32;
-        let saturation = helpers::float_to_percent(hsl.saturation);
-        let lightness = helpers::float_to_percent(hsl.lightness);
-        
-        format!("hsl({hue} {saturation}% {lightness}%)")
+    pub fn to_rec709(&self) -> [f32; 3] {
+        conversions::linear_to_rec709(conversions::rgb_to_linear(self.to_array()))
+    }
+    
+    /// Converts this color into gamma-encoded Rec. 2020 values from 0.0 to 1.0, the color space 
+    /// of UHD video. Every sRGB color is within the Rec. 2020 gamut. Values are full range.
+    pub fn to_rec2020(&self) -> [f32; 3] {
+        conversions::linear_to_rec2020(conversions::rgb_to_linear(self.to_array()))
+    }
+    
+    /// Converts this color into gamma-encoded Adobe RGB (1998) values from 0.0 to 1.0, a wide 
+    /// gamut working space common in photography. Every sRGB color is within its gamut.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, GamutMapping};
+    /// 
+    /// let color = Color { red: 0, green: 255, blue: 0 };
+    /// let adobe_rgb = color.to_adobe_rgb();
+    /// 
+    /// assert!((adobe_rgb[0] - 0.565).abs() < 0.001);
+    /// assert_eq!(Color::from_adobe_rgb(adobe_rgb, GamutMapping::Clip), color);
+    /// ```
+    pub fn to_adobe_rgb(&self) -> [f32; 3] {
+        conversions::linear_to_adobe_rgb(conversions::rgb_to_linear(self.to_array()))
+    }
+    
+    /// Converts this color into gamma-encoded ProPhoto RGB values from 0.0 to 1.0, the very wide 
+    /// gamut working space used by Lightroom. The D65 white point of sRGB is adapted to the D50 
+    /// white point of ProPhoto RGB using the Bradford transform.
+    pub fn to_prophoto_rgb(&self) -> [f32; 3] {
+        conversions::linear_to_prophoto_rgb(conversions::rgb_to_linear(self.to_array()))
+    }
+    
+    /// Converts this color into a hexadecimal color string.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// assert_eq!(Color { red: 255, green: 0, blue: 0 }.to_hex_string(), "#FF0000");
+    /// ```
+    pub fn to_hex_string(&self) -> String {
+        hex_str(&hex_array(self, true)).into()
+    }
+    
+    /// Converts this color into the ASCII bytes of a hexadecimal color string, including the hash 
+    /// symbol, without allocating.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// assert_eq!(Color { red: 255, green: 0, blue: 171 }.to_hex_array(), *b"#FF00AB");
+    /// ```
+    pub fn to_hex_array(&self) -> [u8; 7] {
+        hex_array(self, true)
+    }
+    
+    /// Writes this color as a hexadecimal color string into a writer without allocating.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let mut s = String::from("color: ");
+    /// 
+    /// Color { red: 255, green: 0, blue: 0 }.write_hex(&mut s).unwrap();
+    /// 
+    /// assert_eq!(s, "color: #FF0000");
+    /// ```
+    pub fn write_hex<W>(&self, mut writer: W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        writer.write_str(hex_str(&hex_array(self, true)))
+    }
+    
+    /// Converts colors into hexadecimal color strings separated by newlines, allocating a single 
+    /// [`String`] for all of them.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let colors = [
+    ///     Color { red: 255, green: 0, blue: 0 },
+    ///     Color { red: 0, green: 0, blue: 255 },
+    /// ];
+    /// 
+    /// assert_eq!(Color::to_hex_bulk(&colors), "#FF0000\n#0000FF");
+    /// ```
+    pub fn to_hex_bulk(colors: &[Self]) -> String {
+        let mut s = String::with_capacity(colors.len() * 8);
+        
+        // Writing to a String never fails.
+        let _ = Self::write_hex_bulk(colors, &mut s);
+        s
+    }
+    
+    /// Writes colors as hexadecimal color strings separated by newlines into a writer without 
+    /// allocating.
+    pub fn write_hex_bulk<W>(colors: &[Self], mut writer: W) -> fmt::Result
+    where
+        W: fmt::Write,
+    {
+        for (i, color) in colors.iter().enumerate() {
+            if i > 0 {
+                writer.write_char('\n')?;
+            }
+            
+            writer.write_str(hex_str(&hex_array(color, true)))?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Parses a list of hexadecimal color strings separated by newlines or commas, such as the 
+    /// output of [`Color::to_hex_bulk`]. Whitespace around each color and empty entries are 
+    /// ignored. The hash symbol is optional.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// assert_eq!(
+    ///     Color::parse_hex_bulk("#FF0000, #0000FF\n00FF00"),
+    ///     Ok(vec![
+    ///         Color { red: 255, green: 0, blue: 0 },
+    ///         Color { red: 0, green: 0, blue: 255 },
+    ///         Color { red: 0, green: 255, blue: 0 },
+    ///     ]),
+    /// );
+    /// ```
+    pub fn parse_hex_bulk(s: &str) -> Result<Vec<Self>, ParseColorError> {
+        parse::hex_bulk(s).map(|colors| colors.into_iter().map(Self::from).collect())
+    }
+    
+    /// Converts this color into a hexadecimal color string using lowercase digits.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// assert_eq!(Color { red: 255, green: 0, blue: 171 }.to_hex_lower(), "#ff00ab");
+    /// ```
+    pub fn to_hex_lower(&self) -> String {
+        hex_str(&hex_array(self, false)).into()
+    }
+    
+    /// Converts this color into a hexadecimal color string in its shortest form. Colors where 
+    /// each value has two identical digits are shortened to 3 digits, e.g. `#F00` for `#FF0000`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// assert_eq!(Color { red: 255, green: 0, blue: 0 }.to_hex_short(), "#F00");
+    /// assert_eq!(Color { red: 255, green: 0, blue: 1 }.to_hex_short(), "#FF0001");
+    /// ```
+    pub fn to_hex_short(&self) -> String {
+        shortest_hex(&self.to_array())
+    }
+    
+    /// Converts this color into an 8-digit hexadecimal color string, where the last two digits 
+    /// are the alpha value.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// assert_eq!(Color { red: 255, green: 0, blue: 0 }.to_hex_alpha(0.5), "#FF000080");
+    /// assert_eq!(Color { red: 255, green: 0, blue: 0 }.to_hex_alpha(1.0), "#FF0000FF");
+    /// ```
+    pub fn to_hex_alpha(&self, alpha: Alpha) -> String {
+        let alpha = helpers::float_to_value(alpha * Value::MAX as Alpha);
+        
+        format!("{}{alpha:02X}", self.to_hex_string())
+    }
+    
+    /// Converts this color into a hexadecimal color string with alpha in its shortest form. The 
+    /// alpha digits are omitted for opaque colors, and colors where each value has two identical 
+    /// digits are shortened to 3 or 4 digits.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color { red: 255, green: 0, blue: 0 };
+    /// 
+    /// assert_eq!(color.to_hex_alpha_short(1.0), "#F00");
+    /// assert_eq!(color.to_hex_alpha_short(0.0), "#F000");
+    /// assert_eq!(color.to_hex_alpha_short(0.5), "#FF000080");
+    /// ```
+    pub fn to_hex_alpha_short(&self, alpha: Alpha) -> String {
+        let alpha = helpers::float_to_value(alpha * Value::MAX as Alpha);
+        let [red, green, blue] = self.to_array();
+        
+        if alpha == Value::MAX {
+            shortest_hex(&[red, green, blue])
+        } else {
+            shortest_hex(&[red, green, blue, alpha])
+        }
+    }
+    
+    /// Converts this color into an rgb color string.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// assert_eq!(Color { red: 255, green: 0, blue: 0 }.to_rgb_string(), "rgb(255 0 0)");
+    /// ```
+    pub fn to_rgb_string(&self) -> String {
+        self.display_rgb().to_string()
+    }
+    
+    /// Converts this color into an rgba color string.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// assert_eq!(Color { red: 255, green: 0, blue: 0 }.to_rgba_string(0.5), "rgba(255 0 0 0.5)");
+    /// ```
+    pub fn to_rgba_string(&self, alpha: Alpha) -> String {
+        self.display_rgba(alpha).to_string()
+    }
+    
+    /// Gets a value that displays this color as a hexadecimal color string without allocating a 
+    /// [`String`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color { red: 255, green: 0, blue: 0 };
+    /// 
+    /// assert_eq!(format!("color: {};", color.display_hex()), "color: #FF0000;");
+    /// ```
+    pub fn display_hex(&self) -> DisplayHex {
+        DisplayHex(*self)
+    }
+    
+    /// Gets a value that displays this color as an rgb color string without allocating a 
+    /// [`String`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color { red: 255, green: 0, blue: 0 };
+    /// 
+    /// assert_eq!(format!("color: {};", color.display_rgb()), "color: rgb(255 0 0);");
+    /// ```
+    pub fn display_rgb(&self) -> DisplayRgb {
+        DisplayRgb(*self)
+    }
+    
+    /// Gets a value that displays this color as an rgba color string without allocating a 
+    /// [`String`]. The alpha value is clamped between 0.0 and 1.0.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color { red: 255, green: 0, blue: 0 };
+    /// 
+    /// assert_eq!(format!("color: {};", color.display_rgba(0.5)), "color: rgba(255 0 0 0.5);");
+    /// ```
+    pub fn display_rgba(&self, alpha: Alpha) -> DisplayRgba {
+        DisplayRgba(*self, alpha)
+    }
+    
+    /// Converts this color into an hsl color string.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// assert_eq!(Color { red: 255, green: 0, blue: 0 }.to_hsl_string(), "hsl(0 100% 50%)");
+    /// ```
+    pub fn to_hsl_string(&self) -> String {
+        let hsl: HSLColor = self.into();
+        let hue = hsl.hue.round() as i32;
+        let saturation = helpers::float_to_percent(hsl.saturation);
+        let lightness = helpers::float_to_percent(hsl.lightness);
+        
+        format!("hsl({hue} {saturation}% {lightness}%)")
+    }
+    
+    /// Converts this color into an hsla color string.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// assert_eq!(
+    ///     Color { red: 255, green: 0, blue: 0 }.to_hsla_string(0.5),
+    ///     "hsla(0 100% 50% 0.5)",
+    /// );
+    /// ```
+    pub fn to_hsla_string(&self, mut alpha: Alpha) -> String {
+        alpha = alpha.clamp(0.0, 1.0);
+        
+        let hsl: HSLColor = self.into();
+        let hue = hsl.hue.round() as i32;
+        let saturation = helpers::float_to_percent(hsl.saturation);
+        let lightness = helpers::float_to_percent(hsl.lightness);
+        
+        format!("hsla({hue} {saturation}% {lightness}% {alpha})")
+    }
+    
+    /// Converts this color into a CSS color string using the given options. See 
+    /// [`FormatOptions`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, FormatOptions, Notation};
+    /// 
+    /// let color = Color { red: 255, green: 0, blue: 0 };
+    /// 
+    /// assert_eq!(color.to_css(FormatOptions::new()), "#FF0000");
+    /// assert_eq!(
+    ///     color.to_css(FormatOptions::new().notation(Notation::Rgb).alpha(Some(0.5))),
+    ///     "rgb(255 0 0 / 0.5)",
+    /// );
+    /// ```
+    pub fn to_css(&self, options: FormatOptions) -> String {
+        format::to_css(self, options)
+    }
+    
+    /// Generates a block of this color followed by its hexadecimal color string, using ANSI 
+    /// 24-bit color escape codes, for previewing colors in a terminal.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color { red: 255, green: 0, blue: 0 };
+    /// 
+    /// assert_eq!(color.preview_block(), "\x1b[48;2;255;0;0m    \x1b[0m #FF0000");
+    /// println!("{}", color.preview_block());
+    /// ```
+    pub fn preview_block(&self) -> String {
+        format!(
+            "\x1b[48;2;{};{};{}m    \x1b[0m {}",
+            self.red,
+            self.green,
+            self.blue,
+            self.display_hex(),
+        )
+    }
+    
+    /// Converts a decimal color value into a color.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color::from_decimal(6579300);
+    /// 
+    /// assert_eq!(color, Color { red: 100, green: 100, blue: 100 });
+    /// ```
+    pub fn from_decimal(decimal: DecimalValue) -> Self {
+        let bytes = decimal.to_be_bytes();
+        
+        Self {
+            red: bytes[1],
+            green: bytes[2],
+            blue: bytes[3],
+        }
+    }
+    
+    /// Converts hue, saturation, and lightness values into a color. The hue is in degrees from 
+    /// 0.0 to 360.0, and saturation and lightness are from 0.0 to 1.0. This is the same as 
+    /// converting an [`HSLColor`] using `Color::from`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color::from_hsl_values(120.0, 1.0, 0.25);
+    /// 
+    /// assert_eq!(color, Color { red: 0, green: 128, blue: 0 });
+    /// ```
+    pub fn from_hsl_values(hue: f32, saturation: f32, lightness: f32) -> Self {
+        Self::from(HSLColor { hue, saturation, lightness })
+    }
+    
+    /// Unpacks a color from a `u32` with the given channel order. The alpha byte is ignored, use 
+    /// [`Rgba::from_u32`] to keep it.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{ChannelOrder, Color};
+    /// 
+    /// assert_eq!(
+    ///     Color::from_u32(0x332211FF, ChannelOrder::Bgra),
+    ///     Color { red: 0x11, green: 0x22, blue: 0x33 },
+    /// );
+    /// ```
+    pub fn from_u32(value: u32, order: ChannelOrder) -> Self {
+        Self::from(order.unpack(value).0)
+    }
+    
+    /// Unpacks a color from 16 bits with 5 bits for red, 6 bits for green, and 5 bits for blue. 
+    /// Values are scaled so that the maximum of each field is 255.
+    pub fn from_rgb565(value: u16) -> Self {
+        let [red, green, blue] = unpack_16(value, 6);
+        
+        Self { red, green, blue }
+    }
+    
+    /// Unpacks a color from 16 bits with 5 bits for blue, 6 bits for green, and 5 bits for red.
+    pub fn from_bgr565(value: u16) -> Self {
+        let [blue, green, red] = unpack_16(value, 6);
+        
+        Self { red, green, blue }
+    }
+    
+    /// Unpacks a color from 16 bits with 5 bits for each value. The highest bit is ignored.
+    pub fn from_rgb555(value: u16) -> Self {
+        let [red, green, blue] = unpack_16(value, 5);
+        
+        Self { red, green, blue }
+    }
+    
+    /// Unpacks a color from a `u32` with 10 bits for each value in A2R10G10B10 order. Each value 
+    /// is scaled so that 1023 becomes 255, rounding to the nearest value. The alpha bits are 
+    /// ignored.
+    pub fn from_a2r10g10b10(value: u32) -> Self {
+        let [red, green, blue] = [value >> 20, value >> 10, value].map(|field| {
+            let field = field & MAX_10_BIT;
+            
+            ((field * Value::MAX as u32 + MAX_10_BIT / 2) / MAX_10_BIT) as Value
+        });
+        
+        Self { red, green, blue }
+    }
+    
+    /// Converts gamma-encoded Display P3 values from 0.0 to 1.0 into a color. Display P3 colors 
+    /// outside of the sRGB gamut are brought into it using `mapping`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, GamutMapping};
+    /// 
+    /// let p3_red = [1.0, 0.0, 0.0];
+    /// 
+    /// assert_eq!(
+    ///     Color::from_display_p3(p3_red, GamutMapping::Clip),
+    ///     Color { red: 255, green: 0, blue: 0 },
+    /// );
+    /// assert_eq!(
+    ///     Color::from_display_p3(p3_red, GamutMapping::Perceptual),
+    ///     Color { red: 255, green: 11, blue: 12 },
+    /// );
+    /// ```
+    pub fn from_display_p3(p3: [f32; 3], mapping: GamutMapping) -> Self {
+        let linear = conversions::display_p3_to_linear(p3);
+        
+        Self::from(conversions::linear_to_rgb(mapping.map_linear(linear)))
+    }
+    
+    /// Converts full range gamma-encoded Rec. 709 values from 0.0 to 1.0 into a color. Values 
+    /// outside of that range are clipped.
+    pub fn from_rec709(rec709: [f32; 3]) -> Self {
+        Self::from(conversions::linear_to_rgb(conversions::rec709_to_linear(rec709)))
+    }
+    
+    /// Converts full range gamma-encoded Rec. 2020 values from 0.0 to 1.0 into a color. Rec. 2020 
+    /// colors outside of the sRGB gamut are brought into it using `mapping`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, GamutMapping};
+    /// 
+    /// let color = Color { red: 0, green: 200, blue: 100 };
+    /// 
+    /// assert_eq!(Color::from_rec2020(color.to_rec2020(), GamutMapping::Clip), color);
+    /// assert_eq!(
+    ///     Color::from_rec2020([0.0, 1.0, 0.0], GamutMapping::Clip),
+    ///     Color { red: 0, green: 255, blue: 0 },
+    /// );
+    /// ```
+    pub fn from_rec2020(rec2020: [f32; 3], mapping: GamutMapping) -> Self {
+        let linear = conversions::rec2020_to_linear(rec2020);
+        
+        Self::from(conversions::linear_to_rgb(mapping.map_linear(linear)))
+    }
+    
+    /// Converts gamma-encoded Adobe RGB (1998) values from 0.0 to 1.0 into a color. Adobe RGB 
+    /// colors outside of the sRGB gamut are brought into it using `mapping`.
+    pub fn from_adobe_rgb(adobe_rgb: [f32; 3], mapping: GamutMapping) -> Self {
+        let linear = conversions::adobe_rgb_to_linear(adobe_rgb);
+        
+        Self::from(conversions::linear_to_rgb(mapping.map_linear(linear)))
+    }
+    
+    /// Converts gamma-encoded ProPhoto RGB values from 0.0 to 1.0 into a color. ProPhoto RGB 
+    /// colors outside of the sRGB gamut are brought into it using `mapping`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, GamutMapping};
+    /// 
+    /// let color = Color { red: 50, green: 100, blue: 150 };
+    /// 
+    /// assert_eq!(Color::from_prophoto_rgb(color.to_prophoto_rgb(), GamutMapping::Clip), color);
+    /// ```
+    pub fn from_prophoto_rgb(prophoto_rgb: [f32; 3], mapping: GamutMapping) -> Self {
+        let linear = conversions::prophoto_rgb_to_linear(prophoto_rgb);
+        
+        Self::from(conversions::linear_to_rgb(mapping.map_linear(linear)))
+    }
+    
+    /// Generates a uniformly random color.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color::random(&mut rand::thread_rng());
+    /// 
+    /// assert_eq!(Color::from_hex_str(&color.to_hex_string()), Some(color));
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn random<R>(rng: &mut R) -> Self
+    where
+        R: rand::Rng + ?Sized,
+    {
+        rng.gen()
+    }
+    
+    /// Collects exactly three values into a color, returning `None` if the iterator yields fewer 
+    /// or more values. Use [`FromIterator`] through [`Iterator::collect`] to fill missing values 
+    /// with `0` and ignore extra values instead.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let values = [255, 0, 128];
+    /// 
+    /// assert_eq!(
+    ///     Color::try_from_iter(values.iter().copied()),
+    ///     Some(Color { red: 255, green: 0, blue: 128 }),
+    /// );
+    /// assert_eq!(Color::try_from_iter(values.iter().copied().take(2)), None);
+    /// ```
+    pub fn try_from_iter<I>(iter: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        let mut iter = iter.into_iter();
+        let color = Self {
+            red: iter.next()?,
+            green: iter.next()?,
+            blue: iter.next()?,
+        };
+        
+        if iter.next().is_some() {
+            return None;
+        }
+        
+        Some(color)
+    }
+    
+    /// Attempts to parse a color string into a color, returning an error describing why the string 
+    /// could not be parsed. Any format supported by [`std::str::FromStr`] is accepted.
+    /// 
+    /// Unlike [`std::str::FromStr`], which clamps components outside of their range as per the CSS 
+    /// specification, this is strict and returns [`ParseColorError::ChannelOutOfRange`] for such 
+    /// components, making it suitable for validating configuration files.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, ParseColorError};
+    /// 
+    /// assert_eq!(Color::parse("#FF0000"), Ok(Color { red: 255, green: 0, blue: 0 }));
+    /// assert_eq!(Color::parse("#FF00"), Ok(Color { red: 255, green: 255, blue: 0 }));
+    /// assert_eq!(
+    ///     Color::parse("#FF000"),
+    ///     Err(ParseColorError::InvalidHexLength { len: 5, span: 1..6 }),
+    /// );
+    /// assert_eq!(
+    ///     Color::parse("rgb(255, 0, 999)"),
+    ///     Err(ParseColorError::ChannelOutOfRange { component: "999".into(), span: 12..15 }),
+    /// );
+    /// ```
+    pub fn parse(s: &str) -> Result<Self, ParseColorError> {
+        parse::color(s, true).map(|(colors, _alpha)| colors.into())
+    }
+    
+    /// Attempts to parse a color from a byte string, such as a token read directly from a network 
+    /// buffer, without first converting it to a [`str`]. Any format supported by 
+    /// [`std::str::FromStr`] is accepted and components outside of their range are clamped.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, ParseColorError};
+    /// 
+    /// assert_eq!(Color::from_bytes_str(b"#FF0000"), Ok(Color { red: 255, green: 0, blue: 0 }));
+    /// assert_eq!(Color::from_bytes_str(b"red"), Ok(Color { red: 255, green: 0, blue: 0 }));
+    /// assert_eq!(
+    ///     Color::from_bytes_str(b"#FF\xFF000"),
+    ///     Err(ParseColorError::InvalidUtf8 { span: 3..4 }),
+    /// );
+    /// ```
+    pub fn from_bytes_str(bytes: &[u8]) -> Result<Self, ParseColorError> {
+        parse::bytes(bytes, false).map(|(colors, _alpha)| colors.into())
+    }
+    
+    /// Attempts to parse a hexadecimal color string into a color. Since this is explicitly 
+    /// converting from a hexadecimal string, the hash symbol is optional.
+    /// 
+    /// However if you try converting a string using [`std::std::FromStr`], the hash symbol is 
+    /// required.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// assert_eq!(Color::from_hex_str("FF0000").unwrap(), Color { red: 255, green: 0, blue: 0 });
+    /// assert_eq!(Color::from_hex_str("#FF0000").unwrap(), Color { red: 255, green: 0, blue: 0 });
+    /// assert_eq!(Color::from_hex_str("F00").unwrap(), Color { red: 255, green: 0, blue: 0 });
+    /// ```
+    pub fn from_hex_str(hex: &str) -> Option<Self> {
+        parse::hex(hex, false).ok().map(|colors| colors.into())
+    }
+    
+    /// Attempts to parse a hexadecimal color string into a color with alpha. The alpha value is 
+    /// read from the fourth or eighth digit and defaults to `1.0` if not present. As with 
+    /// [`Color::from_hex_str`], the hash symbol is optional.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let (color, alpha) = Color::from_hex_with_alpha("#FF000080").unwrap();
+    /// 
+    /// assert_eq!(color, Color { red: 255, green: 0, blue: 0 });
+    /// assert_eq!(alpha, 128.0 / 255.0);
+    /// assert_eq!(Color::from_hex_with_alpha("F00").unwrap().1, 1.0);
+    /// ```
+    pub fn from_hex_with_alpha(hex: &str) -> Option<ColorWithAlpha> {
+        parse::hex_with_alpha(hex, false).ok().map(|(colors, alpha)| (colors.into(), alpha))
+    }
+    
+    /// Attempts to parse an rgb or rgba color string into a color. Ignores the alpha value if 
+    /// present.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color::from_rgb_str("rgb(100 100 100)").unwrap();
+    /// 
+    /// assert_eq!(color, Color { red: 100, green: 100, blue: 100 });
+    /// ````
+    pub fn from_rgb_str(rgb: &str) -> Option<Self> {
+        parse::rgba(rgb, false).ok().map(|(colors, _alpha)| colors.into())
+    }
+    
+    /// Attempts to parse an rgb or rgba color string into a color. Alpha defaults to `1.0` if not 
+    /// present.
+    pub fn from_rgba_str(rgb: &str) -> Option<ColorWithAlpha> {
+        parse::rgba(rgb, false).ok().map(|(colors, alpha)| (colors.into(), alpha))
+    }
+    
+    /// Attempts to parse an hsl color string into a color.
+    pub fn from_hsl_str(hsl: &str) -> Option<Self> {
+        parse::hsl(hsl, false).ok().map(|(colors, _alpha)| colors.into())
+    }
+    
+    /// Attempts to parse an hsl color string into a color with alpha.
+    pub fn from_hsla_str(hsl: &str) -> Option<ColorWithAlpha> {
+        parse::hsl(hsl, false).ok().map(|(colors, alpha)| (colors.into(), alpha))
+    }
+    
+    /// Attempts to parse a CIE Lab color string into a color. Ignores the alpha value if present. 
+    /// Colors outside of the sRGB gamut are clipped.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color::from_lab_str("lab(52% 40 59)").unwrap();
+    /// 
+    /// assert_eq!(color, Color { red: 197, green: 92, blue: 10 });
+    /// ```
+    pub fn from_lab_str(lab: &str) -> Option<Self> {
+        parse::lab(lab, false).ok().map(|(colors, _alpha)| colors.into())
+    }
+    
+    /// Attempts to parse a CIE LCH color string into a color. Ignores the alpha value if present. 
+    /// Colors outside of the sRGB gamut are clipped.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color::from_lch_str("lch(54.29% 106.84 40.85)").unwrap();
+    /// 
+    /// assert_eq!(color, Color { red: 255, green: 0, blue: 0 });
+    /// ```
+    pub fn from_lch_str(lch: &str) -> Option<Self> {
+        parse::lch(lch, false).ok().map(|(colors, _alpha)| colors.into())
+    }
+    
+    /// Attempts to parse a CSS `color()` function string into a color. The `srgb`, `srgb-linear`, 
+    /// and `display-p3` color spaces are supported. Ignores the alpha value if present. Colors 
+    /// outside of the sRGB gamut are gamut mapped by reducing chroma.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color::from_color_function_str("color(display-p3 1 0 0.5 / 0.4)").unwrap();
+    /// 
+    /// assert_eq!(color, Color { red: 255, green: 17, blue: 130 });
+    /// ```
+    pub fn from_color_function_str(color: &str) -> Option<Self> {
+        parse::color_function(color, false).ok().map(|(colors, _alpha)| colors.into())
+    }
+    
+    /// Finds all colors within a larger string, such as a stylesheet, yielding the byte range and 
+    /// the color of each one. Hexadecimal colors, color functions, and color names are found. 
+    /// Color names that are part of a larger identifier, such as `red` in `red-500`, are skipped.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let css = "a { color: #F00; border: 1px solid rgba(0, 0, 255, 0.5); }";
+    /// let colors = Color::scan(css).collect::<Vec<_>>();
+    /// 
+    /// assert_eq!(colors, vec![
+    ///     (11..15, (Color { red: 255, green: 0, blue: 0 }, 1.0)),
+    ///     (35..55, (Color { red: 0, green: 0, blue: 255 }, 0.5)),
+    /// ]);
+    /// ```
+    pub fn scan(text: &str) -> impl Iterator<Item = (Range<usize>, ColorWithAlpha)> + '_ {
+        parse::scan(text).map(|(range, (colors, alpha))| (range, (colors.into(), alpha)))
     }
     
-    /// Converts this color into an hsla color string.
+    /// Combines this color with an alpha value into an [`Rgba`].
     /// 
     /// # Examples
     /// ```
-    /// use nice_colors::Color;
+    /// use nice_colors::{Color, Rgba};
     /// 
     /// assert_eq!(
-    ///     Color { red: 255, green: 0, blue: 0 }.to_hsla_string(0.5),
-    ///     "hsla(0 100% 50% 0.5)",
+    ///     Color { red: 255, green: 0, blue: 0 }.with_alpha(0.5),
+    ///     Rgba { red: 255, green: 0, blue: 0, alpha: 0.5 },
     /// );
     /// ```
-    pub fn to_hsla_string(&self, mut alpha: Alpha) -> String {
-        alpha = alpha.max(0.0).min(1.0);
-        
-        let hsl: HSLColor = self.into();
-        let hue = hsl.hue.round() as i32;
-        let saturation = helpers::float_to_percent(hsl.saturation);
-        let lightness = helpers::float_to_percent(hsl.lightness);
-        
-        format!("hsla({hue} {saturation}% {lightness}% {alpha})")
+    pub fn with_alpha(&self, alpha: Alpha) -> Rgba {
+        Rgba::from(*self).alpha(alpha)
     }
     
-    /// Converts a decimal color value into a color.
+    /// Views this color as its red, green, and blue bytes without copying.
     /// 
     /// # Examples
     /// ```
     /// use nice_colors::Color;
     /// 
-    /// let color = Color::from_decimal(6579300);
+    /// let colors = [Color { red: 255, green: 0, blue: 128 }];
+    /// let bytes = colors.iter().flat_map(Color::as_bytes).copied().collect::<Vec<_>>();
     /// 
-    /// assert_eq!(color, Color { red: 100, green: 100, blue: 100 });
+    /// assert_eq!(bytes, [255, 0, 128]);
     /// ```
-    pub fn from_decimal(decimal: DecimalValue) -> Self {
-        let bytes = decimal.to_be_bytes();
-        
-        Self {
-            red: bytes[1],
-            green: bytes[2],
-            blue: bytes[3],
-        }
+    pub fn as_bytes(&self) -> &[Value; SLICE_LENGTH] {
+        // SAFETY: Color is #[repr(C)] with three u8 fields, so it has the same size, alignment, 
+        // and layout as [u8; 3].
+        unsafe { &*(self as *const Self).cast::<[Value; SLICE_LENGTH]>() }
     }
     
-    /// Attempts to parse a hexadecimal color string into a color. Since this is explicitly 
-    /// converting from a hexadecimal string, the hash symbol is optional.
-    /// 
-    /// However if you try converting a string using [`std::std::FromStr`], the hash symbol is 
-    /// required.
+    /// Views this color as its red, green, and blue bytes mutably without copying.
     /// 
     /// # Examples
     /// ```
     /// use nice_colors::Color;
     /// 
-    /// assert_eq!(Color::from_hex_str("FF0000").unwrap(), Color { red: 255, green: 0, blue: 0 });
-    /// assert_eq!(Color::from_hex_str("#FF0000").unwrap(), Color { red: 255, green: 0, blue: 0 });
-    /// assert_eq!(Color::from_hex_str("F00").unwrap(), Color { red: 255, green: 0, blue: 0 });
+    /// let mut color = Color { red: 255, green: 0, blue: 0 };
+    /// 
+    /// color.as_bytes_mut()[2] = 128;
+    /// 
+    /// assert_eq!(color, Color { red: 255, green: 0, blue: 128 });
     /// ```
-    pub fn from_hex_str(hex: &str) -> Option<Self> {
-        parse::hex(hex, false).map(|colors| colors.into())
+    pub fn as_bytes_mut(&mut self) -> &mut [Value; SLICE_LENGTH] {
+        // SAFETY: See as_bytes.
+        unsafe { &mut *(self as *mut Self).cast::<[Value; SLICE_LENGTH]>() }
     }
     
-    /// Attempts to parse an rgb or rgba color string into a color. Ignores the alpha value if 
-    /// present.
+    /// Returns an iterator over the red, green, and blue values of this color.
+    pub fn iter(&self) -> std::slice::Iter<'_, Value> {
+        self.as_bytes().iter()
+    }
+    
+    /// Returns an iterator over mutable references to the red, green, and blue values of this 
+    /// color.
     /// 
     /// # Examples
     /// ```
     /// use nice_colors::Color;
     /// 
-    /// let color = Color::from_rgb_str("rgb(100 100 100)").unwrap();
+    /// let mut color = Color { red: 200, green: 100, blue: 50 };
     /// 
-    /// assert_eq!(color, Color { red: 100, green: 100, blue: 100 });
-    /// ````
-    pub fn from_rgb_str(rgb: &str) -> Option<Self> {
-        parse::rgba(rgb).map(|(colors, _alpha)| colors.into())
-    }
-    
-    /// Attempts to parse an rgb or rgba color string into a color. Alpha defaults to `1.0` if not 
-    /// present.
-    pub fn from_rgba_str(rgb: &str) -> Option<ColorWithAlpha> {
-        parse::rgba(rgb).map(|(colors, alpha)| (colors.into(), alpha))
+    /// color.iter_mut().filter(|value| **value > 75).for_each(|value| *value /= 2);
+    /// 
+    /// assert_eq!(color, Color { red: 100, green: 50, blue: 50 });
+    /// ```
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, Value> {
+        self.as_bytes_mut().iter_mut()
     }
     
-    /// Attempts to parse an hsl color string into a color.
-    pub fn from_hsl_str(hsl: &str) -> Option<Self> {
-        parse::hsl(hsl).map(|(colors, _alpha)| colors.into())
+    /// Converts this color into an array of blue, green, and red values, the byte order used by 
+    /// OpenCV and Windows bitmaps.
+    pub fn to_bgr_array(&self) -> [Value; SLICE_LENGTH] {
+        self.swap_rb().to_array()
     }
     
-    /// Attempts to parse an hsl color string into a color with alpha.
-    pub fn from_hsla_str(hsl: &str) -> Option<ColorWithAlpha> {
-        parse::hsl(hsl).map(|(colors, alpha)| (colors.into(), alpha))
+    /// Converts an array of blue, green, and red values into a color.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let color = Color::from_bgr_array([0, 128, 255]);
+    /// 
+    /// assert_eq!(color, Color { red: 255, green: 128, blue: 0 });
+    /// assert_eq!(color.to_bgr_array(), [0, 128, 255]);
+    /// ```
+    pub fn from_bgr_array([blue, green, red]: [Value; SLICE_LENGTH]) -> Self {
+        Self { red, green, blue }
     }
     
     /// Converts this color into an array.
@@ -391,6 +1745,11 @@ impl Color {
         ]
     }
     
+    /// Converts this color to Oklab.
+    pub(crate) fn to_oklab(self) -> [f32; 3] {
+        conversions::linear_to_oklab(conversions::rgb_to_linear(self.to_array()))
+    }
+    
     /// Converts a slice into a color.
     fn from_slice(slice: [Value; SLICE_LENGTH]) -> Self {
         Self {
@@ -401,6 +1760,49 @@ impl Color {
     }
 }
 
+/// Packs three values into 16 bits, with 5 bits for the first and last values and 
+/// `middle_bits` bits for the middle value.
+fn pack_16(first: Value, middle: Value, last: Value, middle_bits: u32) -> u16 {
+    let first = u16::from(first >> 3);
+    let middle = u16::from(middle >> (8 - middle_bits));
+    let last = u16::from(last >> 3);
+    
+    first << (5 + middle_bits) | middle << 5 | last
+}
+
+/// Unpacks three values from 16 bits packed using [`pack_16`]. Each field is scaled to 8 bits by 
+/// repeating its highest bits in the lowest bits, so that the maximum of each field is 255.
+fn unpack_16(value: u16, middle_bits: u32) -> [Value; SLICE_LENGTH] {
+    let expand = |field: u16, bits: u32| {
+        let field = field & ((1 << bits) - 1);
+        
+        (field << (8 - bits) | field >> (2 * bits - 8)) as Value
+    };
+    
+    [
+        expand(value >> (5 + middle_bits), 5),
+        expand(value >> 5, middle_bits),
+        expand(value, 5),
+    ]
+}
+
+/// Formats values into a hexadecimal color string, using a single digit for each value when 
+/// every value has two identical digits.
+fn shortest_hex(values: &[Value]) -> String {
+    let shorten = values.iter().all(|value| value % 0x11 == 0);
+    
+    values
+        .iter()
+        .fold(String::from('#'), |mut output, value| {
+            let _ = if shorten {
+                write!(output, "{:X}", value / 0x11)
+            } else {
+                write!(output, "{value:02X}")
+            };
+            output
+        })
+}
+
 impl IntoIterator for Color {
     type Item = Value;
     type IntoIter = std::array::IntoIter<Value, SLICE_LENGTH>;
@@ -410,6 +1812,57 @@ impl IntoIterator for Color {
     }
 }
 
+impl<'a> IntoIterator for &'a Color {
+    type Item = &'a Value;
+    type IntoIter = std::slice::Iter<'a, Value>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut Color {
+    type Item = &'a mut Value;
+    type IntoIter = std::slice::IterMut<'a, Value>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// Collects the first three values into a color. Missing values are `0` and extra values are 
+/// ignored. See [`Color::try_from_iter`] for a stricter alternative.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::Color;
+/// 
+/// let color = Color { red: 200, green: 100, blue: 50 };
+/// let halved = color.into_iter().map(|value| value / 2).collect::<Color>();
+/// 
+/// assert_eq!(halved, Color { red: 100, green: 50, blue: 25 });
+/// ```
+impl FromIterator<Value> for Color {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        let mut iter = iter.into_iter();
+        
+        Self {
+            red: iter.next().unwrap_or_default(),
+            green: iter.next().unwrap_or_default(),
+            blue: iter.next().unwrap_or_default(),
+        }
+    }
+}
+
+impl AsRef<[Value; SLICE_LENGTH]> for Color {
+    fn as_ref(&self) -> &[Value; SLICE_LENGTH] {
+        self.as_bytes()
+    }
+}
+
 impl From<[Value; SLICE_LENGTH]> for Color {
     fn from(value: [Value; SLICE_LENGTH]) -> Self {
         Self::from_slice(value)
@@ -422,15 +1875,15 @@ impl From<&[Value; SLICE_LENGTH]> for Color {
     }
 }
 
-impl Into<[Value; SLICE_LENGTH]> for Color {
-    fn into(self) -> [Value; SLICE_LENGTH] {
-        self.to_array()
+impl From<Color> for [Value; SLICE_LENGTH] {
+    fn from(value: Color) -> Self {
+        value.to_array()
     }
 }
 
-impl Into<[Value; SLICE_LENGTH]> for &Color {
-    fn into(self) -> [Value; SLICE_LENGTH] {
-        self.to_array()
+impl From<&Color> for [Value; SLICE_LENGTH] {
+    fn from(value: &Color) -> Self {
+        value.to_array()
     }
 }
 
@@ -522,33 +1975,75 @@ impl From<Color> for i32 {
     }
 }
 
+/// Pads a hexadecimal color string into a formatter, respecting width, fill, and alignment.
+fn pad_hex(
+    color: &Color,
+    f: &mut fmt::Formatter<'_>,
+    uppercase: bool,
+    include_hash: bool,
+) -> fmt::Result {
+    let buffer = hex_array(color, uppercase);
+    let hex = hex_str(&buffer);
+    
+    if include_hash {
+        f.pad(hex)
+    } else {
+        f.pad(&hex[1..])
+    }
+}
+
+/// Converts a color into the ASCII bytes of a hexadecimal color string using a lookup table.
+fn hex_array(color: &Color, uppercase: bool) -> [u8; 7] {
+    let digits = if uppercase {
+        b"0123456789ABCDEF"
+    } else {
+        b"0123456789abcdef"
+    };
+    let mut buffer = [b'#'; 7];
+    
+    for (i, value) in color.into_iter().enumerate() {
+        buffer[1 + i * 2] = digits[usize::from(value >> 4)];
+        buffer[2 + i * 2] = digits[usize::from(value & 0xF)];
+    }
+    
+    buffer
+}
+
+/// Gets the string of a buffer created by [`hex_array`].
+fn hex_str(buffer: &[u8; 7]) -> &str {
+    // The buffer only ever contains ASCII characters.
+    std::str::from_utf8(buffer).unwrap_or_default()
+}
+
+/// Formats as an uppercase hexadecimal color string, e.g. `#FF0000`. Width, fill, and alignment 
+/// are respected.
 impl fmt::Display for Color {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_hex_string())
+        pad_hex(self, f, true, true)
+    }
+}
+
+/// Formats as uppercase hexadecimal digits, e.g. `FF0000`. The alternate flag (`{:#X}`) includes 
+/// the hash symbol.
+impl fmt::UpperHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad_hex(self, f, true, f.alternate())
+    }
+}
+
+/// Formats as lowercase hexadecimal digits, e.g. `ff0000`. The alternate flag (`{:#x}`) includes 
+/// the hash symbol.
+impl fmt::LowerHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        pad_hex(self, f, false, f.alternate())
     }
 }
 
 impl std::str::FromStr for Color {
-    type Err = &'static str;
+    type Err = ParseColorError;
     
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if let Some(color) = parse::hex(s, true).map(|colors| colors.into()) {
-            return Ok(color);
-        }
-        
-        if let Some(color) = Self::from_rgb_str(s) {
-            return Ok(color);
-        }
-        
-        if let Some(color) = Self::from_hsl_str(s) {
-            return Ok(color);
-        }
-        
-        if let Some(color) = html::from_html_color_name(s) {
-            return Ok(color);
-        }
-        
-        return Err("Not a valid color string.");
+        parse::color(s, false).map(|(colors, _alpha)| colors.into())
     }
 }
 
@@ -574,6 +2069,19 @@ mod tests {
         assert_eq!(red.to_string(), "#FF0000");
     }
     
+    #[test]
+    fn formats_with_flags() {
+        let color = Color { red: 255, green: 0, blue: 171 };
+        
+        assert_eq!(format!("{color:x}"), "ff00ab");
+        assert_eq!(format!("{color:X}"), "FF00AB");
+        assert_eq!(format!("{color:#x}"), "#ff00ab");
+        assert_eq!(format!("{color:#X}"), "#FF00AB");
+        assert_eq!(format!("{color:>9}"), "  #FF00AB");
+        assert_eq!(format!("{color:*<8x}"), "ff00ab**");
+        assert_eq!(format!("{color:^#11x}"), "  #ff00ab  ");
+    }
+    
     #[test]
     fn converts_to_hex() {
         let red = Color { red: 255, green: 0, blue: 0 };
@@ -581,6 +2089,18 @@ mod tests {
         assert_eq!(red.to_hex_string(), "#FF0000");
     }
     
+    #[test]
+    fn converts_to_hex_alpha() {
+        let color = Color { red: 17, green: 34, blue: 51 };
+        
+        assert_eq!(color.to_hex_alpha(0.4), "#11223366");
+        assert_eq!(color.to_hex_alpha(2.0), "#112233FF");
+        assert_eq!(color.to_hex_alpha_short(0.4), "#1236");
+        assert_eq!(color.to_hex_alpha_short(1.0), "#123");
+        assert_eq!(color.to_hex_alpha_short(0.5), "#11223380");
+        assert_eq!(Color::from_hex_with_alpha(&color.to_hex_alpha_short(0.4)), Some((color, 0.4)));
+    }
+    
     #[test]
     fn converts_to_rgb() {
         let red = Color { red: 255, green: 0, blue: 0 };
@@ -596,6 +2116,274 @@ mod tests {
         assert_eq!(Color::from_hex_str("F00").unwrap(), red);
     }
     
+    #[test]
+    fn collects_from_iterator() {
+        assert_eq!([1, 2].into_iter().collect::<Color>(), Color { red: 1, green: 2, blue: 0 });
+        assert_eq!((1..=5).collect::<Color>(), Color { red: 1, green: 2, blue: 3 });
+        assert_eq!(Color::try_from_iter(1..=4), None);
+        assert_eq!(Color::try_from_iter(1..=3), Some(Color { red: 1, green: 2, blue: 3 }));
+    }
+    
+    #[test]
+    fn iterates_over_values() {
+        let mut color = Color { red: 1, green: 2, blue: 3 };
+        
+        for value in &mut color {
+            *value *= 10;
+        }
+        
+        assert_eq!(color.iter().copied().collect::<Vec<_>>(), [10, 20, 30]);
+        assert_eq!((&color).into_iter().sum::<u8>(), 60);
+    }
+    
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generates_random_colors() {
+        use rand::SeedableRng;
+        
+        let mut rng = rand::rngs::StdRng::seed_from_u64(0);
+        let colors = (0..100).map(|_| Color::random(&mut rng)).collect::<Vec<_>>();
+        
+        assert!(colors.iter().any(|color| *color != colors[0]));
+    }
+    
+    #[cfg(feature = "zerocopy")]
+    #[test]
+    fn reinterprets_bytes_as_colors() {
+        use zerocopy::{AsBytes, FromBytes};
+        
+        let bytes = [255, 0, 0, 0, 128, 255];
+        let colors = Color::slice_from(&bytes).unwrap();
+        
+        assert_eq!(colors, [
+            Color { red: 255, green: 0, blue: 0 },
+            Color { red: 0, green: 128, blue: 255 },
+        ]);
+        assert_eq!(colors.as_bytes(), bytes);
+        assert!(Color::slice_from(&bytes[1..]).is_none());
+    }
+    
+    #[cfg(feature = "rkyv")]
+    #[test]
+    fn archives_with_rkyv() {
+        use rkyv::rancor::Error;
+        
+        let color = Color { red: 255, green: 0, blue: 128 };
+        let rgba = color.with_alpha(0.5);
+        let hsl = HSLColor::from(color);
+        let bytes = rkyv::to_bytes::<Error>(&color).unwrap();
+        
+        assert_eq!(rkyv::access::<ArchivedColor, Error>(&bytes).unwrap().blue, 128);
+        assert_eq!(rkyv::from_bytes::<Color, Error>(&bytes).unwrap(), color);
+        
+        let bytes = rkyv::to_bytes::<Error>(&rgba).unwrap();
+        
+        assert_eq!(rkyv::from_bytes::<Rgba, Error>(&bytes).unwrap(), rgba);
+        
+        let bytes = rkyv::to_bytes::<Error>(&hsl).unwrap();
+        
+        assert_eq!(rkyv::from_bytes::<HSLColor, Error>(&bytes).unwrap(), hsl);
+    }
+    
+    #[cfg(feature = "borsh")]
+    #[test]
+    fn serializes_with_borsh() {
+        let color = Color { red: 255, green: 0, blue: 128 };
+        let rgba = color.with_alpha(0.5);
+        let hsl = HSLColor::from(color);
+        
+        assert_eq!(borsh::to_vec(&color).unwrap(), [255, 0, 128]);
+        assert_eq!(borsh::from_slice::<Color>(&[255, 0, 128]).unwrap(), color);
+        assert_eq!(borsh::from_slice::<Rgba>(&borsh::to_vec(&rgba).unwrap()).unwrap(), rgba);
+        assert_eq!(borsh::from_slice::<HSLColor>(&borsh::to_vec(&hsl).unwrap()).unwrap(), hsl);
+    }
+    
+    #[cfg(feature = "quickcheck")]
+    #[test]
+    fn shrinks_towards_black() {
+        use quickcheck::Arbitrary;
+        
+        let color = Color { red: 200, green: 0, blue: 3 };
+        let shrunk = color.shrink().collect::<Vec<_>>();
+        
+        assert!(shrunk.contains(&Color { red: 0, green: 0, blue: 3 }));
+        assert!(shrunk.iter().all(|shrunk| shrunk.iter().zip(&color).all(|(a, b)| a <= b)));
+        assert!(Color::new().shrink().next().is_none());
+        
+        quickcheck::quickcheck((|color: Color| {
+            Color::from_hex_str(&color.to_hex_string()) == Some(color)
+        }) as fn(Color) -> bool);
+    }
+    
+    #[test]
+    fn gets_contrast_ratio() {
+        let color = Color { red: 118, green: 118, blue: 118 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        
+        assert!((color.contrast_ratio(white) - 4.54).abs() < 0.01);
+        assert_eq!(color.contrast_ratio(color), 1.0);
+    }
+    
+    #[test]
+    fn adjusts_gamma_and_exposure() {
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let color = Color { red: 10, green: 100, blue: 250 };
+        
+        assert_eq!(black.gamma(2.2), black);
+        assert_eq!(white.gamma(0.5), white);
+        assert_eq!(color.gamma(0.0), color);
+        assert_eq!(color.exposure(0.0), color);
+        assert_eq!(black.exposure(4.0), black);
+        assert_eq!(color.exposure(10.0), white);
+    }
+    
+    #[test]
+    fn adjusts_white_balance() {
+        let gray = Color { red: 128, green: 128, blue: 128 };
+        let warm = gray.white_balance(1.0, 0.0);
+        let magenta = gray.white_balance(0.0, 1.0);
+        let green = gray.white_balance(0.0, -1.0);
+        
+        assert!(warm.red > gray.red && warm.blue < gray.blue, "{warm:?}");
+        assert!(magenta.green < magenta.red && magenta.green < magenta.blue, "{magenta:?}");
+        assert!(green.green > green.red && green.green > green.blue, "{green:?}");
+        assert_eq!(gray.white_balance(5.0, 0.0), warm);
+        
+        for color in [gray, Color { red: 10, green: 200, blue: 90 }] {
+            assert_eq!(color.white_balance(0.0, 0.0), color);
+        }
+    }
+    
+    #[test]
+    fn converts_display_p3() {
+        for value in (0..=255).step_by(15) {
+            let color = Color { red: value, green: 255 - value, blue: value / 2 };
+            
+            assert_eq!(Color::from_display_p3(color.to_display_p3(), GamutMapping::Clip), color);
+        }
+        
+        let white = Color { red: 255, green: 255, blue: 255 };
+        
+        assert!(white.to_display_p3().iter().all(|value| (value - 1.0).abs() < 0.001));
+    }
+    
+    #[test]
+    fn converts_rec709_and_rec2020() {
+        for value in (0..=255).step_by(15) {
+            let color = Color { red: value, green: 255 - value, blue: value / 3 };
+            
+            assert_eq!(Color::from_rec709(color.to_rec709()), color);
+            assert_eq!(Color::from_rec2020(color.to_rec2020(), GamutMapping::Clip), color);
+        }
+        
+        let [red, green, blue] = Color { red: 255, green: 0, blue: 0 }.to_rec2020();
+        
+        assert!(red < 1.0 && green > 0.0 && blue > 0.0);
+        
+        let mapped = Color::from_rec2020([0.0, 1.0, 0.0], GamutMapping::Perceptual);
+        
+        assert!(mapped.green > mapped.red && mapped.green > mapped.blue, "{mapped:?}");
+    }
+    
+    #[test]
+    fn converts_adobe_rgb_and_prophoto_rgb() {
+        for value in (0..=255).step_by(15) {
+            let color = Color { red: value, green: 255 - value, blue: value / 3 };
+            
+            assert_eq!(Color::from_adobe_rgb(color.to_adobe_rgb(), GamutMapping::Clip), color);
+            assert_eq!(
+                Color::from_prophoto_rgb(color.to_prophoto_rgb(), GamutMapping::Clip),
+                color,
+            );
+        }
+        
+        let white = Color { red: 255, green: 255, blue: 255 };
+        
+        assert!(white.to_adobe_rgb().iter().all(|value| (value - 1.0).abs() < 0.001));
+        assert!(white.to_prophoto_rgb().iter().all(|value| (value - 1.0).abs() < 0.001));
+    }
+    
+    #[test]
+    fn describes_colors() {
+        let descriptions = [
+            ((0, 0, 0), "black"),
+            ((255, 255, 255), "white"),
+            ((200, 200, 200), "light gray"),
+            ((255, 0, 0), "vivid red"),
+            ((120, 60, 20), "brown"),
+            ((20, 120, 40), "dark green"),
+            ((40, 160, 60), "green"),
+            ((255, 192, 203), "light pink"),
+            ((90, 110, 130), "desaturated blue"),
+            ((70, 0, 120), "dark purple"),
+        ];
+        
+        for (rgb, description) in descriptions {
+            assert_eq!(Color::from(rgb).describe(), description, "{rgb:?}");
+        }
+    }
+    
+    #[test]
+    fn posterizes() {
+        let color = Color { red: 255, green: 127, blue: 128 };
+        
+        assert_eq!(color.posterize(0), Color { red: 255, green: 0, blue: 255 });
+        assert_eq!(color.posterize(3), Color { red: 255, green: 128, blue: 128 });
+        
+        let color = Color { red: 51, green: 102, blue: 204 };
+        
+        assert_eq!(color.posterize(6), color);
+    }
+    
+    #[test]
+    fn packs_10_bit_colors() {
+        for value in 0..=Value::MAX {
+            let color = Color { red: value, green: value, blue: value };
+            
+            assert_eq!(Color::from_a2r10g10b10(color.to_a2r10g10b10()), color);
+        }
+        
+        assert_eq!(Color::from_a2r10g10b10(0x3FFFFFFF), Color { red: 255, green: 255, blue: 255 });
+        assert_eq!(Color::from_a2r10g10b10(0x00100401), Color { red: 0, green: 0, blue: 0 });
+        assert_eq!(Color::from_a2r10g10b10(0x00401004), Color { red: 1, green: 1, blue: 1 });
+    }
+    
+    #[test]
+    fn adjusts_for_contrast() {
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let gray = Color { red: 119, green: 119, blue: 119 };
+        let blue = Color { red: 30, green: 60, blue: 200 };
+        let dark_blue = Color { red: 20, green: 30, blue: 80 };
+        
+        assert_eq!(black.adjust_for_contrast(white, 7.0), black);
+        
+        let lighter = blue.adjust_for_contrast(dark_blue, 4.5);
+        
+        assert!(lighter.contrast_ratio(dark_blue) >= 4.5, "{lighter:?}");
+        assert!(lighter.blue > lighter.red, "{lighter:?}");
+        assert_eq!(gray.adjust_for_contrast(gray, 21.0), black);
+        
+        for target in [2.0, 3.0, 4.5] {
+            let adjusted = gray.adjust_for_contrast(gray, target);
+            
+            assert!(adjusted.contrast_ratio(gray) >= target, "{target} {adjusted:?}");
+        }
+    }
+    
+    #[test]
+    fn packs_16_bit_colors() {
+        let color = Color { red: 255, green: 0, blue: 8 };
+        
+        assert_eq!(color.to_rgb565(), 0xF801);
+        assert_eq!(color.to_bgr565(), 0x081F);
+        assert_eq!(color.to_rgb555(), 0x7C01);
+        assert_eq!(Color::from_rgb565(0xFFFF), Color { red: 255, green: 255, blue: 255 });
+        assert_eq!(Color::from_bgr565(color.to_bgr565()), Color::from_rgb565(color.to_rgb565()));
+        assert_eq!(Color::from_rgb555(color.to_rgb555()), Color { red: 255, green: 0, blue: 8 });
+    }
+    
     #[test]
     fn converts_from_slice() {
         let color = Color::from([255, 0, 0]);
@@ -615,6 +2403,19 @@ mod tests {
         assert_eq!(color, Color { red: 255, green: 0, blue: 0 });
     }
     
+    #[test]
+    fn converts_from_str_transparent() {
+        let color = "transparent".parse::<Color>().unwrap();
+        
+        assert_eq!(color, Color { red: 0, green: 0, blue: 0 });
+        assert!("currentcolor".parse::<Color>().is_err());
+    }
+    
+    #[test]
+    fn converts_from_str_name() {
+        assert_eq!(" Red ".parse::<Color>(), Ok(Color { red: 255, green: 0, blue: 0 }));
+    }
+    
     #[test]
     fn converts_from_str_800080() {
         let color = "#800080".parse::<Color>().unwrap();
@@ -627,6 +2428,8 @@ mod tests {
         let color = Color { red: 100, green: 100, blue: 100 };
         
         assert_eq!(color.to_decimal(), 6579300);
+        assert_eq!(Color { red: 255, green: 0, blue: 0 }.to_decimal(), 0xFF0000);
+        assert_eq!(Color { red: 17, green: 34, blue: 51 }.to_decimal(), 0x112233);
     }
     
     #[test]
@@ -686,6 +2489,24 @@ mod tests {
         assert_eq!(color, Color { red: 100, green: 100, blue: 100 });
     }
     
+    #[test]
+    fn converts_from_lab_str() {
+        let color = "lab(54.29% 80.8 69.89)".parse::<Color>().unwrap();
+        
+        assert_eq!(color, Color { red: 255, green: 0, blue: 0 });
+        
+        let color = "lch(54.29% 106.84 40.85 / 0.5)".parse::<Color>().unwrap();
+        
+        assert_eq!(color, Color { red: 255, green: 0, blue: 0 });
+    }
+    
+    #[test]
+    fn converts_from_color_function_str() {
+        let color = "color(srgb 0 0.5 1)".parse::<Color>().unwrap();
+        
+        assert_eq!(color, Color { red: 0, green: 128, blue: 255 });
+    }
+    
     #[test]
     fn converts_from_rgba() {
         let (color, alpha) = Color::from_rgba_str("rgba(100 100 100 0.5)").unwrap();
@@ -0,0 +1,346 @@
+use crate::{helpers, Color, Rgba};
+use crate::color::Value;
+use std::ops::{Mul, MulAssign};
+
+/// A 4×5 matrix for mixing the red, green, blue, and alpha values of a color, in the same format 
+/// as the SVG `feColorMatrix` filter. Each row produces one output value as the sum of the input 
+/// red, green, blue, and alpha values multiplied by the first four columns, plus the fifth column 
+/// as an offset.
+/// 
+/// Values are scaled from 0.0 to 1.0 and are gamma-encoded sRGB, matching CSS filter functions. 
+/// Results are clamped to the range of each value.
+/// 
+/// Matrices are combined using `*`, where `a * b` applies `b` and then `a`. The combined matrix 
+/// does not clamp values between the two steps, so it can differ from applying `b` and then `a` 
+/// with [`ColorMatrix::apply`], or from chaining CSS filter functions, when `b` pushes values out 
+/// of range, e.g. `saturate(3)` followed by `hue-rotate(180deg)`.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, ColorMatrix};
+/// 
+/// let swap = ColorMatrix::from_rgb([
+///     [0.0, 0.0, 1.0],
+///     [0.0, 1.0, 0.0],
+///     [1.0, 0.0, 0.0],
+/// ], [0.0, 0.0, 0.0]);
+/// let brighten = ColorMatrix::from_rgb([
+///     [1.0, 0.0, 0.0],
+///     [0.0, 1.0, 0.0],
+///     [0.0, 0.0, 1.0],
+/// ], [0.2, 0.2, 0.2]);
+/// let color = Color { red: 255, green: 128, blue: 0 };
+/// 
+/// assert_eq!(swap.apply(color), Color { red: 0, green: 128, blue: 255 });
+/// assert_eq!((brighten * swap).apply(color), Color { red: 51, green: 179, blue: 255 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorMatrix {
+    rows: [[f32; 5]; 4],
+}
+
+impl ColorMatrix {
+    /// The matrix that leaves colors unchanged.
+    pub const IDENTITY: Self = Self {
+        rows: [
+            [1.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0, 0.0],
+        ],
+    };
+    
+    /// Creates a matrix from 4 rows of 5 values, in the order of the `values` attribute of SVG 
+    /// `feColorMatrix`.
+    pub fn new(rows: [[f32; 5]; 4]) -> Self {
+        Self { rows }
+    }
+    
+    /// Creates a matrix that mixes only red, green, and blue values from a 3×3 matrix and an 
+    /// offset for each value. Alpha is unchanged.
+    pub fn from_rgb(matrix: [[f32; 3]; 3], offset: [f32; 3]) -> Self {
+        let mut rows = Self::IDENTITY.rows;
+        
+        for (row, (values, offset)) in rows.iter_mut().zip(matrix.iter().zip(offset)) {
+            *row = [values[0], values[1], values[2], 0.0, offset];
+        }
+        
+        Self { rows }
+    }
+    
+    /// Creates a matrix for the CSS `grayscale()` filter function. An `amount` of 1.0 converts 
+    /// colors completely to grayscale. The value is clamped between 0.0 and 1.0.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, ColorMatrix};
+    /// 
+    /// let color = Color { red: 255, green: 0, blue: 0 };
+    /// 
+    /// assert_eq!(ColorMatrix::grayscale(1.0).apply(color), Color { red: 54, green: 54, blue: 54 });
+    /// ```
+    pub fn grayscale(amount: f32) -> Self {
+        let a = 1.0 - amount.clamp(0.0, 1.0);
+        
+        Self::from_rgb([
+            [0.2126 + 0.7874 * a, 0.7152 - 0.7152 * a, 0.0722 - 0.0722 * a],
+            [0.2126 - 0.2126 * a, 0.7152 + 0.2848 * a, 0.0722 - 0.0722 * a],
+            [0.2126 - 0.2126 * a, 0.7152 - 0.7152 * a, 0.0722 + 0.9278 * a],
+        ], [0.0; 3])
+    }
+    
+    /// Creates a matrix for the CSS `sepia()` filter function. An `amount` of 1.0 converts 
+    /// colors completely to sepia. The value is clamped between 0.0 and 1.0.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, ColorMatrix};
+    /// 
+    /// let color = ColorMatrix::sepia(1.0).apply(Color { red: 255, green: 255, blue: 255 });
+    /// 
+    /// assert_eq!(color, Color { red: 255, green: 255, blue: 239 });
+    /// ```
+    pub fn sepia(amount: f32) -> Self {
+        let a = 1.0 - amount.clamp(0.0, 1.0);
+        
+        Self::from_rgb([
+            [0.393 + 0.607 * a, 0.769 - 0.769 * a, 0.189 - 0.189 * a],
+            [0.349 - 0.349 * a, 0.686 + 0.314 * a, 0.168 - 0.168 * a],
+            [0.272 - 0.272 * a, 0.534 - 0.534 * a, 0.131 + 0.869 * a],
+        ], [0.0; 3])
+    }
+    
+    /// Creates a matrix for the CSS `saturate()` filter function. An `amount` of 0.0 removes all 
+    /// saturation, 1.0 leaves colors unchanged, and values above 1.0 oversaturate. Negative 
+    /// values are treated as 0.0.
+    pub fn saturate(amount: f32) -> Self {
+        let s = amount.max(0.0);
+        
+        Self::from_rgb([
+            [0.213 + 0.787 * s, 0.715 - 0.715 * s, 0.072 - 0.072 * s],
+            [0.213 - 0.213 * s, 0.715 + 0.285 * s, 0.072 - 0.072 * s],
+            [0.213 - 0.213 * s, 0.715 - 0.715 * s, 0.072 + 0.928 * s],
+        ], [0.0; 3])
+    }
+    
+    /// Creates a matrix for the CSS `hue-rotate()` filter function, rotating hues by a number of 
+    /// degrees.
+    pub fn hue_rotate(degrees: f32) -> Self {
+        let (sin, cos) = degrees.to_radians().sin_cos();
+        
+        Self::from_rgb([
+            [
+                0.213 + cos * 0.787 - sin * 0.213,
+                0.715 - cos * 0.715 - sin * 0.715,
+                0.072 - cos * 0.072 + sin * 0.928,
+            ],
+            [
+                0.213 - cos * 0.213 + sin * 0.143,
+                0.715 + cos * 0.285 + sin * 0.140,
+                0.072 - cos * 0.072 - sin * 0.283,
+            ],
+            [
+                0.213 - cos * 0.213 - sin * 0.787,
+                0.715 - cos * 0.715 + sin * 0.715,
+                0.072 + cos * 0.928 + sin * 0.072,
+            ],
+        ], [0.0; 3])
+    }
+    
+    /// Creates a matrix for the CSS `invert()` filter function. An `amount` of 1.0 inverts 
+    /// colors completely. The value is clamped between 0.0 and 1.0.
+    pub fn invert(amount: f32) -> Self {
+        let amount = amount.clamp(0.0, 1.0);
+        
+        Self::linear(1.0 - 2.0 * amount, amount)
+    }
+    
+    /// Creates a matrix for the CSS `brightness()` filter function, multiplying each value by 
+    /// `amount`. Negative values are treated as 0.0.
+    pub fn brightness(amount: f32) -> Self {
+        Self::linear(amount.max(0.0), 0.0)
+    }
+    
+    /// Creates a matrix for the CSS `contrast()` filter function. An `amount` of 0.0 makes colors 
+    /// completely gray, 1.0 leaves colors unchanged, and values above 1.0 increase contrast. 
+    /// Negative values are treated as 0.0.
+    pub fn contrast(amount: f32) -> Self {
+        let amount = amount.max(0.0);
+        
+        Self::linear(amount, 0.5 - 0.5 * amount)
+    }
+    
+    /// Creates a matrix for the CSS `opacity()` filter function, multiplying alpha by `amount`. 
+    /// The value is clamped between 0.0 and 1.0.
+    pub fn opacity(amount: f32) -> Self {
+        let mut matrix = Self::IDENTITY;
+        
+        matrix.rows[3][3] = amount.clamp(0.0, 1.0);
+        matrix
+    }
+    
+    /// Creates a matrix that multiplies each of the red, green, and blue values by `slope` and 
+    /// adds `intercept`.
+    fn linear(slope: f32, intercept: f32) -> Self {
+        Self::from_rgb([
+            [slope, 0.0, 0.0],
+            [0.0, slope, 0.0],
+            [0.0, 0.0, slope],
+        ], [intercept; 3])
+    }
+    
+    /// Gets the rows of this matrix.
+    pub fn rows(&self) -> [[f32; 5]; 4] {
+        self.rows
+    }
+    
+    /// Applies this matrix to a color, treating it as fully opaque.
+    pub fn apply(&self, color: Color) -> Color {
+        self.apply_rgba(Rgba::from(color)).to_color()
+    }
+    
+    /// Applies this matrix to a color with alpha.
+    pub fn apply_rgba(&self, color: Rgba) -> Rgba {
+        let input = [
+            color.red as f32 / Value::MAX as f32,
+            color.green as f32 / Value::MAX as f32,
+            color.blue as f32 / Value::MAX as f32,
+            color.alpha,
+        ];
+        let [red, green, blue, alpha] = self.rows.map(|row| {
+            row[4] + row.iter().zip(input).map(|(weight, value)| weight * value).sum::<f32>()
+        });
+        let to_value = |value: f32| helpers::float_to_value(value * Value::MAX as f32);
+        
+        Rgba {
+            red: to_value(red),
+            green: to_value(green),
+            blue: to_value(blue),
+            alpha: alpha.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Default for ColorMatrix {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for ColorMatrix {
+    type Output = Self;
+    
+    /// Combines two matrices into one that applies `rhs` and then `self`. Values are not clamped 
+    /// between `rhs` and `self`, unlike applying each matrix in turn.
+    fn mul(self, rhs: Self) -> Self::Output {
+        // The matrices are multiplied as 5×5 matrices, with a last row that keeps the offset.
+        let [r, g, b, a] = rhs.rows;
+        let rhs_rows = [r, g, b, a, [0.0, 0.0, 0.0, 0.0, 1.0]];
+        let mut rows = [[0.0; 5]; 4];
+        
+        for (row, lhs_row) in rows.iter_mut().zip(self.rows) {
+            for (column, value) in row.iter_mut().enumerate() {
+                *value = lhs_row
+                    .iter()
+                    .zip(&rhs_rows)
+                    .map(|(weight, rhs_row)| weight * rhs_row[column])
+                    .sum::<f32>();
+            }
+        }
+        
+        Self { rows }
+    }
+}
+
+impl MulAssign for ColorMatrix {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn composes_matrices() {
+        let invert = ColorMatrix::from_rgb([
+            [-1.0, 0.0, 0.0],
+            [0.0, -1.0, 0.0],
+            [0.0, 0.0, -1.0],
+        ], [1.0, 1.0, 1.0]);
+        let fade = ColorMatrix::new([
+            [1.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0, 0.5, 0.0],
+        ]);
+        let color = Rgba { red: 255, green: 100, blue: 0, alpha: 1.0 };
+        
+        assert_eq!(ColorMatrix::default().apply_rgba(color), color);
+        assert_eq!((invert * invert).apply_rgba(color), color);
+        assert_eq!(
+            (fade * invert).apply_rgba(color),
+            fade.apply_rgba(invert.apply_rgba(color)),
+        );
+        assert_eq!(
+            (invert * fade).apply_rgba(color),
+            Rgba { red: 0, green: 155, blue: 255, alpha: 0.5 },
+        );
+        
+        let mut matrix = ColorMatrix::IDENTITY;
+        
+        matrix *= invert;
+        assert_eq!(matrix.apply(Color { red: 255, green: 0, blue: 1 }), Color::from([0, 255, 254]));
+    }
+    
+    #[test]
+    fn composes_without_clamping_between_steps() {
+        let saturate = ColorMatrix::saturate(3.0);
+        let hue_rotate = ColorMatrix::hue_rotate(180.0);
+        let color = Color { red: 200, green: 100, blue: 50 };
+        let chained = hue_rotate.apply(saturate.apply(color));
+        let composed = (hue_rotate * saturate).apply(color);
+        
+        assert_eq!(saturate.apply(color), Color { red: 255, green: 65, blue: 0 });
+        assert_eq!(chained, Color { red: 0, green: 137, blue: 202 });
+        assert_eq!(composed, Color { red: 0, green: 171, blue: 255 });
+    }
+    
+    #[test]
+    fn creates_css_filter_matrices() {
+        let color = Rgba { red: 200, green: 100, blue: 50, alpha: 1.0 };
+        
+        for identity in [
+            ColorMatrix::grayscale(0.0),
+            ColorMatrix::sepia(0.0),
+            ColorMatrix::saturate(1.0),
+            ColorMatrix::hue_rotate(0.0),
+            ColorMatrix::hue_rotate(360.0),
+            ColorMatrix::invert(0.0),
+            ColorMatrix::brightness(1.0),
+            ColorMatrix::contrast(1.0),
+            ColorMatrix::opacity(1.0),
+        ] {
+            assert_eq!(identity.apply_rgba(color), color, "{identity:?}");
+        }
+        
+        let gray = ColorMatrix::saturate(0.0).apply_rgba(color);
+        
+        assert!(gray.red == gray.green && gray.green == gray.blue, "{gray:?}");
+        assert_eq!(
+            ColorMatrix::invert(1.0).apply_rgba(color),
+            Rgba { red: 55, green: 155, blue: 205, alpha: 1.0 },
+        );
+        assert_eq!(
+            ColorMatrix::contrast(0.0).apply_rgba(color),
+            Rgba { red: 128, green: 128, blue: 128, alpha: 1.0 },
+        );
+        assert_eq!(ColorMatrix::brightness(2.0).apply_rgba(color).red, 255);
+        assert_eq!(ColorMatrix::opacity(0.25).apply_rgba(color).alpha, 0.25);
+        assert_eq!(
+            (ColorMatrix::hue_rotate(120.0) * ColorMatrix::hue_rotate(240.0)).apply_rgba(color),
+            color,
+        );
+    }
+}
\ No newline at end of file
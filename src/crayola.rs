@@ -0,0 +1,434 @@
+//! Classic Crayola crayon colors, available with the `crayola` feature.
+//! 
+//! The values are the commonly published sRGB approximations of each crayon. Names are listed in 
+//! lowercase, including any punctuation, e.g. "robin's egg blue".
+//! 
+//! # Examples
+//! ```
+//! use nice_colors::Color;
+//! use nice_colors::crayola;
+//! 
+//! assert_eq!(crayola::from_crayola_color_name("Screamin' Green"), Some(crayola::SCREAMIN_GREEN));
+//! assert_eq!(crayola::to_crayola_color_name(&crayola::MANGO_TANGO), Some("mango tango"));
+//! 
+//! let green = Color::from_decimal(0x00FF00);
+//! 
+//! assert_eq!(crayola::nearest_crayola_color_name(&green), "green");
+//! ```
+
+use super::Color;
+use crate::named;
+
+/// Almond.
+pub const ALMOND: Color = Color { red: 239, green: 222, blue: 205 };
+/// Antique Brass.
+pub const ANTIQUE_BRASS: Color = Color { red: 205, green: 149, blue: 117 };
+/// Apricot.
+pub const APRICOT: Color = Color { red: 253, green: 217, blue: 181 };
+/// Aquamarine.
+pub const AQUAMARINE: Color = Color { red: 120, green: 219, blue: 226 };
+/// Asparagus.
+pub const ASPARAGUS: Color = Color { red: 135, green: 169, blue: 107 };
+/// Atomic Tangerine.
+pub const ATOMIC_TANGERINE: Color = Color { red: 255, green: 164, blue: 116 };
+/// Banana Mania.
+pub const BANANA_MANIA: Color = Color { red: 250, green: 231, blue: 181 };
+/// Beaver.
+pub const BEAVER: Color = Color { red: 159, green: 129, blue: 112 };
+/// Bittersweet.
+pub const BITTERSWEET: Color = Color { red: 253, green: 124, blue: 110 };
+/// Black.
+pub const BLACK: Color = Color { red: 0, green: 0, blue: 0 };
+/// Blue.
+pub const BLUE: Color = Color { red: 31, green: 117, blue: 254 };
+/// Blue Bell.
+pub const BLUE_BELL: Color = Color { red: 162, green: 162, blue: 208 };
+/// Blue Green.
+pub const BLUE_GREEN: Color = Color { red: 13, green: 152, blue: 186 };
+/// Blue Violet.
+pub const BLUE_VIOLET: Color = Color { red: 115, green: 102, blue: 189 };
+/// Blush.
+pub const BLUSH: Color = Color { red: 222, green: 93, blue: 131 };
+/// Brick Red.
+pub const BRICK_RED: Color = Color { red: 203, green: 65, blue: 84 };
+/// Brown.
+pub const BROWN: Color = Color { red: 180, green: 103, blue: 77 };
+/// Burnt Orange.
+pub const BURNT_ORANGE: Color = Color { red: 255, green: 127, blue: 73 };
+/// Burnt Sienna.
+pub const BURNT_SIENNA: Color = Color { red: 234, green: 126, blue: 93 };
+/// Cadet Blue.
+pub const CADET_BLUE: Color = Color { red: 176, green: 183, blue: 198 };
+/// Canary.
+pub const CANARY: Color = Color { red: 255, green: 255, blue: 153 };
+/// Caribbean Green.
+pub const CARIBBEAN_GREEN: Color = Color { red: 28, green: 211, blue: 162 };
+/// Carnation Pink.
+pub const CARNATION_PINK: Color = Color { red: 255, green: 170, blue: 204 };
+/// Cerise.
+pub const CERISE: Color = Color { red: 221, green: 68, blue: 146 };
+/// Cerulean.
+pub const CERULEAN: Color = Color { red: 29, green: 172, blue: 214 };
+/// Chestnut.
+pub const CHESTNUT: Color = Color { red: 188, green: 93, blue: 88 };
+/// Copper.
+pub const COPPER: Color = Color { red: 221, green: 148, blue: 117 };
+/// Cornflower.
+pub const CORNFLOWER: Color = Color { red: 154, green: 206, blue: 235 };
+/// Cotton Candy.
+pub const COTTON_CANDY: Color = Color { red: 255, green: 188, blue: 217 };
+/// Dandelion.
+pub const DANDELION: Color = Color { red: 253, green: 219, blue: 109 };
+/// Denim.
+pub const DENIM: Color = Color { red: 43, green: 108, blue: 196 };
+/// Desert Sand.
+pub const DESERT_SAND: Color = Color { red: 239, green: 205, blue: 184 };
+/// Eggplant.
+pub const EGGPLANT: Color = Color { red: 110, green: 81, blue: 96 };
+/// Electric Lime.
+pub const ELECTRIC_LIME: Color = Color { red: 206, green: 255, blue: 29 };
+/// Fern.
+pub const FERN: Color = Color { red: 113, green: 188, blue: 120 };
+/// Forest Green.
+pub const FOREST_GREEN: Color = Color { red: 109, green: 174, blue: 129 };
+/// Fuchsia.
+pub const FUCHSIA: Color = Color { red: 195, green: 100, blue: 197 };
+/// Fuzzy Wuzzy.
+pub const FUZZY_WUZZY: Color = Color { red: 204, green: 102, blue: 102 };
+/// Gold.
+pub const GOLD: Color = Color { red: 231, green: 198, blue: 151 };
+/// Goldenrod.
+pub const GOLDENROD: Color = Color { red: 252, green: 217, blue: 117 };
+/// Granny Smith Apple.
+pub const GRANNY_SMITH_APPLE: Color = Color { red: 168, green: 228, blue: 160 };
+/// Gray.
+pub const GRAY: Color = Color { red: 149, green: 145, blue: 140 };
+/// Green.
+pub const GREEN: Color = Color { red: 28, green: 172, blue: 120 };
+/// Green Yellow.
+pub const GREEN_YELLOW: Color = Color { red: 240, green: 232, blue: 145 };
+/// Hot Magenta.
+pub const HOT_MAGENTA: Color = Color { red: 255, green: 29, blue: 206 };
+/// Inchworm.
+pub const INCHWORM: Color = Color { red: 178, green: 236, blue: 93 };
+/// Indigo.
+pub const INDIGO: Color = Color { red: 93, green: 118, blue: 203 };
+/// Jazzberry Jam.
+pub const JAZZBERRY_JAM: Color = Color { red: 202, green: 55, blue: 103 };
+/// Jungle Green.
+pub const JUNGLE_GREEN: Color = Color { red: 59, green: 176, blue: 143 };
+/// Laser Lemon.
+pub const LASER_LEMON: Color = Color { red: 254, green: 254, blue: 34 };
+/// Lavender.
+pub const LAVENDER: Color = Color { red: 252, green: 180, blue: 213 };
+/// Macaroni and Cheese.
+pub const MACARONI_AND_CHEESE: Color = Color { red: 255, green: 189, blue: 136 };
+/// Magenta.
+pub const MAGENTA: Color = Color { red: 246, green: 100, blue: 175 };
+/// Mahogany.
+pub const MAHOGANY: Color = Color { red: 205, green: 74, blue: 76 };
+/// Manatee.
+pub const MANATEE: Color = Color { red: 151, green: 154, blue: 170 };
+/// Mango Tango.
+pub const MANGO_TANGO: Color = Color { red: 255, green: 130, blue: 67 };
+/// Maroon.
+pub const MAROON: Color = Color { red: 200, green: 56, blue: 90 };
+/// Mauvelous.
+pub const MAUVELOUS: Color = Color { red: 239, green: 152, blue: 170 };
+/// Melon.
+pub const MELON: Color = Color { red: 253, green: 188, blue: 180 };
+/// Midnight Blue.
+pub const MIDNIGHT_BLUE: Color = Color { red: 26, green: 72, blue: 118 };
+/// Mountain Meadow.
+pub const MOUNTAIN_MEADOW: Color = Color { red: 48, green: 186, blue: 143 };
+/// Navy Blue.
+pub const NAVY_BLUE: Color = Color { red: 25, green: 116, blue: 210 };
+/// Neon Carrot.
+pub const NEON_CARROT: Color = Color { red: 255, green: 163, blue: 67 };
+/// Olive Green.
+pub const OLIVE_GREEN: Color = Color { red: 186, green: 184, blue: 108 };
+/// Orange.
+pub const ORANGE: Color = Color { red: 255, green: 117, blue: 56 };
+/// Orchid.
+pub const ORCHID: Color = Color { red: 230, green: 168, blue: 215 };
+/// Outer Space.
+pub const OUTER_SPACE: Color = Color { red: 65, green: 74, blue: 76 };
+/// Outrageous Orange.
+pub const OUTRAGEOUS_ORANGE: Color = Color { red: 255, green: 110, blue: 74 };
+/// Pacific Blue.
+pub const PACIFIC_BLUE: Color = Color { red: 28, green: 169, blue: 201 };
+/// Peach.
+pub const PEACH: Color = Color { red: 255, green: 207, blue: 171 };
+/// Periwinkle.
+pub const PERIWINKLE: Color = Color { red: 197, green: 208, blue: 230 };
+/// Piggy Pink.
+pub const PIGGY_PINK: Color = Color { red: 253, green: 221, blue: 230 };
+/// Pine Green.
+pub const PINE_GREEN: Color = Color { red: 21, green: 128, blue: 120 };
+/// Pink Flamingo.
+pub const PINK_FLAMINGO: Color = Color { red: 252, green: 116, blue: 253 };
+/// Pink Sherbert.
+pub const PINK_SHERBERT: Color = Color { red: 247, green: 143, blue: 167 };
+/// Plum.
+pub const PLUM: Color = Color { red: 142, green: 69, blue: 133 };
+/// Purple Heart.
+pub const PURPLE_HEART: Color = Color { red: 116, green: 66, blue: 200 };
+/// Purple Mountain's Majesty.
+pub const PURPLE_MOUNTAINS_MAJESTY: Color = Color { red: 157, green: 129, blue: 186 };
+/// Purple Pizzazz.
+pub const PURPLE_PIZZAZZ: Color = Color { red: 254, green: 78, blue: 218 };
+/// Radical Red.
+pub const RADICAL_RED: Color = Color { red: 255, green: 73, blue: 108 };
+/// Raw Sienna.
+pub const RAW_SIENNA: Color = Color { red: 214, green: 138, blue: 89 };
+/// Razzle Dazzle Rose.
+pub const RAZZLE_DAZZLE_ROSE: Color = Color { red: 255, green: 72, blue: 208 };
+/// Razzmatazz.
+pub const RAZZMATAZZ: Color = Color { red: 227, green: 37, blue: 107 };
+/// Red.
+pub const RED: Color = Color { red: 238, green: 32, blue: 77 };
+/// Red Orange.
+pub const RED_ORANGE: Color = Color { red: 255, green: 83, blue: 73 };
+/// Red Violet.
+pub const RED_VIOLET: Color = Color { red: 192, green: 68, blue: 143 };
+/// Robin's Egg Blue.
+pub const ROBINS_EGG_BLUE: Color = Color { red: 31, green: 206, blue: 203 };
+/// Royal Purple.
+pub const ROYAL_PURPLE: Color = Color { red: 120, green: 81, blue: 169 };
+/// Salmon.
+pub const SALMON: Color = Color { red: 255, green: 155, blue: 170 };
+/// Scarlet.
+pub const SCARLET: Color = Color { red: 252, green: 40, blue: 71 };
+/// Screamin' Green.
+pub const SCREAMIN_GREEN: Color = Color { red: 118, green: 255, blue: 122 };
+/// Sea Green.
+pub const SEA_GREEN: Color = Color { red: 147, green: 223, blue: 184 };
+/// Sepia.
+pub const SEPIA: Color = Color { red: 165, green: 105, blue: 79 };
+/// Shadow.
+pub const SHADOW: Color = Color { red: 138, green: 121, blue: 93 };
+/// Shamrock.
+pub const SHAMROCK: Color = Color { red: 69, green: 206, blue: 162 };
+/// Shocking Pink.
+pub const SHOCKING_PINK: Color = Color { red: 251, green: 126, blue: 253 };
+/// Silver.
+pub const SILVER: Color = Color { red: 205, green: 197, blue: 194 };
+/// Sky Blue.
+pub const SKY_BLUE: Color = Color { red: 128, green: 218, blue: 235 };
+/// Spring Green.
+pub const SPRING_GREEN: Color = Color { red: 236, green: 234, blue: 190 };
+/// Sunglow.
+pub const SUNGLOW: Color = Color { red: 255, green: 207, blue: 72 };
+/// Sunset Orange.
+pub const SUNSET_ORANGE: Color = Color { red: 253, green: 94, blue: 83 };
+/// Tan.
+pub const TAN: Color = Color { red: 250, green: 167, blue: 108 };
+/// Tickle Me Pink.
+pub const TICKLE_ME_PINK: Color = Color { red: 252, green: 137, blue: 172 };
+/// Timberwolf.
+pub const TIMBERWOLF: Color = Color { red: 219, green: 215, blue: 210 };
+/// Tropical Rain Forest.
+pub const TROPICAL_RAIN_FOREST: Color = Color { red: 23, green: 128, blue: 109 };
+/// Tumbleweed.
+pub const TUMBLEWEED: Color = Color { red: 222, green: 170, blue: 136 };
+/// Turquoise Blue.
+pub const TURQUOISE_BLUE: Color = Color { red: 119, green: 221, blue: 231 };
+/// Unmellow Yellow.
+pub const UNMELLOW_YELLOW: Color = Color { red: 255, green: 255, blue: 102 };
+/// Violet.
+pub const VIOLET: Color = Color { red: 146, green: 110, blue: 174 };
+/// Violet Red.
+pub const VIOLET_RED: Color = Color { red: 247, green: 83, blue: 148 };
+/// Vivid Tangerine.
+pub const VIVID_TANGERINE: Color = Color { red: 255, green: 160, blue: 137 };
+/// Vivid Violet.
+pub const VIVID_VIOLET: Color = Color { red: 143, green: 80, blue: 157 };
+/// White.
+pub const WHITE: Color = Color { red: 255, green: 255, blue: 255 };
+/// Wild Blue Yonder.
+pub const WILD_BLUE_YONDER: Color = Color { red: 162, green: 173, blue: 208 };
+/// Wild Strawberry.
+pub const WILD_STRAWBERRY: Color = Color { red: 255, green: 67, blue: 164 };
+/// Wild Watermelon.
+pub const WILD_WATERMELON: Color = Color { red: 252, green: 108, blue: 133 };
+/// Wisteria.
+pub const WISTERIA: Color = Color { red: 205, green: 164, blue: 222 };
+/// Yellow.
+pub const YELLOW: Color = Color { red: 252, green: 232, blue: 131 };
+/// Yellow Green.
+pub const YELLOW_GREEN: Color = Color { red: 197, green: 227, blue: 132 };
+/// Yellow Orange.
+pub const YELLOW_ORANGE: Color = Color { red: 255, green: 174, blue: 66 };
+
+/// Every color name paired with its color, sorted by name.
+pub const COLOR_NAMES: [(&str, Color); 120] = [
+    ("almond", ALMOND),
+    ("antique brass", ANTIQUE_BRASS),
+    ("apricot", APRICOT),
+    ("aquamarine", AQUAMARINE),
+    ("asparagus", ASPARAGUS),
+    ("atomic tangerine", ATOMIC_TANGERINE),
+    ("banana mania", BANANA_MANIA),
+    ("beaver", BEAVER),
+    ("bittersweet", BITTERSWEET),
+    ("black", BLACK),
+    ("blue", BLUE),
+    ("blue bell", BLUE_BELL),
+    ("blue green", BLUE_GREEN),
+    ("blue violet", BLUE_VIOLET),
+    ("blush", BLUSH),
+    ("brick red", BRICK_RED),
+    ("brown", BROWN),
+    ("burnt orange", BURNT_ORANGE),
+    ("burnt sienna", BURNT_SIENNA),
+    ("cadet blue", CADET_BLUE),
+    ("canary", CANARY),
+    ("caribbean green", CARIBBEAN_GREEN),
+    ("carnation pink", CARNATION_PINK),
+    ("cerise", CERISE),
+    ("cerulean", CERULEAN),
+    ("chestnut", CHESTNUT),
+    ("copper", COPPER),
+    ("cornflower", CORNFLOWER),
+    ("cotton candy", COTTON_CANDY),
+    ("dandelion", DANDELION),
+    ("denim", DENIM),
+    ("desert sand", DESERT_SAND),
+    ("eggplant", EGGPLANT),
+    ("electric lime", ELECTRIC_LIME),
+    ("fern", FERN),
+    ("forest green", FOREST_GREEN),
+    ("fuchsia", FUCHSIA),
+    ("fuzzy wuzzy", FUZZY_WUZZY),
+    ("gold", GOLD),
+    ("goldenrod", GOLDENROD),
+    ("granny smith apple", GRANNY_SMITH_APPLE),
+    ("gray", GRAY),
+    ("green", GREEN),
+    ("green yellow", GREEN_YELLOW),
+    ("hot magenta", HOT_MAGENTA),
+    ("inchworm", INCHWORM),
+    ("indigo", INDIGO),
+    ("jazzberry jam", JAZZBERRY_JAM),
+    ("jungle green", JUNGLE_GREEN),
+    ("laser lemon", LASER_LEMON),
+    ("lavender", LAVENDER),
+    ("macaroni and cheese", MACARONI_AND_CHEESE),
+    ("magenta", MAGENTA),
+    ("mahogany", MAHOGANY),
+    ("manatee", MANATEE),
+    ("mango tango", MANGO_TANGO),
+    ("maroon", MAROON),
+    ("mauvelous", MAUVELOUS),
+    ("melon", MELON),
+    ("midnight blue", MIDNIGHT_BLUE),
+    ("mountain meadow", MOUNTAIN_MEADOW),
+    ("navy blue", NAVY_BLUE),
+    ("neon carrot", NEON_CARROT),
+    ("olive green", OLIVE_GREEN),
+    ("orange", ORANGE),
+    ("orchid", ORCHID),
+    ("outer space", OUTER_SPACE),
+    ("outrageous orange", OUTRAGEOUS_ORANGE),
+    ("pacific blue", PACIFIC_BLUE),
+    ("peach", PEACH),
+    ("periwinkle", PERIWINKLE),
+    ("piggy pink", PIGGY_PINK),
+    ("pine green", PINE_GREEN),
+    ("pink flamingo", PINK_FLAMINGO),
+    ("pink sherbert", PINK_SHERBERT),
+    ("plum", PLUM),
+    ("purple heart", PURPLE_HEART),
+    ("purple mountain's majesty", PURPLE_MOUNTAINS_MAJESTY),
+    ("purple pizzazz", PURPLE_PIZZAZZ),
+    ("radical red", RADICAL_RED),
+    ("raw sienna", RAW_SIENNA),
+    ("razzle dazzle rose", RAZZLE_DAZZLE_ROSE),
+    ("razzmatazz", RAZZMATAZZ),
+    ("red", RED),
+    ("red orange", RED_ORANGE),
+    ("red violet", RED_VIOLET),
+    ("robin's egg blue", ROBINS_EGG_BLUE),
+    ("royal purple", ROYAL_PURPLE),
+    ("salmon", SALMON),
+    ("scarlet", SCARLET),
+    ("screamin' green", SCREAMIN_GREEN),
+    ("sea green", SEA_GREEN),
+    ("sepia", SEPIA),
+    ("shadow", SHADOW),
+    ("shamrock", SHAMROCK),
+    ("shocking pink", SHOCKING_PINK),
+    ("silver", SILVER),
+    ("sky blue", SKY_BLUE),
+    ("spring green", SPRING_GREEN),
+    ("sunglow", SUNGLOW),
+    ("sunset orange", SUNSET_ORANGE),
+    ("tan", TAN),
+    ("tickle me pink", TICKLE_ME_PINK),
+    ("timberwolf", TIMBERWOLF),
+    ("tropical rain forest", TROPICAL_RAIN_FOREST),
+    ("tumbleweed", TUMBLEWEED),
+    ("turquoise blue", TURQUOISE_BLUE),
+    ("unmellow yellow", UNMELLOW_YELLOW),
+    ("violet", VIOLET),
+    ("violet red", VIOLET_RED),
+    ("vivid tangerine", VIVID_TANGERINE),
+    ("vivid violet", VIVID_VIOLET),
+    ("white", WHITE),
+    ("wild blue yonder", WILD_BLUE_YONDER),
+    ("wild strawberry", WILD_STRAWBERRY),
+    ("wild watermelon", WILD_WATERMELON),
+    ("wisteria", WISTERIA),
+    ("yellow", YELLOW),
+    ("yellow green", YELLOW_GREEN),
+    ("yellow orange", YELLOW_ORANGE),
+];
+
+/// Every color paired with its name, keyed by its decimal value and sorted by key.
+const COLOR_VALUES: [(u32, &str); COLOR_NAMES.len()] = named::sort_by_value(COLOR_NAMES);
+
+/// Attempts to get a color from a crayon color name. Matching is case-insensitive and leading and 
+/// trailing whitespace is ignored.
+pub fn from_crayola_color_name(s: &str) -> Option<Color> {
+    named::find_by_name(&COLOR_NAMES, s)
+}
+
+/// Converts a color to a crayon color name if it exactly matches a crayon.
+pub fn to_crayola_color_name(color: &Color) -> Option<&'static str> {
+    named::find_by_value(&COLOR_VALUES, color)
+}
+
+/// Gets the name of the crayon color nearest to `color`, measured by the squared distance between 
+/// their RGB values.
+pub fn nearest_crayola_color_name(color: &Color) -> &'static str {
+    named::nearest(&COLOR_NAMES, color).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn parses_crayola_color_name() {
+        assert_eq!(from_crayola_color_name("macaroni and cheese"), Some(MACARONI_AND_CHEESE));
+        assert_eq!(from_crayola_color_name(" ROBIN'S EGG BLUE "), Some(ROBINS_EGG_BLUE));
+        assert_eq!(from_crayola_color_name("robins egg blue"), None);
+        assert_eq!(from_crayola_color_name(""), None);
+    }
+    
+    #[test]
+    fn converts_to_crayola_color_name() {
+        assert_eq!(to_crayola_color_name(&Color { red: 1, green: 2, blue: 3 }), None);
+        assert_eq!(nearest_crayola_color_name(&Color { red: 1, green: 2, blue: 3 }), "black");
+        
+        for (name, color) in COLOR_NAMES {
+            assert_eq!(to_crayola_color_name(&color), Some(name));
+            assert_eq!(nearest_crayola_color_name(&color), name);
+        }
+    }
+    
+    #[test]
+    fn color_names_are_sorted() {
+        assert!(COLOR_NAMES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
\ No newline at end of file
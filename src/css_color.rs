@@ -0,0 +1,90 @@
+use crate::{parse, Color, ColorWithAlpha, ParseColorError};
+use std::str::FromStr;
+
+/// A parsed CSS color value. Unlike [`Color`], this also represents the `currentcolor` keyword, 
+/// which does not resolve to a concrete color on its own.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, CssColor};
+/// 
+/// assert_eq!(
+///     "transparent".parse::<CssColor>().unwrap(),
+///     CssColor::Color((Color { red: 0, green: 0, blue: 0 }, 0.0)),
+/// );
+/// assert_eq!("currentcolor".parse::<CssColor>().unwrap(), CssColor::CurrentColor);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CssColor {
+    /// A concrete color with alpha.
+    Color(ColorWithAlpha),
+    /// The `currentcolor` keyword, which refers to the value of the `color` property.
+    CurrentColor,
+}
+
+impl CssColor {
+    /// Resolves this value into a concrete color, using `current_color` in place of 
+    /// `currentcolor`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, CssColor};
+    /// 
+    /// let current_color = (Color { red: 255, green: 0, blue: 0 }, 1.0);
+    /// 
+    /// assert_eq!(CssColor::CurrentColor.resolve(current_color), current_color);
+    /// ```
+    pub fn resolve(self, current_color: ColorWithAlpha) -> ColorWithAlpha {
+        match self {
+            Self::Color(color) => color,
+            Self::CurrentColor => current_color,
+        }
+    }
+}
+
+impl From<Color> for CssColor {
+    fn from(value: Color) -> Self {
+        Self::Color((value, 1.0))
+    }
+}
+
+impl From<ColorWithAlpha> for CssColor {
+    fn from(value: ColorWithAlpha) -> Self {
+        Self::Color(value)
+    }
+}
+
+impl FromStr for CssColor {
+    type Err = ParseColorError;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().eq_ignore_ascii_case("currentcolor") {
+            return Ok(Self::CurrentColor);
+        }
+        
+        parse::color(s, false).map(|(colors, alpha)| Self::Color((colors.into(), alpha)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn parses_keywords() {
+        assert_eq!("currentColor".parse::<CssColor>().unwrap(), CssColor::CurrentColor);
+        assert_eq!(
+            "TRANSPARENT".parse::<CssColor>().unwrap(),
+            CssColor::Color((Color { red: 0, green: 0, blue: 0 }, 0.0)),
+        );
+    }
+    
+    #[test]
+    fn parses_colors_with_alpha() {
+        assert_eq!(
+            "rgba(255 0 0 0.5)".parse::<CssColor>().unwrap(),
+            CssColor::Color((Color { red: 255, green: 0, blue: 0 }, 0.5)),
+        );
+        assert!("current".parse::<CssColor>().is_err());
+    }
+}
\ No newline at end of file
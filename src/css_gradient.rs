@@ -0,0 +1,694 @@
+use crate::helpers;
+use crate::{parse, Color, ColorStop, Gradient, InterpolationSpace, ParseGradientError};
+use std::str::FromStr;
+
+/// A length or percentage in the geometry of a [`CssGradient`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CssLength {
+    /// A length in pixels.
+    Px(f32),
+    /// A percentage of the size of the box the gradient is drawn in, where 1.0 is 100%.
+    Percent(f32),
+}
+
+/// The shape of a `radial-gradient()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RadialShape {
+    /// A circle.
+    Circle,
+    /// An ellipse aligned to the axes of the box.
+    #[default]
+    Ellipse,
+}
+
+/// The size of a `radial-gradient()`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum RadialSize {
+    /// The shape meets the side of the box closest to its center.
+    ClosestSide,
+    /// The shape passes through the corner of the box closest to its center.
+    ClosestCorner,
+    /// The shape meets the side of the box farthest from its center.
+    FarthestSide,
+    /// The shape passes through the corner of the box farthest from its center.
+    #[default]
+    FarthestCorner,
+    /// The horizontal and vertical radii of the shape. Both are the same for circles.
+    Explicit(CssLength, CssLength),
+}
+
+/// The geometry of a [`CssGradient`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientGeometry {
+    /// A `linear-gradient()`.
+    Linear {
+        /// The direction of the gradient line in degrees, clockwise from pointing up. Directions 
+        /// towards a corner, such as `to top right`, use the angle for a square box.
+        angle: f32,
+    },
+    /// A `radial-gradient()`.
+    Radial {
+        /// The shape of the gradient.
+        shape: RadialShape,
+        /// The size of the shape.
+        size: RadialSize,
+        /// The horizontal and vertical position of the center, from the top left of the box.
+        position: [CssLength; 2],
+    },
+    /// A `conic-gradient()`.
+    Conic {
+        /// The angle the gradient starts at in degrees, clockwise from pointing up.
+        from_angle: f32,
+        /// The horizontal and vertical position of the center, from the top left of the box.
+        position: [CssLength; 2],
+    },
+}
+
+/// A parsed CSS `linear-gradient()`, `radial-gradient()`, or `conic-gradient()`, including their 
+/// `repeating-` variants.
+/// 
+/// Color stops are resolved as in CSS: missing positions are spaced evenly between the stops 
+/// either side, positions before an earlier stop are moved to it, and stops with two positions 
+/// become two stops. Stop positions are stored as a fraction of the gradient line, or of a full 
+/// turn for conic gradients. Alpha is not supported by [`Gradient`], so color stops that are not 
+/// fully opaque are rejected.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, CssGradient, CssLength, GradientGeometry, RadialShape, RadialSize};
+/// 
+/// let parsed = "radial-gradient(circle closest-side at 25% top, red, blue 80%)"
+///     .parse::<CssGradient>()
+///     .unwrap();
+/// 
+/// assert_eq!(parsed.geometry, GradientGeometry::Radial {
+///     shape: RadialShape::Circle,
+///     size: RadialSize::ClosestSide,
+///     position: [CssLength::Percent(0.25), CssLength::Percent(0.0)],
+/// });
+/// assert_eq!(parsed.gradient.stops()[1].position, 0.8);
+/// 
+/// let parsed = "conic-gradient(from 90deg, red, blue 0.25turn)".parse::<CssGradient>().unwrap();
+/// 
+/// assert_eq!(parsed.gradient.sample(0.25), Some(Color { red: 0, green: 0, blue: 255 }));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CssGradient {
+    /// The geometry of the gradient.
+    pub geometry: GradientGeometry,
+    /// Whether the gradient repeats, as in `repeating-linear-gradient()`.
+    pub repeating: bool,
+    /// The color stops and interpolation space of the gradient.
+    pub gradient: Gradient,
+}
+
+/// A color stop or color hint as written, before positions are resolved.
+enum Item {
+    Stop(Color, Option<f32>),
+    Hint(f32, String),
+}
+
+/// A color stop with its position if known and the color hint following it.
+struct PendingStop {
+    color: Color,
+    position: Option<f32>,
+    hint: Option<(f32, String)>,
+}
+
+impl FromStr for CssGradient {
+    type Err = ParseGradientError;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (name, arguments) = s
+            .strip_suffix(')')
+            .and_then(|s| s.split_once('('))
+            .ok_or(ParseGradientError::InvalidSyntax)?;
+        let name = name.trim().to_ascii_lowercase();
+        let (repeating, kind) = match name.strip_prefix("repeating-") {
+            Some(kind) => (true, kind),
+            None => (false, name.as_str()),
+        };
+        let is_conic = match kind {
+            "linear-gradient" | "radial-gradient" => false,
+            "conic-gradient" => true,
+            _ => return Err(ParseGradientError::UnsupportedFunction(name.clone())),
+        };
+        let mut arguments = split_top_level(arguments, ',');
+        let has_prelude = arguments
+            .first()
+            .and_then(|argument| split_top_level(argument, ' ').first().copied())
+            .is_some_and(|first| parse::color(first, false).is_err());
+        let mut tokens = if has_prelude {
+            split_top_level(arguments.remove(0), ' ')
+        } else {
+            Vec::new()
+        };
+        let mut space = InterpolationSpace::Srgb;
+        
+        if let Some(index) = tokens.iter().position(|token| token.eq_ignore_ascii_case("in")) {
+            let (method, len) = interpolation_method(&tokens[index + 1..])?;
+            
+            space = method;
+            tokens.drain(index..=index + len);
+        }
+        
+        let geometry = match kind {
+            "linear-gradient" => linear_geometry(&tokens)?,
+            "radial-gradient" => radial_geometry(&tokens)?,
+            _ => conic_geometry(&tokens)?,
+        };
+        let mut items = Vec::new();
+        
+        for argument in arguments {
+            let tokens = split_top_level(argument, ' ');
+            
+            match tokens.as_slice() {
+                [color, positions @ ..] if positions.len() <= 2 => {
+                    let ([red, green, blue], alpha) = match parse::color(color, false) {
+                        Ok(color) => color,
+                        // A single value that is not a color is a color hint. If it is not a 
+                        // position either, the color error is reported as the likelier mistake.
+                        Err(error) if positions.is_empty() => {
+                            let hint = stop_position(color, is_conic).map_err(|_| error)?;
+                            
+                            items.push(Item::Hint(hint, color.to_string()));
+                            continue;
+                        },
+                        Err(error) => return Err(error.into()),
+                    };
+                    
+                    if alpha < 1.0 {
+                        return Err(ParseGradientError::UnsupportedAlpha(color.to_string()));
+                    }
+                    
+                    let color = Color { red, green, blue };
+                    
+                    if positions.is_empty() {
+                        items.push(Item::Stop(color, None));
+                    }
+                    
+                    for position in positions {
+                        items.push(Item::Stop(color, Some(stop_position(position, is_conic)?)));
+                    }
+                },
+                _ => return Err(ParseGradientError::InvalidArgument(argument.trim().into())),
+            }
+        }
+        
+        Ok(Self {
+            geometry,
+            repeating,
+            gradient: resolve_stops(items)?.space(space),
+        })
+    }
+}
+
+/// Resolves the positions of color stops as in CSS and builds a gradient from them.
+fn resolve_stops(items: Vec<Item>) -> Result<Gradient, ParseGradientError> {
+    let mut stops: Vec<PendingStop> = Vec::new();
+    let mut previous_is_hint = true;
+    
+    for item in items {
+        match item {
+            Item::Stop(color, position) => {
+                stops.push(PendingStop { color, position, hint: None });
+                previous_is_hint = false;
+            },
+            Item::Hint(position, s) => {
+                if previous_is_hint {
+                    return Err(ParseGradientError::MisplacedHint(s));
+                }
+                
+                if let Some(stop) = stops.last_mut() {
+                    stop.hint = Some((position, s));
+                }
+                
+                previous_is_hint = true;
+            },
+        }
+    }
+    
+    if stops.len() < 2 {
+        return Err(ParseGradientError::TooFewStops);
+    }
+    
+    if let Some((_hint, s)) = stops.last_mut().and_then(|stop| stop.hint.take()) {
+        return Err(ParseGradientError::MisplacedHint(s));
+    }
+    
+    let last = stops.len() - 1;
+    
+    stops[0].position.get_or_insert(0.0);
+    stops[last].position.get_or_insert(1.0);
+    
+    let mut max = f32::NEG_INFINITY;
+    
+    for stop in &mut stops {
+        if let Some(position) = &mut stop.position {
+            *position = position.max(max);
+            max = *position;
+        }
+    }
+    
+    let mut index = 0;
+    
+    while index < last {
+        let start = index;
+        
+        index += 1;
+        
+        while stops[index].position.is_none() {
+            index += 1;
+        }
+        
+        let from = stops[start].position.unwrap_or_default();
+        let to = stops[index].position.unwrap_or_default();
+        let steps = (index - start) as f32;
+        
+        for (step, stop) in stops[start + 1..index].iter_mut().enumerate() {
+            stop.position = Some(from + (to - from) * (step + 1) as f32 / steps);
+        }
+    }
+    
+    let mut gradient = Gradient::new();
+    
+    for stop in stops {
+        gradient.push(ColorStop {
+            position: stop.position.unwrap_or_default(),
+            color: stop.color,
+            hint: stop.hint.map(|(hint, _s)| hint),
+        });
+    }
+    
+    Ok(gradient)
+}
+
+/// Parses the direction of a `linear-gradient()`.
+fn linear_geometry(tokens: &[&str]) -> Result<GradientGeometry, ParseGradientError> {
+    let angle = match tokens {
+        [] => 180.0,
+        [angle] => parse_angle(angle)
+            .ok_or_else(|| ParseGradientError::InvalidArgument(angle.to_string()))?,
+        [to, sides @ ..] if to.eq_ignore_ascii_case("to") => {
+            let sides = sides
+                .iter()
+                .map(|side| side.to_ascii_lowercase())
+                .collect::<Vec<_>>();
+            let sides = sides.iter().map(String::as_str).collect::<Vec<_>>();
+            
+            match sides.as_slice() {
+                ["top"] => 0.0,
+                ["right"] => 90.0,
+                ["bottom"] => 180.0,
+                ["left"] => 270.0,
+                ["top", "right"] | ["right", "top"] => 45.0,
+                ["bottom", "right"] | ["right", "bottom"] => 135.0,
+                ["bottom", "left"] | ["left", "bottom"] => 225.0,
+                ["top", "left"] | ["left", "top"] => 315.0,
+                _ => return Err(ParseGradientError::InvalidArgument(tokens.join(" "))),
+            }
+        },
+        _ => return Err(ParseGradientError::InvalidArgument(tokens.join(" "))),
+    };
+    
+    Ok(GradientGeometry::Linear { angle })
+}
+
+/// Parses the shape, size, and position of a `radial-gradient()`.
+fn radial_geometry(tokens: &[&str]) -> Result<GradientGeometry, ParseGradientError> {
+    let invalid = || ParseGradientError::InvalidArgument(tokens.join(" "));
+    let (ending, position) = split_at_keyword(tokens, "at");
+    let position = position.map_or(Ok(CENTER), parse_position)?;
+    let mut shape = None;
+    let mut size = None;
+    let mut lengths = Vec::new();
+    
+    for token in ending {
+        let extent = match token.to_ascii_lowercase().as_str() {
+            "circle" if shape.is_none() => {
+                shape = Some(RadialShape::Circle);
+                continue;
+            },
+            "ellipse" if shape.is_none() => {
+                shape = Some(RadialShape::Ellipse);
+                continue;
+            },
+            "closest-side" => RadialSize::ClosestSide,
+            "closest-corner" => RadialSize::ClosestCorner,
+            "farthest-side" => RadialSize::FarthestSide,
+            "farthest-corner" => RadialSize::FarthestCorner,
+            _ => {
+                lengths.push(parse_length(token).ok_or_else(invalid)?);
+                continue;
+            },
+        };
+        
+        if size.replace(extent).is_some() {
+            return Err(invalid());
+        }
+    }
+    
+    let (shape, size) = match (shape, size, lengths.as_slice()) {
+        (shape, size, []) => (shape.unwrap_or_default(), size.unwrap_or_default()),
+        (None | Some(RadialShape::Circle), None, [radius]) => {
+            (RadialShape::Circle, RadialSize::Explicit(*radius, *radius))
+        },
+        (None | Some(RadialShape::Ellipse), None, [x, y]) => {
+            (RadialShape::Ellipse, RadialSize::Explicit(*x, *y))
+        },
+        _ => return Err(invalid()),
+    };
+    
+    Ok(GradientGeometry::Radial { shape, size, position })
+}
+
+/// Parses the starting angle and position of a `conic-gradient()`.
+fn conic_geometry(tokens: &[&str]) -> Result<GradientGeometry, ParseGradientError> {
+    let invalid = || ParseGradientError::InvalidArgument(tokens.join(" "));
+    let (from, position) = split_at_keyword(tokens, "at");
+    let position = position.map_or(Ok(CENTER), parse_position)?;
+    let from_angle = match from {
+        [] => 0.0,
+        [from, angle] if from.eq_ignore_ascii_case("from") => {
+            parse_angle(angle).ok_or_else(invalid)?
+        },
+        _ => return Err(invalid()),
+    };
+    
+    Ok(GradientGeometry::Conic { from_angle, position })
+}
+
+/// The center of the box.
+const CENTER: [CssLength; 2] = [CssLength::Percent(0.5), CssLength::Percent(0.5)];
+
+/// Splits tokens at a keyword, returning the tokens before it and the tokens after it if the 
+/// keyword is present.
+fn split_at_keyword<'a, 'b>(
+    tokens: &'b [&'a str],
+    keyword: &str,
+) -> (&'b [&'a str], Option<&'b [&'a str]>) {
+    match tokens.iter().position(|token| token.eq_ignore_ascii_case(keyword)) {
+        Some(index) => (&tokens[..index], Some(&tokens[index + 1..])),
+        None => (tokens, None),
+    }
+}
+
+/// Parses a position with 1 or 2 values, using keywords, lengths, or percentages.
+fn parse_position(tokens: &[&str]) -> Result<[CssLength; 2], ParseGradientError> {
+    let invalid = || ParseGradientError::InvalidArgument(tokens.join(" "));
+    let keyword = |token: &str| match token.to_ascii_lowercase().as_str() {
+        "left" | "top" => Some(CssLength::Percent(0.0)),
+        "center" => Some(CssLength::Percent(0.5)),
+        "right" | "bottom" => Some(CssLength::Percent(1.0)),
+        _ => None,
+    };
+    let is_vertical = |token: &str| {
+        token.eq_ignore_ascii_case("top") || token.eq_ignore_ascii_case("bottom")
+    };
+    let is_horizontal = |token: &str| {
+        token.eq_ignore_ascii_case("left") || token.eq_ignore_ascii_case("right")
+    };
+    let value = |token: &str| keyword(token).or_else(|| parse_length(token)).ok_or_else(invalid);
+    
+    match tokens {
+        [token] if is_vertical(token) => Ok([CssLength::Percent(0.5), value(token)?]),
+        [token] => Ok([value(token)?, CssLength::Percent(0.5)]),
+        [first, second] if is_vertical(first) || is_horizontal(second) => {
+            Ok([value(second)?, value(first)?])
+        },
+        [first, second] => Ok([value(first)?, value(second)?]),
+        _ => Err(invalid()),
+    }
+}
+
+/// Parses the color space and optional hue interpolation method following the `in` keyword, 
+/// returning the space and the number of tokens it spans. Only the shorter hue method is 
+/// supported, as that is how [`InterpolationSpace::Hsl`] interpolates hue.
+fn interpolation_method(
+    tokens: &[&str],
+) -> Result<(InterpolationSpace, usize), ParseGradientError> {
+    let invalid = |len: usize| {
+        let clause = tokens.get(..len).unwrap_or(tokens).join(" ");
+        
+        ParseGradientError::InvalidArgument(format!("in {clause}").trim_end().into())
+    };
+    let space = match tokens.first().map(|token| token.to_ascii_lowercase()).as_deref() {
+        Some("srgb") => InterpolationSpace::Srgb,
+        Some("srgb-linear") => InterpolationSpace::LinearSrgb,
+        Some("oklab") => InterpolationSpace::Oklab,
+        Some("hsl") => InterpolationSpace::Hsl,
+        _ => return Err(invalid(1)),
+    };
+    
+    match tokens.get(1..3) {
+        Some([method, hue]) if hue.eq_ignore_ascii_case("hue") => {
+            if space == InterpolationSpace::Hsl && method.eq_ignore_ascii_case("shorter") {
+                Ok((space, 3))
+            } else {
+                Err(invalid(3))
+            }
+        },
+        _ => Ok((space, 1)),
+    }
+}
+
+/// Parses the position of a color stop or color hint as a fraction of the gradient line, or of a 
+/// full turn for conic gradients.
+fn stop_position(s: &str, is_conic: bool) -> Result<f32, ParseGradientError> {
+    let position = match parse_length(s) {
+        Some(CssLength::Percent(percent)) => Some(percent),
+        Some(CssLength::Px(0.0)) => Some(0.0),
+        _ if is_conic => parse_angle(s).map(|degrees| degrees / 360.0),
+        _ => None,
+    };
+    
+    position.ok_or_else(|| ParseGradientError::InvalidPosition(s.into()))
+}
+
+/// Parses a length in pixels or a percentage. Unitless zero is a length.
+fn parse_length(s: &str) -> Option<CssLength> {
+    let length = if let Some(percent) = s.strip_suffix('%') {
+        CssLength::Percent(percent.parse::<f32>().ok()? / 100.0)
+    } else if let Some(px) = s.strip_suffix("px") {
+        CssLength::Px(px.parse().ok()?)
+    } else if s == "0" {
+        CssLength::Px(0.0)
+    } else {
+        return None;
+    };
+    
+    Some(length)
+}
+
+/// Parses an angle into degrees without normalizing it. Unlike hues, angles must have a unit 
+/// unless they are zero.
+fn parse_angle(s: &str) -> Option<f32> {
+    match s.parse::<f32>() {
+        Ok(value) => (value == 0.0).then_some(0.0),
+        Err(_error) => helpers::parse_angle(s),
+    }
+}
+
+/// Splits a string at a separator outside of parentheses, trimming each part and skipping empty 
+/// parts when splitting at whitespace.
+fn split_top_level(s: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+    
+    for (index, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if depth == 0 && (c == separator || separator == ' ' && c.is_whitespace()) => {
+                parts.push(&s[start..index]);
+                start = index + c.len_utf8();
+            },
+            _ => {},
+        }
+    }
+    
+    parts.push(&s[start..]);
+    parts
+        .into_iter()
+        .map(str::trim)
+        .filter(|part| separator != ' ' || !part.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const RED: Color = Color { red: 255, green: 0, blue: 0 };
+    const BLUE: Color = Color { red: 0, green: 0, blue: 255 };
+    
+    fn positions(gradient: &CssGradient) -> Vec<f32> {
+        gradient.gradient.stops().iter().map(|stop| stop.position).collect()
+    }
+    
+    #[test]
+    fn parses_linear_gradients() {
+        let parsed = "linear-gradient(red, rgb(0 0 255) 20%, red, blue)"
+            .parse::<CssGradient>()
+            .unwrap();
+        
+        assert_eq!(parsed.geometry, GradientGeometry::Linear { angle: 180.0 });
+        assert!(!parsed.repeating);
+        assert_eq!(positions(&parsed), vec![0.0, 0.2, 0.6, 1.0]);
+        
+        let parsed = "repeating-linear-gradient(to left top in oklab, red 50%, blue 10% 30%)"
+            .parse::<CssGradient>()
+            .unwrap();
+        
+        assert_eq!(parsed.geometry, GradientGeometry::Linear { angle: 315.0 });
+        assert!(parsed.repeating);
+        assert_eq!(parsed.gradient.interpolation_space(), InterpolationSpace::Oklab);
+        assert_eq!(positions(&parsed), vec![0.5, 0.5, 0.5]);
+        assert_eq!(parsed.gradient.sample(0.6), Some(BLUE));
+    }
+    
+    #[test]
+    fn keeps_negative_positions() {
+        let parsed = "linear-gradient(red -100%, blue 100%)".parse::<CssGradient>().unwrap();
+        
+        assert_eq!(positions(&parsed), vec![-1.0, 1.0]);
+        assert_eq!(parsed.gradient.sample(0.0), Some(Color { red: 128, green: 0, blue: 128 }));
+        
+        let parsed = "linear-gradient(red, blue -50%)".parse::<CssGradient>().unwrap();
+        
+        assert_eq!(positions(&parsed), vec![0.0, 0.0]);
+    }
+    
+    #[test]
+    fn parses_hints() {
+        let parsed = "linear-gradient(0.5turn, red, 25%, blue)".parse::<CssGradient>().unwrap();
+        
+        assert_eq!(parsed.geometry, GradientGeometry::Linear { angle: 180.0 });
+        assert_eq!(parsed.gradient.stops()[0].hint, Some(0.25));
+        assert_eq!(
+            "linear-gradient(red, 25%, 50%, blue)".parse::<CssGradient>(),
+            Err(ParseGradientError::MisplacedHint("50%".into())),
+        );
+        assert_eq!(
+            "linear-gradient(red, blue, 50%)".parse::<CssGradient>(),
+            Err(ParseGradientError::MisplacedHint("50%".into())),
+        );
+    }
+    
+    #[test]
+    fn parses_interpolation_methods() {
+        let parsed = "linear-gradient(in oklab to left, red, blue)".parse::<CssGradient>().unwrap();
+        
+        assert_eq!(parsed.geometry, GradientGeometry::Linear { angle: 270.0 });
+        assert_eq!(parsed.gradient.interpolation_space(), InterpolationSpace::Oklab);
+        
+        let parsed = "radial-gradient(in oklab circle at left, red, blue)"
+            .parse::<CssGradient>()
+            .unwrap();
+        
+        assert_eq!(parsed.geometry, GradientGeometry::Radial {
+            shape: RadialShape::Circle,
+            size: RadialSize::FarthestCorner,
+            position: [CssLength::Percent(0.0), CssLength::Percent(0.5)],
+        });
+        
+        let parsed = "conic-gradient(from 90deg in HSL shorter hue, red, blue)"
+            .parse::<CssGradient>()
+            .unwrap();
+        
+        assert_eq!(parsed.geometry, GradientGeometry::Conic { from_angle: 90.0, position: CENTER });
+        assert_eq!(parsed.gradient.interpolation_space(), InterpolationSpace::Hsl);
+        assert_eq!(
+            "linear-gradient(in hsl longer hue, red, blue)".parse::<CssGradient>(),
+            Err(ParseGradientError::InvalidArgument("in hsl longer hue".into())),
+        );
+        assert_eq!(
+            "linear-gradient(in oklab shorter hue, red, blue)".parse::<CssGradient>(),
+            Err(ParseGradientError::InvalidArgument("in oklab shorter hue".into())),
+        );
+        assert_eq!(
+            "linear-gradient(in lab, red, blue)".parse::<CssGradient>(),
+            Err(ParseGradientError::InvalidArgument("in lab".into())),
+        );
+        assert_eq!(
+            "linear-gradient(in, red, blue)".parse::<CssGradient>(),
+            Err(ParseGradientError::InvalidArgument("in".into())),
+        );
+        assert_eq!(
+            "linear-gradient(in oklab to left in srgb, red, blue)".parse::<CssGradient>(),
+            Err(ParseGradientError::InvalidArgument("to left in srgb".into())),
+        );
+    }
+    
+    #[test]
+    fn parses_radial_gradients() {
+        let parsed = "radial-gradient(red, blue)".parse::<CssGradient>().unwrap();
+        
+        assert_eq!(parsed.geometry, GradientGeometry::Radial {
+            shape: RadialShape::Ellipse,
+            size: RadialSize::FarthestCorner,
+            position: CENTER,
+        });
+        
+        let parsed = "radial-gradient(10px 20% at bottom 10px, red, blue)"
+            .parse::<CssGradient>()
+            .unwrap();
+        
+        assert_eq!(parsed.geometry, GradientGeometry::Radial {
+            shape: RadialShape::Ellipse,
+            size: RadialSize::Explicit(CssLength::Px(10.0), CssLength::Percent(0.2)),
+            position: [CssLength::Px(10.0), CssLength::Percent(1.0)],
+        });
+        assert!("radial-gradient(circle 10px 20px, red, blue)".parse::<CssGradient>().is_err());
+    }
+    
+    #[test]
+    fn parses_conic_gradients() {
+        let parsed = "conic-gradient(from 0.25turn at left, red 90deg, blue)"
+            .parse::<CssGradient>()
+            .unwrap();
+        
+        assert_eq!(parsed.geometry, GradientGeometry::Conic {
+            from_angle: 90.0,
+            position: [CssLength::Percent(0.0), CssLength::Percent(0.5)],
+        });
+        assert_eq!(positions(&parsed), vec![0.25, 1.0]);
+        assert_eq!(parsed.gradient.sample(0.0), Some(RED));
+    }
+    
+    #[test]
+    fn rejects_invalid_gradients() {
+        assert_eq!(
+            "linear-gradient(red)".parse::<CssGradient>(),
+            Err(ParseGradientError::TooFewStops),
+        );
+        assert_eq!(
+            "linear-gradient(red 10px, blue)".parse::<CssGradient>(),
+            Err(ParseGradientError::InvalidPosition("10px".into())),
+        );
+        assert_eq!(
+            "diamond-gradient(red, blue)".parse::<CssGradient>(),
+            Err(ParseGradientError::UnsupportedFunction("diamond-gradient".into())),
+        );
+        assert_eq!("red".parse::<CssGradient>(), Err(ParseGradientError::InvalidSyntax));
+        assert_eq!(
+            "linear-gradient(45, red, blue)".parse::<CssGradient>(),
+            Err(ParseGradientError::InvalidArgument("45".into())),
+        );
+        assert!(matches!(
+            "linear-gradient(45deg, red, bleu)".parse::<CssGradient>(),
+            Err(ParseGradientError::InvalidColor(_)),
+        ));
+        assert_eq!(
+            "linear-gradient(red, transparent)".parse::<CssGradient>(),
+            Err(ParseGradientError::UnsupportedAlpha("transparent".into())),
+        );
+        assert_eq!(
+            "linear-gradient(red, rgb(0 0 255 / 50%) 50%)".parse::<CssGradient>(),
+            Err(ParseGradientError::UnsupportedAlpha("rgb(0 0 255 / 50%)".into())),
+        );
+        assert!("linear-gradient(red, rgb(0 0 255 / 1))".parse::<CssGradient>().is_ok());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,234 @@
+//! Database integrations. Colors are stored either as TEXT in hexadecimal, e.g. `#FF0000`, or as 
+//! an INTEGER decimal value, e.g. `16711680`.
+
+use crate::Color;
+use crate::color::DecimalValue;
+
+/// The largest decimal value of a color, `#FFFFFF`.
+const MAX_DECIMAL: i64 = 0xFFFFFF;
+
+/// Converts an integer column value into a color.
+fn color_from_integer(value: i64) -> Result<Color, String> {
+    if !(0..=MAX_DECIMAL).contains(&value) {
+        return Err(format!("Integer {value} is out of range for a color."));
+    }
+    
+    Ok(Color::from_decimal(value as DecimalValue))
+}
+
+#[cfg(feature = "sqlx")]
+mod sqlx_impls {
+    use super::color_from_integer;
+    use crate::Color;
+    use sqlx::{Database, Decode, Encode, Type, ValueRef};
+    use sqlx::encode::IsNull;
+    use sqlx::error::BoxDynError;
+    
+    /// Encodes as TEXT. TEXT and INTEGER columns are both accepted when decoding.
+    impl<DB> Type<DB> for Color
+    where
+        DB: Database,
+        String: Type<DB>,
+        i32: Type<DB>,
+        i64: Type<DB>,
+    {
+        fn type_info() -> DB::TypeInfo {
+            <String as Type<DB>>::type_info()
+        }
+        
+        fn compatible(ty: &DB::TypeInfo) -> bool {
+            <String as Type<DB>>::compatible(ty)
+                || <i32 as Type<DB>>::compatible(ty)
+                || <i64 as Type<DB>>::compatible(ty)
+        }
+    }
+    
+    /// Encodes as a hexadecimal color string.
+    impl<'q, DB> Encode<'q, DB> for Color
+    where
+        DB: Database,
+        String: Encode<'q, DB>,
+    {
+        fn encode_by_ref(
+            &self,
+            buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+        ) -> Result<IsNull, BoxDynError> {
+            self.to_hex_string().encode(buf)
+        }
+    }
+    
+    /// Decodes from any supported color string in a TEXT column, or from a decimal value in an 
+    /// INTEGER column.
+    impl<'r, DB> Decode<'r, DB> for Color
+    where
+        DB: Database,
+        &'r str: Decode<'r, DB>,
+        i32: Type<DB> + Decode<'r, DB>,
+        i64: Type<DB> + Decode<'r, DB>,
+    {
+        fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+            let type_info = value.type_info().into_owned();
+            
+            if <i32 as Type<DB>>::compatible(&type_info) {
+                Ok(color_from_integer(<i32 as Decode<DB>>::decode(value)?.into())?)
+            } else if <i64 as Type<DB>>::compatible(&type_info) {
+                Ok(color_from_integer(<i64 as Decode<DB>>::decode(value)?)?)
+            } else {
+                Ok(<&str as Decode<DB>>::decode(value)?.parse::<Color>()?)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+mod postgres_impls {
+    use super::color_from_integer;
+    use crate::Color;
+    use bytes::BytesMut;
+    use postgres_types::{to_sql_checked, FromSql, IsNull, ToSql, Type};
+    use std::error::Error;
+    
+    /// Writes a hexadecimal color string to TEXT columns, or a decimal value to INTEGER and BIGINT 
+    /// columns.
+    impl ToSql for Color {
+        fn to_sql(
+            &self,
+            ty: &Type,
+            out: &mut BytesMut,
+        ) -> Result<IsNull, Box<dyn Error + Sync + Send>> {
+            match *ty {
+                Type::INT4 => (self.to_decimal() as i32).to_sql(ty, out),
+                Type::INT8 => i64::from(self.to_decimal()).to_sql(ty, out),
+                _ => self.to_hex_string().to_sql(ty, out),
+            }
+        }
+        
+        fn accepts(ty: &Type) -> bool {
+            matches!(*ty, Type::INT4 | Type::INT8) || <String as ToSql>::accepts(ty)
+        }
+        
+        to_sql_checked!();
+    }
+    
+    /// Reads any supported color string from TEXT columns, or a decimal value from INTEGER and 
+    /// BIGINT columns.
+    impl<'a> FromSql<'a> for Color {
+        fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+            match *ty {
+                Type::INT4 => Ok(color_from_integer(i32::from_sql(ty, raw)?.into())?),
+                Type::INT8 => Ok(color_from_integer(i64::from_sql(ty, raw)?)?),
+                _ => Ok(<&str>::from_sql(ty, raw)?.parse::<Color>()?),
+            }
+        }
+        
+        fn accepts(ty: &Type) -> bool {
+            matches!(*ty, Type::INT4 | Type::INT8) || <&str as FromSql>::accepts(ty)
+        }
+    }
+}
+
+#[cfg(feature = "rusqlite")]
+mod rusqlite_impls {
+    use super::color_from_integer;
+    use crate::Color;
+    use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+    
+    /// Writes a hexadecimal color string. Use [`Color::to_decimal`] to store an INTEGER instead.
+    impl ToSql for Color {
+        fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+            Ok(self.to_hex_string().into())
+        }
+    }
+    
+    /// Reads any supported color string from TEXT values, or a decimal value from INTEGER values.
+    impl FromSql for Color {
+        fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+            match value {
+                ValueRef::Integer(value) => {
+                    color_from_integer(value).map_err(|_error| FromSqlError::OutOfRange(value))
+                },
+                ValueRef::Text(_) => value
+                    .as_str()?
+                    .parse()
+                    .map_err(|error| FromSqlError::Other(Box::new(error))),
+                _ => Err(FromSqlError::InvalidType),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn converts_integers() {
+        assert_eq!(color_from_integer(0xFF0000), Ok(Color { red: 255, green: 0, blue: 0 }));
+        assert!(color_from_integer(-1).is_err());
+        assert!(color_from_integer(0x1000000).is_err());
+    }
+    
+    #[cfg(feature = "sqlx")]
+    #[test]
+    fn converts_sqlx_values() {
+        use sqlx::{Connection, Row, SqliteConnection};
+        
+        let color = Color { red: 1, green: 2, blue: 3 };
+        let red = Color { red: 255, green: 0, blue: 0 };
+        let rows = futures_executor::block_on(async {
+            let mut connection = SqliteConnection::connect("sqlite::memory:").await?;
+            
+            sqlx::query("CREATE TABLE colors (text TEXT, integer INTEGER)")
+                .execute(&mut connection)
+                .await?;
+            sqlx::query("INSERT INTO colors VALUES (?1, ?2), ('red', 16711680)")
+                .bind(color)
+                .bind(i64::from(color.to_decimal()))
+                .execute(&mut connection)
+                .await?;
+            sqlx::query("SELECT text, integer FROM colors")
+                .fetch_all(&mut connection)
+                .await
+        })
+            .unwrap();
+        let colors = rows
+            .iter()
+            .map(|row| (row.get::<Color, _>(0), row.get::<Color, _>(1)))
+            .collect::<Vec<_>>();
+        
+        assert_eq!(colors, vec![(color, color), (red, red)]);
+    }
+    
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn converts_postgres_values() {
+        use bytes::BytesMut;
+        use postgres_types::{FromSql, ToSql, Type};
+        
+        let color = Color { red: 255, green: 0, blue: 128 };
+        
+        for ty in [Type::TEXT, Type::VARCHAR, Type::INT4, Type::INT8] {
+            let mut buffer = BytesMut::new();
+            
+            assert!(<Color as ToSql>::accepts(&ty));
+            color.to_sql(&ty, &mut buffer).unwrap();
+            assert_eq!(Color::from_sql(&ty, &buffer).unwrap(), color, "{ty}");
+        }
+        
+        assert!(!<Color as ToSql>::accepts(&Type::BOOL));
+        assert!(Color::from_sql(&Type::TEXT, b"reddish").is_err());
+    }
+    
+    #[cfg(feature = "rusqlite")]
+    #[test]
+    fn converts_rusqlite_values() {
+        let connection = rusqlite::Connection::open_in_memory().unwrap();
+        let color = Color { red: 1, green: 2, blue: 3 };
+        let red = Color { red: 255, green: 0, blue: 0 };
+        let row = connection.query_row("SELECT ?1, 16711680, 'red', 1.5", [color], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get::<_, Color>(3).is_err()))
+        });
+        
+        assert_eq!(row, Ok((color, red, red, true)));
+    }
+}
\ No newline at end of file
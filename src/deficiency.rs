@@ -0,0 +1,75 @@
+/// A color vision deficiency, for simulating how colors appear to people with color blindness 
+/// using [`Color::simulate_deficiency`](crate::Color::simulate_deficiency).
+/// 
+/// Deficiencies are simulated at full severity using the matrices from Machado, Oliveira, and 
+/// Fernandes (2009), applied to linear-light sRGB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Deficiency {
+    /// No functioning long-wavelength (red) cones, confusing reds and greens and making reds 
+    /// appear darker.
+    Protanopia,
+    /// No functioning medium-wavelength (green) cones, confusing reds and greens.
+    Deuteranopia,
+    /// No functioning short-wavelength (blue) cones, confusing blues and greens and yellows and 
+    /// pinks.
+    Tritanopia,
+    /// No color vision, seeing only luminance.
+    Achromatopsia,
+}
+
+impl Deficiency {
+    /// Every deficiency, for checking colors against each of them.
+    pub const ALL: [Self; 4] = [
+        Self::Protanopia,
+        Self::Deuteranopia,
+        Self::Tritanopia,
+        Self::Achromatopsia,
+    ];
+    
+    /// Gets the matrix that simulates this deficiency in linear-light sRGB.
+    pub(crate) fn matrix(&self) -> [[f32; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.152286, 1.052583, -0.204868],
+                [0.114503, 0.786281, 0.099216],
+                [-0.003882, -0.048116, 1.051998],
+            ],
+            Self::Deuteranopia => [
+                [0.367322, 0.860646, -0.227968],
+                [0.280085, 0.672501, 0.047413],
+                [-0.011820, 0.042940, 0.968881],
+            ],
+            Self::Tritanopia => [
+                [1.255528, -0.076749, -0.178779],
+                [-0.078411, 0.930809, 0.147602],
+                [0.004733, 0.691367, 0.303900],
+            ],
+            // The relative luminance of sRGB.
+            Self::Achromatopsia => [[0.2126, 0.7152, 0.0722]; 3],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Color;
+    use super::*;
+    
+    #[test]
+    fn simulates_machado_reference_values() {
+        let red = Color { red: 255, green: 0, blue: 0 };
+        let orange = Color { red: 255, green: 128, blue: 0 };
+        // The published full-severity matrices applied to linear-light sRGB.
+        let cases = [
+            (Deficiency::Protanopia, [109, 95, 0], [166, 145, 0]),
+            (Deficiency::Deuteranopia, [163, 144, 0], [196, 174, 0]),
+            (Deficiency::Tritanopia, [255, 0, 15], [255, 98, 109]),
+            (Deficiency::Achromatopsia, [127, 127, 127], [163, 163, 163]),
+        ];
+        
+        for (deficiency, simulated_red, simulated_orange) in cases {
+            assert_eq!(red.simulate_deficiency(deficiency).to_array(), simulated_red);
+            assert_eq!(orange.simulate_deficiency(deficiency).to_array(), simulated_orange);
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{Color, HSLColor};
+
+/// The golden angle in degrees, 360 divided by the golden ratio squared.
+const GOLDEN_ANGLE: f32 = 137.507_76;
+
+/// Creates an infinite iterator of visually distinct colors, for assigning colors to chart series. 
+/// Each hue is a golden angle from the previous hue, which spreads hues as evenly as possible no 
+/// matter how many colors are taken. Saturation defaults to 0.65 and lightness to 0.5.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{distinct_colors, Color};
+/// 
+/// let colors = distinct_colors().lightness(0.4).take(3).collect::<Vec<_>>();
+/// 
+/// assert_eq!(colors[0], Color { red: 168, green: 36, blue: 36 });
+/// assert_ne!(colors[1], colors[2]);
+/// ```
+pub fn distinct_colors() -> DistinctColors {
+    DistinctColors {
+        hue: 0.0,
+        saturation: 0.65,
+        lightness: 0.5,
+    }
+}
+
+/// An infinite iterator of visually distinct colors. Created using [`distinct_colors`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DistinctColors {
+    hue: f32,
+    saturation: f32,
+    lightness: f32,
+}
+
+impl DistinctColors {
+    /// Sets the hue of the first color. The value is wrapped between 0.0 and 360.0.
+    pub fn start_hue(self, hue: f32) -> Self {
+        Self { hue: hue.rem_euclid(360.0), ..self }
+    }
+    
+    /// Sets the saturation of every color. The value is clamped between 0.0 and 1.0.
+    pub fn saturation(self, saturation: f32) -> Self {
+        Self { saturation: saturation.clamp(0.0, 1.0), ..self }
+    }
+    
+    /// Sets the lightness of every color. The value is clamped between 0.0 and 1.0.
+    pub fn lightness(self, lightness: f32) -> Self {
+        Self { lightness: lightness.clamp(0.0, 1.0), ..self }
+    }
+}
+
+impl Iterator for DistinctColors {
+    type Item = Color;
+    
+    fn next(&mut self) -> Option<Self::Item> {
+        let color = HSLColor {
+            hue: self.hue,
+            saturation: self.saturation,
+            lightness: self.lightness,
+        };
+        
+        self.hue = (self.hue + GOLDEN_ANGLE).rem_euclid(360.0);
+        
+        Some(color.into())
+    }
+    
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn spreads_hues() {
+        let hues = distinct_colors()
+            .take(10)
+            .map(|color| HSLColor::from(color).hue)
+            .collect::<Vec<_>>();
+        
+        for (i, a) in hues.iter().enumerate() {
+            for b in &hues[i + 1..] {
+                let difference = (a - b).abs();
+                
+                assert!(difference.min(360.0 - difference) > 10.0, "{a} {b}");
+            }
+        }
+    }
+}
\ No newline at end of file
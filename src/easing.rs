@@ -0,0 +1,97 @@
+/// An easing function for warping the progress of a transition, matching the CSS 
+/// `<easing-function>` keywords and `cubic-bezier()`.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::Easing;
+/// 
+/// assert_eq!(Easing::Linear.apply(0.25), 0.25);
+/// assert!(Easing::EaseIn.apply(0.25) < 0.25);
+/// assert!(Easing::EaseOut.apply(0.25) > 0.25);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Easing {
+    /// No easing.
+    #[default]
+    Linear,
+    /// The CSS `ease` keyword, equivalent to `cubic-bezier(0.25, 0.1, 0.25, 1)`.
+    Ease,
+    /// The CSS `ease-in` keyword, equivalent to `cubic-bezier(0.42, 0, 1, 1)`.
+    EaseIn,
+    /// The CSS `ease-out` keyword, equivalent to `cubic-bezier(0, 0, 0.58, 1)`.
+    EaseOut,
+    /// The CSS `ease-in-out` keyword, equivalent to `cubic-bezier(0.42, 0, 0.58, 1)`.
+    EaseInOut,
+    /// A cubic Bézier curve with the control points `(x1, y1)` and `(x2, y2)`, as in CSS 
+    /// `cubic-bezier(x1, y1, x2, y2)`. The x values are clamped between 0.0 and 1.0.
+    CubicBezier(f32, f32, f32, f32),
+}
+
+impl Easing {
+    /// Applies this easing function to `t`, which is clamped between 0.0 and 1.0.
+    pub fn apply(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        
+        match *self {
+            Self::Linear => t,
+            Self::Ease => cubic_bezier(0.25, 0.1, 0.25, 1.0, t),
+            Self::EaseIn => cubic_bezier(0.42, 0.0, 1.0, 1.0, t),
+            Self::EaseOut => cubic_bezier(0.0, 0.0, 0.58, 1.0, t),
+            Self::EaseInOut => cubic_bezier(0.42, 0.0, 0.58, 1.0, t),
+            Self::CubicBezier(x1, y1, x2, y2) => {
+                cubic_bezier(x1.clamp(0.0, 1.0), y1, x2.clamp(0.0, 1.0), y2, t)
+            },
+        }
+    }
+}
+
+/// Evaluates a cubic Bézier easing curve at `x`, by solving for the curve parameter that gives 
+/// `x` and returning the y value at that parameter.
+fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32, x: f32) -> f32 {
+    // One dimension of a Bézier curve starting at 0.0 and ending at 1.0.
+    let curve = |a: f32, b: f32, s: f32| {
+        let inverse = 1.0 - s;
+        
+        3.0 * inverse * inverse * s * a + 3.0 * inverse * s * s * b + s * s * s
+    };
+    // Since the x values are between 0.0 and 1.0, x increases with s and bisection always
+    // converges.
+    let mut low = 0.0;
+    let mut high = 1.0;
+    let mut s = x;
+    
+    for _ in 0..32 {
+        let found = curve(x1, x2, s);
+        
+        if (found - x).abs() < 1e-6 {
+            break;
+        }
+        
+        if found < x {
+            low = s;
+        } else {
+            high = s;
+        }
+        
+        s = (low + high) / 2.0;
+    }
+    
+    curve(y1, y2, s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn applies_easing() {
+        for easing in [Easing::Ease, Easing::EaseIn, Easing::EaseOut, Easing::EaseInOut] {
+            assert_eq!(easing.apply(0.0), 0.0);
+            assert!((easing.apply(1.0) - 1.0).abs() < 1e-5);
+        }
+        
+        assert!((Easing::EaseInOut.apply(0.5) - 0.5).abs() < 1e-5);
+        assert!((Easing::CubicBezier(0.0, 0.0, 1.0, 1.0).apply(0.3) - 0.3).abs() < 1e-5);
+        assert_eq!(Easing::Linear.apply(2.0), 1.0);
+    }
+}
\ No newline at end of file
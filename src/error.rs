@@ -0,0 +1,289 @@
+use std::fmt;
+use std::ops::Range;
+
+/// An error returned when a color string cannot be parsed. Errors caused by a specific part of 
+/// the string carry the byte range of that part, which can be retrieved using 
+/// [`ParseColorError::span`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The string is empty.
+    Empty,
+    /// A hexadecimal color string is missing the hash symbol.
+    MissingHash,
+    /// A hexadecimal color string does not have 3, 4, 6, or 8 digits.
+    InvalidHexLength {
+        /// The number of digits found.
+        len: usize,
+        /// The byte range of the digits within the string.
+        span: Range<usize>,
+    },
+    /// A hexadecimal color string contains a character that is not a hexadecimal digit.
+    InvalidDigit {
+        /// The invalid character.
+        digit: char,
+        /// The byte range of the character within the string.
+        span: Range<usize>,
+    },
+    /// A byte string is not valid UTF-8.
+    InvalidUtf8 {
+        /// The byte range of the invalid sequence within the byte string.
+        span: Range<usize>,
+    },
+    /// The string does not follow the syntax of the color format.
+    InvalidSyntax,
+    /// A color function is missing its closing parenthesis.
+    MissingParenthesis,
+    /// A color function has the wrong number of components.
+    InvalidComponentCount {
+        /// The number of components expected.
+        expected: usize,
+        /// The number of components found.
+        found: usize,
+    },
+    /// A component could not be parsed as a number, percentage, or angle.
+    InvalidComponent {
+        /// The component.
+        component: String,
+        /// The byte range of the component within the string.
+        span: Range<usize>,
+    },
+    /// A component is outside of its allowed range. This is only returned by 
+    /// [`Color::parse`](crate::Color::parse), as other methods clamp out of range values as per 
+    /// the CSS specification.
+    ChannelOutOfRange {
+        /// The component.
+        component: String,
+        /// The byte range of the component within the string.
+        span: Range<usize>,
+    },
+    /// The color function is not supported.
+    UnsupportedFunction {
+        /// The name of the function.
+        name: String,
+        /// The byte range of the name within the string.
+        span: Range<usize>,
+    },
+    /// The color space given to the `color()` function is not supported.
+    UnsupportedColorSpace {
+        /// The name of the color space.
+        name: String,
+        /// The byte range of the name within the string.
+        span: Range<usize>,
+    },
+    /// The string is not a known color name. Contains the string.
+    UnknownName(String),
+}
+
+impl ParseColorError {
+    /// Gets the byte range of the part of the string that caused the error, if the error was 
+    /// caused by a specific part of the string.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Color;
+    /// 
+    /// let s = "rgb(255, 0, 999)";
+    /// let error = Color::parse(s).unwrap_err();
+    /// 
+    /// assert_eq!(error.span(), Some(12..15));
+    /// assert_eq!(&s[error.span().unwrap()], "999");
+    /// ```
+    pub fn span(&self) -> Option<Range<usize>> {
+        match self {
+            Self::InvalidHexLength { span, .. } |
+            Self::InvalidDigit { span, .. } |
+            Self::InvalidUtf8 { span } |
+            Self::InvalidComponent { span, .. } |
+            Self::ChannelOutOfRange { span, .. } |
+            Self::UnsupportedFunction { span, .. } |
+            Self::UnsupportedColorSpace { span, .. } => Some(span.clone()),
+            _ => None,
+        }
+    }
+    
+    /// Moves the span of this error by `offset` bytes, for errors in a string that was parsed from 
+    /// within a larger string.
+    pub(crate) fn offset(mut self, offset: usize) -> Self {
+        match &mut self {
+            Self::InvalidHexLength { span, .. } |
+            Self::InvalidDigit { span, .. } |
+            Self::InvalidUtf8 { span } |
+            Self::InvalidComponent { span, .. } |
+            Self::ChannelOutOfRange { span, .. } |
+            Self::UnsupportedFunction { span, .. } |
+            Self::UnsupportedColorSpace { span, .. } => {
+                *span = span.start + offset..span.end + offset;
+            },
+            _ => {},
+        }
+        
+        self
+    }
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "Color string is empty."),
+            Self::MissingHash => write!(f, "Hexadecimal color string must start with \"#\"."),
+            Self::InvalidHexLength { len, .. } => write!(
+                f,
+                "Hexadecimal color string must have 3, 4, 6, or 8 digits, found {len}.",
+            ),
+            Self::InvalidDigit { digit, .. } => write!(f, "Invalid hexadecimal digit \"{digit}\"."),
+            Self::InvalidUtf8 { .. } => write!(f, "Color string is not valid UTF-8."),
+            Self::InvalidSyntax => write!(f, "Not a valid color string."),
+            Self::MissingParenthesis => write!(f, "Color function is missing \")\"."),
+            Self::InvalidComponentCount { expected, found } => write!(
+                f,
+                "Expected {expected} components, found {found}.",
+            ),
+            Self::InvalidComponent { component, .. } => write!(
+                f,
+                "Invalid component \"{component}\".",
+            ),
+            Self::ChannelOutOfRange { component, .. } => write!(
+                f,
+                "Component \"{component}\" is out of range.",
+            ),
+            Self::UnsupportedFunction { name, .. } => write!(
+                f,
+                "Unsupported color function \"{name}\".",
+            ),
+            Self::UnsupportedColorSpace { name, .. } => write!(
+                f,
+                "Unsupported color space \"{name}\".",
+            ),
+            Self::UnknownName(name) => write!(f, "Unknown color name \"{name}\"."),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// An error returned when a `.cube` lookup table cannot be parsed. Line numbers start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseCubeError {
+    /// The file does not contain a `LUT_3D_SIZE` keyword.
+    MissingSize,
+    /// The `LUT_3D_SIZE` keyword does not have a size between 2 and 256.
+    InvalidSize {
+        /// The line of the keyword.
+        line: usize,
+    },
+    /// The domain maximum is not greater than the domain minimum for every channel.
+    InvalidDomain,
+    /// The file uses a keyword that is not supported, such as `LUT_1D_SIZE`.
+    UnsupportedKeyword {
+        /// The keyword.
+        keyword: String,
+        /// The line of the keyword.
+        line: usize,
+    },
+    /// A line is neither a keyword nor an entry of 3 finite numbers.
+    InvalidLine {
+        /// The line.
+        line: usize,
+    },
+    /// The number of entries does not match the size of the table.
+    InvalidEntryCount {
+        /// The number of entries expected.
+        expected: usize,
+        /// The number of entries found.
+        found: usize,
+    },
+}
+
+impl fmt::Display for ParseCubeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingSize => write!(f, "Missing LUT_3D_SIZE."),
+            Self::InvalidSize { line } => write!(
+                f,
+                "LUT_3D_SIZE must be between 2 and 256 on line {line}.",
+            ),
+            Self::InvalidDomain => write!(f, "DOMAIN_MAX must be greater than DOMAIN_MIN."),
+            Self::UnsupportedKeyword { keyword, line } => write!(
+                f,
+                "Unsupported keyword \"{keyword}\" on line {line}.",
+            ),
+            Self::InvalidLine { line } => write!(f, "Invalid entry on line {line}."),
+            Self::InvalidEntryCount { expected, found } => write!(
+                f,
+                "Expected {expected} entries, found {found}.",
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseCubeError {}
+
+/// An error returned when a CSS gradient string cannot be parsed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseGradientError {
+    /// The string is not a gradient function, or is missing a parenthesis.
+    InvalidSyntax,
+    /// The gradient function is not supported. Contains the name of the function.
+    UnsupportedFunction(String),
+    /// The shape, size, position, angle, or color space of the gradient could not be parsed. 
+    /// Contains the argument.
+    InvalidArgument(String),
+    /// A color stop could not be parsed. Contains the error from parsing the color.
+    InvalidColor(ParseColorError),
+    /// A color stop is not fully opaque. Alpha is not supported by [`Gradient`](crate::Gradient), 
+    /// so these stops are rejected rather than drawn opaque. Contains the color.
+    UnsupportedAlpha(String),
+    /// The position of a color stop or color hint could not be parsed. Only percentages are 
+    /// supported, plus angles for `conic-gradient()`, as lengths depend on the size of the box 
+    /// the gradient is drawn in. Contains the position.
+    InvalidPosition(String),
+    /// A color hint is not between two color stops. Contains the position of the hint.
+    MisplacedHint(String),
+    /// The gradient has fewer than 2 color stops.
+    TooFewStops,
+}
+
+impl fmt::Display for ParseGradientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidSyntax => write!(f, "Not a valid gradient string."),
+            Self::UnsupportedFunction(name) => write!(
+                f,
+                "Unsupported gradient function \"{name}\".",
+            ),
+            Self::InvalidArgument(argument) => write!(
+                f,
+                "Invalid gradient argument \"{argument}\".",
+            ),
+            Self::InvalidColor(error) => write!(f, "Invalid color stop: {error}"),
+            Self::UnsupportedAlpha(color) => write!(
+                f,
+                "Color stop \"{color}\" is not opaque, which gradients do not support.",
+            ),
+            Self::InvalidPosition(position) => write!(
+                f,
+                "Invalid color stop position \"{position}\".",
+            ),
+            Self::MisplacedHint(position) => write!(
+                f,
+                "Color hint \"{position}\" must be between two color stops.",
+            ),
+            Self::TooFewStops => write!(f, "Gradient must have at least 2 color stops."),
+        }
+    }
+}
+
+impl std::error::Error for ParseGradientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidColor(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseColorError> for ParseGradientError {
+    fn from(error: ParseColorError) -> Self {
+        Self::InvalidColor(error)
+    }
+}
\ No newline at end of file
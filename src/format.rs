@@ -0,0 +1,297 @@
+use crate::color::Alpha;
+use crate::helpers;
+use crate::{Color, HSLColor};
+use std::fmt;
+
+/// The notation used by [`Color::to_css`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Notation {
+    /// A hexadecimal color string, e.g. `#FF0000`.
+    #[default]
+    Hex,
+    /// An rgb color string, e.g. `rgb(255 0 0)`.
+    Rgb,
+    /// An hsl color string, e.g. `hsl(0 100% 50%)`.
+    Hsl,
+}
+
+/// Options for formatting a color as a CSS color string using [`Color::to_css`].
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, FormatOptions, Notation};
+/// 
+/// let options = FormatOptions::new()
+///     .notation(Notation::Rgb)
+///     .legacy(true)
+///     .alpha(Some(0.5));
+/// 
+/// assert_eq!(Color { red: 255, green: 0, blue: 0 }.to_css(options), "rgba(255, 0, 0, 0.5)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FormatOptions {
+    /// The notation to use. Defaults to [`Notation::Hex`].
+    pub notation: Notation,
+    /// Whether to use the legacy comma-separated syntax for functions, e.g. `rgb(255, 0, 0)`, 
+    /// rather than the space-separated syntax, e.g. `rgb(255 0 0)`. Colors with alpha use the 
+    /// `rgba` and `hsla` functions with the legacy syntax. Defaults to `false`.
+    pub legacy: bool,
+    /// The alpha value to include, if any. Defaults to `None`.
+    pub alpha: Option<Alpha>,
+    /// Whether to format red, green, and blue values as percentages, e.g. `rgb(100% 0% 0%)`. 
+    /// Only used by [`Notation::Rgb`]. Defaults to `false`.
+    pub percentages: bool,
+    /// Whether to use uppercase hexadecimal digits. Only used by [`Notation::Hex`]. Defaults to 
+    /// `true`, matching [`Color::to_hex_string`].
+    pub uppercase: bool,
+}
+
+impl FormatOptions {
+    /// Creates new format options. This is equivalent to [`FormatOptions::default()`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Sets the notation.
+    pub fn notation(self, notation: Notation) -> Self {
+        Self { notation, ..self }
+    }
+    
+    /// Sets whether to use the legacy comma-separated syntax.
+    pub fn legacy(self, legacy: bool) -> Self {
+        Self { legacy, ..self }
+    }
+    
+    /// Sets the alpha value to include, if any. The value is clamped between 0.0 and 1.0.
+    pub fn alpha(self, alpha: Option<Alpha>) -> Self {
+        let alpha = alpha.map(|alpha| alpha.clamp(0.0, 1.0));
+        
+        Self { alpha, ..self }
+    }
+    
+    /// Sets whether to format red, green, and blue values as percentages.
+    pub fn percentages(self, percentages: bool) -> Self {
+        Self { percentages, ..self }
+    }
+    
+    /// Sets whether to use uppercase hexadecimal digits.
+    pub fn uppercase(self, uppercase: bool) -> Self {
+        Self { uppercase, ..self }
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            notation: Notation::Hex,
+            legacy: false,
+            alpha: None,
+            percentages: false,
+            uppercase: true,
+        }
+    }
+}
+
+/// Formats a color as a CSS color string.
+pub fn to_css(color: &Color, options: FormatOptions) -> String {
+    match options.notation {
+        Notation::Hex => {
+            let hex = match options.alpha {
+                Some(alpha) => color.to_hex_alpha(alpha),
+                None => color.to_hex_string(),
+            };
+            
+            if options.uppercase {
+                hex
+            } else {
+                hex.to_ascii_lowercase()
+            }
+        },
+        Notation::Rgb => {
+            let components = color.to_array().map(|value| if options.percentages {
+                format!("{}%", helpers::float_to_percent(value as f32 / 255.0))
+            } else {
+                value.to_string()
+            });
+            
+            function("rgb", components, options)
+        },
+        Notation::Hsl => {
+            let hsl = HSLColor::from(color);
+            let components = [
+                (hsl.hue.round() as i32).to_string(),
+                format!("{}%", helpers::float_to_percent(hsl.saturation)),
+                format!("{}%", helpers::float_to_percent(hsl.lightness)),
+            ];
+            
+            function("hsl", components, options)
+        },
+    }
+}
+
+/// Formats the components of a color function, e.g. `rgb(255 0 0 / 0.5)`.
+fn function(name: &str, [a, b, c]: [String; 3], options: FormatOptions) -> String {
+    match (options.legacy, options.alpha) {
+        (true, Some(alpha)) => format!("{name}a({a}, {b}, {c}, {alpha})"),
+        (true, None) => format!("{name}({a}, {b}, {c})"),
+        (false, Some(alpha)) => format!("{name}({a} {b} {c} / {alpha})"),
+        (false, None) => format!("{name}({a} {b} {c})"),
+    }
+}
+
+/// Formats CSS custom properties as a `:root` block, with one declaration per line.
+pub fn css_root<I, N>(properties: I) -> String
+where
+    I: IntoIterator<Item = (N, Color)>,
+    N: fmt::Display,
+{
+    let mut s = String::from(":root {\n");
+    
+    for (name, color) in properties {
+        s.push_str(&format!("  --{name}: {};\n", color.display_hex()));
+    }
+    
+    s.push('}');
+    s
+}
+
+/// Escapes the characters that cannot appear in XML attribute values or text.
+pub fn escape_xml(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    
+    escaped
+}
+
+/// Displays a color as a hexadecimal color string without allocating. Created using 
+/// [`Color::display_hex`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisplayHex(pub(crate) Color);
+
+impl fmt::Display for DisplayHex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Displays a color as an rgb color string without allocating. Created using 
+/// [`Color::display_rgb`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisplayRgb(pub(crate) Color);
+
+impl fmt::Display for DisplayRgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Color { red, green, blue } = self.0;
+        
+        write!(f, "rgb({red} {green} {blue})")
+    }
+}
+
+/// Displays a color with alpha as an rgba color string without allocating. Created using 
+/// [`Color::display_rgba`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DisplayRgba(pub(crate) Color, pub(crate) Alpha);
+
+impl fmt::Display for DisplayRgba {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(Color { red, green, blue }, alpha) = *self;
+        
+        write!(f, "rgba({red} {green} {blue} {})", alpha.clamp(0.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn formats_hex() {
+        let color = Color { red: 255, green: 0, blue: 171 };
+        
+        assert_eq!(color.to_css(FormatOptions::new()), "#FF00AB");
+        assert_eq!(color.to_css(FormatOptions::new().uppercase(false)), "#ff00ab");
+        assert_eq!(color.to_css(FormatOptions::new().alpha(Some(0.5))), "#FF00AB80");
+    }
+    
+    #[test]
+    fn formats_rgb() {
+        let color = Color { red: 255, green: 0, blue: 128 };
+        let options = FormatOptions::new().notation(Notation::Rgb);
+        
+        assert_eq!(color.to_css(options), "rgb(255 0 128)");
+        assert_eq!(color.to_css(options.legacy(true)), "rgb(255, 0, 128)");
+        assert_eq!(color.to_css(options.alpha(Some(0.5))), "rgb(255 0 128 / 0.5)");
+        assert_eq!(
+            color.to_css(options.legacy(true).alpha(Some(0.5))),
+            "rgba(255, 0, 128, 0.5)",
+        );
+        assert_eq!(
+            color.to_css(options.percentages(true)),
+            "rgb(100% 0% 50.196%)",
+        );
+    }
+    
+    #[test]
+    fn formats_hsl() {
+        let color = Color { red: 255, green: 0, blue: 0 };
+        let options = FormatOptions::new().notation(Notation::Hsl);
+        
+        assert_eq!(color.to_css(options), "hsl(0 100% 50%)");
+        assert_eq!(color.to_css(options.alpha(Some(0.25))), "hsl(0 100% 50% / 0.25)");
+        assert_eq!(
+            color.to_css(options.legacy(true).alpha(Some(0.25))),
+            "hsla(0, 100%, 50%, 0.25)",
+        );
+    }
+    
+    #[test]
+    fn displays_without_allocating() {
+        use std::fmt::Write;
+        
+        let color = Color { red: 255, green: 0, blue: 128 };
+        let mut buffer = String::with_capacity(64);
+        
+        write!(buffer, "{} {}", color.display_hex(), color.display_rgb()).unwrap();
+        write!(buffer, " {}", color.display_rgba(2.0)).unwrap();
+        
+        assert_eq!(buffer, "#FF0080 rgb(255 0 128) rgba(255 0 128 1)");
+    }
+    
+    #[test]
+    fn formatted_strings_parse() {
+        // hsl(320 60% 50%), which HSL notation can represent exactly.
+        let color = Color { red: 204, green: 51, blue: 153 };
+        
+        for notation in [Notation::Hex, Notation::Rgb, Notation::Hsl] {
+            for legacy in [false, true] {
+                for percentages in [false, true] {
+                    for alpha in [None, Some(0.0), Some(1.0), Some(0.4)] {
+                        let options = FormatOptions {
+                            notation,
+                            legacy,
+                            alpha,
+                            percentages,
+                            uppercase: false,
+                        };
+                        let s = color.to_css(options);
+                        let (parsed, parsed_alpha) = crate::parse::color(&s, true)
+                            .unwrap_or_else(|error| panic!("{s}: {error}"));
+                        
+                        assert_eq!(Color::from(parsed), color, "{s}");
+                        assert!((parsed_alpha - alpha.unwrap_or(1.0)).abs() < 0.002, "{s}");
+                    }
+                }
+            }
+        }
+    }
+}
\ No newline at end of file
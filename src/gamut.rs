@@ -0,0 +1,56 @@
+use crate::helpers::conversions;
+
+/// How colors outside of the sRGB gamut are brought into it when converting from a wider color 
+/// space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum GamutMapping {
+    /// Clamps each value. This is fast but can shift the hue and lightness of saturated colors.
+    #[default]
+    Clip,
+    /// Reduces chroma in Oklch until the color is in gamut, as in the CSS Color 4 gamut mapping 
+    /// algorithm. This preserves hue and lightness.
+    Perceptual,
+}
+
+impl GamutMapping {
+    /// Brings linear-light sRGB values into the sRGB gamut.
+    pub(crate) fn map_linear(&self, linear: [f32; 3]) -> [f32; 3] {
+        match self {
+            Self::Clip => linear.map(|value| value.clamp(0.0, 1.0)),
+            Self::Perceptual => conversions::gamut_map_linear(linear),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn oklch_hue(linear: [f32; 3]) -> f32 {
+        let [_lightness, a, b] = conversions::linear_to_oklab(linear);
+        
+        b.atan2(a).to_degrees()
+    }
+    
+    #[test]
+    fn keeps_hue_of_display_p3_red() {
+        let linear = conversions::display_p3_to_linear([1.0, 0.0, 0.0]);
+        let hue = oklch_hue(linear);
+        let clipped = GamutMapping::Clip.map_linear(linear);
+        let mapped = GamutMapping::Perceptual.map_linear(linear);
+        
+        assert!(linear.iter().any(|value| !(0.0..=1.0).contains(value)));
+        assert_eq!(clipped, [1.0, 0.0, 0.0]);
+        assert!(mapped.iter().all(|value| (0.0..=1.0).contains(value)));
+        assert!((oklch_hue(mapped) - hue).abs() < 0.1);
+        assert!((oklch_hue(clipped) - hue).abs() > 0.2);
+    }
+    
+    #[test]
+    fn leaves_in_gamut_colors_unchanged() {
+        let linear = [0.2, 0.5, 0.8];
+        
+        assert_eq!(GamutMapping::Clip.map_linear(linear), linear);
+        assert_eq!(GamutMapping::Perceptual.map_linear(linear), linear);
+    }
+}
@@ -0,0 +1,550 @@
+use crate::{format, Color, InterpolationSpace};
+use crate::helpers::conversions;
+
+/// A color at a position within a [`Gradient`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColorStop {
+    /// The position of this stop, where 0.0 is the start of the gradient and 1.0 is the end.
+    pub position: f32,
+    /// The color at this stop.
+    pub color: Color,
+    /// The position of the color hint between this stop and the next, where the two colors are 
+    /// mixed evenly, as in CSS. The colors are mixed evenly halfway between the stops if this is 
+    /// `None`.
+    pub hint: Option<f32>,
+}
+
+/// A color gradient made of color stops, such as a CSS `linear-gradient()`.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, Gradient, InterpolationSpace};
+/// 
+/// let red = Color { red: 255, green: 0, blue: 0 };
+/// let blue = Color { red: 0, green: 0, blue: 255 };
+/// let gradient = Gradient::new()
+///     .stop(0.0, red)
+///     .stop(1.0, blue);
+/// 
+/// assert_eq!(gradient.sample(0.5), Some(Color { red: 128, green: 0, blue: 128 }));
+/// assert_eq!(gradient.sample(2.0), Some(blue));
+/// assert_eq!(gradient.colors(3), vec![red, Color { red: 128, green: 0, blue: 128 }, blue]);
+/// 
+/// let oklab = gradient.space(InterpolationSpace::Oklab);
+/// 
+/// assert_ne!(oklab.sample(0.5), Some(Color { red: 128, green: 0, blue: 128 }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Gradient {
+    stops: Vec<ColorStop>,
+    space: InterpolationSpace,
+}
+
+impl Gradient {
+    /// Creates an empty gradient interpolated in sRGB.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Creates a gradient with the colors spaced evenly from 0.0 to 1.0.
+    pub fn from_colors(colors: &[Color]) -> Self {
+        let last = colors.len().saturating_sub(1).max(1) as f32;
+        
+        colors
+            .iter()
+            .enumerate()
+            .fold(Self::new(), |gradient, (index, color)| {
+                gradient.stop(index as f32 / last, *color)
+            })
+    }
+    
+    /// Creates a gradient following a Bézier curve in Oklab with `colors` as its control points, 
+    /// like `chroma.bezier` in chroma.js. The curve starts at the first color and ends at the 
+    /// last, while the colors between pull it towards them without necessarily being reached. 
+    /// Unlike stops, which change direction abruptly at each color, the curve is smooth, so ramps 
+    /// through several colors have no visible kinks. The curve is approximated with stops sampled 
+    /// evenly along it, interpolated in Oklab.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Gradient, InterpolationSpace};
+    /// 
+    /// let black = Color { red: 0, green: 0, blue: 0 };
+    /// let red = Color { red: 255, green: 0, blue: 0 };
+    /// let white = Color { red: 255, green: 255, blue: 255 };
+    /// let gradient = Gradient::bezier(&[black, red, white]);
+    /// 
+    /// assert_eq!(gradient.sample(0.0), Some(black));
+    /// assert_eq!(gradient.sample(1.0), Some(white));
+    /// assert_ne!(gradient.sample(0.5), Some(red));
+    /// assert_eq!(gradient.interpolation_space(), InterpolationSpace::Oklab);
+    /// ```
+    pub fn bezier(colors: &[Color]) -> Self {
+        // The number of stops sampled along the curve for each control color after the first.
+        const STOPS_PER_COLOR: usize = 16;
+        
+        if colors.len() < 3 {
+            return Self::from_colors(colors).space(InterpolationSpace::Oklab);
+        }
+        
+        let points = colors.iter().map(|color| color.to_oklab()).collect::<Vec<_>>();
+        let last = (colors.len() - 1) * STOPS_PER_COLOR;
+        
+        (0..=last).fold(Self::new().space(InterpolationSpace::Oklab), |gradient, index| {
+            let t = index as f32 / last as f32;
+            let linear = conversions::oklab_to_linear(de_casteljau(&points, t));
+            let color = Color::from(conversions::linear_to_rgb(
+                conversions::gamut_map_linear(linear),
+            ));
+            
+            gradient.stop(t, color)
+        })
+    }
+    
+    /// Creates a gradient through the colors of light between two color temperatures in kelvin, 
+    /// such as 2000 K for sunrise to 6500 K for noon daylight, for lighting animations. Each 
+    /// color is the brightest sRGB color with the chromaticity of a blackbody at that temperature, 
+    /// which daylight follows closely. Positions map linearly to temperature, so 0.5 is halfway 
+    /// between `from_kelvin` and `to_kelvin`. Temperatures are clamped between 1667 and 25000 
+    /// kelvin. The locus is approximated with stops sampled evenly along it, interpolated in 
+    /// linear-light sRGB.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Gradient;
+    /// 
+    /// let sunrise_to_noon = Gradient::color_temperature(2000.0, 6500.0);
+    /// let sunrise = sunrise_to_noon.sample(0.0).unwrap();
+    /// let noon = sunrise_to_noon.sample(1.0).unwrap();
+    /// 
+    /// assert_eq!(sunrise.red, 255);
+    /// assert!(sunrise.blue < 50);
+    /// assert!(noon.green > 240 && noon.blue > 240);
+    /// ```
+    pub fn color_temperature(from_kelvin: f32, to_kelvin: f32) -> Self {
+        // The number of stops sampled along the locus, including both ends.
+        const STOPS: usize = 33;
+        
+        let last = (STOPS - 1) as f32;
+        
+        (0..STOPS).fold(Self::new().space(InterpolationSpace::LinearSrgb), |gradient, index| {
+            let t = index as f32 / last;
+            let (x, y) = conversions::planckian_chromaticity(
+                from_kelvin + (to_kelvin - from_kelvin) * t,
+            );
+            let linear = conversions::xyz_d65_to_linear(conversions::xy_to_xyz(x, y))
+                .map(|value| value.max(0.0));
+            let max = linear.iter().copied().fold(f32::MIN_POSITIVE, f32::max);
+            
+            gradient.stop(t, Color::from(conversions::linear_to_rgb(linear.map(|v| v / max))))
+        })
+    }
+    
+    /// Adds a color stop.
+    pub fn stop(mut self, position: f32, color: Color) -> Self {
+        self.push(ColorStop { position, color, hint: None });
+        self
+    }
+    
+    /// Adds a color hint at `position`, moving where the colors of the stops either side are mixed 
+    /// evenly, as in CSS. The hint applies to the segment containing `position`, so it should be 
+    /// added after the stops either side of it. A hint at a stop applies to the segment ending at 
+    /// that stop, or to the first segment for a hint at the first stop. Hints outside of every 
+    /// segment are ignored.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Gradient};
+    /// 
+    /// let black = Color { red: 0, green: 0, blue: 0 };
+    /// let white = Color { red: 255, green: 255, blue: 255 };
+    /// let gradient = Gradient::new()
+    ///     .stop(0.0, black)
+    ///     .stop(1.0, white)
+    ///     .hint(0.25);
+    /// 
+    /// assert_eq!(gradient.sample(0.25), Some(Color { red: 128, green: 128, blue: 128 }));
+    /// assert!(gradient.sample(0.5).unwrap().red > 128);
+    /// ```
+    pub fn hint(mut self, position: f32) -> Self {
+        let end = self.stops.partition_point(|stop| stop.position < position).max(1);
+        
+        if end < self.stops.len() && self.stops[end - 1].position <= position {
+            self.stops[end - 1].hint = Some(position);
+        }
+        
+        self
+    }
+    
+    /// Sets the color space used to interpolate between stops.
+    pub fn space(self, space: InterpolationSpace) -> Self {
+        Self { space, ..self }
+    }
+    
+    /// Adds a color stop, keeping stops ordered by position. A stop added at the same position as 
+    /// an existing stop is placed after it, creating a hard change in color.
+    pub fn push(&mut self, stop: ColorStop) {
+        let index = self.stops.partition_point(|existing| existing.position <= stop.position);
+        
+        self.stops.insert(index, stop);
+    }
+    
+    /// Gets the color stops, ordered by position.
+    pub fn stops(&self) -> &[ColorStop] {
+        &self.stops
+    }
+    
+    /// Gets the color space used to interpolate between stops.
+    pub fn interpolation_space(&self) -> InterpolationSpace {
+        self.space
+    }
+    
+    /// Samples the color at a position. Positions before the first stop or after the last stop 
+    /// use the color of that stop. Returns `None` if there are no stops.
+    pub fn sample(&self, position: f32) -> Option<Color> {
+        let index = self.stops.partition_point(|stop| stop.position <= position);
+        let (start, end) = match (index.checked_sub(1), self.stops.get(index)) {
+            (Some(previous), Some(end)) => (&self.stops[previous], end),
+            (Some(previous), None) => return Some(self.stops[previous].color),
+            (None, end) => return end.map(|stop| stop.color),
+        };
+        let progress = segment_progress(start, end, position);
+        
+        Some(self.space.lerp(start.color, end.color, progress))
+    }
+    
+    /// Samples `count` colors spaced evenly from 0.0 to 1.0. Returns an empty list if there are no 
+    /// stops.
+    pub fn colors(&self, count: usize) -> Vec<Color> {
+        let last = count.saturating_sub(1).max(1) as f32;
+        
+        (0..count)
+            .filter_map(|index| self.sample(index as f32 / last))
+            .collect()
+    }
+    
+    /// Divides this gradient into `count` classes of equal width, each with a single color, for 
+    /// discrete buckets such as the legend of a choropleth map. The colors are sampled evenly from 
+    /// 0.0 to 1.0, so the first class has the color at the start of the gradient and the last 
+    /// class has the color at the end.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Gradient};
+    /// 
+    /// let black = Color { red: 0, green: 0, blue: 0 };
+    /// let white = Color { red: 255, green: 255, blue: 255 };
+    /// let gray = Color { red: 128, green: 128, blue: 128 };
+    /// let classes = Gradient::new().stop(0.0, black).stop(1.0, white).classes(3);
+    /// 
+    /// assert_eq!(classes.colors(), &[black, gray, white]);
+    /// assert_eq!(classes.sample(0.2), Some(black));
+    /// assert_eq!(classes.sample(0.5), Some(gray));
+    /// assert_eq!(classes.sample(0.7), Some(white));
+    /// ```
+    pub fn classes(&self, count: usize) -> GradientClasses {
+        GradientClasses {
+            colors: self.colors(count),
+        }
+    }
+    
+    /// Renders this gradient from left to right as an image. Returns an empty image if there are 
+    /// no stops.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Gradient};
+    /// 
+    /// let black = Color { red: 0, green: 0, blue: 0 };
+    /// let white = Color { red: 255, green: 255, blue: 255 };
+    /// let image = Gradient::new().stop(0.0, black).stop(1.0, white).render_strip(3, 2);
+    /// 
+    /// assert_eq!(image.dimensions(), (3, 2));
+    /// assert_eq!(image.get_pixel(0, 1).0, black.to_array());
+    /// assert_eq!(image.get_pixel(2, 1).0, white.to_array());
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn render_strip(&self, width: u32, height: u32) -> image::RgbImage {
+        let row = self.colors(width as usize);
+        
+        image::RgbImage::from_fn(row.len() as u32, height, |x, _y| {
+            image::Rgb(row[x as usize].to_array())
+        })
+    }
+    
+    /// Generates an SVG `<linearGradient>` element with the given `id`, for use in the `<defs>` of 
+    /// an SVG image. SVG interpolates colors in sRGB and does not support color hints, so segments 
+    /// using another interpolation space or a color hint include extra stops sampled along them to 
+    /// approximate it.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Gradient};
+    /// 
+    /// let gradient = Gradient::new()
+    ///     .stop(0.0, Color { red: 255, green: 0, blue: 0 })
+    ///     .stop(1.0, Color { red: 0, green: 0, blue: 255 });
+    /// 
+    /// assert_eq!(
+    ///     gradient.to_svg_lineargradient("fade"),
+    ///     "<linearGradient id=\"fade\">\
+    ///     <stop offset=\"0\" stop-color=\"#FF0000\"/>\
+    ///     <stop offset=\"1\" stop-color=\"#0000FF\"/>\
+    ///     </linearGradient>",
+    /// );
+    /// ```
+    pub fn to_svg_lineargradient(&self, id: &str) -> String {
+        // The number of stops sampled along each segment for interpolation spaces other than sRGB.
+        const SEGMENT_SAMPLES: usize = 8;
+        
+        let mut s = format!("<linearGradient id=\"{}\">", format::escape_xml(id));
+        let mut push_stop = |position: f32, color: Color| {
+            s.push_str(&format!(
+                "<stop offset=\"{position}\" stop-color=\"{}\"/>",
+                color.display_hex(),
+            ));
+        };
+        
+        for (index, stop) in self.stops.iter().enumerate() {
+            push_stop(stop.position, stop.color);
+            
+            let Some(next) = self.stops.get(index + 1) else {
+                continue;
+            };
+            
+            if next.position == stop.position {
+                continue;
+            }
+            
+            if self.space == InterpolationSpace::Srgb && stop.hint.is_none() {
+                continue;
+            }
+            
+            for sample in 1..SEGMENT_SAMPLES {
+                let t = sample as f32 / SEGMENT_SAMPLES as f32;
+                let position = stop.position + (next.position - stop.position) * t;
+                let progress = segment_progress(stop, next, position);
+                
+                push_stop(position, self.space.lerp(stop.color, next.color, progress));
+            }
+        }
+        
+        s.push_str("</linearGradient>");
+        s
+    }
+}
+
+/// A gradient divided into classes of equal width, each with a single color. Created using 
+/// [`Gradient::classes`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GradientClasses {
+    colors: Vec<Color>,
+}
+
+impl GradientClasses {
+    /// Gets the color of each class, from the start of the gradient to the end.
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+    
+    /// Gets the index of the class containing `position`, where 0.0 is the start of the gradient 
+    /// and 1.0 is the end. Positions outside of 0.0 to 1.0 are clamped. Returns `None` if there 
+    /// are no classes.
+    pub fn class_index(&self, position: f32) -> Option<usize> {
+        let last = self.colors.len().checked_sub(1)?;
+        let index = (position.clamp(0.0, 1.0) * self.colors.len() as f32) as usize;
+        
+        Some(index.min(last))
+    }
+    
+    /// Samples the color of the class containing `position`. See 
+    /// [`GradientClasses::class_index`].
+    pub fn sample(&self, position: f32) -> Option<Color> {
+        self.class_index(position).map(|index| self.colors[index])
+    }
+}
+
+/// Evaluates a Bézier curve with the given control points at `t` using De Casteljau's algorithm.
+fn de_casteljau(points: &[[f32; 3]], t: f32) -> [f32; 3] {
+    let mut points = points.to_vec();
+    
+    for level in (1..points.len()).rev() {
+        for index in 0..level {
+            let next = points[index + 1];
+            
+            for (value, next) in points[index].iter_mut().zip(next) {
+                *value += (next - *value) * t;
+            }
+        }
+    }
+    
+    points.first().copied().unwrap_or_default()
+}
+
+/// Gets the progress from `start` to `end` at `position`, applying the color hint of `start` 
+/// using the CSS color hint formula.
+fn segment_progress(start: &ColorStop, end: &ColorStop, position: f32) -> f32 {
+    let length = end.position - start.position;
+    let progress = (position - start.position) / length;
+    let Some(hint) = start.hint else {
+        return progress;
+    };
+    let hint = (hint - start.position) / length;
+    
+    if hint <= 0.0 {
+        1.0
+    } else if hint >= 1.0 {
+        0.0
+    } else {
+        progress.clamp(0.0, 1.0).powf(0.5_f32.ln() / hint.ln())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn samples_stops() {
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let gradient = Gradient::new()
+            .stop(1.0, white)
+            .stop(0.0, black)
+            .stop(0.5, white)
+            .stop(0.5, black);
+        
+        assert_eq!(Gradient::new().sample(0.0), None);
+        assert_eq!(Gradient::new().colors(3), Vec::new());
+        assert_eq!(gradient.sample(0.25), Some(Color { red: 128, green: 128, blue: 128 }));
+        assert_eq!(gradient.sample(0.5), Some(black));
+        assert_eq!(gradient.sample(0.75), Some(Color { red: 128, green: 128, blue: 128 }));
+        assert_eq!(Gradient::from_colors(&[black, white]).colors(2), vec![black, white]);
+        assert_eq!(Gradient::from_colors(&[white]).colors(2), vec![white, white]);
+    }
+    
+    #[cfg(feature = "image")]
+    #[test]
+    fn renders_strip() {
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let image = Gradient::new().stop(0.0, black).stop(1.0, white).render_strip(5, 2);
+        
+        assert_eq!(image.dimensions(), (5, 2));
+        assert_eq!(image.get_pixel(2, 0).0, [128, 128, 128]);
+        assert_eq!(image.get_pixel(2, 1), image.get_pixel(2, 0));
+        assert_eq!(Gradient::new().render_strip(5, 2).dimensions(), (0, 2));
+    }
+    
+    #[test]
+    fn applies_hints() {
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let gray = Color { red: 128, green: 128, blue: 128 };
+        let gradient = Gradient::new().stop(0.0, black).stop(0.5, white).stop(1.0, black);
+        
+        assert_eq!(gradient.clone().hint(0.1).sample(0.1), Some(gray));
+        assert_eq!(gradient.clone().hint(0.9).sample(0.9), Some(gray));
+        assert_eq!(gradient.clone().hint(0.9).sample(0.25), gradient.sample(0.25));
+        assert_eq!(gradient.clone().hint(0.0).sample(0.01), Some(white));
+        assert_eq!(gradient.clone().hint(0.5).sample(0.25), Some(black));
+        assert_eq!(gradient.clone().hint(0.5).sample(0.75), gradient.sample(0.75));
+        assert_eq!(gradient.clone().hint(2.0), gradient);
+        assert_eq!(gradient.hint(0.75).to_svg_lineargradient("a").matches("<stop ").count(), 10);
+    }
+    
+    #[test]
+    fn applies_hints_at_stops() {
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let gray = Color { red: 128, green: 128, blue: 128 };
+        let gradient = Gradient::new().stop(0.0, black).stop(1.0, white);
+        
+        assert_eq!(gradient.clone().hint(1.0).sample(0.5), Some(black));
+        assert_eq!(gradient.clone().hint(0.0).sample(0.5), Some(white));
+        assert_eq!(
+            gradient.clone().hint(1.0),
+            "linear-gradient(black, 100%, white)".parse::<crate::CssGradient>().unwrap().gradient,
+        );
+        
+        let gradient = gradient.stop(0.5, white).hint(0.5);
+        
+        assert_eq!(gradient.stops()[0].hint, Some(0.5));
+        assert_eq!(gradient.stops()[1].hint, None);
+        assert_eq!(gradient.sample(0.25), Some(black));
+        assert_eq!(gradient.clone().hint(0.0).sample(0.25), Some(white));
+        assert_eq!(gradient.sample(0.75), Some(white));
+        assert_eq!(Gradient::new().stop(0.0, black).hint(0.0).stops()[0].hint, None);
+        assert_eq!(
+            Gradient::new().stop(0.0, black).stop(1.0, gray).hint(-0.5).stops()[0].hint,
+            None,
+        );
+    }
+    
+    #[test]
+    fn follows_bezier_curve() {
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let gradient = Gradient::bezier(&[black, white, black]);
+        let middle = gradient.sample(0.5).unwrap();
+        
+        assert_eq!(gradient.stops().len(), 33);
+        assert_eq!(gradient.sample(1.0), Some(black));
+        // Halfway along the curve is a quarter of each black and half of white.
+        assert!((middle.to_oklab()[0] - 0.5).abs() < 0.01, "{middle:?}");
+        assert_eq!(
+            Gradient::bezier(&[black, white]).sample(0.5),
+            Some(InterpolationSpace::Oklab.lerp(black, white, 0.5)),
+        );
+        assert_eq!(Gradient::bezier(&[]).sample(0.5), None);
+    }
+    
+    #[test]
+    fn follows_color_temperature() {
+        let gradient = Gradient::color_temperature(6500.0, 1000.0);
+        let daylight = gradient.sample(0.0).unwrap();
+        let candle = gradient.sample(1.0).unwrap();
+        
+        assert_eq!(gradient.stops().len(), 33);
+        assert!(daylight.delta_e_ok(Color { red: 255, green: 255, blue: 255 }) < 0.02);
+        assert_eq!(candle, Gradient::color_temperature(1667.0, 1667.0).sample(0.0).unwrap());
+        assert!(candle.red == 255 && candle.green < 150, "{candle:?}");
+        
+        for pair in gradient.stops().windows(2) {
+            assert!(pair[0].color.blue >= pair[1].color.blue);
+        }
+    }
+    
+    #[test]
+    fn samples_classes() {
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let classes = Gradient::from_colors(&[black, white]).classes(4);
+        
+        assert_eq!(classes.class_index(-1.0), Some(0));
+        assert_eq!(classes.class_index(0.25), Some(1));
+        assert_eq!(classes.class_index(0.99), Some(3));
+        assert_eq!(classes.class_index(1.0), Some(3));
+        assert_eq!(classes.sample(1.0), Some(white));
+        assert_eq!(Gradient::new().classes(4).sample(0.5), None);
+        assert_eq!(Gradient::from_colors(&[black]).classes(0).sample(0.5), None);
+    }
+    
+    #[test]
+    fn generates_svg_lineargradient() {
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let gradient = Gradient::new()
+            .stop(0.0, black)
+            .stop(0.5, white)
+            .stop(0.5, black)
+            .stop(1.0, white)
+            .space(InterpolationSpace::Oklab);
+        let svg = gradient.to_svg_lineargradient("a\"b");
+        
+        assert!(svg.starts_with("<linearGradient id=\"a&quot;b\"><stop offset=\"0\" "));
+        assert!(svg.contains("<stop offset=\"0.25\" stop-color=\"#636363\"/>"));
+        assert!(svg.contains("\"#FFFFFF\"/><stop offset=\"0.5\" stop-color=\"#000000\"/>"));
+        assert_eq!(svg.matches("<stop ").count(), 18);
+    }
+}
\ No newline at end of file
@@ -14,34 +14,56 @@ pub fn remove_suffix<'a>(
     None
 }
 
+/// Removes a prefix from a string if it exists, ignoring ASCII case. Returns `None` if the prefix 
+/// does not exist.
+pub fn remove_prefix_ignore_ascii_case<'a>(
+    s: &'a str,
+    prefix: &str,
+) -> Option<&'a str> {
+    if s.get(..prefix.len())?.eq_ignore_ascii_case(prefix) {
+        return Some(&s[prefix.len()..]);
+    }
+    
+    None
+}
+
 /// Fits a percentage into the range of 0.0 to 1.0.
 pub fn fit_percent(value: f32) -> f32 {
-    value.max(0.0).min(1.0)
+    value.clamp(0.0, 1.0)
 }
 
-/// Parses a percentage value from a string.
-pub fn parse_percent(s: &str) -> Option<f32> {
-    if s.ends_with('%') {
-        let value = remove_suffix(s.trim(), "%")?.parse::<f32>().ok()?;
-        let percent = fit_percent(value / 100.0);
-        
-        return Some(percent);
-    } else if s.starts_with("0.") || s.starts_with('.') {
-        let percent = fit_percent(s.parse::<f32>().ok()?);
-        
-        return Some(percent);
-    }
+/// Parses an angle from a string, returning the angle in degrees without normalizing it. Angles 
+/// may include a `deg`, `rad`, `grad`, or `turn` unit, matched case-insensitively, and unitless 
+/// values are treated as degrees.
+pub fn parse_angle(s: &str) -> Option<f32> {
+    let s = s.trim().to_ascii_lowercase();
+    // "grad" must be checked before "rad".
+    let degrees = if let Some(value) = remove_suffix(&s, "deg") {
+        value.parse::<f32>().ok()?
+    } else if let Some(value) = remove_suffix(&s, "grad") {
+        value.parse::<f32>().ok()? * 0.9
+    } else if let Some(value) = remove_suffix(&s, "rad") {
+        value.parse::<f32>().ok()?.to_degrees()
+    } else if let Some(value) = remove_suffix(&s, "turn") {
+        value.parse::<f32>().ok()? * 360.0
+    } else {
+        s.parse::<f32>().ok()?
+    };
     
-    None
+    degrees.is_finite().then_some(degrees)
+}
+
+/// Parses a hue angle from a string, returning the angle in degrees normalized to the range of 
+/// 0.0 to 360.0. Angles are parsed as with [`parse_angle`].
+pub fn parse_hue(s: &str) -> Option<f32> {
+    parse_angle(s).map(|degrees| degrees.rem_euclid(360.0))
 }
 
 /// Converts a floating point value to a percentage string.
 pub fn float_to_percent(value: f32) -> f32 {
     let percent = value * 100.0;
     // Keep only 3 decimal places.
-    let rounded = (percent * 1000.0).round() / 1000.0;
-    
-    return rounded;
+    (percent * 1000.0).round() / 1000.0
 }
 
 /// Converts a floating point value to a u8 integer.
@@ -81,7 +103,7 @@ pub mod conversions {
             return m1 + (m2 - m1) * (2.0 / 3.0 - hue) * 6.0;
         }
         
-        return m1;
+        m1
     }
     
     /// Converts an rgb color to HSL
@@ -119,7 +141,7 @@ pub mod conversions {
             } else {
                 (r - g) / difference + 4.0
             };
-            hue = hue / 6.0;
+            hue /= 6.0;
         }
         
         (hue * 360.0, saturation, lightness)
@@ -130,8 +152,8 @@ pub mod conversions {
         mut saturation: f32,
         mut lightness: f32,
     ) -> (Value, Value, Value) {
-        hue = hue.max(0.0).min(360.0);
-        hue = hue / 360.0;
+        hue = hue.clamp(0.0, 360.0);
+        hue /= 360.0;
         saturation = fit_percent(saturation);
         lightness = fit_percent(lightness);
         
@@ -147,12 +169,524 @@ pub mod conversions {
         
         (r, g, b)
     }
+    
+    /// The D50 reference white used by CIE Lab.
+    const D50_WHITE: [f32; 3] = [0.3457 / 0.3585, 1.0, (1.0 - 0.3457 - 0.3585) / 0.3585];
+    
+    /// Converts CIE XYZ with a D65 white point to linear-light sRGB.
+    const XYZ_D65_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
+        [3.240_97, -1.537_383_2, -0.498_610_76],
+        [-0.969_243_65, 1.875_967_5, 0.041_555_06],
+        [0.055_630_08, -0.203_976_96, 1.056_971_5],
+    ];
+    
+    /// Converts linear-light sRGB to CIE XYZ with a D65 white point.
+    const LINEAR_SRGB_TO_XYZ_D65: [[f32; 3]; 3] = [
+        [0.412_390_8, 0.357_584_34, 0.180_480_8],
+        [0.212_639, 0.715_168_7, 0.072_192_32],
+        [0.019_330_818, 0.119_194_78, 0.950_532_15],
+    ];
+    
+    /// Converts CIE XYZ to the cone response space used by Bradford chromatic adaptation.
+    const XYZ_TO_BRADFORD: [[f32; 3]; 3] = [
+        [0.8951, 0.2664, -0.1614],
+        [-0.7502, 1.7135, 0.0367],
+        [0.0389, -0.0685, 1.0296],
+    ];
+    
+    /// Converts the cone response space used by Bradford chromatic adaptation to CIE XYZ.
+    const BRADFORD_TO_XYZ: [[f32; 3]; 3] = [
+        [0.986_992_9, -0.147_054_26, 0.159_962_65],
+        [0.432_305_27, 0.518_360_3, 0.049_291_23],
+        [-0.008_528_664, 0.040_042_82, 0.968_486_7],
+    ];
+    
+    /// Bradford chromatic adaptation from D50 to D65.
+    const D50_TO_D65: [[f32; 3]; 3] = [
+        [0.955_473_4, -0.023_098_456, 0.063_259_244],
+        [-0.028_369_71, 1.009_995_3, 0.021_041_442],
+        [0.012_314_015, -0.020_507_649, 1.330_365_9],
+    ];
+    
+    /// Converts linear-light Display P3 to CIE XYZ with a D65 white point.
+    const LINEAR_DISPLAY_P3_TO_XYZ_D65: [[f32; 3]; 3] = [
+        [0.486_570_95, 0.265_667_7, 0.198_217_29],
+        [0.228_974_56, 0.691_738_5, 0.079_286_91],
+        [0.0, 0.045_113_38, 1.043_944_4],
+    ];
+    
+    /// Converts CIE XYZ with a D65 white point to linear-light Display P3.
+    const XYZ_D65_TO_LINEAR_DISPLAY_P3: [[f32; 3]; 3] = [
+        [2.493_497, -0.931_383_6, -0.402_710_8],
+        [-0.829_489, 1.762_664_1, 0.023_624_685],
+        [0.035_845_83, -0.076_172_39, 0.956_884_5],
+    ];
+    
+    /// Converts linear-light sRGB to linear-light Rec. 2020.
+    const LINEAR_SRGB_TO_LINEAR_REC2020: [[f32; 3]; 3] = [
+        [0.627_404, 0.329_282, 0.043_313_6],
+        [0.069_097, 0.919_54, 0.011_361_2],
+        [0.016_391_6, 0.088_013_2, 0.895_595],
+    ];
+    
+    /// Converts linear-light Rec. 2020 to linear-light sRGB.
+    const LINEAR_REC2020_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
+        [1.660_491, -0.587_641_1, -0.072_849_9],
+        [-0.124_550_5, 1.132_899_9, -0.008_349_4],
+        [-0.018_150_8, -0.100_578_9, 1.118_729_7],
+    ];
+    
+    /// Converts linear-light Adobe RGB (1998) to CIE XYZ with a D65 white point.
+    const LINEAR_ADOBE_RGB_TO_XYZ_D65: [[f32; 3]; 3] = [
+        [0.576_669, 0.185_558_2, 0.188_228_6],
+        [0.297_345, 0.627_363_6, 0.075_291_5],
+        [0.027_031_4, 0.070_688_9, 0.991_337_5],
+    ];
+    
+    /// Converts CIE XYZ with a D65 white point to linear-light Adobe RGB (1998).
+    const XYZ_D65_TO_LINEAR_ADOBE_RGB: [[f32; 3]; 3] = [
+        [2.041_588, -0.565_007, -0.344_731_4],
+        [-0.969_243_6, 1.875_967_5, 0.041_555_1],
+        [0.013_444_3, -0.118_362_4, 1.015_175],
+    ];
+    
+    /// The gamma of the Adobe RGB (1998) transfer function.
+    const ADOBE_RGB_GAMMA: f32 = 563.0 / 256.0;
+    
+    /// Converts linear-light ProPhoto RGB to CIE XYZ with a D50 white point.
+    const LINEAR_PROPHOTO_RGB_TO_XYZ_D50: [[f32; 3]; 3] = [
+        [0.797_674_9, 0.135_191_7, 0.031_353_4],
+        [0.288_040_2, 0.711_874_1, 0.000_085_7],
+        [0.0, 0.0, 0.825_21],
+    ];
+    
+    /// Converts CIE XYZ with a D50 white point to linear-light ProPhoto RGB.
+    const XYZ_D50_TO_LINEAR_PROPHOTO_RGB: [[f32; 3]; 3] = [
+        [1.345_943_3, -0.255_607_5, -0.051_111_8],
+        [-0.544_598_9, 1.508_167_3, 0.020_535_1],
+        [0.0, 0.0, 1.211_812_8],
+    ];
+    
+    /// Bradford chromatic adaptation from D65 to D50.
+    const D65_TO_D50: [[f32; 3]; 3] = [
+        [1.047_929_8, 0.022_946_9, -0.050_192_3],
+        [0.029_627_8, 0.990_434_4, -0.017_073_8],
+        [-0.009_243, 0.015_055_2, 0.751_874_3],
+    ];
+    
+    /// The constants of the Rec. 709 transfer function.
+    const REC709_TRANSFER: (f32, f32) = (1.099, 0.018);
+    
+    /// The constants of the Rec. 2020 transfer function.
+    const REC2020_TRANSFER: (f32, f32) = (1.099_296_8, 0.018_053_97);
+    
+    /// Converts linear-light sRGB to the LMS cone space used by Oklab.
+    const LINEAR_SRGB_TO_LMS: [[f32; 3]; 3] = [
+        [0.412_221_46, 0.536_332_55, 0.051_445_995],
+        [0.211_903_5, 0.680_699_5, 0.107_396_96],
+        [0.088_302_46, 0.281_718_85, 0.629_978_7],
+    ];
+    
+    /// Converts non-linear LMS to Oklab.
+    const LMS_TO_OKLAB: [[f32; 3]; 3] = [
+        [0.210_454_26, 0.793_617_8, -0.004_072_047],
+        [1.977_998_5, -2.428_592_2, 0.450_593_7],
+        [0.025_904_037, 0.782_771_77, -0.808_675_77],
+    ];
+    
+    /// Converts Oklab to non-linear LMS.
+    const OKLAB_TO_LMS: [[f32; 3]; 3] = [
+        [1.0, 0.396_337_78, 0.215_803_76],
+        [1.0, -0.105_561_346, -0.063_854_17],
+        [1.0, -0.089_484_18, -1.291_485_5],
+    ];
+    
+    /// Converts the LMS cone space used by Oklab to linear-light sRGB.
+    const LMS_TO_LINEAR_SRGB: [[f32; 3]; 3] = [
+        [4.076_741_7, -3.307_711_6, 0.230_969_94],
+        [-1.268_438, 2.609_757_4, -0.341_319_38],
+        [-0.004_196_086_3, -0.703_418_6, 1.707_614_7],
+    ];
+    
+    /// Multiplies a 3x3 matrix by a vector.
+    pub fn multiply_matrix(matrix: &[[f32; 3]; 3], vector: [f32; 3]) -> [f32; 3] {
+        let [a, b, c] = vector;
+        
+        matrix.map(|row| row[0] * a + row[1] * b + row[2] * c)
+    }
+    
+    /// Converts a gamma-encoded sRGB value (0.0 to 1.0) to linear light.
+    pub fn srgb_to_linear(value: f32) -> f32 {
+        let abs = value.abs();
+        
+        if abs <= 0.04045 {
+            value / 12.92
+        } else {
+            value.signum() * ((abs + 0.055) / 1.055).powf(2.4)
+        }
+    }
+    
+    /// Converts a linear-light value (0.0 to 1.0) to gamma-encoded sRGB.
+    pub fn linear_to_srgb(value: f32) -> f32 {
+        let abs = value.abs();
+        
+        if abs <= 0.0031308 {
+            value * 12.92
+        } else {
+            value.signum() * (1.055 * abs.powf(1.0 / 2.4) - 0.055)
+        }
+    }
+    
+    /// Converts rgb values to linear-light sRGB values (0.0 to 1.0).
+    pub fn rgb_to_linear(rgb: [Value; 3]) -> [f32; 3] {
+        rgb.map(|value| srgb_to_linear(value as f32 / 255.0))
+    }
+    
+    /// Converts linear-light sRGB values to an rgb color. Values outside of the sRGB gamut are 
+    /// clipped.
+    pub fn linear_to_rgb(linear: [f32; 3]) -> (Value, Value, Value) {
+        let [r, g, b] = linear.map(|c| float_to_value(linear_to_srgb(c) * 255.0));
+        
+        (r, g, b)
+    }
+    
+    /// Converts CIE XYZ with a D65 white point to linear-light sRGB.
+    pub fn xyz_d65_to_linear(xyz: [f32; 3]) -> [f32; 3] {
+        multiply_matrix(&XYZ_D65_TO_LINEAR_SRGB, xyz)
+    }
+    
+    /// Converts linear-light sRGB to CIE XYZ with a D65 white point.
+    pub fn linear_to_xyz_d65(linear: [f32; 3]) -> [f32; 3] {
+        multiply_matrix(&LINEAR_SRGB_TO_XYZ_D65, linear)
+    }
+    
+    /// Adapts CIE XYZ values from one white point to another using the Bradford transform, so 
+    /// that a surface color under the first white appears the same under the second white.
+    pub fn bradford_adapt(xyz: [f32; 3], from_white: [f32; 3], to_white: [f32; 3]) -> [f32; 3] {
+        let from = multiply_matrix(&XYZ_TO_BRADFORD, from_white);
+        let to = multiply_matrix(&XYZ_TO_BRADFORD, to_white);
+        let cone = multiply_matrix(&XYZ_TO_BRADFORD, xyz);
+        let adapted = [0, 1, 2].map(|i| cone[i] * to[i] / from[i]);
+        
+        multiply_matrix(&BRADFORD_TO_XYZ, adapted)
+    }
+    
+    /// Gets the CIE xy chromaticity of daylight at a correlated color temperature in kelvin, 
+    /// using the CIE daylight locus. The temperature is clamped between 4000 and 25000 kelvin, 
+    /// the range the locus is defined for.
+    pub fn daylight_chromaticity(kelvin: f32) -> (f32, f32) {
+        let t = kelvin.clamp(4000.0, 25000.0);
+        let x = if t <= 7000.0 {
+            -4.607e9 / t.powi(3) + 2.9678e6 / t.powi(2) + 99.11 / t + 0.244_063
+        } else {
+            -2.0064e9 / t.powi(3) + 1.9018e6 / t.powi(2) + 247.48 / t + 0.237_04
+        };
+        let y = -3.0 * x * x + 2.87 * x - 0.275;
+        
+        (x, y)
+    }
+    
+    /// Gets the CIE xy chromaticity of a blackbody at a temperature in kelvin, using the cubic 
+    /// spline approximation of the Planckian locus by Kim et al. The temperature is clamped 
+    /// between 1667 and 25000 kelvin, the range the approximation is defined for.
+    pub fn planckian_chromaticity(kelvin: f32) -> (f32, f32) {
+        let t = kelvin.clamp(1667.0, 25000.0);
+        let x = if t <= 4000.0 {
+            -0.266_123_9e9 / t.powi(3) - 0.234_358_9e6 / t.powi(2) + 0.877_695_6e3 / t + 0.179_910
+        } else {
+            -3.025_846_9e9 / t.powi(3) + 2.107_038e6 / t.powi(2) + 0.222_634_7e3 / t + 0.240_390
+        };
+        let y = if t <= 2222.0 {
+            -1.106_381_4 * x.powi(3) - 1.348_110_2 * x.powi(2) + 2.185_558_3 * x - 0.202_196_83
+        } else if t <= 4000.0 {
+            -0.954_947_6 * x.powi(3) - 1.374_185_9 * x.powi(2) + 2.091_37 * x - 0.167_488_67
+        } else {
+            3.081_758 * x.powi(3) - 5.873_387 * x.powi(2) + 3.751_13 * x - 0.370_014_83
+        };
+        
+        (x, y)
+    }
+    
+    /// Converts CIE xy chromaticity coordinates to CIE XYZ with a luminance of 1.0.
+    pub fn xy_to_xyz(x: f32, y: f32) -> [f32; 3] {
+        [x / y, 1.0, (1.0 - x - y) / y]
+    }
+    
+    /// Converts gamma-encoded Display P3 values (0.0 to 1.0) to linear-light sRGB. The result 
+    /// may be outside of the sRGB gamut.
+    pub fn display_p3_to_linear(p3: [f32; 3]) -> [f32; 3] {
+        let linear_p3 = p3.map(srgb_to_linear);
+        
+        xyz_d65_to_linear(multiply_matrix(&LINEAR_DISPLAY_P3_TO_XYZ_D65, linear_p3))
+    }
+    
+    /// Converts linear-light sRGB to gamma-encoded Display P3 values (0.0 to 1.0).
+    pub fn linear_to_display_p3(linear: [f32; 3]) -> [f32; 3] {
+        let xyz = linear_to_xyz_d65(linear);
+        
+        multiply_matrix(&XYZ_D65_TO_LINEAR_DISPLAY_P3, xyz).map(linear_to_srgb)
+    }
+    
+    /// Converts linear-light sRGB to gamma-encoded Rec. 709 values (0.0 to 1.0). Rec. 709 shares 
+    /// its primaries with sRGB, so only the transfer function differs.
+    pub fn linear_to_rec709(linear: [f32; 3]) -> [f32; 3] {
+        linear.map(|value| encode_rec(value, REC709_TRANSFER))
+    }
+    
+    /// Converts gamma-encoded Rec. 709 values (0.0 to 1.0) to linear-light sRGB.
+    pub fn rec709_to_linear(rec709: [f32; 3]) -> [f32; 3] {
+        rec709.map(|value| decode_rec(value, REC709_TRANSFER))
+    }
+    
+    /// Converts linear-light sRGB to gamma-encoded Rec. 2020 values (0.0 to 1.0).
+    pub fn linear_to_rec2020(linear: [f32; 3]) -> [f32; 3] {
+        multiply_matrix(&LINEAR_SRGB_TO_LINEAR_REC2020, linear)
+            .map(|value| encode_rec(value, REC2020_TRANSFER))
+    }
+    
+    /// Converts gamma-encoded Rec. 2020 values (0.0 to 1.0) to linear-light sRGB. The result may 
+    /// be outside of the sRGB gamut.
+    pub fn rec2020_to_linear(rec2020: [f32; 3]) -> [f32; 3] {
+        let linear_rec2020 = rec2020.map(|value| decode_rec(value, REC2020_TRANSFER));
+        
+        multiply_matrix(&LINEAR_REC2020_TO_LINEAR_SRGB, linear_rec2020)
+    }
+    
+    /// Converts linear-light sRGB to gamma-encoded Adobe RGB (1998) values (0.0 to 1.0).
+    pub fn linear_to_adobe_rgb(linear: [f32; 3]) -> [f32; 3] {
+        multiply_matrix(&XYZ_D65_TO_LINEAR_ADOBE_RGB, linear_to_xyz_d65(linear))
+            .map(|value| value.signum() * value.abs().powf(1.0 / ADOBE_RGB_GAMMA))
+    }
+    
+    /// Converts gamma-encoded Adobe RGB (1998) values (0.0 to 1.0) to linear-light sRGB. The 
+    /// result may be outside of the sRGB gamut.
+    pub fn adobe_rgb_to_linear(adobe_rgb: [f32; 3]) -> [f32; 3] {
+        let linear_adobe_rgb = adobe_rgb.map(|value| {
+            value.signum() * value.abs().powf(ADOBE_RGB_GAMMA)
+        });
+        
+        xyz_d65_to_linear(multiply_matrix(&LINEAR_ADOBE_RGB_TO_XYZ_D65, linear_adobe_rgb))
+    }
+    
+    /// Converts linear-light sRGB to gamma-encoded ProPhoto RGB values (0.0 to 1.0).
+    pub fn linear_to_prophoto_rgb(linear: [f32; 3]) -> [f32; 3] {
+        let xyz_d50 = multiply_matrix(&D65_TO_D50, linear_to_xyz_d65(linear));
+        
+        multiply_matrix(&XYZ_D50_TO_LINEAR_PROPHOTO_RGB, xyz_d50).map(|value| {
+            let abs = value.abs();
+            
+            if abs < 1.0 / 512.0 {
+                value * 16.0
+            } else {
+                value.signum() * abs.powf(1.0 / 1.8)
+            }
+        })
+    }
+    
+    /// Converts gamma-encoded ProPhoto RGB values (0.0 to 1.0) to linear-light sRGB. The result 
+    /// may be outside of the sRGB gamut.
+    pub fn prophoto_rgb_to_linear(prophoto_rgb: [f32; 3]) -> [f32; 3] {
+        let linear_prophoto_rgb = prophoto_rgb.map(|value| {
+            let abs = value.abs();
+            
+            if abs < 16.0 / 512.0 {
+                value / 16.0
+            } else {
+                value.signum() * abs.powf(1.8)
+            }
+        });
+        let xyz_d50 = multiply_matrix(&LINEAR_PROPHOTO_RGB_TO_XYZ_D50, linear_prophoto_rgb);
+        
+        xyz_d65_to_linear(multiply_matrix(&D50_TO_D65, xyz_d50))
+    }
+    
+    /// Applies the Rec. 709 style transfer function with the constants `(alpha, beta)` to a 
+    /// linear-light value.
+    fn encode_rec(value: f32, (alpha, beta): (f32, f32)) -> f32 {
+        let abs = value.abs();
+        
+        if abs < beta {
+            value * 4.5
+        } else {
+            value.signum() * (alpha * abs.powf(0.45) - (alpha - 1.0))
+        }
+    }
+    
+    /// Inverts the Rec. 709 style transfer function with the constants `(alpha, beta)`.
+    fn decode_rec(value: f32, (alpha, beta): (f32, f32)) -> f32 {
+        let abs = value.abs();
+        
+        if abs < beta * 4.5 {
+            value / 4.5
+        } else {
+            value.signum() * ((abs + alpha - 1.0) / alpha).powf(1.0 / 0.45)
+        }
+    }
+    
+    /// Converts linear-light sRGB to Oklab.
+    pub fn linear_to_oklab(linear: [f32; 3]) -> [f32; 3] {
+        let lms = multiply_matrix(&LINEAR_SRGB_TO_LMS, linear).map(f32::cbrt);
+        
+        multiply_matrix(&LMS_TO_OKLAB, lms)
+    }
+    
+    /// Converts Oklab to linear-light sRGB.
+    pub fn oklab_to_linear(oklab: [f32; 3]) -> [f32; 3] {
+        let lms = multiply_matrix(&OKLAB_TO_LMS, oklab).map(|c| c * c * c);
+        
+        multiply_matrix(&LMS_TO_LINEAR_SRGB, lms)
+    }
+    
+    /// Gets the Euclidean distance between two Oklab colors, known as ΔEOK.
+    pub fn delta_e_ok(a: [f32; 3], b: [f32; 3]) -> f32 {
+        a.iter().zip(b).map(|(a, b)| (a - b).powi(2)).sum::<f32>().sqrt()
+    }
+    
+    /// Maps linear-light sRGB values that may be outside of the sRGB gamut into the gamut using 
+    /// the CSS Color 4 algorithm, which reduces chroma in Oklch until the clipped color is 
+    /// indistinguishable from the chroma-reduced one. Hue and lightness are preserved.
+    pub fn gamut_map_linear(linear: [f32; 3]) -> [f32; 3] {
+        // Just noticeable difference in Oklab.
+        const JND: f32 = 0.02;
+        const EPSILON: f32 = 0.0001;
+        
+        let in_gamut = |linear: [f32; 3]| {
+            linear.iter().all(|c| (-EPSILON..=1.0 + EPSILON).contains(c))
+        };
+        let clip = |linear: [f32; 3]| linear.map(|c| c.clamp(0.0, 1.0));
+        if in_gamut(linear) {
+            return clip(linear);
+        }
+        
+        let [lightness, a, b] = linear_to_oklab(linear);
+        
+        if lightness >= 1.0 {
+            return [1.0; 3];
+        }
+        
+        if lightness <= 0.0 {
+            return [0.0; 3];
+        }
+        
+        let chroma = (a * a + b * b).sqrt();
+        let with_chroma = |new_chroma: f32| {
+            let scale = new_chroma / chroma;
+            
+            [lightness, a * scale, b * scale]
+        };
+        let mut clipped = clip(linear);
+        
+        if delta_e_ok(linear_to_oklab(clipped), [lightness, a, b]) < JND {
+            return clipped;
+        }
+        
+        let mut min = 0.0;
+        let mut max = chroma;
+        let mut min_in_gamut = true;
+        
+        while max - min > EPSILON {
+            let current_chroma = (min + max) / 2.0;
+            let current = with_chroma(current_chroma);
+            let current_linear = oklab_to_linear(current);
+            
+            if min_in_gamut && in_gamut(current_linear) {
+                min = current_chroma;
+                continue;
+            }
+            
+            clipped = clip(current_linear);
+            
+            let difference = delta_e_ok(linear_to_oklab(clipped), current);
+            
+            if difference < JND {
+                if JND - difference < EPSILON {
+                    break;
+                }
+                
+                min_in_gamut = false;
+                min = current_chroma;
+            } else {
+                max = current_chroma;
+            }
+        }
+        
+        clipped
+    }
+    
+    /// Converts CIE Lab (D50) to CIE XYZ with a D65 white point.
+    pub fn lab_to_xyz_d65(
+        lightness: f32,
+        a: f32,
+        b: f32,
+    ) -> [f32; 3] {
+        const KAPPA: f32 = 24389.0 / 27.0;
+        const EPSILON: f32 = 216.0 / 24389.0;
+        
+        let fy = (lightness + 16.0) / 116.0;
+        let fx = fy + a / 500.0;
+        let fz = fy - b / 200.0;
+        let x = if fx.powi(3) > EPSILON {
+            fx.powi(3)
+        } else {
+            (116.0 * fx - 16.0) / KAPPA
+        };
+        let y = if lightness > KAPPA * EPSILON {
+            fy.powi(3)
+        } else {
+            lightness / KAPPA
+        };
+        let z = if fz.powi(3) > EPSILON {
+            fz.powi(3)
+        } else {
+            (116.0 * fz - 16.0) / KAPPA
+        };
+        let xyz_d50 = [x * D50_WHITE[0], y * D50_WHITE[1], z * D50_WHITE[2]];
+        
+        multiply_matrix(&D50_TO_D65, xyz_d50)
+    }
+    
+    /// Converts CIE Lab (D50) to an rgb color. Values outside of the sRGB gamut are clipped.
+    pub fn lab_to_rgb(
+        lightness: f32,
+        a: f32,
+        b: f32,
+    ) -> (Value, Value, Value) {
+        linear_to_rgb(xyz_d65_to_linear(lab_to_xyz_d65(lightness, a, b)))
+    }
+    
+    /// Converts polar LCH coordinates to Lab. The hue is in degrees.
+    pub fn lch_to_lab(
+        lightness: f32,
+        chroma: f32,
+        hue: f32,
+    ) -> (f32, f32, f32) {
+        let hue = hue.to_radians();
+        
+        (lightness, chroma * hue.cos(), chroma * hue.sin())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     
+    #[test]
+    fn parses_hue() {
+        assert_eq!(parse_hue("90"), Some(90.0));
+        assert_eq!(parse_hue("90.5"), Some(90.5));
+        assert_eq!(parse_hue("-90"), Some(270.0));
+        assert_eq!(parse_hue("450deg"), Some(90.0));
+        assert_eq!(parse_hue("100grad"), Some(90.0));
+        assert_eq!(parse_hue("0.5turn"), Some(180.0));
+        assert!((parse_hue("3.14159rad").unwrap() - 180.0).abs() < 0.001);
+        assert_eq!(parse_hue("90px"), None);
+        assert_eq!(parse_hue("deg"), None);
+        assert_eq!(parse_hue("0.25TURN"), Some(90.0));
+        assert_eq!(parse_angle("-90deg"), Some(-90.0));
+        assert_eq!(parse_angle("1.5turn"), Some(540.0));
+        assert_eq!(parse_angle("infdeg"), None);
+    }
+    
     #[test]
     fn converts_rgb_to_hsl() {
         let (hue, saturation, lightness) = conversions::rgb_to_hsl(255, 0, 0);
@@ -194,4 +728,37 @@ mod tests {
         assert_eq!(g, 64);
         assert_eq!(b, 106);
     }
+    
+    #[test]
+    fn converts_display_p3_to_linear() {
+        let linear = conversions::display_p3_to_linear([1.0, 1.0, 1.0]);
+        
+        assert!(linear.iter().all(|c| (c - 1.0).abs() < 0.001));
+        
+        let [r, g, b] = conversions::display_p3_to_linear([1.0, 0.0, 0.0]);
+        
+        // Display P3 red is outside of the sRGB gamut.
+        assert!(r > 1.0);
+        assert!(g < 0.0);
+        assert!(b < 0.0);
+    }
+    
+    #[test]
+    fn gamut_maps_linear() {
+        assert_eq!(conversions::gamut_map_linear([0.5, 0.25, 0.0]), [0.5, 0.25, 0.0]);
+        assert_eq!(conversions::gamut_map_linear([2.0, 2.0, 2.0]), [1.0, 1.0, 1.0]);
+        
+        let p3_red = conversions::display_p3_to_linear([1.0, 0.0, 0.0]);
+        let mapped = conversions::gamut_map_linear(p3_red);
+        
+        assert!(mapped.iter().all(|c| (0.0..=1.0).contains(c)));
+        assert_eq!(conversions::linear_to_rgb(mapped), (255, 11, 12));
+    }
+    
+    #[test]
+    fn converts_lab_to_rgb() {
+        assert_eq!(conversions::lab_to_rgb(54.29, 80.82, 69.88), (255, 0, 0));
+        assert_eq!(conversions::lab_to_rgb(100.0, 0.0, 0.0), (255, 255, 255));
+        assert_eq!(conversions::lab_to_rgb(0.0, 0.0, 0.0), (0, 0, 0));
+    }
 }
\ No newline at end of file
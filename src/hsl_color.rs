@@ -3,6 +3,8 @@ use crate::helpers::conversions;
 
 /// A color containing values for hue, saturation, and lightness.
 #[derive(Debug, Clone, Copy, PartialEq, Default, PartialOrd)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
 pub struct HSLColor {
     /// The hue value (0.0 to 360.0).
     pub hue: f32,
@@ -38,7 +40,7 @@ impl HSLColor {
     /// assert_eq!(color.hue, 180.0);
     /// ```
     pub fn hue(self, mut hue: f32) -> Self {
-        hue = hue.max(0.0).min(360.0);
+        hue = hue.clamp(0.0, 360.0);
         
         Self { hue, ..self }
     }
@@ -50,7 +52,7 @@ impl HSLColor {
         } else if self.hue + hue < 0.0 {
             hue = self.hue + hue + 360.0;
         } else {
-            hue = self.hue + hue;
+            hue += self.hue;
         }
         
         Self { hue, ..self }
@@ -62,7 +64,7 @@ impl HSLColor {
     /// - If the value is less than 0.0, it will be set to 0.0.
     /// - If the value is greater than 1.0, it will be set to 1.0.
     pub fn saturation(self, mut saturation: f32) -> Self {
-        saturation = saturation.max(0.0).min(1.0);
+        saturation = saturation.clamp(0.0, 1.0);
         
         Self { saturation, ..self }
     }
@@ -73,10 +75,83 @@ impl HSLColor {
     /// - If the value is less than 0.0, it will be set to 0.0.
     /// - If the value is greater than 1.0, it will be set to 1.0.
     pub fn lightness(self, mut lightness: f32) -> Self {
-        lightness = lightness.max(0.0).min(1.0);
+        lightness = lightness.clamp(0.0, 1.0);
         
         Self { lightness, ..self }
     }
+    
+    /// Increases the lightness value by `amount`, e.g. 0.1 for 10 percentage points, like the 
+    /// Sass `lighten()` function. The result is clamped between 0.0 and 1.0.
+    /// 
+    /// # Example
+    /// ```
+    /// use nice_colors::HSLColor;
+    /// 
+    /// let color = HSLColor { hue: 0.0, saturation: 0.5, lightness: 0.5 };
+    /// 
+    /// assert_eq!(color.lighten_by(0.2).lightness, 0.7);
+    /// assert_eq!(color.lighten_by(0.8).lightness, 1.0);
+    /// ```
+    pub fn lighten_by(self, amount: f32) -> Self {
+        self.lightness(self.lightness + amount)
+    }
+    
+    /// Decreases the lightness value by `amount`, like the Sass `darken()` function. The result is 
+    /// clamped between 0.0 and 1.0.
+    pub fn darken_by(self, amount: f32) -> Self {
+        self.lighten_by(-amount)
+    }
+    
+    /// Increases the saturation value by `amount`, like the Sass `saturate()` function. The result 
+    /// is clamped between 0.0 and 1.0.
+    pub fn saturate_by(self, amount: f32) -> Self {
+        self.saturation(self.saturation + amount)
+    }
+    
+    /// Decreases the saturation value by `amount`, like the Sass `desaturate()` function. The 
+    /// result is clamped between 0.0 and 1.0.
+    pub fn desaturate_by(self, amount: f32) -> Self {
+        self.saturate_by(-amount)
+    }
+}
+
+/// Serializes to an hsl color string for human-readable formats such as JSON, and to the hue, 
+/// saturation, and lightness values for binary formats such as bincode.
+#[cfg(feature = "serde")]
+impl serde::Serialize for HSLColor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&format_args!(
+                "hsl({} {}% {}%)",
+                self.hue,
+                crate::helpers::float_to_percent(self.saturation),
+                crate::helpers::float_to_percent(self.lightness),
+            ))
+        } else {
+            [self.hue, self.saturation, self.lightness].serialize(serializer)
+        }
+    }
+}
+
+/// Deserializes from any supported color string for human-readable formats such as JSON, and 
+/// from the hue, saturation, and lightness values for binary formats such as bincode.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HSLColor {
+    fn deserialize<D>(deserializer: D) -> Result<HSLColor, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(crate::serializers::HSLColorVisitor)
+        } else {
+            let [hue, saturation, lightness] = <[f32; 3]>::deserialize(deserializer)?;
+            
+            Ok(Self { hue, saturation, lightness })
+        }
+    }
 }
 
 impl From<Color> for HSLColor {
@@ -120,4 +195,19 @@ mod tests {
         
         assert_eq!(color.hue, 190.0);
     }
+    
+    #[test]
+    fn adjusts_relatively() {
+        let color = HSLColor {
+            hue: 10.0,
+            saturation: 0.5,
+            lightness: 0.5,
+        };
+        
+        assert_eq!(color.darken_by(0.25).lightness, 0.25);
+        assert_eq!(color.darken_by(0.75).lightness, 0.0);
+        assert_eq!(color.saturate_by(0.25).saturation, 0.75);
+        assert_eq!(color.desaturate_by(0.5).saturation, 0.0);
+        assert_eq!(color.desaturate_by(-1.0).saturation, 1.0);
+    }
 }
\ No newline at end of file
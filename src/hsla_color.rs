@@ -0,0 +1,216 @@
+use crate::{helpers, parse, Color, HSLColor, ParseColorError, Rgba};
+use crate::color::Alpha;
+use std::fmt;
+use std::str::FromStr;
+
+/// A color containing values for hue, saturation, lightness, and alpha.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{HSLAColor, Rgba};
+/// 
+/// let color = "hsla(120 100% 25% / 0.5)".parse::<HSLAColor>().unwrap();
+/// 
+/// assert_eq!(color.alpha, 0.5);
+/// assert_eq!(Rgba::from(color), Rgba { red: 0, green: 128, blue: 0, alpha: 0.5 });
+/// assert_eq!(color.to_string(), "hsl(120 100% 25% / 0.5)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct HSLAColor {
+    /// The hue value (0.0 to 360.0).
+    pub hue: f32,
+    /// The saturation value (0.0 to 1.0).
+    pub saturation: f32,
+    /// The lightness value (0.0 to 1.0).
+    pub lightness: f32,
+    /// The alpha value (0.0 to 1.0).
+    pub alpha: Alpha,
+}
+
+impl HSLAColor {
+    /// Creates a new HSLA color. This defaults to opaque black.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Creates an HSLA color from an HSL color and an alpha value.
+    pub fn from_hsl(hsl: HSLColor, alpha: Alpha) -> Self {
+        Self {
+            hue: hsl.hue,
+            saturation: hsl.saturation,
+            lightness: hsl.lightness,
+            alpha,
+        }
+    }
+    
+    /// Gets the hue, saturation, and lightness values without alpha.
+    pub fn to_hsl(&self) -> HSLColor {
+        HSLColor {
+            hue: self.hue,
+            saturation: self.saturation,
+            lightness: self.lightness,
+        }
+    }
+    
+    /// Sets the alpha value.
+    /// 
+    /// The alpha value is a float between 0.0 and 1.0:
+    /// - If the value is less than 0.0, it will be set to 0.0.
+    /// - If the value is greater than 1.0, it will be set to 1.0.
+    pub fn alpha(self, alpha: Alpha) -> Self {
+        Self { alpha: alpha.clamp(0.0, 1.0), ..self }
+    }
+}
+
+impl Default for HSLAColor {
+    fn default() -> Self {
+        Self::from_hsl(HSLColor::default(), 1.0)
+    }
+}
+
+/// Formats as an hsl color string, e.g. `hsl(120 100% 25% / 0.5)`. Alpha is left out when the 
+/// color is fully opaque.
+impl fmt::Display for HSLAColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "hsl({} {}% {}%",
+            self.hue,
+            helpers::float_to_percent(self.saturation),
+            helpers::float_to_percent(self.lightness),
+        )?;
+        
+        if self.alpha < 1.0 {
+            write!(f, " / {}", self.alpha)?;
+        }
+        
+        write!(f, ")")
+    }
+}
+
+/// Parses any supported color string. hsl color strings are parsed without converting through 
+/// RGB so that no precision is lost.
+impl FromStr for HSLAColor {
+    type Err = ParseColorError;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        
+        if helpers::remove_prefix_ignore_ascii_case(s, "hsl").is_some() {
+            let ([hue, saturation, lightness], alpha) = parse::hsl_values(s, false)?;
+            
+            return Ok(Self { hue, saturation, lightness, alpha });
+        }
+        
+        s.parse::<Rgba>().map(Self::from)
+    }
+}
+
+/// Serializes to an hsl color string for human-readable formats such as JSON, and to the hue, 
+/// saturation, lightness, and alpha values for binary formats such as bincode.
+#[cfg(feature = "serde")]
+impl serde::Serialize for HSLAColor {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            [self.hue, self.saturation, self.lightness, self.alpha].serialize(serializer)
+        }
+    }
+}
+
+/// Deserializes from any supported color string for human-readable formats such as JSON, and 
+/// from the hue, saturation, lightness, and alpha values for binary formats such as bincode.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for HSLAColor {
+    fn deserialize<D>(deserializer: D) -> Result<HSLAColor, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(crate::serializers::HSLAColorVisitor)
+        } else {
+            let [hue, saturation, lightness, alpha] = <[f32; 4]>::deserialize(deserializer)?;
+            
+            Ok(Self { hue, saturation, lightness, alpha })
+        }
+    }
+}
+
+impl From<HSLColor> for HSLAColor {
+    fn from(hsl: HSLColor) -> Self {
+        Self::from_hsl(hsl, 1.0)
+    }
+}
+
+impl From<HSLAColor> for HSLColor {
+    fn from(hsla: HSLAColor) -> Self {
+        hsla.to_hsl()
+    }
+}
+
+impl From<Color> for HSLAColor {
+    fn from(color: Color) -> Self {
+        Self::from(HSLColor::from(color))
+    }
+}
+
+impl From<Rgba> for HSLAColor {
+    fn from(rgba: Rgba) -> Self {
+        Self::from_hsl(HSLColor::from(rgba.to_color()), rgba.alpha)
+    }
+}
+
+impl From<HSLAColor> for Rgba {
+    fn from(hsla: HSLAColor) -> Self {
+        (Color::from(hsla.to_hsl()), hsla.alpha).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn converts_to_and_from_rgba() {
+        let rgba = Rgba { red: 255, green: 0, blue: 0, alpha: 0.25 };
+        let hsla = HSLAColor::from(rgba);
+        
+        assert_eq!(hsla, HSLAColor { hue: 0.0, saturation: 1.0, lightness: 0.5, alpha: 0.25 });
+        assert_eq!(Rgba::from(hsla), rgba);
+        assert_eq!(HSLAColor::new().alpha, 1.0);
+        assert_eq!(hsla.alpha(2.0).alpha, 1.0);
+    }
+    
+    #[test]
+    fn converts_from_str() {
+        assert_eq!(
+            "hsla(200, 50%, 40%, 0.3)".parse::<HSLAColor>(),
+            Ok(HSLAColor { hue: 200.0, saturation: 0.5, lightness: 0.4, alpha: 0.3 }),
+        );
+        assert_eq!("#FF000080".parse::<HSLAColor>().unwrap().alpha, 128.0 / 255.0);
+        assert_eq!(HSLAColor::from(Color::from_decimal(0xFF0000)).to_string(), "hsl(0 100% 50%)");
+        assert!("hsl(200 50%)".parse::<HSLAColor>().is_err());
+        
+        for s in ["HSLA(200, 50%, 40%, 0.3)", "  hsla(200, 50%, 40%, 0.3)\n"] {
+            let hsla = s.parse::<HSLAColor>().unwrap();
+            
+            assert_eq!(hsla.hue, 200.0);
+            assert_eq!(hsla.saturation, 0.5);
+        }
+    }
+    
+    #[test]
+    fn round_trips_through_string() {
+        for alpha in [0.0, 0.5, 1.0] {
+            let hsla = HSLAColor { hue: 200.0, saturation: 0.5, lightness: 0.4, alpha };
+            
+            assert_eq!(hsla.to_string().parse::<HSLAColor>(), Ok(hsla));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,107 @@
+use crate::{Color, HSLColor};
+
+/// A color containing values for hue, saturation, and value (also known as HSB, for brightness), 
+/// as used by most color pickers.
+/// 
+/// Conversions to and from [`HSLColor`] use the closed-form formulas, so no precision is lost by 
+/// rounding to 8-bit values.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, HSLColor, HSVColor};
+/// 
+/// let hsv = HSVColor { hue: 120.0, saturation: 1.0, value: 0.5 };
+/// 
+/// assert_eq!(Color::from(hsv), Color { red: 0, green: 128, blue: 0 });
+/// assert_eq!(HSLColor::from(hsv), HSLColor { hue: 120.0, saturation: 1.0, lightness: 0.25 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default, PartialOrd)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct HSVColor {
+    /// The hue value (0.0 to 360.0).
+    pub hue: f32,
+    /// The saturation value (0.0 to 1.0).
+    pub saturation: f32,
+    /// The value, or brightness (0.0 to 1.0).
+    pub value: f32,
+}
+
+impl HSVColor {
+    /// Creates a new HSV color.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl From<HSLColor> for HSVColor {
+    fn from(hsl: HSLColor) -> Self {
+        let value = hsl.lightness + hsl.saturation * hsl.lightness.min(1.0 - hsl.lightness);
+        let saturation = if value == 0.0 {
+            0.0
+        } else {
+            2.0 * (1.0 - hsl.lightness / value)
+        };
+        
+        Self {
+            hue: hsl.hue,
+            saturation,
+            value,
+        }
+    }
+}
+
+impl From<HSVColor> for HSLColor {
+    fn from(hsv: HSVColor) -> Self {
+        let lightness = hsv.value * (1.0 - hsv.saturation / 2.0);
+        let saturation = if lightness == 0.0 || lightness == 1.0 {
+            0.0
+        } else {
+            (hsv.value - lightness) / lightness.min(1.0 - lightness)
+        };
+        
+        Self {
+            hue: hsv.hue,
+            saturation,
+            lightness,
+        }
+    }
+}
+
+impl From<Color> for HSVColor {
+    fn from(color: Color) -> Self {
+        Self::from(HSLColor::from(color))
+    }
+}
+
+impl From<HSVColor> for Color {
+    fn from(hsv: HSVColor) -> Self {
+        Self::from(HSLColor::from(hsv))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn converts_between_hsl_and_hsv() {
+        let hsl = HSLColor { hue: 200.0, saturation: 0.6, lightness: 0.3 };
+        let hsv = HSVColor::from(hsl);
+        let back = HSLColor::from(hsv);
+        
+        assert!((hsv.value - 0.48).abs() < 0.0001, "{hsv:?}");
+        assert!((hsv.saturation - 0.75).abs() < 0.0001, "{hsv:?}");
+        assert!((back.saturation - hsl.saturation).abs() < 0.0001, "{back:?}");
+        assert!((back.lightness - hsl.lightness).abs() < 0.0001, "{back:?}");
+        
+        let white = HSLColor { hue: 0.0, saturation: 0.0, lightness: 1.0 };
+        
+        assert_eq!(HSVColor::from(white), HSVColor { hue: 0.0, saturation: 0.0, value: 1.0 });
+        assert_eq!(HSLColor::from(HSVColor::new()), HSLColor::new());
+        assert_eq!(
+            HSVColor::from(Color { red: 255, green: 0, blue: 0 }),
+            HSVColor { hue: 0.0, saturation: 1.0, value: 1.0 },
+        );
+    }
+}
\ No newline at end of file
@@ -1,6 +1,7 @@
 //! HTML color constants.
 
 use super::Color;
+use crate::named;
 
 /// Alice blue.
 pub const ALICE_BLUE: Color = Color { red: 240, green: 248, blue: 255 };
@@ -299,303 +300,205 @@ pub const YELLOW: Color = Color { red: 255, green: 255, blue: 0 };
 /// Yellow green.
 pub const YELLOW_GREEN: Color = Color { red: 154, green: 205, blue: 50 };
 
-/// Attempts to parse a color from a color name.
+/// Every color name paired with its color, sorted by name.
+pub(crate) const COLOR_NAMES: [(&str, Color); 148] = [
+    ("aliceblue", ALICE_BLUE),
+    ("antiquewhite", ANTIQUE_WHITE),
+    ("aqua", AQUA),
+    ("aquamarine", AQUAMARINE),
+    ("azure", AZURE),
+    ("beige", BEIGE),
+    ("bisque", BISQUE),
+    ("black", BLACK),
+    ("blanchedalmond", BLANCHED_ALMOND),
+    ("blue", BLUE),
+    ("blueviolet", BLUE_VIOLET),
+    ("brown", BROWN),
+    ("burlywood", BURLY_WOOD),
+    ("cadetblue", CADET_BLUE),
+    ("chartreuse", CHARTREUSE),
+    ("chocolate", CHOCOLATE),
+    ("coral", CORAL),
+    ("cornflowerblue", CORNFLOWER_BLUE),
+    ("cornsilk", CORNSILK),
+    ("crimson", CRIMSON),
+    ("cyan", CYAN),
+    ("darkblue", DARK_BLUE),
+    ("darkcyan", DARK_CYAN),
+    ("darkgoldenrod", DARK_GOLDEN_ROD),
+    ("darkgray", DARK_GRAY),
+    ("darkgreen", DARK_GREEN),
+    ("darkgrey", DARK_GREY),
+    ("darkkhaki", DARK_KHAKI),
+    ("darkmagenta", DARK_MAGENTA),
+    ("darkolivegreen", DARK_OLIVE_GREEN),
+    ("darkorange", DARK_ORANGE),
+    ("darkorchid", DARK_ORCHID),
+    ("darkred", DARK_RED),
+    ("darksalmon", DARK_SALMON),
+    ("darkseagreen", DARK_SEA_GREEN),
+    ("darkslateblue", DARK_SLATE_BLUE),
+    ("darkslategray", DARK_SLATE_GRAY),
+    ("darkslategrey", DARK_SLATE_GREY),
+    ("darkturquoise", DARK_TURQUOISE),
+    ("darkviolet", DARK_VIOLET),
+    ("deeppink", DEEP_PINK),
+    ("deepskyblue", DEEP_SKY_BLUE),
+    ("dimgray", DIM_GRAY),
+    ("dimgrey", DIM_GREY),
+    ("dodgerblue", DODGER_BLUE),
+    ("firebrick", FIRE_BRICK),
+    ("floralwhite", FLORAL_WHITE),
+    ("forestgreen", FOREST_GREEN),
+    ("fuchsia", FUCHSIA),
+    ("gainsboro", GAINSBORO),
+    ("ghostwhite", GHOST_WHITE),
+    ("gold", GOLD),
+    ("goldenrod", GOLDEN_ROD),
+    ("gray", GRAY),
+    ("green", GREEN),
+    ("greenyellow", GREEN_YELLOW),
+    ("grey", GREY),
+    ("honeydew", HONEY_DEW),
+    ("hotpink", HOT_PINK),
+    ("indianred", INDIAN_RED),
+    ("indigo", INDIGO),
+    ("ivory", IVORY),
+    ("khaki", KHAKI),
+    ("lavender", LAVENDER),
+    ("lavenderblush", LAVENDER_BLUSH),
+    ("lawngreen", LAWN_GREEN),
+    ("lemonchiffon", LEMON_CHIFFON),
+    ("lightblue", LIGHT_BLUE),
+    ("lightcoral", LIGHT_CORAL),
+    ("lightcyan", LIGHT_CYAN),
+    ("lightgoldenrodyellow", LIGHT_GOLDEN_ROD_YELLOW),
+    ("lightgray", LIGHT_GRAY),
+    ("lightgreen", LIGHT_GREEN),
+    ("lightgrey", LIGHT_GREY),
+    ("lightpink", LIGHT_PINK),
+    ("lightsalmon", LIGHT_SALMON),
+    ("lightseagreen", LIGHT_SEA_GREEN),
+    ("lightskyblue", LIGHT_SKY_BLUE),
+    ("lightslategray", LIGHT_SLATE_GRAY),
+    ("lightslategrey", LIGHT_SLATE_GREY),
+    ("lightsteelblue", LIGHT_STEEL_BLUE),
+    ("lightyellow", LIGHT_YELLOW),
+    ("lime", LIME),
+    ("limegreen", LIME_GREEN),
+    ("linen", LINEN),
+    ("magenta", MAGENTA),
+    ("maroon", MAROON),
+    ("mediumaquamarine", MEDIUM_AQUA_MARINE),
+    ("mediumblue", MEDIUM_BLUE),
+    ("mediumorchid", MEDIUM_ORCHID),
+    ("mediumpurple", MEDIUM_PURPLE),
+    ("mediumseagreen", MEDIUM_SEA_GREEN),
+    ("mediumslateblue", MEDIUM_SLATE_BLUE),
+    ("mediumspringgreen", MEDIUM_SPRING_GREEN),
+    ("mediumturquoise", MEDIUM_TURQUOISE),
+    ("mediumvioletred", MEDIUM_VIOLET_RED),
+    ("midnightblue", MIDNIGHT_BLUE),
+    ("mintcream", MINT_CREAM),
+    ("mistyrose", MISTY_ROSE),
+    ("moccasin", MOCCASIN),
+    ("navajowhite", NAVAJO_WHITE),
+    ("navy", NAVY),
+    ("oldlace", OLD_LACE),
+    ("olive", OLIVE),
+    ("olivedrab", OLIVE_DRAB),
+    ("orange", ORANGE),
+    ("orangered", ORANGE_RED),
+    ("orchid", ORCHID),
+    ("palegoldenrod", PALE_GOLDEN_ROD),
+    ("palegreen", PALE_GREEN),
+    ("paleturquoise", PALE_TURQUOISE),
+    ("palevioletred", PALE_VIOLET_RED),
+    ("papayawhip", PAPAYA_WHIP),
+    ("peachpuff", PEACH_PUFF),
+    ("peru", PERU),
+    ("pink", PINK),
+    ("plum", PLUM),
+    ("powderblue", POWDER_BLUE),
+    ("purple", PURPLE),
+    ("rebeccapurple", REBECCA_PURPLE),
+    ("red", RED),
+    ("rosybrown", ROSY_BROWN),
+    ("royalblue", ROYAL_BLUE),
+    ("saddlebrown", SADDLE_BROWN),
+    ("salmon", SALMON),
+    ("sandybrown", SANDY_BROWN),
+    ("seagreen", SEA_GREEN),
+    ("seashell", SEA_SHELL),
+    ("sienna", SIENNA),
+    ("silver", SILVER),
+    ("skyblue", SKY_BLUE),
+    ("slateblue", SLATE_BLUE),
+    ("slategray", SLATE_GRAY),
+    ("slategrey", SLATE_GREY),
+    ("snow", SNOW),
+    ("springgreen", SPRING_GREEN),
+    ("steelblue", STEEL_BLUE),
+    ("tan", TAN),
+    ("teal", TEAL),
+    ("thistle", THISTLE),
+    ("tomato", TOMATO),
+    ("turquoise", TURQUOISE),
+    ("violet", VIOLET),
+    ("wheat", WHEAT),
+    ("white", WHITE),
+    ("whitesmoke", WHITE_SMOKE),
+    ("yellow", YELLOW),
+    ("yellowgreen", YELLOW_GREEN),
+];
+
+/// Attempts to parse a color from a color name. Matching is case-insensitive and leading and 
+/// trailing whitespace is ignored.
 pub fn from_html_color_name(s: &str) -> Option<Color> {
-    match s.to_ascii_lowercase().as_str() {
-        "aliceblue" => Some(ALICE_BLUE),
-        "antiquewhite" => Some(ANTIQUE_WHITE),
-        "aqua" => Some(AQUA),
-        "aquamarine" => Some(AQUAMARINE),
-        "azure" => Some(AZURE),
-        "beige" => Some(BEIGE),
-        "bisque" => Some(BISQUE),
-        "black" => Some(BLACK),
-        "blanchedalmond" => Some(BLANCHED_ALMOND),
-        "blue" => Some(BLUE),
-        "blueviolet" => Some(BLUE_VIOLET),
-        "brown" => Some(BROWN),
-        "burlywood" => Some(BURLY_WOOD),
-        "cadetblue" => Some(CADET_BLUE),
-        "chartreuse" => Some(CHARTREUSE),
-        "chocolate" => Some(CHOCOLATE),
-        "coral" => Some(CORAL),
-        "cornflowerblue" => Some(CORNFLOWER_BLUE),
-        "cornsilk" => Some(CORNSILK),
-        "crimson" => Some(CRIMSON),
-        "cyan" => Some(CYAN),
-        "darkblue" => Some(DARK_BLUE),
-        "darkcyan" => Some(DARK_CYAN),
-        "darkgoldenrod" => Some(DARK_GOLDEN_ROD),
-        "darkgray" => Some(DARK_GRAY),
-        "darkgrey" => Some(DARK_GREY),
-        "darkgreen" => Some(DARK_GREEN),
-        "darkkhaki" => Some(DARK_KHAKI),
-        "darkmagenta" => Some(DARK_MAGENTA),
-        "darkolivegreen" => Some(DARK_OLIVE_GREEN),
-        "darkorange" => Some(DARK_ORANGE),
-        "darkorchid" => Some(DARK_ORCHID),
-        "darkred" => Some(DARK_RED),
-        "darksalmon" => Some(DARK_SALMON),
-        "darksea_green" => Some(DARK_SEA_GREEN),
-        "darkslateblue" => Some(DARK_SLATE_BLUE),
-        "darkslategray" => Some(DARK_SLATE_GRAY),
-        "darkslategrey" => Some(DARK_SLATE_GREY),
-        "darkturquoise" => Some(DARK_TURQUOISE),
-        "darkviolet" => Some(DARK_VIOLET),
-        "deeppink" => Some(DEEP_PINK),
-        "deepskyblue" => Some(DEEP_SKY_BLUE),
-        "dimgray" => Some(DIM_GRAY),
-        "dimgrey" => Some(DIM_GREY),
-        "dodgerblue" => Some(DODGER_BLUE),
-        "firebrick" => Some(FIRE_BRICK),
-        "floralwhite" => Some(FLORAL_WHITE),
-        "forestgreen" => Some(FOREST_GREEN),
-        "fuchsia" => Some(FUCHSIA),
-        "gainsboro" => Some(GAINSBORO),
-        "ghostwhite" => Some(GHOST_WHITE),
-        "gold" => Some(GOLD),
-        "goldenrod" => Some(GOLDEN_ROD),
-        "gray" => Some(GRAY),
-        "grey" => Some(GREY),
-        "green" => Some(GREEN),
-        "greenyellow" => Some(GREEN_YELLOW),
-        "honeydew" => Some(HONEY_DEW),
-        "hotpink" => Some(HOT_PINK),
-        "indianred" => Some(INDIAN_RED),
-        "indigo" => Some(INDIGO),
-        "ivory" => Some(IVORY),
-        "khaki" => Some(KHAKI),
-        "lavender" => Some(LAVENDER),
-        "lavenderblush" => Some(LAVENDER_BLUSH),
-        "lawngreen" => Some(LAWN_GREEN),
-        "lemonchiffon" => Some(LEMON_CHIFFON),
-        "lightblue" => Some(LIGHT_BLUE),
-        "lightcoral" => Some(LIGHT_CORAL),
-        "lightcyan" => Some(LIGHT_CYAN),
-        "lightgoldenrodyellow" => Some(LIGHT_GOLDEN_ROD_YELLOW),
-        "lightgray" => Some(LIGHT_GRAY),
-        "lightgrey" => Some(LIGHT_GREY),
-        "lightgreen" => Some(LIGHT_GREEN),
-        "lightpink" => Some(LIGHT_PINK),
-        "lightsalmon" => Some(LIGHT_SALMON),
-        "lightseagreen" => Some(LIGHT_SEA_GREEN),
-        "lightskyblue" => Some(LIGHT_SKY_BLUE),
-        "lightslategray" => Some(LIGHT_SLATE_GRAY),
-        "lightslategrey" => Some(LIGHT_SLATE_GREY),
-        "lightsteelblue" => Some(LIGHT_STEEL_BLUE),
-        "lightyellow" => Some(LIGHT_YELLOW),
-        "lime" => Some(LIME),
-        "limegreen" => Some(LIME_GREEN),
-        "linen" => Some(LINEN),
-        "magenta" => Some(MAGENTA),
-        "maroon" => Some(MAROON),
-        "mediumaquamarine" => Some(MEDIUM_AQUA_MARINE),
-        "mediumblue" => Some(MEDIUM_BLUE),
-        "mediumorchid" => Some(MEDIUM_ORCHID),
-        "mediumpurple" => Some(MEDIUM_PURPLE),
-        "mediumseagreen" => Some(MEDIUM_SEA_GREEN),
-        "mediumslateblue" => Some(MEDIUM_SLATE_BLUE),
-        "mediumspringgreen" => Some(MEDIUM_SPRING_GREEN),
-        "mediumturquoise" => Some(MEDIUM_TURQUOISE),
-        "mediumvioletred" => Some(MEDIUM_VIOLET_RED),
-        "midnightblue" => Some(MIDNIGHT_BLUE),
-        "mintcream" => Some(MINT_CREAM),
-        "mistyrose" => Some(MISTY_ROSE),
-        "moccasin" => Some(MOCCASIN),
-        "navajowhite" => Some(NAVAJO_WHITE),
-        "navy" => Some(NAVY),
-        "oldlace" => Some(OLD_LACE),
-        "olive" => Some(OLIVE),
-        "olivedrab" => Some(OLIVE_DRAB),
-        "orange" => Some(ORANGE),
-        "orangered" => Some(ORANGE_RED),
-        "orchid" => Some(ORCHID),
-        "palegoldenrod" => Some(PALE_GOLDEN_ROD),
-        "palegreen" => Some(PALE_GREEN),
-        "paleturquoise" => Some(PALE_TURQUOISE),
-        "palevioletred" => Some(PALE_VIOLET_RED),
-        "papayawhip" => Some(PAPAYA_WHIP),
-        "peachpuff" => Some(PEACH_PUFF),
-        "peru" => Some(PERU),
-        "pink" => Some(PINK),
-        "plum" => Some(PLUM),
-        "powderblue" => Some(POWDER_BLUE),
-        "purple" => Some(PURPLE),
-        "rebeccapurple" => Some(REBECCA_PURPLE),
-        "red" => Some(RED),
-        "rosybrown" => Some(ROSY_BROWN),
-        "royalblue" => Some(ROYAL_BLUE),
-        "saddlebrown" => Some(SADDLE_BROWN),
-        "salmon" => Some(SALMON),
-        "sandybrown" => Some(SANDY_BROWN),
-        "seagreen" => Some(SEA_GREEN),
-        "seashell" => Some(SEA_SHELL),
-        "sienna" => Some(SIENNA),
-        "silver" => Some(SILVER),
-        "skyblue" => Some(SKY_BLUE),
-        "slateblue" => Some(SLATE_BLUE),
-        "slategray" => Some(SLATE_GRAY),
-        "slategrey" => Some(SLATE_GREY),
-        "snow" => Some(SNOW),
-        "springgreen" => Some(SPRING_GREEN),
-        "steelblue" => Some(STEEL_BLUE),
-        "tan" => Some(TAN),
-        "teal" => Some(TEAL),
-        "thistle" => Some(THISTLE),
-        "tomato" => Some(TOMATO),
-        "turquoise" => Some(TURQUOISE),
-        "violet" => Some(VIOLET),
-        "wheat" => Some(WHEAT),
-        "white" => Some(WHITE),
-        "whitesmoke" => Some(WHITE_SMOKE),
-        "yellow" => Some(YELLOW),
-        "yellowgreen" => Some(YELLOW_GREEN),
-        _ => None,
-    }
+    named::find_by_name(&COLOR_NAMES, s)
 }
 
-/// Converts a color to a color name if possible.
+/// Every color paired with its name, keyed by its decimal value and sorted by key. Colors with 
+/// more than one name, such as "aqua" and "cyan", keep their names in alphabetical order.
+const COLOR_VALUES: [(u32, &str); COLOR_NAMES.len()] = named::sort_by_value(COLOR_NAMES);
+
+/// Converts a color to a color name if possible. For colors with more than one name, the first 
+/// name in alphabetical order is used, e.g. "aqua" rather than "cyan".
 pub fn to_html_color_name(color: &Color) -> Option<&'static str> {
-    match *color {
-        ALICE_BLUE => Some("aliceblue"),
-        ANTIQUE_WHITE => Some("antiquewhite"),
-        AQUA => Some("aqua"),
-        AQUAMARINE => Some("aquamarine"),
-        AZURE => Some("azure"),
-        BEIGE => Some("beige"),
-        BISQUE => Some("bisque"),
-        BLACK => Some("black"),
-        BLANCHED_ALMOND => Some("blanchedalmond"),
-        BLUE => Some("blue"),
-        BLUE_VIOLET => Some("blueviolet"),
-        BROWN => Some("brown"),
-        BURLY_WOOD => Some("burlywood"),
-        CADET_BLUE => Some("cadetblue"),
-        CHARTREUSE => Some("chartreuse"),
-        CHOCOLATE => Some("chocolate"),
-        CORAL => Some("coral"),
-        CORNFLOWER_BLUE => Some("cornflowerblue"),
-        CORNSILK => Some("cornsilk"),
-        CRIMSON => Some("crimson"),
-        DARK_BLUE => Some("darkblue"),
-        DARK_CYAN => Some("darkcyan"),
-        DARK_GOLDEN_ROD => Some("darkgoldenrod"),
-        DARK_GRAY => Some("darkgray"),
-        DARK_GREEN => Some("darkgreen"),
-        DARK_KHAKI => Some("darkkhaki"),
-        DARK_MAGENTA => Some("darkmagenta"),
-        DARK_OLIVE_GREEN => Some("darkolivegreen"),
-        DARK_ORANGE => Some("darkorange"),
-        DARK_ORCHID => Some("darkorchid"),
-        DARK_RED => Some("darkred"),
-        DARK_SALMON => Some("darksalmon"),
-        DARK_SEA_GREEN => Some("darksea_green"),
-        DARK_SLATE_BLUE => Some("darkslateblue"),
-        DARK_SLATE_GRAY => Some("darkslategray"),
-        DARK_TURQUOISE => Some("darkturquoise"),
-        DARK_VIOLET => Some("darkviolet"),
-        DEEP_PINK => Some("deeppink"),
-        DEEP_SKY_BLUE => Some("deepskyblue"),
-        DIM_GRAY => Some("dimgray"),
-        DODGER_BLUE => Some("dodgerblue"),
-        FIRE_BRICK => Some("firebrick"),
-        FLORAL_WHITE => Some("floralwhite"),
-        FOREST_GREEN => Some("forestgreen"),
-        FUCHSIA => Some("fuchsia"),
-        GAINSBORO => Some("gainsboro"),
-        GHOST_WHITE => Some("ghostwhite"),
-        GOLD => Some("gold"),
-        GOLDEN_ROD => Some("goldenrod"),
-        GRAY => Some("gray"),
-        GREEN => Some("green"),
-        GREEN_YELLOW => Some("greenyellow"),
-        HONEY_DEW => Some("honeydew"),
-        HOT_PINK => Some("hotpink"),
-        INDIAN_RED => Some("indianred"),
-        INDIGO => Some("indigo"),
-        IVORY => Some("ivory"),
-        KHAKI => Some("khaki"),
-        LAVENDER => Some("lavender"),
-        LAVENDER_BLUSH => Some("lavenderblush"),
-        LAWN_GREEN => Some("lawngreen"),
-        LEMON_CHIFFON => Some("lemonchiffon"),
-        LIGHT_BLUE => Some("lightblue"),
-        LIGHT_CORAL => Some("lightcoral"),
-        LIGHT_CYAN => Some("lightcyan"),
-        LIGHT_GOLDEN_ROD_YELLOW => Some("lightgoldenrodyellow"),
-        LIGHT_GRAY => Some("lightgray"),
-        LIGHT_GREEN => Some("lightgreen"),
-        LIGHT_PINK => Some("lightpink"),
-        LIGHT_SALMON => Some("lightsalmon"),
-        LIGHT_SEA_GREEN => Some("lightseagreen"),
-        LIGHT_SKY_BLUE => Some("lightskyblue"),
-        LIGHT_SLATE_GRAY => Some("lightslategray"),
-        LIGHT_STEEL_BLUE => Some("lightsteelblue"),
-        LIGHT_YELLOW => Some("lightyellow"),
-        LIME => Some("lime"),
-        LIME_GREEN => Some("limegreen"),
-        LINEN => Some("linen"),
-        MAROON => Some("maroon"),
-        MEDIUM_AQUA_MARINE => Some("mediumaquamarine"),
-        MEDIUM_BLUE => Some("mediumblue"),
-        MEDIUM_ORCHID => Some("mediumorchid"),
-        MEDIUM_PURPLE => Some("mediumpurple"),
-        MEDIUM_SEA_GREEN => Some("mediumseagreen"),
-        MEDIUM_SLATE_BLUE => Some("mediumslateblue"),
-        MEDIUM_SPRING_GREEN => Some("mediumspringgreen"),
-        MEDIUM_TURQUOISE => Some("mediumturquoise"),
-        MEDIUM_VIOLET_RED => Some("mediumvioletred"),
-        MIDNIGHT_BLUE => Some("midnightblue"),
-        MINT_CREAM => Some("mintcream"),
-        MISTY_ROSE => Some("mistyrose"),
-        MOCCASIN => Some("moccasin"),
-        NAVAJO_WHITE => Some("navajowhite"),
-        NAVY => Some("navy"),
-        OLD_LACE => Some("oldlace"),
-        OLIVE => Some("olive"),
-        OLIVE_DRAB => Some("olivedrab"),
-        ORANGE => Some("orange"),
-        ORANGE_RED => Some("orangered"),
-        ORCHID => Some("orchid"),
-        PALE_GOLDEN_ROD => Some("palegoldenrod"),
-        PALE_GREEN => Some("palegreen"),
-        PALE_TURQUOISE => Some("paleturquoise"),
-        PALE_VIOLET_RED => Some("palevioletred"),
-        PAPAYA_WHIP => Some("papayawhip"),
-        PEACH_PUFF => Some("peachpuff"),
-        PERU => Some("peru"),
-        PINK => Some("pink"),
-        PLUM => Some("plum"),
-        POWDER_BLUE => Some("powderblue"),
-        PURPLE => Some("purple"),
-        REBECCA_PURPLE => Some("rebeccapurple"),
-        RED => Some("red"),
-        ROSY_BROWN => Some("rosybrown"),
-        ROYAL_BLUE => Some("royalblue"),
-        SADDLE_BROWN => Some("saddlebrown"),
-        SALMON => Some("salmon"),
-        SANDY_BROWN => Some("sandybrown"),
-        SEA_GREEN => Some("seagreen"),
-        SEA_SHELL => Some("seashell"),
-        SIENNA => Some("sienna"),
-        SILVER => Some("silver"),
-        SKY_BLUE => Some("skyblue"),
-        SLATE_BLUE => Some("slateblue"),
-        SLATE_GRAY => Some("slategray"),
-        SNOW => Some("snow"),
-        SPRING_GREEN => Some("springgreen"),
-        STEEL_BLUE => Some("steelblue"),
-        TAN => Some("tan"),
-        TEAL => Some("teal"),
-        THISTLE => Some("thistle"),
-        TOMATO => Some("tomato"),
-        TURQUOISE => Some("turquoise"),
-        VIOLET => Some("violet"),
-        WHEAT => Some("wheat"),
-        WHITE => Some("white"),
-        WHITE_SMOKE => Some("whitesmoke"),
-        YELLOW => Some("yellow"),
-        YELLOW_GREEN => Some("yellowgreen"),
-        _ => None,        
+    named::find_by_value(&COLOR_VALUES, color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn parses_html_color_name() {
+        assert_eq!(from_html_color_name("red"), Some(RED));
+        assert_eq!(from_html_color_name("LightGoldenrodYellow"), Some(LIGHT_GOLDEN_ROD_YELLOW));
+        assert_eq!(from_html_color_name("  Red\n"), Some(RED));
+        assert_eq!(from_html_color_name("lightgoldenrodyellows"), None);
+        assert_eq!(from_html_color_name("rëd"), None);
+        assert_eq!(from_html_color_name(""), None);
+    }
+    
+    #[test]
+    fn converts_to_html_color_name() {
+        assert_eq!(to_html_color_name(&RED), Some("red"));
+        assert_eq!(to_html_color_name(&DARK_SEA_GREEN), Some("darkseagreen"));
+        assert_eq!(to_html_color_name(&CYAN), Some("aqua"));
+        assert_eq!(to_html_color_name(&DARK_GREY), Some("darkgray"));
+        assert_eq!(to_html_color_name(&Color { red: 1, green: 2, blue: 3 }), None);
+        
+        for (name, color) in COLOR_NAMES {
+            let found = to_html_color_name(&color).and_then(from_html_color_name);
+            
+            assert_eq!(found, Some(color), "{name}");
+        }
+    }
+    
+    #[test]
+    fn color_names_are_sorted() {
+        assert!(COLOR_NAMES.windows(2).all(|pair| pair[0].0 < pair[1].0));
     }
 }
\ No newline at end of file
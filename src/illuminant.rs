@@ -0,0 +1,52 @@
+/// A standard CIE illuminant, for previewing how surface colors look under different light 
+/// sources using [`Color::under_illuminant`](crate::Color::under_illuminant).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Illuminant {
+    /// Average daylight at about 6500 K, the white point of sRGB.
+    #[default]
+    D65,
+    /// Horizon daylight at about 5000 K, used for print viewing booths and ICC profiles.
+    D50,
+    /// Incandescent tungsten light at about 2856 K.
+    A,
+    /// Cool white fluorescent light at about 4230 K.
+    F2,
+}
+
+impl Illuminant {
+    /// Gets the CIE 1931 xy chromaticity of this illuminant for the 2° standard observer.
+    pub(crate) fn chromaticity(&self) -> (f32, f32) {
+        match self {
+            Self::D65 => (0.31271, 0.32902),
+            Self::D50 => (0.34567, 0.35850),
+            Self::A => (0.44757, 0.40745),
+            Self::F2 => (0.37208, 0.37529),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Color;
+    use super::*;
+    
+    #[test]
+    fn maps_white_to_white_point() {
+        let white = Color { red: 255, green: 255, blue: 255 };
+        // The sRGB encoding of each white point at a luminance of 1.0, clipped to the gamut.
+        let cases = [
+            (Illuminant::D65, [255, 255, 255]),
+            (Illuminant::D50, [255, 252, 221]),
+            (Illuminant::A, [255, 234, 133]),
+            (Illuminant::F2, [255, 249, 198]),
+        ];
+        
+        for (illuminant, expected) in cases {
+            let adapted = white.under_illuminant(illuminant).to_array();
+            
+            for (value, expected) in adapted.into_iter().zip(expected) {
+                assert!(value.abs_diff(expected) <= 1, "{illuminant:?}: {adapted:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{Color, HSLColor, Rgba};
+use crate::color::Value;
+use crate::helpers::conversions;
+
+/// Linear interpolation between two colors of the same type, so that animation and gradient code 
+/// can be generic over the color type.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, Interpolate};
+/// 
+/// fn midpoint<T: Interpolate>(a: &T, b: &T) -> T {
+///     a.lerp(b, 0.5)
+/// }
+/// 
+/// let red = Color { red: 255, green: 0, blue: 0 };
+/// let blue = Color { red: 0, green: 0, blue: 255 };
+/// 
+/// assert_eq!(midpoint(&red, &blue), Color { red: 128, green: 0, blue: 128 });
+/// ```
+pub trait Interpolate {
+    /// Interpolates between this color and another color, where a `t` of 0.0 is this color and 
+    /// 1.0 is the other color. Values of `t` outside of 0.0 to 1.0 are clamped.
+    fn lerp(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for Color {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self.blend(*other, t)
+    }
+}
+
+impl Interpolate for Rgba {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        
+        (
+            self.to_color().blend(other.to_color(), t),
+            lerp(self.alpha, other.alpha, t),
+        ).into()
+    }
+}
+
+/// Interpolates hue along the shorter arc around the color wheel, e.g. from 350 to 10 degrees 
+/// passes through 0 rather than 180.
+impl Interpolate for HSLColor {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let mut difference = other.hue - self.hue;
+        
+        if difference > 180.0 {
+            difference -= 360.0;
+        } else if difference < -180.0 {
+            difference += 360.0;
+        }
+        
+        Self {
+            hue: (self.hue + difference * t).rem_euclid(360.0),
+            saturation: lerp(self.saturation, other.saturation, t),
+            lightness: lerp(self.lightness, other.lightness, t),
+        }
+    }
+}
+
+/// The color space in which [`Color`] values are interpolated.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, InterpolationSpace};
+/// 
+/// let red = Color { red: 255, green: 0, blue: 0 };
+/// let blue = Color { red: 0, green: 0, blue: 255 };
+/// 
+/// assert_eq!(
+///     InterpolationSpace::Srgb.lerp(red, blue, 0.5),
+///     Color { red: 128, green: 0, blue: 128 },
+/// );
+/// assert_eq!(
+///     InterpolationSpace::LinearSrgb.lerp(red, blue, 0.5),
+///     Color { red: 188, green: 0, blue: 188 },
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InterpolationSpace {
+    /// Gamma-encoded sRGB, the same as [`Color::blend`].
+    #[default]
+    Srgb,
+    /// Linear-light sRGB, which avoids dark bands between saturated colors.
+    LinearSrgb,
+    /// HSL, interpolating hue along the shorter arc.
+    Hsl,
+    /// Oklab, which keeps perceived lightness even across the interpolation.
+    Oklab,
+}
+
+impl InterpolationSpace {
+    /// Interpolates between two colors in this color space, where a `t` of 0.0 is `a` and 1.0 is 
+    /// `b`. Values of `t` outside of 0.0 to 1.0 are clamped.
+    pub fn lerp(&self, a: Color, b: Color, t: f32) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        
+        match self {
+            Self::Srgb => a.lerp(&b, t),
+            Self::LinearSrgb => {
+                conversions::linear_to_rgb(lerp_each(to_linear(a), to_linear(b), t)).into()
+            },
+            Self::Hsl => HSLColor::from(a).lerp(&HSLColor::from(b), t).into(),
+            Self::Oklab => {
+                let a = conversions::linear_to_oklab(to_linear(a));
+                let b = conversions::linear_to_oklab(to_linear(b));
+                
+                conversions::linear_to_rgb(conversions::oklab_to_linear(lerp_each(a, b, t))).into()
+            },
+        }
+    }
+}
+
+/// Converts a color to linear-light sRGB values.
+fn to_linear(color: Color) -> [f32; 3] {
+    color.to_array().map(|value| conversions::srgb_to_linear(value as f32 / Value::MAX as f32))
+}
+
+/// Linearly interpolates between each of two sets of values.
+fn lerp_each(a: [f32; 3], b: [f32; 3], t: f32) -> [f32; 3] {
+    [lerp(a[0], b[0], t), lerp(a[1], b[1], t), lerp(a[2], b[2], t)]
+}
+
+/// Linearly interpolates between two values.
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn interpolates_rgba() {
+        let a = Rgba { red: 0, green: 0, blue: 0, alpha: 0.0 };
+        let b = Rgba { red: 255, green: 255, blue: 255, alpha: 1.0 };
+        
+        assert_eq!(a.lerp(&b, 0.5), Rgba { red: 128, green: 128, blue: 128, alpha: 0.5 });
+        assert_eq!(a.lerp(&b, 2.0), b);
+    }
+    
+    #[test]
+    fn interpolates_hue_along_shorter_arc() {
+        let a = HSLColor { hue: 350.0, saturation: 1.0, lightness: 0.5 };
+        let b = HSLColor { hue: 30.0, saturation: 0.0, lightness: 0.5 };
+        
+        assert_eq!(a.lerp(&b, 0.25), HSLColor { hue: 0.0, saturation: 0.75, lightness: 0.5 });
+        assert_eq!(b.lerp(&a, 0.5), HSLColor { hue: 10.0, saturation: 0.5, lightness: 0.5 });
+    }
+    
+    #[test]
+    fn interpolates_in_color_spaces() {
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        
+        for space in [
+            InterpolationSpace::Srgb,
+            InterpolationSpace::LinearSrgb,
+            InterpolationSpace::Hsl,
+            InterpolationSpace::Oklab,
+        ] {
+            assert_eq!(space.lerp(black, white, 0.0), black, "{space:?}");
+            assert_eq!(space.lerp(black, white, 1.0), white, "{space:?}");
+        }
+        
+        assert_eq!(
+            InterpolationSpace::Oklab.lerp(black, white, 0.5),
+            Color { red: 99, green: 99, blue: 99 },
+        );
+    }
+}
\ No newline at end of file
@@ -0,0 +1,112 @@
+use crate::{Color, Easing, InterpolationSpace};
+
+/// A color at a point in time within [`ColorKeyframes`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Keyframe {
+    /// The time of this keyframe.
+    pub time: f32,
+    /// The color at this keyframe.
+    pub color: Color,
+    /// The easing applied to the segment from this keyframe to the next.
+    pub easing: Easing,
+    /// The color space used to interpolate the segment from this keyframe to the next.
+    pub space: InterpolationSpace,
+}
+
+/// A color animation made of time-stamped colors, for LED animations and theme transitions.
+/// 
+/// Each segment between two keyframes uses the easing and interpolation space of the keyframe 
+/// that starts it. Times can use any unit, such as seconds or a 0.0 to 1.0 progress value.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, ColorKeyframes, Easing, InterpolationSpace};
+/// 
+/// let red = Color { red: 255, green: 0, blue: 0 };
+/// let blue = Color { red: 0, green: 0, blue: 255 };
+/// let keyframes = ColorKeyframes::new()
+///     .keyframe(0.0, red)
+///     .keyframe_with(2.0, blue, Easing::EaseInOut, InterpolationSpace::Oklab)
+///     .keyframe(4.0, red);
+/// 
+/// assert_eq!(keyframes.sample(-1.0), Some(red));
+/// assert_eq!(keyframes.sample(1.0), Some(Color { red: 128, green: 0, blue: 128 }));
+/// assert_eq!(keyframes.sample(2.0), Some(blue));
+/// assert_eq!(keyframes.sample(5.0), Some(red));
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ColorKeyframes {
+    keyframes: Vec<Keyframe>,
+}
+
+impl ColorKeyframes {
+    /// Creates an empty set of keyframes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Adds a keyframe with linear easing interpolated in sRGB.
+    pub fn keyframe(self, time: f32, color: Color) -> Self {
+        self.keyframe_with(time, color, Easing::Linear, InterpolationSpace::Srgb)
+    }
+    
+    /// Adds a keyframe with the easing and interpolation space used for the segment that it 
+    /// starts.
+    pub fn keyframe_with(
+        mut self,
+        time: f32,
+        color: Color,
+        easing: Easing,
+        space: InterpolationSpace,
+    ) -> Self {
+        self.push(Keyframe { time, color, easing, space });
+        self
+    }
+    
+    /// Adds a keyframe, keeping keyframes ordered by time. A keyframe added at the same time as 
+    /// an existing keyframe is placed after it, creating an instant change in color.
+    pub fn push(&mut self, keyframe: Keyframe) {
+        let index = self.keyframes.partition_point(|existing| existing.time <= keyframe.time);
+        
+        self.keyframes.insert(index, keyframe);
+    }
+    
+    /// Gets the keyframes, ordered by time.
+    pub fn keyframes(&self) -> &[Keyframe] {
+        &self.keyframes
+    }
+    
+    /// Samples the color at a point in time. Times before the first keyframe or after the last 
+    /// keyframe use the color of that keyframe. Returns `None` if there are no keyframes.
+    pub fn sample(&self, time: f32) -> Option<Color> {
+        let index = self.keyframes.partition_point(|keyframe| keyframe.time <= time);
+        let (start, end) = match (index.checked_sub(1), self.keyframes.get(index)) {
+            (Some(previous), Some(end)) => (&self.keyframes[previous], end),
+            (Some(previous), None) => return Some(self.keyframes[previous].color),
+            (None, end) => return end.map(|keyframe| keyframe.color),
+        };
+        let progress = (time - start.time) / (end.time - start.time);
+        
+        Some(start.space.lerp(start.color, end.color, start.easing.apply(progress)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn samples_keyframes() {
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let keyframes = ColorKeyframes::new()
+            .keyframe(1.0, white)
+            .keyframe(0.0, black)
+            .keyframe(1.0, black);
+        
+        assert_eq!(ColorKeyframes::new().sample(0.0), None);
+        assert_eq!(keyframes.sample(0.5), Some(Color { red: 128, green: 128, blue: 128 }));
+        assert_eq!(keyframes.sample(0.999), Some(Color { red: 255, green: 255, blue: 255 }));
+        assert_eq!(keyframes.sample(1.0), Some(black));
+    }
+}
\ No newline at end of file
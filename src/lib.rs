@@ -1,27 +1,130 @@
 //! # nice-colors
 //! 
-//! Zero-dependency module (support for serde is optional) for working with colors. The aim is to 
-//! provide a wrapper for RGB color values in a way that does not compromise performance. Colors 
-//! are packed as three `u8` values to provide as lean of a memory footprint as possible. While 
-//! there are also some helpers for working with alpha values, they are not a main feature of this 
-//! module.
+//! Zero-dependency module (integrations with other crates are optional) for working with colors. 
+//! The aim is to provide a wrapper for RGB color values in a way that does not compromise 
+//! performance. Colors are packed as three `u8` values to provide as lean of a memory footprint as 
+//! possible. While there are also some helpers for working with alpha values, they are not a main 
+//! feature of this module.
 //! 
 //! ## Features
 //! - Color manipulation (lighten, darken, saturate, desaturate, etc.)
-//! - CSS color parsing (RGB, RGBA, HSL, HSLA). Not all CSS color formats are supported but 
-//! provides enough for most use cases.
+//! - CSS color parsing (RGB, RGBA, HSL, HSLA, Lab, LCH, and `color()` with the `srgb`, 
+//!   `srgb-linear`, and `display-p3` color spaces). Not all CSS color formats are supported but 
+//!   provides enough for most use cases.
 //! - Color serialization to and from CSS color strings.
+//! - [`Rgba`] and [`HSLAColor`] types for colors with alpha.
+//! - An [`HSVColor`] type for the hue, saturation, and value used by color pickers.
+//! - Bulk operations over slices of colors in the [`bulk`] module.
+//! - Reducing images to a [`Palette`] with dithering in the [`quantize`] module.
+//! - A [`ColorMatrix`] type for mixing channels like the SVG `feColorMatrix` filter.
+//! - A [`Lut1D`] lookup table for applying tone adjustments to many pixels quickly, and a 
+//!   [`Lut3D`] lookup table for color grading with `.cube` files.
+//! - Previewing colors under other light sources with [`Color::under_illuminant`].
+//! - Color blindness simulation with [`Color::simulate_deficiency`], and checking that a 
+//!   [`Palette`] stays distinguishable with [`Palette::is_cvd_safe`].
+//! - A [`Theme`] type for the semantic colors of a user interface, with light and dark variants.
+//! - A [`SortKey`] type for sorting colors by hue, lightness, or luminance, or into hue bands for 
+//!   swatches.
+//! - A [`Gradient`] type for sampling color gradients made of color stops, and parsing of CSS 
+//!   linear, radial, and conic gradients into a [`CssGradient`].
+//! - A [`Scale`] type for mapping numeric data onto a gradient, with log and power transforms.
+//! - An [`Interpolate`] trait for interpolating between colors generically.
+//! - X11 color names in the [`x11`] module, and well-known palettes such as Material Design in 
+//!   the [`palettes`] module.
+//! - A [`color!`] macro for checking color literals at compile time.
+//! 
+//! ## Optional features
+//! - `serde`: Serialization and deserialization of colors, plus the `serializers` module.
+//! - `rand`: Random colors using `Color::random`.
+//! - `zerocopy`: Reinterpreting bytes as colors using the `zerocopy` traits.
+//! - `rkyv`: Zero-copy archiving of [`Color`], [`Rgba`], [`HSLColor`], [`HSLAColor`], and 
+//!   [`Palette`] using `rkyv`.
+//! - `borsh`: Serialization of [`Color`], [`Rgba`], [`HSLColor`], [`HSLAColor`], and 
+//!   [`Palette`] using `borsh`.
+//! - `proptest`: Strategies for property testing in the `strategies` module.
+//! - `quickcheck`: An `Arbitrary` implementation for [`Color`] using `quickcheck`.
+//! - `sqlx`: Storing [`Color`] in TEXT (hexadecimal) or INTEGER (decimal) columns using `sqlx`.
+//! - `postgres`: Storing [`Color`] in TEXT or INTEGER columns using `postgres-types`.
+//! - `rusqlite`: Storing [`Color`] in TEXT or INTEGER columns using `rusqlite`.
+//! - `wasm`: JavaScript bindings in the `wasm` module using `wasm-bindgen`.
+//! - `image`: Rendering palette swatches and gradient strips as `image::RgbImage` buffers.
+//! - `colorbrewer`: The ColorBrewer schemes for maps and charts in the 
+//!   `palettes::colorbrewer` module.
+//! - `crayola`: Classic Crayola crayon colors in the `crayola` module.
+//! - `open-color`: The Open Color palette in the `palettes::open_color` module.
+//! - `xkcd`: Color names from the xkcd color survey in the `xkcd` module.
 
 #![warn(missing_docs)]
 
+#[cfg(feature = "crayola")]
+pub mod crayola;
 #[cfg(feature = "serde")]
 pub mod serializers;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "xkcd")]
+pub mod xkcd;
+pub mod bulk;
 pub mod html;
+pub mod palettes;
+pub mod quantize;
+pub mod x11;
 
 mod helpers;
 mod color;
+mod color_matrix;
+mod css_color;
+mod css_gradient;
+#[cfg(any(feature = "sqlx", feature = "postgres", feature = "rusqlite"))]
+mod database;
+mod deficiency;
+mod distinct;
+mod easing;
+mod error;
+mod format;
+mod gamut;
+mod gradient;
 mod hsl_color;
+mod hsla_color;
+mod hsv_color;
+mod illuminant;
+mod interpolate;
+mod keyframes;
+mod lut;
+mod macros;
+mod named;
+mod ops;
+mod palette;
 mod parse;
+mod rgba;
+mod scale;
+mod sort_key;
+mod theme;
 
-pub use color::{Color, ColorWithAlpha};
-pub use hsl_color::HSLColor;
\ No newline at end of file
+pub use color::{Channel, ChannelOrder, Color, ColorWithAlpha};
+pub use color_matrix::ColorMatrix;
+pub use css_color::CssColor;
+pub use css_gradient::{CssGradient, CssLength, GradientGeometry, RadialShape, RadialSize};
+pub use deficiency::Deficiency;
+pub use distinct::{distinct_colors, DistinctColors};
+pub use easing::Easing;
+pub use error::{ParseColorError, ParseCubeError, ParseGradientError};
+pub use format::{DisplayHex, DisplayRgb, DisplayRgba, FormatOptions, Notation};
+pub use gamut::GamutMapping;
+pub use gradient::{ColorStop, Gradient, GradientClasses};
+pub use hsl_color::HSLColor;
+pub use hsla_color::HSLAColor;
+pub use hsv_color::HSVColor;
+pub use illuminant::Illuminant;
+pub use interpolate::{Interpolate, InterpolationSpace};
+pub use keyframes::{ColorKeyframes, Keyframe};
+pub use lut::{Lut1D, Lut3D};
+pub use palette::{ClusterBy, ColorCluster, ContrastCheck, ContrastLevel, Palette};
+pub use rgba::Rgba;
+pub use scale::{Scale, ScaleTransform};
+pub use sort_key::SortKey;
+pub use theme::Theme;
+#[doc(hidden)]
+pub use macros::__color_from_str;
\ No newline at end of file
@@ -0,0 +1,423 @@
+use crate::{helpers, Color, ParseCubeError};
+use crate::color::{Value, SLICE_LENGTH};
+use std::str::FromStr;
+
+/// The number of entries in each table of a [`Lut1D`], one for each value.
+const LUT_1D_SIZE: usize = Value::MAX as usize + 1;
+
+/// A lookup table with an entry for every red, green, and blue value. Building a table once and 
+/// applying it to many pixels is much faster than repeating the same tone adjustment for each 
+/// pixel.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, Lut1D};
+/// 
+/// let contrast = Lut1D::from_curve(|value| (value - 0.5) * 1.5 + 0.5);
+/// let mut pixels = [Color { red: 64, green: 128, blue: 255 }; 4];
+/// 
+/// contrast.apply_in_place(&mut pixels);
+/// 
+/// assert_eq!(pixels, [Color { red: 32, green: 128, blue: 255 }; 4]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Lut1D {
+    tables: [[Value; LUT_1D_SIZE]; SLICE_LENGTH],
+}
+
+impl Lut1D {
+    /// Creates a table that leaves colors unchanged.
+    pub fn identity() -> Self {
+        Self::from_fn(|value| value)
+    }
+    
+    /// Creates a table from tables for red, green, and blue, in that order.
+    pub fn from_tables(tables: [[Value; LUT_1D_SIZE]; SLICE_LENGTH]) -> Self {
+        Self { tables }
+    }
+    
+    /// Creates a table that maps every value using the same function.
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(Value) -> Value,
+    {
+        Self::from_channel_fns(&f, &f, &f)
+    }
+    
+    /// Creates a table that maps red, green, and blue values using separate functions.
+    pub fn from_channel_fns<R, G, B>(red: R, green: G, blue: B) -> Self
+    where
+        R: Fn(Value) -> Value,
+        G: Fn(Value) -> Value,
+        B: Fn(Value) -> Value,
+    {
+        let table = |f: &dyn Fn(Value) -> Value| {
+            std::array::from_fn(|index| f(index as Value))
+        };
+        
+        Self {
+            tables: [table(&red), table(&green), table(&blue)],
+        }
+    }
+    
+    /// Creates a table from a tone curve that maps values from 0.0 to 1.0. Results are rounded 
+    /// and clamped.
+    pub fn from_curve<F>(curve: F) -> Self
+    where
+        F: Fn(f32) -> f32,
+    {
+        Self::from_fn(|value| {
+            let value = curve(value as f32 / Value::MAX as f32);
+            
+            helpers::float_to_value(value * Value::MAX as f32)
+        })
+    }
+    
+    /// Gets the tables for red, green, and blue, in that order.
+    pub fn tables(&self) -> &[[Value; LUT_1D_SIZE]; SLICE_LENGTH] {
+        &self.tables
+    }
+    
+    /// Creates a table that applies this table and then `other`.
+    pub fn then(&self, other: &Self) -> Self {
+        let mut tables = self.tables;
+        
+        for (table, other) in tables.iter_mut().zip(&other.tables) {
+            for value in table.iter_mut() {
+                *value = other[*value as usize];
+            }
+        }
+        
+        Self { tables }
+    }
+    
+    /// Applies this table to a color.
+    pub fn apply(&self, color: Color) -> Color {
+        let [red, green, blue] = &self.tables;
+        
+        Color {
+            red: red[color.red as usize],
+            green: green[color.green as usize],
+            blue: blue[color.blue as usize],
+        }
+    }
+    
+    /// Applies this table to each color in `colors` in place.
+    pub fn apply_in_place(&self, colors: &mut [Color]) {
+        for color in colors.iter_mut() {
+            *color = self.apply(*color);
+        }
+    }
+}
+
+impl Default for Lut1D {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+/// The largest size of a [`Lut3D`] allowed by the `.cube` format.
+const MAX_LUT_3D_SIZE: usize = 256;
+
+/// A 3D lookup table mapping red, green, and blue values to new values, as used for color 
+/// grading. Colors between entries are interpolated trilinearly.
+/// 
+/// Tables are usually loaded from `.cube` files exported by Adobe and DaVinci Resolve tools, by 
+/// reading the file into a string and parsing it. `LUT_3D_SIZE`, `DOMAIN_MIN`, `DOMAIN_MAX`, 
+/// `LUT_3D_INPUT_RANGE`, and `TITLE` are supported. Other keywords are ignored, except for 
+/// `LUT_1D_SIZE` which is an error.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, Lut3D};
+/// 
+/// let cube = "
+/// TITLE \"Swap red and blue\"
+/// LUT_3D_SIZE 2
+/// 0 0 0
+/// 0 0 1
+/// 0 1 0
+/// 0 1 1
+/// 1 0 0
+/// 1 0 1
+/// 1 1 0
+/// 1 1 1
+/// ";
+/// let lut = cube.parse::<Lut3D>().unwrap();
+/// let color = lut.apply(Color { red: 255, green: 128, blue: 0 });
+/// 
+/// assert_eq!(lut.title(), Some("Swap red and blue"));
+/// assert_eq!(color, Color { red: 0, green: 128, blue: 255 });
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lut3D {
+    title: Option<String>,
+    size: usize,
+    domain_min: [f32; SLICE_LENGTH],
+    domain_max: [f32; SLICE_LENGTH],
+    entries: Vec<[f32; SLICE_LENGTH]>,
+}
+
+impl Lut3D {
+    /// Creates a table with `size` entries along each axis and a domain of 0.0 to 1.0. Entries 
+    /// are ordered with red changing fastest, then green, then blue, as in `.cube` files. Returns 
+    /// `None` if `size` is not between 2 and 256 or the number of entries is not `size` cubed.
+    pub fn new(size: usize, entries: Vec<[f32; SLICE_LENGTH]>) -> Option<Self> {
+        if !(2..=MAX_LUT_3D_SIZE).contains(&size) || entries.len() != size.pow(3) {
+            return None;
+        }
+        
+        Some(Self {
+            title: None,
+            size,
+            domain_min: [0.0; SLICE_LENGTH],
+            domain_max: [1.0; SLICE_LENGTH],
+            entries,
+        })
+    }
+    
+    /// Parses a table from the contents of a `.cube` file.
+    pub fn parse_cube(s: &str) -> Result<Self, ParseCubeError> {
+        let mut title = None;
+        let mut size = None;
+        let mut domain_min = [0.0; SLICE_LENGTH];
+        let mut domain_max = [1.0; SLICE_LENGTH];
+        let mut entries = Vec::new();
+        
+        for (index, line) in s.lines().enumerate() {
+            let line_number = index + 1;
+            let line = line.trim();
+            let invalid_line = ParseCubeError::InvalidLine { line: line_number };
+            
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            
+            let (keyword, rest) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            
+            // Words such as "nan" and "inf" parse as numbers, so they are entries rather than 
+            // keywords.
+            let entry = keyword.parse::<f32>().is_ok()
+                || !keyword.starts_with(|c: char| c.is_ascii_alphabetic());
+            
+            if entry {
+                entries.push(parse_floats(line).ok_or(invalid_line)?);
+                continue;
+            }
+            
+            match keyword {
+                "TITLE" => {
+                    title = Some(rest.trim().trim_matches('"').to_string());
+                },
+                "LUT_3D_SIZE" => {
+                    size = rest
+                        .trim()
+                        .parse::<usize>()
+                        .ok()
+                        .filter(|size| (2..=MAX_LUT_3D_SIZE).contains(size));
+                    
+                    if size.is_none() {
+                        return Err(ParseCubeError::InvalidSize { line: line_number });
+                    }
+                },
+                "DOMAIN_MIN" => domain_min = parse_floats(rest).ok_or(invalid_line)?,
+                "DOMAIN_MAX" => domain_max = parse_floats(rest).ok_or(invalid_line)?,
+                "LUT_3D_INPUT_RANGE" => {
+                    let [min, max] = parse_floats(rest).ok_or(invalid_line)?;
+                    
+                    domain_min = [min; SLICE_LENGTH];
+                    domain_max = [max; SLICE_LENGTH];
+                },
+                "LUT_1D_SIZE" => return Err(ParseCubeError::UnsupportedKeyword {
+                    keyword: keyword.to_string(),
+                    line: line_number,
+                }),
+                _ => {},
+            }
+        }
+        
+        let size = size.ok_or(ParseCubeError::MissingSize)?;
+        
+        if domain_min.iter().zip(domain_max).any(|(min, max)| max <= *min) {
+            return Err(ParseCubeError::InvalidDomain);
+        }
+        
+        if entries.len() != size.pow(3) {
+            return Err(ParseCubeError::InvalidEntryCount {
+                expected: size.pow(3),
+                found: entries.len(),
+            });
+        }
+        
+        Ok(Self {
+            title,
+            size,
+            domain_min,
+            domain_max,
+            entries,
+        })
+    }
+    
+    /// Gets the title of this table, if it has one.
+    pub fn title(&self) -> Option<&str> {
+        self.title.as_deref()
+    }
+    
+    /// Gets the number of entries along each axis of this table.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+    
+    /// Gets the entries of this table, ordered with red changing fastest, then green, then blue.
+    pub fn entries(&self) -> &[[f32; SLICE_LENGTH]] {
+        &self.entries
+    }
+    
+    /// Looks up red, green, and blue values from 0.0 to 1.0, interpolating between the 
+    /// surrounding entries. Values outside of the domain of this table are clamped to it.
+    pub fn sample(&self, rgb: [f32; SLICE_LENGTH]) -> [f32; SLICE_LENGTH] {
+        let last = (self.size - 1) as f32;
+        let mut lower = [0; SLICE_LENGTH];
+        let mut fraction = [0.0; SLICE_LENGTH];
+        
+        for i in 0..SLICE_LENGTH {
+            let range = self.domain_max[i] - self.domain_min[i];
+            let position = ((rgb[i] - self.domain_min[i]) / range).clamp(0.0, 1.0) * last;
+            
+            lower[i] = (position as usize).min(self.size - 2);
+            fraction[i] = position - lower[i] as f32;
+        }
+        
+        let mut output = [0.0; SLICE_LENGTH];
+        
+        for corner in 0..8 {
+            let mut index = 0;
+            let mut weight = 1.0;
+            
+            for (axis, stride) in [1, self.size, self.size.pow(2)].into_iter().enumerate() {
+                let upper = corner >> axis & 1 == 1;
+                
+                index += (lower[axis] + usize::from(upper)) * stride;
+                weight *= if upper { fraction[axis] } else { 1.0 - fraction[axis] };
+            }
+            
+            for (output, value) in output.iter_mut().zip(self.entries[index]) {
+                *output += value * weight;
+            }
+        }
+        
+        output
+    }
+    
+    /// Applies this table to a color.
+    pub fn apply(&self, color: Color) -> Color {
+        let rgb = color.to_array().map(|value| value as f32 / Value::MAX as f32);
+        let output = self.sample(rgb);
+        
+        Color::from(output.map(|value| helpers::float_to_value(value * Value::MAX as f32)))
+    }
+    
+    /// Applies this table to each color in `colors` in place.
+    pub fn apply_in_place(&self, colors: &mut [Color]) {
+        for color in colors.iter_mut() {
+            *color = self.apply(*color);
+        }
+    }
+}
+
+impl FromStr for Lut3D {
+    type Err = ParseCubeError;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_cube(s)
+    }
+}
+
+/// Parses a fixed number of whitespace-separated numbers.
+fn parse_floats<const N: usize>(s: &str) -> Option<[f32; N]> {
+    let mut values = [0.0; N];
+    let mut parts = s.split_whitespace();
+    
+    for value in values.iter_mut() {
+        *value = parts.next()?.parse().ok().filter(|value: &f32| value.is_finite())?;
+    }
+    
+    if parts.next().is_some() {
+        return None;
+    }
+    
+    Some(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn applies_1d_tables() {
+        let color = Color { red: 10, green: 100, blue: 200 };
+        let invert = Lut1D::from_fn(|value| Value::MAX - value);
+        let red_only = Lut1D::from_channel_fns(|value| value, |_value| 0, |_value| 0);
+        
+        assert_eq!(Lut1D::default().apply(color), color);
+        assert_eq!(invert.apply(color), color.invert());
+        assert_eq!(invert.then(&invert), Lut1D::identity());
+        assert_eq!(invert.then(&red_only).apply(color), Color { red: 245, green: 0, blue: 0 });
+        assert_eq!(Lut1D::from_tables(*invert.tables()), invert);
+    }
+    
+    #[test]
+    fn applies_3d_tables() {
+        let size = 5;
+        let entries = (0..size * size * size)
+            .map(|i| [i % size, i / size % size, i / (size * size)].map(|v| v as f32 / 4.0))
+            .collect::<Vec<_>>();
+        let identity = Lut3D::new(size, entries).unwrap();
+        
+        for color in [
+            Color { red: 0, green: 0, blue: 0 },
+            Color { red: 255, green: 255, blue: 255 },
+            Color { red: 13, green: 200, blue: 97 },
+        ] {
+            assert_eq!(identity.apply(color), color);
+        }
+        
+        assert_eq!(Lut3D::new(1, vec![[0.0; 3]]), None);
+        assert_eq!(Lut3D::new(2, vec![[0.0; 3]; 7]), None);
+    }
+    
+    #[test]
+    fn parses_cube_files() {
+        let cube = "# Created by hand\n\
+            LUT_3D_SIZE 2\n\
+            DOMAIN_MIN 0 0 0\n\
+            DOMAIN_MAX 0.5 1 1\n\
+            \n\
+            0 0 0\n1 0 0\n0 1 0\n1 1 0\n0 0 1\n1 0 1\n0 1 1\n1 1 1\n";
+        let lut = cube.parse::<Lut3D>().unwrap();
+        
+        assert_eq!(lut.size(), 2);
+        assert_eq!(lut.title(), None);
+        assert_eq!(lut.sample([0.25, 0.5, 2.0]), [0.5, 0.5, 1.0]);
+        assert_eq!(lut.apply(Color { red: 255, green: 0, blue: 0 }), Color::from([255, 0, 0]));
+        
+        let errors = [
+            ("0 0 0", ParseCubeError::MissingSize),
+            ("LUT_3D_SIZE 1", ParseCubeError::InvalidSize { line: 1 }),
+            ("LUT_3D_SIZE 2\n0 0", ParseCubeError::InvalidLine { line: 2 }),
+            ("LUT_1D_SIZE 2", ParseCubeError::UnsupportedKeyword {
+                keyword: "LUT_1D_SIZE".into(),
+                line: 1,
+            }),
+            ("LUT_3D_SIZE 2\n0 0 0", ParseCubeError::InvalidEntryCount { expected: 8, found: 1 }),
+            ("LUT_3D_SIZE 2\nnan 0 0", ParseCubeError::InvalidLine { line: 2 }),
+            ("LUT_3D_SIZE 2\n0 inf 0", ParseCubeError::InvalidLine { line: 2 }),
+            ("LUT_3D_SIZE 2\nDOMAIN_MAX 1 0 1", ParseCubeError::InvalidDomain),
+            ("LUT_3D_SIZE 2\nLUT_3D_INPUT_RANGE 1 1", ParseCubeError::InvalidDomain),
+        ];
+        
+        for (cube, error) in errors {
+            assert_eq!(cube.parse::<Lut3D>(), Err(error));
+        }
+    }
+}
\ No newline at end of file
@@ -0,0 +1,108 @@
+use crate::Color;
+use crate::html::COLOR_NAMES;
+
+/// Creates a [`Color`](crate::Color) from a hexadecimal color string or a color name, evaluated 
+/// at compile time. Hexadecimal color strings must include the hash symbol and have 3 or 6 
+/// digits. Color names are case-insensitive.
+/// 
+/// Invalid input fails to compile rather than panicking at runtime.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{color, Color};
+/// 
+/// const ACCENT: Color = color!("#ff8800");
+/// 
+/// assert_eq!(ACCENT, Color { red: 255, green: 136, blue: 0 });
+/// assert_eq!(color!("#F80"), Color { red: 255, green: 136, blue: 0 });
+/// assert_eq!(color!("RebeccaPurple"), Color { red: 102, green: 51, blue: 153 });
+/// ```
+/// 
+/// ```compile_fail
+/// let color = nice_colors::color!("#ff88");
+/// ```
+#[macro_export]
+macro_rules! color {
+    ($s:expr) => {{
+        const COLOR: $crate::Color = match $crate::__color_from_str($s) {
+            ::core::option::Option::Some(color) => color,
+            ::core::option::Option::None => panic!("invalid color string"),
+        };
+        
+        COLOR
+    }};
+}
+
+/// Parses a hexadecimal color string or a color name in a const context. Used by [`color!`].
+#[doc(hidden)]
+pub const fn __color_from_str(s: &str) -> Option<Color> {
+    let bytes = s.as_bytes();
+    
+    if let [b'#', digits @ ..] = bytes {
+        return hex(digits);
+    }
+    
+    let mut i = 0;
+    
+    while i < COLOR_NAMES.len() {
+        let (name, color) = COLOR_NAMES[i];
+        
+        if name.as_bytes().eq_ignore_ascii_case(bytes) {
+            return Some(color);
+        }
+        
+        i += 1;
+    }
+    
+    None
+}
+
+/// Parses 3 or 6 hexadecimal digits into a color.
+const fn hex(digits: &[u8]) -> Option<Color> {
+    let mut values = [0; 6];
+    let mut i = 0;
+    
+    if digits.len() != 3 && digits.len() != 6 {
+        return None;
+    }
+    
+    while i < digits.len() {
+        values[i] = match digits[i] {
+            digit @ b'0'..=b'9' => digit - b'0',
+            digit @ b'a'..=b'f' => digit - b'a' + 10,
+            digit @ b'A'..=b'F' => digit - b'A' + 10,
+            _ => return None,
+        };
+        i += 1;
+    }
+    
+    if digits.len() == 3 {
+        return Some(Color {
+            red: values[0] * 0x11,
+            green: values[1] * 0x11,
+            blue: values[2] * 0x11,
+        });
+    }
+    
+    Some(Color {
+        red: values[0] << 4 | values[1],
+        green: values[2] << 4 | values[3],
+        blue: values[4] << 4 | values[5],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn parses_const_colors() {
+        assert_eq!(__color_from_str("#FF8800"), Some(Color { red: 255, green: 136, blue: 0 }));
+        assert_eq!(__color_from_str("#abc"), Some(Color { red: 170, green: 187, blue: 204 }));
+        assert_eq!(__color_from_str("Red"), Some(Color { red: 255, green: 0, blue: 0 }));
+        assert_eq!(__color_from_str("#FF880"), None);
+        assert_eq!(__color_from_str("#GG8800"), None);
+        assert_eq!(__color_from_str("reddish"), None);
+        assert_eq!(__color_from_str("FF8800"), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,76 @@
+//! Helpers shared by the modules containing tables of named colors.
+
+use crate::Color;
+use std::cmp::Ordering;
+
+/// Packs color names into a table sorted by decimal value. The sort is stable so that names for 
+/// the same color stay in alphabetical order.
+pub const fn sort_by_value<const N: usize>(
+    color_names: [(&'static str, Color); N],
+) -> [(u32, &'static str); N] {
+    let mut table = [(0, ""); N];
+    let mut i = 0;
+    
+    while i < table.len() {
+        let (name, color) = color_names[i];
+        let key = (color.red as u32) << 16 | (color.green as u32) << 8 | color.blue as u32;
+        let mut j = i;
+        
+        // Insertion sort, since iterators and sort methods are not available in const functions.
+        while j > 0 && table[j - 1].0 > key {
+            table[j] = table[j - 1];
+            j -= 1;
+        }
+        
+        table[j] = (key, name);
+        i += 1;
+    }
+    
+    table
+}
+
+/// Finds a color in a table of lowercase names sorted by name. Matching is case-insensitive and 
+/// leading and trailing whitespace is ignored.
+pub fn find_by_name(color_names: &[(&'static str, Color)], s: &str) -> Option<Color> {
+    let name = s.trim().as_bytes();
+    
+    color_names
+        .binary_search_by(|(color_name, _)| compare_ignore_ascii_case(color_name.as_bytes(), name))
+        .ok()
+        .map(|index| color_names[index].1)
+}
+
+/// Finds the first name for a color in a table sorted by decimal value.
+pub fn find_by_value(color_values: &[(u32, &'static str)], color: &Color) -> Option<&'static str> {
+    let key = color.to_decimal();
+    let index = color_values.partition_point(|(value, _)| *value < key);
+    
+    match color_values.get(index) {
+        Some((value, name)) if *value == key => Some(name),
+        _ => None,
+    }
+}
+
+/// Finds the name of the color nearest to `color`, measured by the squared distance between their 
+/// RGB values.
+#[cfg_attr(not(any(feature = "crayola", feature = "xkcd")), allow(dead_code))]
+pub fn nearest(color_names: &[(&'static str, Color)], color: &Color) -> Option<&'static str> {
+    let distance = |other: &Color| {
+        color
+            .to_array()
+            .iter()
+            .zip(other.to_array())
+            .map(|(a, b)| (i32::from(*a) - i32::from(b)).pow(2))
+            .sum::<i32>()
+    };
+    
+    color_names
+        .iter()
+        .min_by_key(|(_name, other)| distance(other))
+        .map(|(name, _other)| *name)
+}
+
+/// Compares a lowercase name against a name in any case without allocating.
+fn compare_ignore_ascii_case(lowercase: &[u8], other: &[u8]) -> Ordering {
+    lowercase.iter().copied().cmp(other.iter().map(u8::to_ascii_lowercase))
+}
\ No newline at end of file
@@ -0,0 +1,207 @@
+use crate::{Channel, Color};
+use crate::color::Value;
+use std::ops::{Add, AddAssign, Div, Index, IndexMut, Mul, Not, Sub, SubAssign};
+
+/// Adds each value of two colors, saturating at 255.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::Color;
+/// 
+/// let color = Color { red: 250, green: 100, blue: 0 };
+/// let brighter = color + Color { red: 10, green: 10, blue: 10 };
+/// 
+/// assert_eq!(brighter, Color { red: 255, green: 110, blue: 10 });
+/// ```
+impl Add for Color {
+    type Output = Self;
+    
+    fn add(self, other: Self) -> Self::Output {
+        self.map_each_with(other, Value::saturating_add)
+    }
+}
+
+/// Subtracts each value of one color from another, saturating at 0.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::Color;
+/// 
+/// let color = Color { red: 250, green: 100, blue: 0 };
+/// let darker = color - Color { red: 10, green: 10, blue: 10 };
+/// 
+/// assert_eq!(darker, Color { red: 240, green: 90, blue: 0 });
+/// ```
+impl Sub for Color {
+    type Output = Self;
+    
+    fn sub(self, other: Self) -> Self::Output {
+        self.map_each_with(other, Value::saturating_sub)
+    }
+}
+
+impl AddAssign for Color {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Color {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+/// Multiplies each value of a color by a factor. Results are rounded and saturate at 0 and 255.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::Color;
+/// 
+/// let color = Color { red: 200, green: 100, blue: 51 };
+/// 
+/// assert_eq!(color * 1.5, Color { red: 255, green: 150, blue: 77 });
+/// ```
+impl Mul<f32> for Color {
+    type Output = Self;
+    
+    fn mul(self, factor: f32) -> Self::Output {
+        // Float to integer casts saturate, and NaN becomes 0.
+        self.map_each(|value| (value as f32 * factor).round() as Value)
+    }
+}
+
+/// Divides each value of a color by a divisor. Results are rounded and saturate at 0 and 255.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::Color;
+/// 
+/// let color = Color { red: 200, green: 100, blue: 51 };
+/// 
+/// assert_eq!(color / 2.0, Color { red: 100, green: 50, blue: 26 });
+/// ```
+impl Div<f32> for Color {
+    type Output = Self;
+    
+    fn div(self, divisor: f32) -> Self::Output {
+        self.map_each(|value| (value as f32 / divisor).round() as Value)
+    }
+}
+
+/// Multiplies each value of a color by a factor, saturating at 255.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::Color;
+/// 
+/// let color = Color { red: 200, green: 100, blue: 0 };
+/// 
+/// assert_eq!(color * 2, Color { red: 255, green: 200, blue: 0 });
+/// ```
+impl Mul<Value> for Color {
+    type Output = Self;
+    
+    fn mul(self, factor: Value) -> Self::Output {
+        self.map_each(|value| value.saturating_mul(factor))
+    }
+}
+
+/// Inverts a color. See [`Color::invert`].
+impl Not for Color {
+    type Output = Self;
+    
+    fn not(self) -> Self::Output {
+        self.invert()
+    }
+}
+
+/// Gets a value of a color by index, where red is 0, green is 1, and blue is 2.
+/// 
+/// # Panics
+/// Panics if the index is greater than 2.
+impl Index<usize> for Color {
+    type Output = Value;
+    
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.as_bytes()[index]
+    }
+}
+
+/// Gets a mutable value of a color by index, where red is 0, green is 1, and blue is 2.
+/// 
+/// # Panics
+/// Panics if the index is greater than 2.
+impl IndexMut<usize> for Color {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        &mut self.as_bytes_mut()[index]
+    }
+}
+
+impl Index<Channel> for Color {
+    type Output = Value;
+    
+    fn index(&self, channel: Channel) -> &Self::Output {
+        &self[channel as usize]
+    }
+}
+
+impl IndexMut<Channel> for Color {
+    fn index_mut(&mut self, channel: Channel) -> &mut Self::Output {
+        &mut self[channel as usize]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn adds_and_subtracts_saturating() {
+        let mut color = Color { red: 200, green: 100, blue: 50 };
+        
+        color += Color { red: 100, green: 100, blue: 100 };
+        
+        assert_eq!(color, Color { red: 255, green: 200, blue: 150 });
+        
+        color -= Color { red: 0, green: 255, blue: 100 };
+        
+        assert_eq!(color, Color { red: 255, green: 0, blue: 50 });
+    }
+    
+    #[test]
+    fn scales_by_scalars() {
+        let color = Color { red: 200, green: 100, blue: 1 };
+        
+        assert_eq!(color * 0.5, Color { red: 100, green: 50, blue: 1 });
+        assert_eq!(color * -1.0, Color { red: 0, green: 0, blue: 0 });
+        assert_eq!(color / 0.0, Color { red: 255, green: 255, blue: 255 });
+        assert_eq!(color * 3, Color { red: 255, green: 255, blue: 3 });
+    }
+    
+    #[test]
+    fn inverts() {
+        let color = Color { red: 255, green: 128, blue: 0 };
+        
+        assert_eq!(!color, Color { red: 0, green: 127, blue: 255 });
+        assert_eq!(!!color, color);
+    }
+    
+    #[test]
+    fn indexes_by_channel() {
+        let mut color = Color { red: 10, green: 20, blue: 30 };
+        
+        for channel in Channel::ALL {
+            color[channel] += 1;
+        }
+        
+        assert_eq!([color[0], color[1], color[2]], [11, 21, 31]);
+        assert_eq!(color[Channel::Green], 21);
+    }
+    
+    #[test]
+    #[should_panic]
+    fn panics_on_index_out_of_bounds() {
+        let _ = Color::new()[3];
+    }
+}
\ No newline at end of file
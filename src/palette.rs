@@ -0,0 +1,601 @@
+use crate::{format, Color, Deficiency, SortKey};
+use crate::helpers::conversions;
+
+/// A WCAG 2 conformance level for the contrast between text and its background.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ContrastLevel {
+    /// Level AA for normal text, requiring a contrast ratio of at least 4.5.
+    #[default]
+    Aa,
+    /// Level AA for large text, requiring a contrast ratio of at least 3.0.
+    AaLarge,
+    /// Level AAA for normal text, requiring a contrast ratio of at least 7.0.
+    Aaa,
+    /// Level AAA for large text, requiring a contrast ratio of at least 4.5.
+    AaaLarge,
+}
+
+impl ContrastLevel {
+    /// Gets the minimum contrast ratio required by this level.
+    pub fn min_ratio(&self) -> f32 {
+        match self {
+            Self::Aa | Self::AaaLarge => 4.5,
+            Self::AaLarge => 3.0,
+            Self::Aaa => 7.0,
+        }
+    }
+}
+
+/// The result of checking the contrast of a color in a [`Palette`] against a background. Created 
+/// using [`Palette::check_accessibility`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ContrastCheck {
+    /// The color that was checked.
+    pub color: Color,
+    /// The WCAG 2 contrast ratio between the color and the background.
+    pub contrast_ratio: f32,
+    /// Whether the contrast ratio meets the level that was checked.
+    pub passes: bool,
+}
+
+/// When to stop merging clusters in [`Palette::cluster`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClusterBy {
+    /// Merges clusters until no two clusters are closer than this difference, as measured by 
+    /// [`Color::delta_e_ok`].
+    Threshold(f32),
+    /// Merges clusters until there are at most this many clusters. Fewer than 1 cluster is treated 
+    /// as 1 cluster.
+    Count(usize),
+}
+
+/// A group of similar colors. Created using [`Palette::cluster`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ColorCluster {
+    /// The average of the members in Oklab.
+    pub representative: Color,
+    /// The colors in this cluster, in the order they appear in the palette.
+    pub members: Vec<Color>,
+}
+
+/// A list of colors, such as the colors an image is reduced to when quantizing.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, Palette};
+/// 
+/// let black = Color { red: 0, green: 0, blue: 0 };
+/// let white = Color { red: 255, green: 255, blue: 255 };
+/// let palette = Palette::new(vec![black, white]);
+/// 
+/// assert_eq!(palette.nearest(Color { red: 200, green: 180, blue: 190 }), Some(white));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct Palette {
+    colors: Vec<Color>,
+}
+
+impl Palette {
+    /// Creates a palette from a list of colors.
+    pub fn new(colors: Vec<Color>) -> Self {
+        Self { colors }
+    }
+    
+    /// Gets the colors in this palette.
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+    
+    /// Gets the number of colors in this palette.
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+    
+    /// Checks whether this palette has no colors.
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+    
+    /// Adds a color to the end of this palette.
+    pub fn push(&mut self, color: Color) {
+        self.colors.push(color);
+    }
+    
+    /// Gets the index of the color in this palette closest to `color`, measured by the distance 
+    /// between red, green, and blue values. Returns `None` if this palette is empty.
+    pub fn nearest_index(&self, color: Color) -> Option<usize> {
+        let distance = |other: &Color| {
+            color
+                .to_array()
+                .iter()
+                .zip(other.to_array())
+                .map(|(a, b)| (i32::from(*a) - i32::from(b)).pow(2))
+                .sum::<i32>()
+        };
+        
+        self.colors
+            .iter()
+            .enumerate()
+            .min_by_key(|(_index, other)| distance(other))
+            .map(|(index, _other)| index)
+    }
+    
+    /// Gets the color in this palette closest to `color`. See [`Palette::nearest_index`].
+    pub fn nearest(&self, color: Color) -> Option<Color> {
+        self.nearest_index(color).map(|index| self.colors[index])
+    }
+    
+    /// Groups similar colors in this palette using agglomerative clustering in Oklab, repeatedly 
+    /// merging the two clusters with the closest averages. Clusters are ordered from the most 
+    /// members to the fewest, and clusters with the same number of members are ordered by their 
+    /// first member. This compares every pair of clusters on each merge, so it is meant for 
+    /// palettes rather than every pixel of an image; reduce images using 
+    /// [`octree_palette`](crate::quantize::octree_palette) first.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{ClusterBy, Color, Palette};
+    /// 
+    /// let palette = Palette::new(vec![
+    ///     Color { red: 250, green: 0, blue: 0 },
+    ///     Color { red: 0, green: 0, blue: 250 },
+    ///     Color { red: 255, green: 10, blue: 10 },
+    /// ]);
+    /// let clusters = palette.cluster(ClusterBy::Count(2));
+    /// 
+    /// assert_eq!(clusters.len(), 2);
+    /// assert_eq!(clusters[0].members, vec![palette.colors()[0], palette.colors()[2]]);
+    /// assert_eq!(clusters[1].representative, Color { red: 0, green: 0, blue: 250 });
+    /// assert_eq!(palette.cluster(ClusterBy::Threshold(0.1)).len(), 2);
+    /// ```
+    pub fn cluster(&self, by: ClusterBy) -> Vec<ColorCluster> {
+        // The sum of the members in Oklab, and the indexes of the members.
+        let mut clusters = self
+            .colors
+            .iter()
+            .enumerate()
+            .map(|(index, color)| (color.to_oklab(), vec![index]))
+            .collect::<Vec<_>>();
+        let centroid = |(sum, members): &([f32; 3], Vec<usize>)| {
+            sum.map(|value| value / members.len() as f32)
+        };
+        
+        loop {
+            if let ClusterBy::Count(count) = by {
+                if clusters.len() <= count.max(1) {
+                    break;
+                }
+            }
+            
+            let mut closest = None;
+            
+            for i in 0..clusters.len() {
+                for j in i + 1..clusters.len() {
+                    let distance = conversions::delta_e_ok(
+                        centroid(&clusters[i]),
+                        centroid(&clusters[j]),
+                    );
+                    
+                    if closest.is_none_or(|(_i, _j, closest)| distance < closest) {
+                        closest = Some((i, j, distance));
+                    }
+                }
+            }
+            
+            let Some((i, j, distance)) = closest else {
+                break;
+            };
+            
+            if let ClusterBy::Threshold(threshold) = by {
+                if distance > threshold {
+                    break;
+                }
+            }
+            
+            let (sum, members) = clusters.remove(j);
+            let cluster = &mut clusters[i];
+            
+            cluster.0 = [cluster.0[0] + sum[0], cluster.0[1] + sum[1], cluster.0[2] + sum[2]];
+            cluster.1.extend(members);
+            cluster.1.sort_unstable();
+        }
+        
+        clusters.sort_by(|(_a_sum, a), (_b_sum, b)| b.len().cmp(&a.len()).then(a[0].cmp(&b[0])));
+        clusters
+            .iter()
+            .map(|cluster| {
+                let linear = conversions::oklab_to_linear(centroid(cluster));
+                
+                ColorCluster {
+                    representative: conversions::linear_to_rgb(
+                        conversions::gamut_map_linear(linear),
+                    ).into(),
+                    members: cluster.1.iter().map(|index| self.colors[*index]).collect(),
+                }
+            })
+            .collect()
+    }
+    
+    /// Sorts the colors in this palette using `key`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Palette, SortKey};
+    /// 
+    /// let black = Color { red: 0, green: 0, blue: 0 };
+    /// let white = Color { red: 255, green: 255, blue: 255 };
+    /// let mut palette = Palette::new(vec![white, black]);
+    /// 
+    /// palette.sort(SortKey::luminance());
+    /// 
+    /// assert_eq!(palette.colors(), &[black, white]);
+    /// ```
+    pub fn sort(&mut self, key: SortKey) {
+        key.sort(&mut self.colors);
+    }
+    
+    /// Removes colors that are perceptually near-identical to an earlier color in this palette, 
+    /// i.e. colors with a difference of at most `threshold` from a color that is kept, as measured 
+    /// by [`Color::delta_e_ok`]. The first of each group of similar colors is kept.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Palette};
+    /// 
+    /// let red = Color { red: 255, green: 0, blue: 0 };
+    /// let blue = Color { red: 0, green: 0, blue: 255 };
+    /// let mut palette = Palette::new(vec![
+    ///     red,
+    ///     Color { red: 250, green: 2, blue: 0 },
+    ///     blue,
+    ///     red,
+    /// ]);
+    /// 
+    /// palette.dedup_by_delta_e(0.02);
+    /// 
+    /// assert_eq!(palette.colors(), &[red, blue]);
+    /// ```
+    pub fn dedup_by_delta_e(&mut self, threshold: f32) {
+        let mut kept: Vec<[f32; 3]> = Vec::with_capacity(self.colors.len());
+        
+        self.colors.retain(|color| {
+            let oklab = color.to_oklab();
+            let is_duplicate = kept
+                .iter()
+                .any(|other| conversions::delta_e_ok(oklab, *other) <= threshold);
+            
+            if !is_duplicate {
+                kept.push(oklab);
+            }
+            
+            !is_duplicate
+        });
+    }
+    
+    /// Checks whether every pair of colors in this palette can still be told apart by someone with 
+    /// a color vision deficiency, i.e. whether the difference between each pair, as measured by 
+    /// [`Color::delta_e_ok`] after simulating the deficiency with 
+    /// [`Color::simulate_deficiency`], is at least `min_delta_e`. Palettes with fewer than 2 
+    /// colors are always safe. Use [`Deficiency::ALL`] to check every deficiency.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Deficiency, Palette};
+    /// 
+    /// let traffic_lights = Palette::new(vec![
+    ///     Color { red: 220, green: 40, blue: 40 },
+    ///     Color { red: 40, green: 160, blue: 40 },
+    /// ]);
+    /// let blue_and_orange = Palette::new(vec![
+    ///     Color { red: 0, green: 114, blue: 178 },
+    ///     Color { red: 230, green: 159, blue: 0 },
+    /// ]);
+    /// 
+    /// assert!(!traffic_lights.is_cvd_safe(0.1, Deficiency::Deuteranopia));
+    /// assert!(blue_and_orange.is_cvd_safe(0.1, Deficiency::Deuteranopia));
+    /// assert!(Deficiency::ALL
+    ///     .iter()
+    ///     .all(|deficiency| blue_and_orange.is_cvd_safe(0.1, *deficiency)));
+    /// ```
+    pub fn is_cvd_safe(&self, min_delta_e: f32, deficiency: Deficiency) -> bool {
+        let simulated = self
+            .colors
+            .iter()
+            .map(|color| color.simulate_deficiency(deficiency).to_oklab())
+            .collect::<Vec<_>>();
+        
+        simulated.iter().enumerate().all(|(index, a)| {
+            simulated[index + 1..]
+                .iter()
+                .all(|b| conversions::delta_e_ok(*a, *b) >= min_delta_e)
+        })
+    }
+    
+    /// Checks the WCAG 2 contrast of each color in this palette against `background`, in the 
+    /// order of the colors in this palette.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, ContrastLevel, Palette};
+    /// 
+    /// let white = Color { red: 255, green: 255, blue: 255 };
+    /// let palette = Palette::new(vec![
+    ///     Color { red: 0, green: 0, blue: 0 },
+    ///     Color { red: 150, green: 150, blue: 150 },
+    /// ]);
+    /// let checks = palette.check_accessibility(white, ContrastLevel::Aa);
+    /// 
+    /// assert!(checks[0].passes);
+    /// assert!(!checks[1].passes);
+    /// assert!(checks.iter().any(|check| !check.passes));
+    /// ```
+    pub fn check_accessibility(
+        &self,
+        background: Color,
+        level: ContrastLevel,
+    ) -> Vec<ContrastCheck> {
+        self.colors
+            .iter()
+            .map(|color| {
+                let contrast_ratio = color.contrast_ratio(background);
+                
+                ContrastCheck {
+                    color: *color,
+                    contrast_ratio,
+                    passes: contrast_ratio >= level.min_ratio(),
+                }
+            })
+            .collect()
+    }
+    
+    /// Renders this palette as an image with a square cell of `cell_size` pixels for each color, 
+    /// from left to right. The image is `cell_size` times the number of colors wide and 
+    /// `cell_size` high.
+    /// 
+    /// # Panics
+    /// Panics if the width of the image, `cell_size` times the number of colors, does not fit in a 
+    /// `u32`.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Palette};
+    /// 
+    /// let red = Color { red: 255, green: 0, blue: 0 };
+    /// let blue = Color { red: 0, green: 0, blue: 255 };
+    /// let image = Palette::new(vec![red, blue]).render_swatch(2);
+    /// 
+    /// assert_eq!(image.dimensions(), (4, 2));
+    /// assert_eq!(image.get_pixel(1, 1).0, red.to_array());
+    /// assert_eq!(image.get_pixel(2, 0).0, blue.to_array());
+    /// ```
+    #[cfg(feature = "image")]
+    pub fn render_swatch(&self, cell_size: u32) -> image::RgbImage {
+        let width = u32::try_from(self.colors.len())
+            .ok()
+            .and_then(|len| len.checked_mul(cell_size))
+            .expect("swatch width should fit in a u32");
+        
+        image::RgbImage::from_fn(width, cell_size, |x, _y| {
+            image::Rgb(self.colors[(x / cell_size) as usize].to_array())
+        })
+    }
+    
+    /// Generates an SVG image with a square cell for each color, from left to right, for previews 
+    /// in documentation and web pages. Each cell is 32 pixels wide and high.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Palette};
+    /// 
+    /// let palette = Palette::new(vec![
+    ///     Color { red: 255, green: 0, blue: 0 },
+    ///     Color { red: 0, green: 0, blue: 255 },
+    /// ]);
+    /// 
+    /// assert_eq!(
+    ///     palette.to_svg(),
+    ///     "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"64\" height=\"32\" \
+    ///     viewBox=\"0 0 2 1\">\
+    ///     <rect x=\"0\" width=\"1\" height=\"1\" fill=\"#FF0000\"/>\
+    ///     <rect x=\"1\" width=\"1\" height=\"1\" fill=\"#0000FF\"/>\
+    ///     </svg>",
+    /// );
+    /// ```
+    pub fn to_svg(&self) -> String {
+        const CELL_SIZE: usize = 32;
+        
+        let len = self.colors.len();
+        let mut s = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{CELL_SIZE}\" \
+            viewBox=\"0 0 {len} 1\">",
+            len * CELL_SIZE,
+        );
+        
+        for (index, color) in self.colors.iter().enumerate() {
+            s.push_str(&format!(
+                "<rect x=\"{index}\" width=\"1\" height=\"1\" fill=\"{}\"/>",
+                color.display_hex(),
+            ));
+        }
+        
+        s.push_str("</svg>");
+        s
+    }
+    
+    /// Prints a block of each color in this palette with its hexadecimal color string to standard 
+    /// output, one color per line, for debugging palettes in a terminal. See 
+    /// [`Color::preview_block`].
+    pub fn print_preview(&self) {
+        for color in &self.colors {
+            println!("{}", color.preview_block());
+        }
+    }
+    
+    /// Generates a `:root` block declaring a CSS custom property for each color in this palette, 
+    /// named with `prefix` and the index of the color.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{Color, Palette};
+    /// 
+    /// let palette = Palette::new(vec![
+    ///     Color { red: 255, green: 0, blue: 0 },
+    ///     Color { red: 0, green: 0, blue: 255 },
+    /// ]);
+    /// 
+    /// assert_eq!(
+    ///     palette.to_css_variables("brand"),
+    ///     ":root {\n  --brand-0: #FF0000;\n  --brand-1: #0000FF;\n}",
+    /// );
+    /// ```
+    pub fn to_css_variables(&self, prefix: &str) -> String {
+        format::css_root(
+            self.colors
+                .iter()
+                .enumerate()
+                .map(|(index, color)| (format!("{prefix}-{index}"), *color)),
+        )
+    }
+}
+
+impl From<Vec<Color>> for Palette {
+    fn from(colors: Vec<Color>) -> Self {
+        Self::new(colors)
+    }
+}
+
+impl From<&[Color]> for Palette {
+    fn from(colors: &[Color]) -> Self {
+        Self::new(colors.to_vec())
+    }
+}
+
+impl FromIterator<Color> for Palette {
+    fn from_iter<I: IntoIterator<Item = Color>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<'a> IntoIterator for &'a Palette {
+    type Item = &'a Color;
+    type IntoIter = std::slice::Iter<'a, Color>;
+    
+    fn into_iter(self) -> Self::IntoIter {
+        self.colors.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn clusters_colors() {
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let palette = Palette::new(vec![black, white, black, white, white]);
+        let clusters = palette.cluster(ClusterBy::Threshold(0.0));
+        
+        assert_eq!(clusters.len(), 2);
+        assert_eq!(clusters[0], ColorCluster { representative: white, members: vec![white; 3] });
+        assert_eq!(clusters[1], ColorCluster { representative: black, members: vec![black; 2] });
+        assert_eq!(palette.cluster(ClusterBy::Count(0)).len(), 1);
+        assert_eq!(palette.cluster(ClusterBy::Count(10)).len(), 5);
+        assert_eq!(Palette::new(Vec::new()).cluster(ClusterBy::Count(1)), Vec::new());
+    }
+    
+    #[cfg(feature = "image")]
+    #[test]
+    fn renders_swatch() {
+        let red = Color { red: 255, green: 0, blue: 0 };
+        let blue = Color { red: 0, green: 0, blue: 255 };
+        let image = Palette::new(vec![red, blue]).render_swatch(3);
+        
+        assert_eq!(image.dimensions(), (6, 3));
+        assert!(image.enumerate_pixels().all(|(x, _y, pixel)| {
+            pixel.0 == if x < 3 { red } else { blue }.to_array()
+        }));
+        assert_eq!(Palette::new(Vec::new()).render_swatch(3).dimensions(), (0, 3));
+        assert_eq!(Palette::new(vec![red]).render_swatch(0).dimensions(), (0, 0));
+    }
+    
+    #[cfg(feature = "image")]
+    #[test]
+    #[should_panic]
+    fn rejects_oversized_swatch() {
+        let red = Color { red: 255, green: 0, blue: 0 };
+        
+        Palette::new(vec![red; 2]).render_swatch(u32::MAX);
+    }
+    
+    #[test]
+    fn dedups_by_delta_e() {
+        let gray = Color { red: 128, green: 128, blue: 128 };
+        let mut palette = Palette::new(vec![
+            gray,
+            Color { red: 129, green: 128, blue: 128 },
+            Color { red: 140, green: 128, blue: 128 },
+            gray,
+        ]);
+        
+        palette.dedup_by_delta_e(0.0);
+        
+        assert_eq!(palette.len(), 3);
+        
+        palette.dedup_by_delta_e(0.01);
+        
+        assert_eq!(palette.colors(), &[gray, Color { red: 140, green: 128, blue: 128 }]);
+        
+        palette.dedup_by_delta_e(1.0);
+        
+        assert_eq!(palette.colors(), &[gray]);
+    }
+    
+    #[test]
+    fn finds_nearest_color() {
+        let palette = [
+            Color { red: 255, green: 0, blue: 0 },
+            Color { red: 0, green: 255, blue: 0 },
+            Color { red: 0, green: 0, blue: 255 },
+        ]
+        .into_iter()
+        .collect::<Palette>();
+        
+        assert_eq!(palette.nearest_index(Color { red: 20, green: 10, blue: 200 }), Some(2));
+        assert_eq!(palette.nearest_index(Color { red: 128, green: 127, blue: 0 }), Some(0));
+        assert_eq!(Palette::default().nearest(Color { red: 0, green: 0, blue: 0 }), None);
+    }
+    
+    #[test]
+    fn checks_accessibility() {
+        let background = Color { red: 255, green: 255, blue: 255 };
+        // A contrast ratio of about 5.1.
+        let gray = Color { red: 110, green: 110, blue: 110 };
+        let palette = Palette::new(vec![gray]);
+        let check = |level| palette.check_accessibility(background, level)[0];
+        let passes = |level| check(level).passes;
+        
+        assert!(passes(ContrastLevel::Aa));
+        assert!(passes(ContrastLevel::AaLarge));
+        assert!(!passes(ContrastLevel::Aaa));
+        assert!(passes(ContrastLevel::AaaLarge));
+        assert!((check(ContrastLevel::Aa).contrast_ratio - 5.1).abs() < 0.01);
+    }
+    
+    #[test]
+    fn checks_cvd_safety() {
+        let red = Color { red: 255, green: 0, blue: 0 };
+        // The same luminance as red, so only hue tells them apart.
+        let gray = Color { red: 127, green: 127, blue: 127 };
+        let palette = Palette::new(vec![red, gray]);
+        
+        // Once simulated, the two are about 0.145 apart.
+        assert!(palette.is_cvd_safe(0.1, Deficiency::Deuteranopia));
+        assert!(!palette.is_cvd_safe(0.2, Deficiency::Deuteranopia));
+        assert!(!palette.is_cvd_safe(0.01, Deficiency::Achromatopsia));
+        assert!(Palette::new(vec![red]).is_cvd_safe(1.0, Deficiency::Tritanopia));
+        assert!(Palette::default().is_cvd_safe(1.0, Deficiency::Protanopia));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,20 @@
+//! Well-known color palettes.
+
+#[cfg(feature = "colorbrewer")]
+pub mod colorbrewer;
+pub mod material;
+#[cfg(feature = "open-color")]
+pub mod open_color;
+
+use crate::{Color, Palette};
+
+/// Finds the colors for a hue by name in a table of palettes, ignoring case and leading and 
+/// trailing whitespace.
+fn find<const N: usize>(palettes: &[(&str, [Color; N])], name: &str) -> Option<Palette> {
+    let name = name.trim();
+    
+    palettes
+        .iter()
+        .find(|(palette_name, _colors)| palette_name.eq_ignore_ascii_case(name))
+        .map(|(_palette_name, colors)| Palette::from(&colors[..]))
+}
\ No newline at end of file
@@ -0,0 +1,2528 @@
+//! The [ColorBrewer](https://colorbrewer2.org/) color schemes by Cynthia Brewer, available with 
+//! the `colorbrewer` feature.
+//! 
+//! Each scheme has a set of colors for each number of classes it supports, from 3 up to 8 to 12 
+//! depending on the scheme. The colors for each number of classes are chosen to be told apart on 
+//! maps and charts, so they are not simply samples of one gradient. Schemes are named as on the 
+//! ColorBrewer website, e.g. "YlGnBu" or "Set1".
+//! 
+//! # Examples
+//! ```
+//! use nice_colors::Color;
+//! use nice_colors::palettes::colorbrewer::{self, SchemeKind};
+//! 
+//! let blues = colorbrewer::palette("Blues", 3).unwrap();
+//! 
+//! assert_eq!(blues.colors(), &[
+//!     Color::from_decimal(0xDEEBF7),
+//!     Color::from_decimal(0x9ECAE1),
+//!     Color::from_decimal(0x3182BD),
+//! ]);
+//! assert_eq!(colorbrewer::palette("Blues", 12), None);
+//! 
+//! let scheme = colorbrewer::scheme("rdylbu").unwrap();
+//! 
+//! assert_eq!(scheme.kind(), SchemeKind::Diverging);
+//! assert_eq!(scheme.max_classes(), 11);
+//! ```
+
+use crate::{Color, Palette};
+
+/// The kind of data a ColorBrewer scheme is designed for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemeKind {
+    /// Ordered data from low to high, with colors from light to dark.
+    Sequential,
+    /// Ordered data diverging from a critical midpoint, with light colors in the middle and dark 
+    /// colors of two contrasting hues at either end.
+    Diverging,
+    /// Categories with no order, with colors of different hues.
+    Qualitative,
+}
+
+/// A ColorBrewer color scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Scheme {
+    name: &'static str,
+    kind: SchemeKind,
+    classes: &'static [&'static [Color]],
+}
+
+impl Scheme {
+    /// The smallest number of classes every scheme supports.
+    pub const MIN_CLASSES: usize = 3;
+    
+    /// Gets the name of this scheme, e.g. "YlGnBu".
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+    
+    /// Gets the kind of data this scheme is designed for.
+    pub fn kind(&self) -> SchemeKind {
+        self.kind
+    }
+    
+    /// Gets the largest number of classes this scheme supports.
+    pub fn max_classes(&self) -> usize {
+        Self::MIN_CLASSES + self.classes.len() - 1
+    }
+    
+    /// Gets the colors for a number of classes. Returns `None` if this scheme does not support 
+    /// that many classes.
+    pub fn colors(&self, classes: usize) -> Option<&'static [Color]> {
+        classes
+            .checked_sub(Self::MIN_CLASSES)
+            .and_then(|index| self.classes.get(index))
+            .copied()
+    }
+    
+    /// Gets the colors for a number of classes as a palette. Returns `None` if this scheme does 
+    /// not support that many classes.
+    pub fn palette(&self, classes: usize) -> Option<Palette> {
+        self.colors(classes).map(Palette::from)
+    }
+    
+    /// Creates a scheme from its colors for each number of classes, starting at 3.
+    const fn new(
+        name: &'static str,
+        kind: SchemeKind,
+        classes: &'static [&'static [Color]],
+    ) -> Self {
+        Self { name, kind, classes }
+    }
+}
+
+/// Finds a scheme by name, e.g. "YlGnBu". Matching is case-insensitive and leading and trailing 
+/// whitespace is ignored.
+pub fn scheme(name: &str) -> Option<&'static Scheme> {
+    let name = name.trim();
+    
+    SCHEMES.iter().find(|scheme| scheme.name.eq_ignore_ascii_case(name))
+}
+
+/// Gets the colors of a scheme for a number of classes as a palette, e.g. "Set1" with 5 classes. 
+/// Returns `None` if there is no scheme with this name or it does not support that many classes. 
+/// See [`scheme`].
+pub fn palette(name: &str, classes: usize) -> Option<Palette> {
+    scheme(name)?.palette(classes)
+}
+
+/// Every scheme, with the sequential schemes first, then the diverging schemes, then the 
+/// qualitative schemes.
+pub static SCHEMES: [Scheme; 35] = [
+    Scheme::new("YlGn", SchemeKind::Sequential, YL_GN),
+    Scheme::new("YlGnBu", SchemeKind::Sequential, YL_GN_BU),
+    Scheme::new("GnBu", SchemeKind::Sequential, GN_BU),
+    Scheme::new("BuGn", SchemeKind::Sequential, BU_GN),
+    Scheme::new("PuBuGn", SchemeKind::Sequential, PU_BU_GN),
+    Scheme::new("PuBu", SchemeKind::Sequential, PU_BU),
+    Scheme::new("BuPu", SchemeKind::Sequential, BU_PU),
+    Scheme::new("RdPu", SchemeKind::Sequential, RD_PU),
+    Scheme::new("PuRd", SchemeKind::Sequential, PU_RD),
+    Scheme::new("OrRd", SchemeKind::Sequential, OR_RD),
+    Scheme::new("YlOrRd", SchemeKind::Sequential, YL_OR_RD),
+    Scheme::new("YlOrBr", SchemeKind::Sequential, YL_OR_BR),
+    Scheme::new("Purples", SchemeKind::Sequential, PURPLES),
+    Scheme::new("Blues", SchemeKind::Sequential, BLUES),
+    Scheme::new("Greens", SchemeKind::Sequential, GREENS),
+    Scheme::new("Oranges", SchemeKind::Sequential, ORANGES),
+    Scheme::new("Reds", SchemeKind::Sequential, REDS),
+    Scheme::new("Greys", SchemeKind::Sequential, GREYS),
+    Scheme::new("PuOr", SchemeKind::Diverging, PU_OR),
+    Scheme::new("BrBG", SchemeKind::Diverging, BR_BG),
+    Scheme::new("PRGn", SchemeKind::Diverging, PR_GN),
+    Scheme::new("PiYG", SchemeKind::Diverging, PI_YG),
+    Scheme::new("RdBu", SchemeKind::Diverging, RD_BU),
+    Scheme::new("RdGy", SchemeKind::Diverging, RD_GY),
+    Scheme::new("RdYlBu", SchemeKind::Diverging, RD_YL_BU),
+    Scheme::new("Spectral", SchemeKind::Diverging, SPECTRAL),
+    Scheme::new("RdYlGn", SchemeKind::Diverging, RD_YL_GN),
+    Scheme::new("Accent", SchemeKind::Qualitative, ACCENT),
+    Scheme::new("Dark2", SchemeKind::Qualitative, DARK2),
+    Scheme::new("Paired", SchemeKind::Qualitative, PAIRED),
+    Scheme::new("Pastel1", SchemeKind::Qualitative, PASTEL1),
+    Scheme::new("Pastel2", SchemeKind::Qualitative, PASTEL2),
+    Scheme::new("Set1", SchemeKind::Qualitative, SET1),
+    Scheme::new("Set2", SchemeKind::Qualitative, SET2),
+    Scheme::new("Set3", SchemeKind::Qualitative, SET3),
+];
+
+/// Yellow to green, with 3 to 9 classes.
+const YL_GN: &[&[Color]] = &[
+    &[
+        Color { red: 247, green: 252, blue: 185 },
+        Color { red: 173, green: 221, blue: 142 },
+        Color { red: 49, green: 163, blue: 84 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 204 },
+        Color { red: 194, green: 230, blue: 153 },
+        Color { red: 120, green: 198, blue: 121 },
+        Color { red: 35, green: 132, blue: 67 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 204 },
+        Color { red: 194, green: 230, blue: 153 },
+        Color { red: 120, green: 198, blue: 121 },
+        Color { red: 49, green: 163, blue: 84 },
+        Color { red: 0, green: 104, blue: 55 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 204 },
+        Color { red: 217, green: 240, blue: 163 },
+        Color { red: 173, green: 221, blue: 142 },
+        Color { red: 120, green: 198, blue: 121 },
+        Color { red: 49, green: 163, blue: 84 },
+        Color { red: 0, green: 104, blue: 55 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 204 },
+        Color { red: 217, green: 240, blue: 163 },
+        Color { red: 173, green: 221, blue: 142 },
+        Color { red: 120, green: 198, blue: 121 },
+        Color { red: 65, green: 171, blue: 93 },
+        Color { red: 35, green: 132, blue: 67 },
+        Color { red: 0, green: 90, blue: 50 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 229 },
+        Color { red: 247, green: 252, blue: 185 },
+        Color { red: 217, green: 240, blue: 163 },
+        Color { red: 173, green: 221, blue: 142 },
+        Color { red: 120, green: 198, blue: 121 },
+        Color { red: 65, green: 171, blue: 93 },
+        Color { red: 35, green: 132, blue: 67 },
+        Color { red: 0, green: 90, blue: 50 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 229 },
+        Color { red: 247, green: 252, blue: 185 },
+        Color { red: 217, green: 240, blue: 163 },
+        Color { red: 173, green: 221, blue: 142 },
+        Color { red: 120, green: 198, blue: 121 },
+        Color { red: 65, green: 171, blue: 93 },
+        Color { red: 35, green: 132, blue: 67 },
+        Color { red: 0, green: 104, blue: 55 },
+        Color { red: 0, green: 69, blue: 41 },
+    ],
+];
+
+/// Yellow to green to blue, with 3 to 9 classes.
+const YL_GN_BU: &[&[Color]] = &[
+    &[
+        Color { red: 237, green: 248, blue: 177 },
+        Color { red: 127, green: 205, blue: 187 },
+        Color { red: 44, green: 127, blue: 184 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 204 },
+        Color { red: 161, green: 218, blue: 180 },
+        Color { red: 65, green: 182, blue: 196 },
+        Color { red: 34, green: 94, blue: 168 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 204 },
+        Color { red: 161, green: 218, blue: 180 },
+        Color { red: 65, green: 182, blue: 196 },
+        Color { red: 44, green: 127, blue: 184 },
+        Color { red: 37, green: 52, blue: 148 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 204 },
+        Color { red: 199, green: 233, blue: 180 },
+        Color { red: 127, green: 205, blue: 187 },
+        Color { red: 65, green: 182, blue: 196 },
+        Color { red: 44, green: 127, blue: 184 },
+        Color { red: 37, green: 52, blue: 148 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 204 },
+        Color { red: 199, green: 233, blue: 180 },
+        Color { red: 127, green: 205, blue: 187 },
+        Color { red: 65, green: 182, blue: 196 },
+        Color { red: 29, green: 145, blue: 192 },
+        Color { red: 34, green: 94, blue: 168 },
+        Color { red: 12, green: 44, blue: 132 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 217 },
+        Color { red: 237, green: 248, blue: 177 },
+        Color { red: 199, green: 233, blue: 180 },
+        Color { red: 127, green: 205, blue: 187 },
+        Color { red: 65, green: 182, blue: 196 },
+        Color { red: 29, green: 145, blue: 192 },
+        Color { red: 34, green: 94, blue: 168 },
+        Color { red: 12, green: 44, blue: 132 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 217 },
+        Color { red: 237, green: 248, blue: 177 },
+        Color { red: 199, green: 233, blue: 180 },
+        Color { red: 127, green: 205, blue: 187 },
+        Color { red: 65, green: 182, blue: 196 },
+        Color { red: 29, green: 145, blue: 192 },
+        Color { red: 34, green: 94, blue: 168 },
+        Color { red: 37, green: 52, blue: 148 },
+        Color { red: 8, green: 29, blue: 88 },
+    ],
+];
+
+/// Green to blue, with 3 to 9 classes.
+const GN_BU: &[&[Color]] = &[
+    &[
+        Color { red: 224, green: 243, blue: 219 },
+        Color { red: 168, green: 221, blue: 181 },
+        Color { red: 67, green: 162, blue: 202 },
+    ],
+    &[
+        Color { red: 240, green: 249, blue: 232 },
+        Color { red: 186, green: 228, blue: 188 },
+        Color { red: 123, green: 204, blue: 196 },
+        Color { red: 43, green: 140, blue: 190 },
+    ],
+    &[
+        Color { red: 240, green: 249, blue: 232 },
+        Color { red: 186, green: 228, blue: 188 },
+        Color { red: 123, green: 204, blue: 196 },
+        Color { red: 67, green: 162, blue: 202 },
+        Color { red: 8, green: 104, blue: 172 },
+    ],
+    &[
+        Color { red: 240, green: 249, blue: 232 },
+        Color { red: 204, green: 235, blue: 197 },
+        Color { red: 168, green: 221, blue: 181 },
+        Color { red: 123, green: 204, blue: 196 },
+        Color { red: 67, green: 162, blue: 202 },
+        Color { red: 8, green: 104, blue: 172 },
+    ],
+    &[
+        Color { red: 240, green: 249, blue: 232 },
+        Color { red: 204, green: 235, blue: 197 },
+        Color { red: 168, green: 221, blue: 181 },
+        Color { red: 123, green: 204, blue: 196 },
+        Color { red: 78, green: 179, blue: 211 },
+        Color { red: 43, green: 140, blue: 190 },
+        Color { red: 8, green: 88, blue: 158 },
+    ],
+    &[
+        Color { red: 247, green: 252, blue: 240 },
+        Color { red: 224, green: 243, blue: 219 },
+        Color { red: 204, green: 235, blue: 197 },
+        Color { red: 168, green: 221, blue: 181 },
+        Color { red: 123, green: 204, blue: 196 },
+        Color { red: 78, green: 179, blue: 211 },
+        Color { red: 43, green: 140, blue: 190 },
+        Color { red: 8, green: 88, blue: 158 },
+    ],
+    &[
+        Color { red: 247, green: 252, blue: 240 },
+        Color { red: 224, green: 243, blue: 219 },
+        Color { red: 204, green: 235, blue: 197 },
+        Color { red: 168, green: 221, blue: 181 },
+        Color { red: 123, green: 204, blue: 196 },
+        Color { red: 78, green: 179, blue: 211 },
+        Color { red: 43, green: 140, blue: 190 },
+        Color { red: 8, green: 104, blue: 172 },
+        Color { red: 8, green: 64, blue: 129 },
+    ],
+];
+
+/// Blue to green, with 3 to 9 classes.
+const BU_GN: &[&[Color]] = &[
+    &[
+        Color { red: 229, green: 245, blue: 249 },
+        Color { red: 153, green: 216, blue: 201 },
+        Color { red: 44, green: 162, blue: 95 },
+    ],
+    &[
+        Color { red: 237, green: 248, blue: 251 },
+        Color { red: 178, green: 226, blue: 226 },
+        Color { red: 102, green: 194, blue: 164 },
+        Color { red: 35, green: 139, blue: 69 },
+    ],
+    &[
+        Color { red: 237, green: 248, blue: 251 },
+        Color { red: 178, green: 226, blue: 226 },
+        Color { red: 102, green: 194, blue: 164 },
+        Color { red: 44, green: 162, blue: 95 },
+        Color { red: 0, green: 109, blue: 44 },
+    ],
+    &[
+        Color { red: 237, green: 248, blue: 251 },
+        Color { red: 204, green: 236, blue: 230 },
+        Color { red: 153, green: 216, blue: 201 },
+        Color { red: 102, green: 194, blue: 164 },
+        Color { red: 44, green: 162, blue: 95 },
+        Color { red: 0, green: 109, blue: 44 },
+    ],
+    &[
+        Color { red: 237, green: 248, blue: 251 },
+        Color { red: 204, green: 236, blue: 230 },
+        Color { red: 153, green: 216, blue: 201 },
+        Color { red: 102, green: 194, blue: 164 },
+        Color { red: 65, green: 174, blue: 118 },
+        Color { red: 35, green: 139, blue: 69 },
+        Color { red: 0, green: 88, blue: 36 },
+    ],
+    &[
+        Color { red: 247, green: 252, blue: 253 },
+        Color { red: 229, green: 245, blue: 249 },
+        Color { red: 204, green: 236, blue: 230 },
+        Color { red: 153, green: 216, blue: 201 },
+        Color { red: 102, green: 194, blue: 164 },
+        Color { red: 65, green: 174, blue: 118 },
+        Color { red: 35, green: 139, blue: 69 },
+        Color { red: 0, green: 88, blue: 36 },
+    ],
+    &[
+        Color { red: 247, green: 252, blue: 253 },
+        Color { red: 229, green: 245, blue: 249 },
+        Color { red: 204, green: 236, blue: 230 },
+        Color { red: 153, green: 216, blue: 201 },
+        Color { red: 102, green: 194, blue: 164 },
+        Color { red: 65, green: 174, blue: 118 },
+        Color { red: 35, green: 139, blue: 69 },
+        Color { red: 0, green: 109, blue: 44 },
+        Color { red: 0, green: 68, blue: 27 },
+    ],
+];
+
+/// Purple to blue to green, with 3 to 9 classes.
+const PU_BU_GN: &[&[Color]] = &[
+    &[
+        Color { red: 236, green: 226, blue: 240 },
+        Color { red: 166, green: 189, blue: 219 },
+        Color { red: 28, green: 144, blue: 153 },
+    ],
+    &[
+        Color { red: 246, green: 239, blue: 247 },
+        Color { red: 189, green: 201, blue: 225 },
+        Color { red: 103, green: 169, blue: 207 },
+        Color { red: 2, green: 129, blue: 138 },
+    ],
+    &[
+        Color { red: 246, green: 239, blue: 247 },
+        Color { red: 189, green: 201, blue: 225 },
+        Color { red: 103, green: 169, blue: 207 },
+        Color { red: 28, green: 144, blue: 153 },
+        Color { red: 1, green: 108, blue: 89 },
+    ],
+    &[
+        Color { red: 246, green: 239, blue: 247 },
+        Color { red: 208, green: 209, blue: 230 },
+        Color { red: 166, green: 189, blue: 219 },
+        Color { red: 103, green: 169, blue: 207 },
+        Color { red: 28, green: 144, blue: 153 },
+        Color { red: 1, green: 108, blue: 89 },
+    ],
+    &[
+        Color { red: 246, green: 239, blue: 247 },
+        Color { red: 208, green: 209, blue: 230 },
+        Color { red: 166, green: 189, blue: 219 },
+        Color { red: 103, green: 169, blue: 207 },
+        Color { red: 54, green: 144, blue: 192 },
+        Color { red: 2, green: 129, blue: 138 },
+        Color { red: 1, green: 100, blue: 80 },
+    ],
+    &[
+        Color { red: 255, green: 247, blue: 251 },
+        Color { red: 236, green: 226, blue: 240 },
+        Color { red: 208, green: 209, blue: 230 },
+        Color { red: 166, green: 189, blue: 219 },
+        Color { red: 103, green: 169, blue: 207 },
+        Color { red: 54, green: 144, blue: 192 },
+        Color { red: 2, green: 129, blue: 138 },
+        Color { red: 1, green: 100, blue: 80 },
+    ],
+    &[
+        Color { red: 255, green: 247, blue: 251 },
+        Color { red: 236, green: 226, blue: 240 },
+        Color { red: 208, green: 209, blue: 230 },
+        Color { red: 166, green: 189, blue: 219 },
+        Color { red: 103, green: 169, blue: 207 },
+        Color { red: 54, green: 144, blue: 192 },
+        Color { red: 2, green: 129, blue: 138 },
+        Color { red: 1, green: 108, blue: 89 },
+        Color { red: 1, green: 70, blue: 54 },
+    ],
+];
+
+/// Purple to blue, with 3 to 9 classes.
+const PU_BU: &[&[Color]] = &[
+    &[
+        Color { red: 236, green: 231, blue: 242 },
+        Color { red: 166, green: 189, blue: 219 },
+        Color { red: 43, green: 140, blue: 190 },
+    ],
+    &[
+        Color { red: 241, green: 238, blue: 246 },
+        Color { red: 189, green: 201, blue: 225 },
+        Color { red: 116, green: 169, blue: 207 },
+        Color { red: 5, green: 112, blue: 176 },
+    ],
+    &[
+        Color { red: 241, green: 238, blue: 246 },
+        Color { red: 189, green: 201, blue: 225 },
+        Color { red: 116, green: 169, blue: 207 },
+        Color { red: 43, green: 140, blue: 190 },
+        Color { red: 4, green: 90, blue: 141 },
+    ],
+    &[
+        Color { red: 241, green: 238, blue: 246 },
+        Color { red: 208, green: 209, blue: 230 },
+        Color { red: 166, green: 189, blue: 219 },
+        Color { red: 116, green: 169, blue: 207 },
+        Color { red: 43, green: 140, blue: 190 },
+        Color { red: 4, green: 90, blue: 141 },
+    ],
+    &[
+        Color { red: 241, green: 238, blue: 246 },
+        Color { red: 208, green: 209, blue: 230 },
+        Color { red: 166, green: 189, blue: 219 },
+        Color { red: 116, green: 169, blue: 207 },
+        Color { red: 54, green: 144, blue: 192 },
+        Color { red: 5, green: 112, blue: 176 },
+        Color { red: 3, green: 78, blue: 123 },
+    ],
+    &[
+        Color { red: 255, green: 247, blue: 251 },
+        Color { red: 236, green: 231, blue: 242 },
+        Color { red: 208, green: 209, blue: 230 },
+        Color { red: 166, green: 189, blue: 219 },
+        Color { red: 116, green: 169, blue: 207 },
+        Color { red: 54, green: 144, blue: 192 },
+        Color { red: 5, green: 112, blue: 176 },
+        Color { red: 3, green: 78, blue: 123 },
+    ],
+    &[
+        Color { red: 255, green: 247, blue: 251 },
+        Color { red: 236, green: 231, blue: 242 },
+        Color { red: 208, green: 209, blue: 230 },
+        Color { red: 166, green: 189, blue: 219 },
+        Color { red: 116, green: 169, blue: 207 },
+        Color { red: 54, green: 144, blue: 192 },
+        Color { red: 5, green: 112, blue: 176 },
+        Color { red: 4, green: 90, blue: 141 },
+        Color { red: 2, green: 56, blue: 88 },
+    ],
+];
+
+/// Blue to purple, with 3 to 9 classes.
+const BU_PU: &[&[Color]] = &[
+    &[
+        Color { red: 224, green: 236, blue: 244 },
+        Color { red: 158, green: 188, blue: 218 },
+        Color { red: 136, green: 86, blue: 167 },
+    ],
+    &[
+        Color { red: 237, green: 248, blue: 251 },
+        Color { red: 179, green: 205, blue: 227 },
+        Color { red: 140, green: 150, blue: 198 },
+        Color { red: 136, green: 65, blue: 157 },
+    ],
+    &[
+        Color { red: 237, green: 248, blue: 251 },
+        Color { red: 179, green: 205, blue: 227 },
+        Color { red: 140, green: 150, blue: 198 },
+        Color { red: 136, green: 86, blue: 167 },
+        Color { red: 129, green: 15, blue: 124 },
+    ],
+    &[
+        Color { red: 237, green: 248, blue: 251 },
+        Color { red: 191, green: 211, blue: 230 },
+        Color { red: 158, green: 188, blue: 218 },
+        Color { red: 140, green: 150, blue: 198 },
+        Color { red: 136, green: 86, blue: 167 },
+        Color { red: 129, green: 15, blue: 124 },
+    ],
+    &[
+        Color { red: 237, green: 248, blue: 251 },
+        Color { red: 191, green: 211, blue: 230 },
+        Color { red: 158, green: 188, blue: 218 },
+        Color { red: 140, green: 150, blue: 198 },
+        Color { red: 140, green: 107, blue: 177 },
+        Color { red: 136, green: 65, blue: 157 },
+        Color { red: 110, green: 1, blue: 107 },
+    ],
+    &[
+        Color { red: 247, green: 252, blue: 253 },
+        Color { red: 224, green: 236, blue: 244 },
+        Color { red: 191, green: 211, blue: 230 },
+        Color { red: 158, green: 188, blue: 218 },
+        Color { red: 140, green: 150, blue: 198 },
+        Color { red: 140, green: 107, blue: 177 },
+        Color { red: 136, green: 65, blue: 157 },
+        Color { red: 110, green: 1, blue: 107 },
+    ],
+    &[
+        Color { red: 247, green: 252, blue: 253 },
+        Color { red: 224, green: 236, blue: 244 },
+        Color { red: 191, green: 211, blue: 230 },
+        Color { red: 158, green: 188, blue: 218 },
+        Color { red: 140, green: 150, blue: 198 },
+        Color { red: 140, green: 107, blue: 177 },
+        Color { red: 136, green: 65, blue: 157 },
+        Color { red: 129, green: 15, blue: 124 },
+        Color { red: 77, green: 0, blue: 75 },
+    ],
+];
+
+/// Red to purple, with 3 to 9 classes.
+const RD_PU: &[&[Color]] = &[
+    &[
+        Color { red: 253, green: 224, blue: 221 },
+        Color { red: 250, green: 159, blue: 181 },
+        Color { red: 197, green: 27, blue: 138 },
+    ],
+    &[
+        Color { red: 254, green: 235, blue: 226 },
+        Color { red: 251, green: 180, blue: 185 },
+        Color { red: 247, green: 104, blue: 161 },
+        Color { red: 174, green: 1, blue: 126 },
+    ],
+    &[
+        Color { red: 254, green: 235, blue: 226 },
+        Color { red: 251, green: 180, blue: 185 },
+        Color { red: 247, green: 104, blue: 161 },
+        Color { red: 197, green: 27, blue: 138 },
+        Color { red: 122, green: 1, blue: 119 },
+    ],
+    &[
+        Color { red: 254, green: 235, blue: 226 },
+        Color { red: 252, green: 197, blue: 192 },
+        Color { red: 250, green: 159, blue: 181 },
+        Color { red: 247, green: 104, blue: 161 },
+        Color { red: 197, green: 27, blue: 138 },
+        Color { red: 122, green: 1, blue: 119 },
+    ],
+    &[
+        Color { red: 254, green: 235, blue: 226 },
+        Color { red: 252, green: 197, blue: 192 },
+        Color { red: 250, green: 159, blue: 181 },
+        Color { red: 247, green: 104, blue: 161 },
+        Color { red: 221, green: 52, blue: 151 },
+        Color { red: 174, green: 1, blue: 126 },
+        Color { red: 122, green: 1, blue: 119 },
+    ],
+    &[
+        Color { red: 255, green: 247, blue: 243 },
+        Color { red: 253, green: 224, blue: 221 },
+        Color { red: 252, green: 197, blue: 192 },
+        Color { red: 250, green: 159, blue: 181 },
+        Color { red: 247, green: 104, blue: 161 },
+        Color { red: 221, green: 52, blue: 151 },
+        Color { red: 174, green: 1, blue: 126 },
+        Color { red: 122, green: 1, blue: 119 },
+    ],
+    &[
+        Color { red: 255, green: 247, blue: 243 },
+        Color { red: 253, green: 224, blue: 221 },
+        Color { red: 252, green: 197, blue: 192 },
+        Color { red: 250, green: 159, blue: 181 },
+        Color { red: 247, green: 104, blue: 161 },
+        Color { red: 221, green: 52, blue: 151 },
+        Color { red: 174, green: 1, blue: 126 },
+        Color { red: 122, green: 1, blue: 119 },
+        Color { red: 73, green: 0, blue: 106 },
+    ],
+];
+
+/// Purple to red, with 3 to 9 classes.
+const PU_RD: &[&[Color]] = &[
+    &[
+        Color { red: 231, green: 225, blue: 239 },
+        Color { red: 201, green: 148, blue: 199 },
+        Color { red: 221, green: 28, blue: 119 },
+    ],
+    &[
+        Color { red: 241, green: 238, blue: 246 },
+        Color { red: 215, green: 181, blue: 216 },
+        Color { red: 223, green: 101, blue: 176 },
+        Color { red: 206, green: 18, blue: 86 },
+    ],
+    &[
+        Color { red: 241, green: 238, blue: 246 },
+        Color { red: 215, green: 181, blue: 216 },
+        Color { red: 223, green: 101, blue: 176 },
+        Color { red: 221, green: 28, blue: 119 },
+        Color { red: 152, green: 0, blue: 67 },
+    ],
+    &[
+        Color { red: 241, green: 238, blue: 246 },
+        Color { red: 212, green: 185, blue: 218 },
+        Color { red: 201, green: 148, blue: 199 },
+        Color { red: 223, green: 101, blue: 176 },
+        Color { red: 221, green: 28, blue: 119 },
+        Color { red: 152, green: 0, blue: 67 },
+    ],
+    &[
+        Color { red: 241, green: 238, blue: 246 },
+        Color { red: 212, green: 185, blue: 218 },
+        Color { red: 201, green: 148, blue: 199 },
+        Color { red: 223, green: 101, blue: 176 },
+        Color { red: 231, green: 41, blue: 138 },
+        Color { red: 206, green: 18, blue: 86 },
+        Color { red: 145, green: 0, blue: 63 },
+    ],
+    &[
+        Color { red: 247, green: 244, blue: 249 },
+        Color { red: 231, green: 225, blue: 239 },
+        Color { red: 212, green: 185, blue: 218 },
+        Color { red: 201, green: 148, blue: 199 },
+        Color { red: 223, green: 101, blue: 176 },
+        Color { red: 231, green: 41, blue: 138 },
+        Color { red: 206, green: 18, blue: 86 },
+        Color { red: 145, green: 0, blue: 63 },
+    ],
+    &[
+        Color { red: 247, green: 244, blue: 249 },
+        Color { red: 231, green: 225, blue: 239 },
+        Color { red: 212, green: 185, blue: 218 },
+        Color { red: 201, green: 148, blue: 199 },
+        Color { red: 223, green: 101, blue: 176 },
+        Color { red: 231, green: 41, blue: 138 },
+        Color { red: 206, green: 18, blue: 86 },
+        Color { red: 152, green: 0, blue: 67 },
+        Color { red: 103, green: 0, blue: 31 },
+    ],
+];
+
+/// Orange to red, with 3 to 9 classes.
+const OR_RD: &[&[Color]] = &[
+    &[
+        Color { red: 254, green: 232, blue: 200 },
+        Color { red: 253, green: 187, blue: 132 },
+        Color { red: 227, green: 74, blue: 51 },
+    ],
+    &[
+        Color { red: 254, green: 240, blue: 217 },
+        Color { red: 253, green: 204, blue: 138 },
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 215, green: 48, blue: 31 },
+    ],
+    &[
+        Color { red: 254, green: 240, blue: 217 },
+        Color { red: 253, green: 204, blue: 138 },
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 227, green: 74, blue: 51 },
+        Color { red: 179, green: 0, blue: 0 },
+    ],
+    &[
+        Color { red: 254, green: 240, blue: 217 },
+        Color { red: 253, green: 212, blue: 158 },
+        Color { red: 253, green: 187, blue: 132 },
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 227, green: 74, blue: 51 },
+        Color { red: 179, green: 0, blue: 0 },
+    ],
+    &[
+        Color { red: 254, green: 240, blue: 217 },
+        Color { red: 253, green: 212, blue: 158 },
+        Color { red: 253, green: 187, blue: 132 },
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 239, green: 101, blue: 72 },
+        Color { red: 215, green: 48, blue: 31 },
+        Color { red: 153, green: 0, blue: 0 },
+    ],
+    &[
+        Color { red: 255, green: 247, blue: 236 },
+        Color { red: 254, green: 232, blue: 200 },
+        Color { red: 253, green: 212, blue: 158 },
+        Color { red: 253, green: 187, blue: 132 },
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 239, green: 101, blue: 72 },
+        Color { red: 215, green: 48, blue: 31 },
+        Color { red: 153, green: 0, blue: 0 },
+    ],
+    &[
+        Color { red: 255, green: 247, blue: 236 },
+        Color { red: 254, green: 232, blue: 200 },
+        Color { red: 253, green: 212, blue: 158 },
+        Color { red: 253, green: 187, blue: 132 },
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 239, green: 101, blue: 72 },
+        Color { red: 215, green: 48, blue: 31 },
+        Color { red: 179, green: 0, blue: 0 },
+        Color { red: 127, green: 0, blue: 0 },
+    ],
+];
+
+/// Yellow to orange to red, with 3 to 9 classes.
+const YL_OR_RD: &[&[Color]] = &[
+    &[
+        Color { red: 255, green: 237, blue: 160 },
+        Color { red: 254, green: 178, blue: 76 },
+        Color { red: 240, green: 59, blue: 32 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 178 },
+        Color { red: 254, green: 204, blue: 92 },
+        Color { red: 253, green: 141, blue: 60 },
+        Color { red: 227, green: 26, blue: 28 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 178 },
+        Color { red: 254, green: 204, blue: 92 },
+        Color { red: 253, green: 141, blue: 60 },
+        Color { red: 240, green: 59, blue: 32 },
+        Color { red: 189, green: 0, blue: 38 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 178 },
+        Color { red: 254, green: 217, blue: 118 },
+        Color { red: 254, green: 178, blue: 76 },
+        Color { red: 253, green: 141, blue: 60 },
+        Color { red: 240, green: 59, blue: 32 },
+        Color { red: 189, green: 0, blue: 38 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 178 },
+        Color { red: 254, green: 217, blue: 118 },
+        Color { red: 254, green: 178, blue: 76 },
+        Color { red: 253, green: 141, blue: 60 },
+        Color { red: 252, green: 78, blue: 42 },
+        Color { red: 227, green: 26, blue: 28 },
+        Color { red: 177, green: 0, blue: 38 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 204 },
+        Color { red: 255, green: 237, blue: 160 },
+        Color { red: 254, green: 217, blue: 118 },
+        Color { red: 254, green: 178, blue: 76 },
+        Color { red: 253, green: 141, blue: 60 },
+        Color { red: 252, green: 78, blue: 42 },
+        Color { red: 227, green: 26, blue: 28 },
+        Color { red: 177, green: 0, blue: 38 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 204 },
+        Color { red: 255, green: 237, blue: 160 },
+        Color { red: 254, green: 217, blue: 118 },
+        Color { red: 254, green: 178, blue: 76 },
+        Color { red: 253, green: 141, blue: 60 },
+        Color { red: 252, green: 78, blue: 42 },
+        Color { red: 227, green: 26, blue: 28 },
+        Color { red: 189, green: 0, blue: 38 },
+        Color { red: 128, green: 0, blue: 38 },
+    ],
+];
+
+/// Yellow to orange to brown, with 3 to 9 classes.
+const YL_OR_BR: &[&[Color]] = &[
+    &[
+        Color { red: 255, green: 247, blue: 188 },
+        Color { red: 254, green: 196, blue: 79 },
+        Color { red: 217, green: 95, blue: 14 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 212 },
+        Color { red: 254, green: 217, blue: 142 },
+        Color { red: 254, green: 153, blue: 41 },
+        Color { red: 204, green: 76, blue: 2 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 212 },
+        Color { red: 254, green: 217, blue: 142 },
+        Color { red: 254, green: 153, blue: 41 },
+        Color { red: 217, green: 95, blue: 14 },
+        Color { red: 153, green: 52, blue: 4 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 212 },
+        Color { red: 254, green: 227, blue: 145 },
+        Color { red: 254, green: 196, blue: 79 },
+        Color { red: 254, green: 153, blue: 41 },
+        Color { red: 217, green: 95, blue: 14 },
+        Color { red: 153, green: 52, blue: 4 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 212 },
+        Color { red: 254, green: 227, blue: 145 },
+        Color { red: 254, green: 196, blue: 79 },
+        Color { red: 254, green: 153, blue: 41 },
+        Color { red: 236, green: 112, blue: 20 },
+        Color { red: 204, green: 76, blue: 2 },
+        Color { red: 140, green: 45, blue: 4 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 229 },
+        Color { red: 255, green: 247, blue: 188 },
+        Color { red: 254, green: 227, blue: 145 },
+        Color { red: 254, green: 196, blue: 79 },
+        Color { red: 254, green: 153, blue: 41 },
+        Color { red: 236, green: 112, blue: 20 },
+        Color { red: 204, green: 76, blue: 2 },
+        Color { red: 140, green: 45, blue: 4 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 229 },
+        Color { red: 255, green: 247, blue: 188 },
+        Color { red: 254, green: 227, blue: 145 },
+        Color { red: 254, green: 196, blue: 79 },
+        Color { red: 254, green: 153, blue: 41 },
+        Color { red: 236, green: 112, blue: 20 },
+        Color { red: 204, green: 76, blue: 2 },
+        Color { red: 153, green: 52, blue: 4 },
+        Color { red: 102, green: 37, blue: 6 },
+    ],
+];
+
+/// Purples, with 3 to 9 classes.
+const PURPLES: &[&[Color]] = &[
+    &[
+        Color { red: 239, green: 237, blue: 245 },
+        Color { red: 188, green: 189, blue: 220 },
+        Color { red: 117, green: 107, blue: 177 },
+    ],
+    &[
+        Color { red: 242, green: 240, blue: 247 },
+        Color { red: 203, green: 201, blue: 226 },
+        Color { red: 158, green: 154, blue: 200 },
+        Color { red: 106, green: 81, blue: 163 },
+    ],
+    &[
+        Color { red: 242, green: 240, blue: 247 },
+        Color { red: 203, green: 201, blue: 226 },
+        Color { red: 158, green: 154, blue: 200 },
+        Color { red: 117, green: 107, blue: 177 },
+        Color { red: 84, green: 39, blue: 143 },
+    ],
+    &[
+        Color { red: 242, green: 240, blue: 247 },
+        Color { red: 218, green: 218, blue: 235 },
+        Color { red: 188, green: 189, blue: 220 },
+        Color { red: 158, green: 154, blue: 200 },
+        Color { red: 117, green: 107, blue: 177 },
+        Color { red: 84, green: 39, blue: 143 },
+    ],
+    &[
+        Color { red: 242, green: 240, blue: 247 },
+        Color { red: 218, green: 218, blue: 235 },
+        Color { red: 188, green: 189, blue: 220 },
+        Color { red: 158, green: 154, blue: 200 },
+        Color { red: 128, green: 125, blue: 186 },
+        Color { red: 106, green: 81, blue: 163 },
+        Color { red: 74, green: 20, blue: 134 },
+    ],
+    &[
+        Color { red: 252, green: 251, blue: 253 },
+        Color { red: 239, green: 237, blue: 245 },
+        Color { red: 218, green: 218, blue: 235 },
+        Color { red: 188, green: 189, blue: 220 },
+        Color { red: 158, green: 154, blue: 200 },
+        Color { red: 128, green: 125, blue: 186 },
+        Color { red: 106, green: 81, blue: 163 },
+        Color { red: 74, green: 20, blue: 134 },
+    ],
+    &[
+        Color { red: 252, green: 251, blue: 253 },
+        Color { red: 239, green: 237, blue: 245 },
+        Color { red: 218, green: 218, blue: 235 },
+        Color { red: 188, green: 189, blue: 220 },
+        Color { red: 158, green: 154, blue: 200 },
+        Color { red: 128, green: 125, blue: 186 },
+        Color { red: 106, green: 81, blue: 163 },
+        Color { red: 84, green: 39, blue: 143 },
+        Color { red: 63, green: 0, blue: 125 },
+    ],
+];
+
+/// Blues, with 3 to 9 classes.
+const BLUES: &[&[Color]] = &[
+    &[
+        Color { red: 222, green: 235, blue: 247 },
+        Color { red: 158, green: 202, blue: 225 },
+        Color { red: 49, green: 130, blue: 189 },
+    ],
+    &[
+        Color { red: 239, green: 243, blue: 255 },
+        Color { red: 189, green: 215, blue: 231 },
+        Color { red: 107, green: 174, blue: 214 },
+        Color { red: 33, green: 113, blue: 181 },
+    ],
+    &[
+        Color { red: 239, green: 243, blue: 255 },
+        Color { red: 189, green: 215, blue: 231 },
+        Color { red: 107, green: 174, blue: 214 },
+        Color { red: 49, green: 130, blue: 189 },
+        Color { red: 8, green: 81, blue: 156 },
+    ],
+    &[
+        Color { red: 239, green: 243, blue: 255 },
+        Color { red: 198, green: 219, blue: 239 },
+        Color { red: 158, green: 202, blue: 225 },
+        Color { red: 107, green: 174, blue: 214 },
+        Color { red: 49, green: 130, blue: 189 },
+        Color { red: 8, green: 81, blue: 156 },
+    ],
+    &[
+        Color { red: 239, green: 243, blue: 255 },
+        Color { red: 198, green: 219, blue: 239 },
+        Color { red: 158, green: 202, blue: 225 },
+        Color { red: 107, green: 174, blue: 214 },
+        Color { red: 66, green: 146, blue: 198 },
+        Color { red: 33, green: 113, blue: 181 },
+        Color { red: 8, green: 69, blue: 148 },
+    ],
+    &[
+        Color { red: 247, green: 251, blue: 255 },
+        Color { red: 222, green: 235, blue: 247 },
+        Color { red: 198, green: 219, blue: 239 },
+        Color { red: 158, green: 202, blue: 225 },
+        Color { red: 107, green: 174, blue: 214 },
+        Color { red: 66, green: 146, blue: 198 },
+        Color { red: 33, green: 113, blue: 181 },
+        Color { red: 8, green: 69, blue: 148 },
+    ],
+    &[
+        Color { red: 247, green: 251, blue: 255 },
+        Color { red: 222, green: 235, blue: 247 },
+        Color { red: 198, green: 219, blue: 239 },
+        Color { red: 158, green: 202, blue: 225 },
+        Color { red: 107, green: 174, blue: 214 },
+        Color { red: 66, green: 146, blue: 198 },
+        Color { red: 33, green: 113, blue: 181 },
+        Color { red: 8, green: 81, blue: 156 },
+        Color { red: 8, green: 48, blue: 107 },
+    ],
+];
+
+/// Greens, with 3 to 9 classes.
+const GREENS: &[&[Color]] = &[
+    &[
+        Color { red: 229, green: 245, blue: 224 },
+        Color { red: 161, green: 217, blue: 155 },
+        Color { red: 49, green: 163, blue: 84 },
+    ],
+    &[
+        Color { red: 237, green: 248, blue: 233 },
+        Color { red: 186, green: 228, blue: 179 },
+        Color { red: 116, green: 196, blue: 118 },
+        Color { red: 35, green: 139, blue: 69 },
+    ],
+    &[
+        Color { red: 237, green: 248, blue: 233 },
+        Color { red: 186, green: 228, blue: 179 },
+        Color { red: 116, green: 196, blue: 118 },
+        Color { red: 49, green: 163, blue: 84 },
+        Color { red: 0, green: 109, blue: 44 },
+    ],
+    &[
+        Color { red: 237, green: 248, blue: 233 },
+        Color { red: 199, green: 233, blue: 192 },
+        Color { red: 161, green: 217, blue: 155 },
+        Color { red: 116, green: 196, blue: 118 },
+        Color { red: 49, green: 163, blue: 84 },
+        Color { red: 0, green: 109, blue: 44 },
+    ],
+    &[
+        Color { red: 237, green: 248, blue: 233 },
+        Color { red: 199, green: 233, blue: 192 },
+        Color { red: 161, green: 217, blue: 155 },
+        Color { red: 116, green: 196, blue: 118 },
+        Color { red: 65, green: 171, blue: 93 },
+        Color { red: 35, green: 139, blue: 69 },
+        Color { red: 0, green: 90, blue: 50 },
+    ],
+    &[
+        Color { red: 247, green: 252, blue: 245 },
+        Color { red: 229, green: 245, blue: 224 },
+        Color { red: 199, green: 233, blue: 192 },
+        Color { red: 161, green: 217, blue: 155 },
+        Color { red: 116, green: 196, blue: 118 },
+        Color { red: 65, green: 171, blue: 93 },
+        Color { red: 35, green: 139, blue: 69 },
+        Color { red: 0, green: 90, blue: 50 },
+    ],
+    &[
+        Color { red: 247, green: 252, blue: 245 },
+        Color { red: 229, green: 245, blue: 224 },
+        Color { red: 199, green: 233, blue: 192 },
+        Color { red: 161, green: 217, blue: 155 },
+        Color { red: 116, green: 196, blue: 118 },
+        Color { red: 65, green: 171, blue: 93 },
+        Color { red: 35, green: 139, blue: 69 },
+        Color { red: 0, green: 109, blue: 44 },
+        Color { red: 0, green: 68, blue: 27 },
+    ],
+];
+
+/// Oranges, with 3 to 9 classes.
+const ORANGES: &[&[Color]] = &[
+    &[
+        Color { red: 254, green: 230, blue: 206 },
+        Color { red: 253, green: 174, blue: 107 },
+        Color { red: 230, green: 85, blue: 13 },
+    ],
+    &[
+        Color { red: 254, green: 237, blue: 222 },
+        Color { red: 253, green: 190, blue: 133 },
+        Color { red: 253, green: 141, blue: 60 },
+        Color { red: 217, green: 71, blue: 1 },
+    ],
+    &[
+        Color { red: 254, green: 237, blue: 222 },
+        Color { red: 253, green: 190, blue: 133 },
+        Color { red: 253, green: 141, blue: 60 },
+        Color { red: 230, green: 85, blue: 13 },
+        Color { red: 166, green: 54, blue: 3 },
+    ],
+    &[
+        Color { red: 254, green: 237, blue: 222 },
+        Color { red: 253, green: 208, blue: 162 },
+        Color { red: 253, green: 174, blue: 107 },
+        Color { red: 253, green: 141, blue: 60 },
+        Color { red: 230, green: 85, blue: 13 },
+        Color { red: 166, green: 54, blue: 3 },
+    ],
+    &[
+        Color { red: 254, green: 237, blue: 222 },
+        Color { red: 253, green: 208, blue: 162 },
+        Color { red: 253, green: 174, blue: 107 },
+        Color { red: 253, green: 141, blue: 60 },
+        Color { red: 241, green: 105, blue: 19 },
+        Color { red: 217, green: 72, blue: 1 },
+        Color { red: 140, green: 45, blue: 4 },
+    ],
+    &[
+        Color { red: 255, green: 245, blue: 235 },
+        Color { red: 254, green: 230, blue: 206 },
+        Color { red: 253, green: 208, blue: 162 },
+        Color { red: 253, green: 174, blue: 107 },
+        Color { red: 253, green: 141, blue: 60 },
+        Color { red: 241, green: 105, blue: 19 },
+        Color { red: 217, green: 72, blue: 1 },
+        Color { red: 140, green: 45, blue: 4 },
+    ],
+    &[
+        Color { red: 255, green: 245, blue: 235 },
+        Color { red: 254, green: 230, blue: 206 },
+        Color { red: 253, green: 208, blue: 162 },
+        Color { red: 253, green: 174, blue: 107 },
+        Color { red: 253, green: 141, blue: 60 },
+        Color { red: 241, green: 105, blue: 19 },
+        Color { red: 217, green: 72, blue: 1 },
+        Color { red: 166, green: 54, blue: 3 },
+        Color { red: 127, green: 39, blue: 4 },
+    ],
+];
+
+/// Reds, with 3 to 9 classes.
+const REDS: &[&[Color]] = &[
+    &[
+        Color { red: 254, green: 224, blue: 210 },
+        Color { red: 252, green: 146, blue: 114 },
+        Color { red: 222, green: 45, blue: 38 },
+    ],
+    &[
+        Color { red: 254, green: 229, blue: 217 },
+        Color { red: 252, green: 174, blue: 145 },
+        Color { red: 251, green: 106, blue: 74 },
+        Color { red: 203, green: 24, blue: 29 },
+    ],
+    &[
+        Color { red: 254, green: 229, blue: 217 },
+        Color { red: 252, green: 174, blue: 145 },
+        Color { red: 251, green: 106, blue: 74 },
+        Color { red: 222, green: 45, blue: 38 },
+        Color { red: 165, green: 15, blue: 21 },
+    ],
+    &[
+        Color { red: 254, green: 229, blue: 217 },
+        Color { red: 252, green: 187, blue: 161 },
+        Color { red: 252, green: 146, blue: 114 },
+        Color { red: 251, green: 106, blue: 74 },
+        Color { red: 222, green: 45, blue: 38 },
+        Color { red: 165, green: 15, blue: 21 },
+    ],
+    &[
+        Color { red: 254, green: 229, blue: 217 },
+        Color { red: 252, green: 187, blue: 161 },
+        Color { red: 252, green: 146, blue: 114 },
+        Color { red: 251, green: 106, blue: 74 },
+        Color { red: 239, green: 59, blue: 44 },
+        Color { red: 203, green: 24, blue: 29 },
+        Color { red: 153, green: 0, blue: 13 },
+    ],
+    &[
+        Color { red: 255, green: 245, blue: 240 },
+        Color { red: 254, green: 224, blue: 210 },
+        Color { red: 252, green: 187, blue: 161 },
+        Color { red: 252, green: 146, blue: 114 },
+        Color { red: 251, green: 106, blue: 74 },
+        Color { red: 239, green: 59, blue: 44 },
+        Color { red: 203, green: 24, blue: 29 },
+        Color { red: 153, green: 0, blue: 13 },
+    ],
+    &[
+        Color { red: 255, green: 245, blue: 240 },
+        Color { red: 254, green: 224, blue: 210 },
+        Color { red: 252, green: 187, blue: 161 },
+        Color { red: 252, green: 146, blue: 114 },
+        Color { red: 251, green: 106, blue: 74 },
+        Color { red: 239, green: 59, blue: 44 },
+        Color { red: 203, green: 24, blue: 29 },
+        Color { red: 165, green: 15, blue: 21 },
+        Color { red: 103, green: 0, blue: 13 },
+    ],
+];
+
+/// Greys, with 3 to 9 classes.
+const GREYS: &[&[Color]] = &[
+    &[
+        Color { red: 240, green: 240, blue: 240 },
+        Color { red: 189, green: 189, blue: 189 },
+        Color { red: 99, green: 99, blue: 99 },
+    ],
+    &[
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 204, green: 204, blue: 204 },
+        Color { red: 150, green: 150, blue: 150 },
+        Color { red: 82, green: 82, blue: 82 },
+    ],
+    &[
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 204, green: 204, blue: 204 },
+        Color { red: 150, green: 150, blue: 150 },
+        Color { red: 99, green: 99, blue: 99 },
+        Color { red: 37, green: 37, blue: 37 },
+    ],
+    &[
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 217, green: 217, blue: 217 },
+        Color { red: 189, green: 189, blue: 189 },
+        Color { red: 150, green: 150, blue: 150 },
+        Color { red: 99, green: 99, blue: 99 },
+        Color { red: 37, green: 37, blue: 37 },
+    ],
+    &[
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 217, green: 217, blue: 217 },
+        Color { red: 189, green: 189, blue: 189 },
+        Color { red: 150, green: 150, blue: 150 },
+        Color { red: 115, green: 115, blue: 115 },
+        Color { red: 82, green: 82, blue: 82 },
+        Color { red: 37, green: 37, blue: 37 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 255 },
+        Color { red: 240, green: 240, blue: 240 },
+        Color { red: 217, green: 217, blue: 217 },
+        Color { red: 189, green: 189, blue: 189 },
+        Color { red: 150, green: 150, blue: 150 },
+        Color { red: 115, green: 115, blue: 115 },
+        Color { red: 82, green: 82, blue: 82 },
+        Color { red: 37, green: 37, blue: 37 },
+    ],
+    &[
+        Color { red: 255, green: 255, blue: 255 },
+        Color { red: 240, green: 240, blue: 240 },
+        Color { red: 217, green: 217, blue: 217 },
+        Color { red: 189, green: 189, blue: 189 },
+        Color { red: 150, green: 150, blue: 150 },
+        Color { red: 115, green: 115, blue: 115 },
+        Color { red: 82, green: 82, blue: 82 },
+        Color { red: 37, green: 37, blue: 37 },
+        Color { red: 0, green: 0, blue: 0 },
+    ],
+];
+
+/// Orange to purple, with 3 to 11 classes.
+const PU_OR: &[&[Color]] = &[
+    &[
+        Color { red: 241, green: 163, blue: 64 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 153, green: 142, blue: 195 },
+    ],
+    &[
+        Color { red: 230, green: 97, blue: 1 },
+        Color { red: 253, green: 184, blue: 99 },
+        Color { red: 178, green: 171, blue: 210 },
+        Color { red: 94, green: 60, blue: 153 },
+    ],
+    &[
+        Color { red: 230, green: 97, blue: 1 },
+        Color { red: 253, green: 184, blue: 99 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 178, green: 171, blue: 210 },
+        Color { red: 94, green: 60, blue: 153 },
+    ],
+    &[
+        Color { red: 179, green: 88, blue: 6 },
+        Color { red: 241, green: 163, blue: 64 },
+        Color { red: 254, green: 224, blue: 182 },
+        Color { red: 216, green: 218, blue: 235 },
+        Color { red: 153, green: 142, blue: 195 },
+        Color { red: 84, green: 39, blue: 136 },
+    ],
+    &[
+        Color { red: 179, green: 88, blue: 6 },
+        Color { red: 241, green: 163, blue: 64 },
+        Color { red: 254, green: 224, blue: 182 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 216, green: 218, blue: 235 },
+        Color { red: 153, green: 142, blue: 195 },
+        Color { red: 84, green: 39, blue: 136 },
+    ],
+    &[
+        Color { red: 179, green: 88, blue: 6 },
+        Color { red: 224, green: 130, blue: 20 },
+        Color { red: 253, green: 184, blue: 99 },
+        Color { red: 254, green: 224, blue: 182 },
+        Color { red: 216, green: 218, blue: 235 },
+        Color { red: 178, green: 171, blue: 210 },
+        Color { red: 128, green: 115, blue: 172 },
+        Color { red: 84, green: 39, blue: 136 },
+    ],
+    &[
+        Color { red: 179, green: 88, blue: 6 },
+        Color { red: 224, green: 130, blue: 20 },
+        Color { red: 253, green: 184, blue: 99 },
+        Color { red: 254, green: 224, blue: 182 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 216, green: 218, blue: 235 },
+        Color { red: 178, green: 171, blue: 210 },
+        Color { red: 128, green: 115, blue: 172 },
+        Color { red: 84, green: 39, blue: 136 },
+    ],
+    &[
+        Color { red: 127, green: 59, blue: 8 },
+        Color { red: 179, green: 88, blue: 6 },
+        Color { red: 224, green: 130, blue: 20 },
+        Color { red: 253, green: 184, blue: 99 },
+        Color { red: 254, green: 224, blue: 182 },
+        Color { red: 216, green: 218, blue: 235 },
+        Color { red: 178, green: 171, blue: 210 },
+        Color { red: 128, green: 115, blue: 172 },
+        Color { red: 84, green: 39, blue: 136 },
+        Color { red: 45, green: 0, blue: 75 },
+    ],
+    &[
+        Color { red: 127, green: 59, blue: 8 },
+        Color { red: 179, green: 88, blue: 6 },
+        Color { red: 224, green: 130, blue: 20 },
+        Color { red: 253, green: 184, blue: 99 },
+        Color { red: 254, green: 224, blue: 182 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 216, green: 218, blue: 235 },
+        Color { red: 178, green: 171, blue: 210 },
+        Color { red: 128, green: 115, blue: 172 },
+        Color { red: 84, green: 39, blue: 136 },
+        Color { red: 45, green: 0, blue: 75 },
+    ],
+];
+
+/// Brown to blue-green, with 3 to 11 classes.
+const BR_BG: &[&[Color]] = &[
+    &[
+        Color { red: 216, green: 179, blue: 101 },
+        Color { red: 245, green: 245, blue: 245 },
+        Color { red: 90, green: 180, blue: 172 },
+    ],
+    &[
+        Color { red: 166, green: 97, blue: 26 },
+        Color { red: 223, green: 194, blue: 125 },
+        Color { red: 128, green: 205, blue: 193 },
+        Color { red: 1, green: 133, blue: 113 },
+    ],
+    &[
+        Color { red: 166, green: 97, blue: 26 },
+        Color { red: 223, green: 194, blue: 125 },
+        Color { red: 245, green: 245, blue: 245 },
+        Color { red: 128, green: 205, blue: 193 },
+        Color { red: 1, green: 133, blue: 113 },
+    ],
+    &[
+        Color { red: 140, green: 81, blue: 10 },
+        Color { red: 216, green: 179, blue: 101 },
+        Color { red: 246, green: 232, blue: 195 },
+        Color { red: 199, green: 234, blue: 229 },
+        Color { red: 90, green: 180, blue: 172 },
+        Color { red: 1, green: 102, blue: 94 },
+    ],
+    &[
+        Color { red: 140, green: 81, blue: 10 },
+        Color { red: 216, green: 179, blue: 101 },
+        Color { red: 246, green: 232, blue: 195 },
+        Color { red: 245, green: 245, blue: 245 },
+        Color { red: 199, green: 234, blue: 229 },
+        Color { red: 90, green: 180, blue: 172 },
+        Color { red: 1, green: 102, blue: 94 },
+    ],
+    &[
+        Color { red: 140, green: 81, blue: 10 },
+        Color { red: 191, green: 129, blue: 45 },
+        Color { red: 223, green: 194, blue: 125 },
+        Color { red: 246, green: 232, blue: 195 },
+        Color { red: 199, green: 234, blue: 229 },
+        Color { red: 128, green: 205, blue: 193 },
+        Color { red: 53, green: 151, blue: 143 },
+        Color { red: 1, green: 102, blue: 94 },
+    ],
+    &[
+        Color { red: 140, green: 81, blue: 10 },
+        Color { red: 191, green: 129, blue: 45 },
+        Color { red: 223, green: 194, blue: 125 },
+        Color { red: 246, green: 232, blue: 195 },
+        Color { red: 245, green: 245, blue: 245 },
+        Color { red: 199, green: 234, blue: 229 },
+        Color { red: 128, green: 205, blue: 193 },
+        Color { red: 53, green: 151, blue: 143 },
+        Color { red: 1, green: 102, blue: 94 },
+    ],
+    &[
+        Color { red: 84, green: 48, blue: 5 },
+        Color { red: 140, green: 81, blue: 10 },
+        Color { red: 191, green: 129, blue: 45 },
+        Color { red: 223, green: 194, blue: 125 },
+        Color { red: 246, green: 232, blue: 195 },
+        Color { red: 199, green: 234, blue: 229 },
+        Color { red: 128, green: 205, blue: 193 },
+        Color { red: 53, green: 151, blue: 143 },
+        Color { red: 1, green: 102, blue: 94 },
+        Color { red: 0, green: 60, blue: 48 },
+    ],
+    &[
+        Color { red: 84, green: 48, blue: 5 },
+        Color { red: 140, green: 81, blue: 10 },
+        Color { red: 191, green: 129, blue: 45 },
+        Color { red: 223, green: 194, blue: 125 },
+        Color { red: 246, green: 232, blue: 195 },
+        Color { red: 245, green: 245, blue: 245 },
+        Color { red: 199, green: 234, blue: 229 },
+        Color { red: 128, green: 205, blue: 193 },
+        Color { red: 53, green: 151, blue: 143 },
+        Color { red: 1, green: 102, blue: 94 },
+        Color { red: 0, green: 60, blue: 48 },
+    ],
+];
+
+/// Purple to green, with 3 to 11 classes.
+const PR_GN: &[&[Color]] = &[
+    &[
+        Color { red: 175, green: 141, blue: 195 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 127, green: 191, blue: 123 },
+    ],
+    &[
+        Color { red: 123, green: 50, blue: 148 },
+        Color { red: 194, green: 165, blue: 207 },
+        Color { red: 166, green: 219, blue: 160 },
+        Color { red: 0, green: 136, blue: 55 },
+    ],
+    &[
+        Color { red: 123, green: 50, blue: 148 },
+        Color { red: 194, green: 165, blue: 207 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 166, green: 219, blue: 160 },
+        Color { red: 0, green: 136, blue: 55 },
+    ],
+    &[
+        Color { red: 118, green: 42, blue: 131 },
+        Color { red: 175, green: 141, blue: 195 },
+        Color { red: 231, green: 212, blue: 232 },
+        Color { red: 217, green: 240, blue: 211 },
+        Color { red: 127, green: 191, blue: 123 },
+        Color { red: 27, green: 120, blue: 55 },
+    ],
+    &[
+        Color { red: 118, green: 42, blue: 131 },
+        Color { red: 175, green: 141, blue: 195 },
+        Color { red: 231, green: 212, blue: 232 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 217, green: 240, blue: 211 },
+        Color { red: 127, green: 191, blue: 123 },
+        Color { red: 27, green: 120, blue: 55 },
+    ],
+    &[
+        Color { red: 118, green: 42, blue: 131 },
+        Color { red: 153, green: 112, blue: 171 },
+        Color { red: 194, green: 165, blue: 207 },
+        Color { red: 231, green: 212, blue: 232 },
+        Color { red: 217, green: 240, blue: 211 },
+        Color { red: 166, green: 219, blue: 160 },
+        Color { red: 90, green: 174, blue: 97 },
+        Color { red: 27, green: 120, blue: 55 },
+    ],
+    &[
+        Color { red: 118, green: 42, blue: 131 },
+        Color { red: 153, green: 112, blue: 171 },
+        Color { red: 194, green: 165, blue: 207 },
+        Color { red: 231, green: 212, blue: 232 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 217, green: 240, blue: 211 },
+        Color { red: 166, green: 219, blue: 160 },
+        Color { red: 90, green: 174, blue: 97 },
+        Color { red: 27, green: 120, blue: 55 },
+    ],
+    &[
+        Color { red: 64, green: 0, blue: 75 },
+        Color { red: 118, green: 42, blue: 131 },
+        Color { red: 153, green: 112, blue: 171 },
+        Color { red: 194, green: 165, blue: 207 },
+        Color { red: 231, green: 212, blue: 232 },
+        Color { red: 217, green: 240, blue: 211 },
+        Color { red: 166, green: 219, blue: 160 },
+        Color { red: 90, green: 174, blue: 97 },
+        Color { red: 27, green: 120, blue: 55 },
+        Color { red: 0, green: 68, blue: 27 },
+    ],
+    &[
+        Color { red: 64, green: 0, blue: 75 },
+        Color { red: 118, green: 42, blue: 131 },
+        Color { red: 153, green: 112, blue: 171 },
+        Color { red: 194, green: 165, blue: 207 },
+        Color { red: 231, green: 212, blue: 232 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 217, green: 240, blue: 211 },
+        Color { red: 166, green: 219, blue: 160 },
+        Color { red: 90, green: 174, blue: 97 },
+        Color { red: 27, green: 120, blue: 55 },
+        Color { red: 0, green: 68, blue: 27 },
+    ],
+];
+
+/// Pink to yellow-green, with 3 to 11 classes.
+const PI_YG: &[&[Color]] = &[
+    &[
+        Color { red: 233, green: 163, blue: 201 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 161, green: 215, blue: 106 },
+    ],
+    &[
+        Color { red: 208, green: 28, blue: 139 },
+        Color { red: 241, green: 182, blue: 218 },
+        Color { red: 184, green: 225, blue: 134 },
+        Color { red: 77, green: 172, blue: 38 },
+    ],
+    &[
+        Color { red: 208, green: 28, blue: 139 },
+        Color { red: 241, green: 182, blue: 218 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 184, green: 225, blue: 134 },
+        Color { red: 77, green: 172, blue: 38 },
+    ],
+    &[
+        Color { red: 197, green: 27, blue: 125 },
+        Color { red: 233, green: 163, blue: 201 },
+        Color { red: 253, green: 224, blue: 239 },
+        Color { red: 230, green: 245, blue: 208 },
+        Color { red: 161, green: 215, blue: 106 },
+        Color { red: 77, green: 146, blue: 33 },
+    ],
+    &[
+        Color { red: 197, green: 27, blue: 125 },
+        Color { red: 233, green: 163, blue: 201 },
+        Color { red: 253, green: 224, blue: 239 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 230, green: 245, blue: 208 },
+        Color { red: 161, green: 215, blue: 106 },
+        Color { red: 77, green: 146, blue: 33 },
+    ],
+    &[
+        Color { red: 197, green: 27, blue: 125 },
+        Color { red: 222, green: 119, blue: 174 },
+        Color { red: 241, green: 182, blue: 218 },
+        Color { red: 253, green: 224, blue: 239 },
+        Color { red: 230, green: 245, blue: 208 },
+        Color { red: 184, green: 225, blue: 134 },
+        Color { red: 127, green: 188, blue: 65 },
+        Color { red: 77, green: 146, blue: 33 },
+    ],
+    &[
+        Color { red: 197, green: 27, blue: 125 },
+        Color { red: 222, green: 119, blue: 174 },
+        Color { red: 241, green: 182, blue: 218 },
+        Color { red: 253, green: 224, blue: 239 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 230, green: 245, blue: 208 },
+        Color { red: 184, green: 225, blue: 134 },
+        Color { red: 127, green: 188, blue: 65 },
+        Color { red: 77, green: 146, blue: 33 },
+    ],
+    &[
+        Color { red: 142, green: 1, blue: 82 },
+        Color { red: 197, green: 27, blue: 125 },
+        Color { red: 222, green: 119, blue: 174 },
+        Color { red: 241, green: 182, blue: 218 },
+        Color { red: 253, green: 224, blue: 239 },
+        Color { red: 230, green: 245, blue: 208 },
+        Color { red: 184, green: 225, blue: 134 },
+        Color { red: 127, green: 188, blue: 65 },
+        Color { red: 77, green: 146, blue: 33 },
+        Color { red: 39, green: 100, blue: 25 },
+    ],
+    &[
+        Color { red: 142, green: 1, blue: 82 },
+        Color { red: 197, green: 27, blue: 125 },
+        Color { red: 222, green: 119, blue: 174 },
+        Color { red: 241, green: 182, blue: 218 },
+        Color { red: 253, green: 224, blue: 239 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 230, green: 245, blue: 208 },
+        Color { red: 184, green: 225, blue: 134 },
+        Color { red: 127, green: 188, blue: 65 },
+        Color { red: 77, green: 146, blue: 33 },
+        Color { red: 39, green: 100, blue: 25 },
+    ],
+];
+
+/// Red to blue, with 3 to 11 classes.
+const RD_BU: &[&[Color]] = &[
+    &[
+        Color { red: 239, green: 138, blue: 98 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 103, green: 169, blue: 207 },
+    ],
+    &[
+        Color { red: 202, green: 0, blue: 32 },
+        Color { red: 244, green: 165, blue: 130 },
+        Color { red: 146, green: 197, blue: 222 },
+        Color { red: 5, green: 113, blue: 176 },
+    ],
+    &[
+        Color { red: 202, green: 0, blue: 32 },
+        Color { red: 244, green: 165, blue: 130 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 146, green: 197, blue: 222 },
+        Color { red: 5, green: 113, blue: 176 },
+    ],
+    &[
+        Color { red: 178, green: 24, blue: 43 },
+        Color { red: 239, green: 138, blue: 98 },
+        Color { red: 253, green: 219, blue: 199 },
+        Color { red: 209, green: 229, blue: 240 },
+        Color { red: 103, green: 169, blue: 207 },
+        Color { red: 33, green: 102, blue: 172 },
+    ],
+    &[
+        Color { red: 178, green: 24, blue: 43 },
+        Color { red: 239, green: 138, blue: 98 },
+        Color { red: 253, green: 219, blue: 199 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 209, green: 229, blue: 240 },
+        Color { red: 103, green: 169, blue: 207 },
+        Color { red: 33, green: 102, blue: 172 },
+    ],
+    &[
+        Color { red: 178, green: 24, blue: 43 },
+        Color { red: 214, green: 96, blue: 77 },
+        Color { red: 244, green: 165, blue: 130 },
+        Color { red: 253, green: 219, blue: 199 },
+        Color { red: 209, green: 229, blue: 240 },
+        Color { red: 146, green: 197, blue: 222 },
+        Color { red: 67, green: 147, blue: 195 },
+        Color { red: 33, green: 102, blue: 172 },
+    ],
+    &[
+        Color { red: 178, green: 24, blue: 43 },
+        Color { red: 214, green: 96, blue: 77 },
+        Color { red: 244, green: 165, blue: 130 },
+        Color { red: 253, green: 219, blue: 199 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 209, green: 229, blue: 240 },
+        Color { red: 146, green: 197, blue: 222 },
+        Color { red: 67, green: 147, blue: 195 },
+        Color { red: 33, green: 102, blue: 172 },
+    ],
+    &[
+        Color { red: 103, green: 0, blue: 31 },
+        Color { red: 178, green: 24, blue: 43 },
+        Color { red: 214, green: 96, blue: 77 },
+        Color { red: 244, green: 165, blue: 130 },
+        Color { red: 253, green: 219, blue: 199 },
+        Color { red: 209, green: 229, blue: 240 },
+        Color { red: 146, green: 197, blue: 222 },
+        Color { red: 67, green: 147, blue: 195 },
+        Color { red: 33, green: 102, blue: 172 },
+        Color { red: 5, green: 48, blue: 97 },
+    ],
+    &[
+        Color { red: 103, green: 0, blue: 31 },
+        Color { red: 178, green: 24, blue: 43 },
+        Color { red: 214, green: 96, blue: 77 },
+        Color { red: 244, green: 165, blue: 130 },
+        Color { red: 253, green: 219, blue: 199 },
+        Color { red: 247, green: 247, blue: 247 },
+        Color { red: 209, green: 229, blue: 240 },
+        Color { red: 146, green: 197, blue: 222 },
+        Color { red: 67, green: 147, blue: 195 },
+        Color { red: 33, green: 102, blue: 172 },
+        Color { red: 5, green: 48, blue: 97 },
+    ],
+];
+
+/// Red to grey, with 3 to 11 classes.
+const RD_GY: &[&[Color]] = &[
+    &[
+        Color { red: 239, green: 138, blue: 98 },
+        Color { red: 255, green: 255, blue: 255 },
+        Color { red: 153, green: 153, blue: 153 },
+    ],
+    &[
+        Color { red: 202, green: 0, blue: 32 },
+        Color { red: 244, green: 165, blue: 130 },
+        Color { red: 186, green: 186, blue: 186 },
+        Color { red: 64, green: 64, blue: 64 },
+    ],
+    &[
+        Color { red: 202, green: 0, blue: 32 },
+        Color { red: 244, green: 165, blue: 130 },
+        Color { red: 255, green: 255, blue: 255 },
+        Color { red: 186, green: 186, blue: 186 },
+        Color { red: 64, green: 64, blue: 64 },
+    ],
+    &[
+        Color { red: 178, green: 24, blue: 43 },
+        Color { red: 239, green: 138, blue: 98 },
+        Color { red: 253, green: 219, blue: 199 },
+        Color { red: 224, green: 224, blue: 224 },
+        Color { red: 153, green: 153, blue: 153 },
+        Color { red: 77, green: 77, blue: 77 },
+    ],
+    &[
+        Color { red: 178, green: 24, blue: 43 },
+        Color { red: 239, green: 138, blue: 98 },
+        Color { red: 253, green: 219, blue: 199 },
+        Color { red: 255, green: 255, blue: 255 },
+        Color { red: 224, green: 224, blue: 224 },
+        Color { red: 153, green: 153, blue: 153 },
+        Color { red: 77, green: 77, blue: 77 },
+    ],
+    &[
+        Color { red: 178, green: 24, blue: 43 },
+        Color { red: 214, green: 96, blue: 77 },
+        Color { red: 244, green: 165, blue: 130 },
+        Color { red: 253, green: 219, blue: 199 },
+        Color { red: 224, green: 224, blue: 224 },
+        Color { red: 186, green: 186, blue: 186 },
+        Color { red: 135, green: 135, blue: 135 },
+        Color { red: 77, green: 77, blue: 77 },
+    ],
+    &[
+        Color { red: 178, green: 24, blue: 43 },
+        Color { red: 214, green: 96, blue: 77 },
+        Color { red: 244, green: 165, blue: 130 },
+        Color { red: 253, green: 219, blue: 199 },
+        Color { red: 255, green: 255, blue: 255 },
+        Color { red: 224, green: 224, blue: 224 },
+        Color { red: 186, green: 186, blue: 186 },
+        Color { red: 135, green: 135, blue: 135 },
+        Color { red: 77, green: 77, blue: 77 },
+    ],
+    &[
+        Color { red: 103, green: 0, blue: 31 },
+        Color { red: 178, green: 24, blue: 43 },
+        Color { red: 214, green: 96, blue: 77 },
+        Color { red: 244, green: 165, blue: 130 },
+        Color { red: 253, green: 219, blue: 199 },
+        Color { red: 224, green: 224, blue: 224 },
+        Color { red: 186, green: 186, blue: 186 },
+        Color { red: 135, green: 135, blue: 135 },
+        Color { red: 77, green: 77, blue: 77 },
+        Color { red: 26, green: 26, blue: 26 },
+    ],
+    &[
+        Color { red: 103, green: 0, blue: 31 },
+        Color { red: 178, green: 24, blue: 43 },
+        Color { red: 214, green: 96, blue: 77 },
+        Color { red: 244, green: 165, blue: 130 },
+        Color { red: 253, green: 219, blue: 199 },
+        Color { red: 255, green: 255, blue: 255 },
+        Color { red: 224, green: 224, blue: 224 },
+        Color { red: 186, green: 186, blue: 186 },
+        Color { red: 135, green: 135, blue: 135 },
+        Color { red: 77, green: 77, blue: 77 },
+        Color { red: 26, green: 26, blue: 26 },
+    ],
+];
+
+/// Red to yellow to blue, with 3 to 11 classes.
+const RD_YL_BU: &[&[Color]] = &[
+    &[
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 145, green: 191, blue: 219 },
+    ],
+    &[
+        Color { red: 215, green: 25, blue: 28 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 171, green: 217, blue: 233 },
+        Color { red: 44, green: 123, blue: 182 },
+    ],
+    &[
+        Color { red: 215, green: 25, blue: 28 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 171, green: 217, blue: 233 },
+        Color { red: 44, green: 123, blue: 182 },
+    ],
+    &[
+        Color { red: 215, green: 48, blue: 39 },
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 254, green: 224, blue: 144 },
+        Color { red: 224, green: 243, blue: 248 },
+        Color { red: 145, green: 191, blue: 219 },
+        Color { red: 69, green: 117, blue: 180 },
+    ],
+    &[
+        Color { red: 215, green: 48, blue: 39 },
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 254, green: 224, blue: 144 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 224, green: 243, blue: 248 },
+        Color { red: 145, green: 191, blue: 219 },
+        Color { red: 69, green: 117, blue: 180 },
+    ],
+    &[
+        Color { red: 215, green: 48, blue: 39 },
+        Color { red: 244, green: 109, blue: 67 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 254, green: 224, blue: 144 },
+        Color { red: 224, green: 243, blue: 248 },
+        Color { red: 171, green: 217, blue: 233 },
+        Color { red: 116, green: 173, blue: 209 },
+        Color { red: 69, green: 117, blue: 180 },
+    ],
+    &[
+        Color { red: 215, green: 48, blue: 39 },
+        Color { red: 244, green: 109, blue: 67 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 254, green: 224, blue: 144 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 224, green: 243, blue: 248 },
+        Color { red: 171, green: 217, blue: 233 },
+        Color { red: 116, green: 173, blue: 209 },
+        Color { red: 69, green: 117, blue: 180 },
+    ],
+    &[
+        Color { red: 165, green: 0, blue: 38 },
+        Color { red: 215, green: 48, blue: 39 },
+        Color { red: 244, green: 109, blue: 67 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 254, green: 224, blue: 144 },
+        Color { red: 224, green: 243, blue: 248 },
+        Color { red: 171, green: 217, blue: 233 },
+        Color { red: 116, green: 173, blue: 209 },
+        Color { red: 69, green: 117, blue: 180 },
+        Color { red: 49, green: 54, blue: 149 },
+    ],
+    &[
+        Color { red: 165, green: 0, blue: 38 },
+        Color { red: 215, green: 48, blue: 39 },
+        Color { red: 244, green: 109, blue: 67 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 254, green: 224, blue: 144 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 224, green: 243, blue: 248 },
+        Color { red: 171, green: 217, blue: 233 },
+        Color { red: 116, green: 173, blue: 209 },
+        Color { red: 69, green: 117, blue: 180 },
+        Color { red: 49, green: 54, blue: 149 },
+    ],
+];
+
+/// Red to yellow to blue through the spectrum, with 3 to 11 classes.
+const SPECTRAL: &[&[Color]] = &[
+    &[
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 153, green: 213, blue: 148 },
+    ],
+    &[
+        Color { red: 215, green: 25, blue: 28 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 171, green: 221, blue: 164 },
+        Color { red: 43, green: 131, blue: 186 },
+    ],
+    &[
+        Color { red: 215, green: 25, blue: 28 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 171, green: 221, blue: 164 },
+        Color { red: 43, green: 131, blue: 186 },
+    ],
+    &[
+        Color { red: 213, green: 62, blue: 79 },
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 254, green: 224, blue: 139 },
+        Color { red: 230, green: 245, blue: 152 },
+        Color { red: 153, green: 213, blue: 148 },
+        Color { red: 50, green: 136, blue: 189 },
+    ],
+    &[
+        Color { red: 213, green: 62, blue: 79 },
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 254, green: 224, blue: 139 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 230, green: 245, blue: 152 },
+        Color { red: 153, green: 213, blue: 148 },
+        Color { red: 50, green: 136, blue: 189 },
+    ],
+    &[
+        Color { red: 213, green: 62, blue: 79 },
+        Color { red: 244, green: 109, blue: 67 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 254, green: 224, blue: 139 },
+        Color { red: 230, green: 245, blue: 152 },
+        Color { red: 171, green: 221, blue: 164 },
+        Color { red: 102, green: 194, blue: 165 },
+        Color { red: 50, green: 136, blue: 189 },
+    ],
+    &[
+        Color { red: 213, green: 62, blue: 79 },
+        Color { red: 244, green: 109, blue: 67 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 254, green: 224, blue: 139 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 230, green: 245, blue: 152 },
+        Color { red: 171, green: 221, blue: 164 },
+        Color { red: 102, green: 194, blue: 165 },
+        Color { red: 50, green: 136, blue: 189 },
+    ],
+    &[
+        Color { red: 158, green: 1, blue: 66 },
+        Color { red: 213, green: 62, blue: 79 },
+        Color { red: 244, green: 109, blue: 67 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 254, green: 224, blue: 139 },
+        Color { red: 230, green: 245, blue: 152 },
+        Color { red: 171, green: 221, blue: 164 },
+        Color { red: 102, green: 194, blue: 165 },
+        Color { red: 50, green: 136, blue: 189 },
+        Color { red: 94, green: 79, blue: 162 },
+    ],
+    &[
+        Color { red: 158, green: 1, blue: 66 },
+        Color { red: 213, green: 62, blue: 79 },
+        Color { red: 244, green: 109, blue: 67 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 254, green: 224, blue: 139 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 230, green: 245, blue: 152 },
+        Color { red: 171, green: 221, blue: 164 },
+        Color { red: 102, green: 194, blue: 165 },
+        Color { red: 50, green: 136, blue: 189 },
+        Color { red: 94, green: 79, blue: 162 },
+    ],
+];
+
+/// Red to yellow to green, with 3 to 11 classes.
+const RD_YL_GN: &[&[Color]] = &[
+    &[
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 145, green: 207, blue: 96 },
+    ],
+    &[
+        Color { red: 215, green: 25, blue: 28 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 166, green: 217, blue: 106 },
+        Color { red: 26, green: 150, blue: 65 },
+    ],
+    &[
+        Color { red: 215, green: 25, blue: 28 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 166, green: 217, blue: 106 },
+        Color { red: 26, green: 150, blue: 65 },
+    ],
+    &[
+        Color { red: 215, green: 48, blue: 39 },
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 254, green: 224, blue: 139 },
+        Color { red: 217, green: 239, blue: 139 },
+        Color { red: 145, green: 207, blue: 96 },
+        Color { red: 26, green: 152, blue: 80 },
+    ],
+    &[
+        Color { red: 215, green: 48, blue: 39 },
+        Color { red: 252, green: 141, blue: 89 },
+        Color { red: 254, green: 224, blue: 139 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 217, green: 239, blue: 139 },
+        Color { red: 145, green: 207, blue: 96 },
+        Color { red: 26, green: 152, blue: 80 },
+    ],
+    &[
+        Color { red: 215, green: 48, blue: 39 },
+        Color { red: 244, green: 109, blue: 67 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 254, green: 224, blue: 139 },
+        Color { red: 217, green: 239, blue: 139 },
+        Color { red: 166, green: 217, blue: 106 },
+        Color { red: 102, green: 189, blue: 99 },
+        Color { red: 26, green: 152, blue: 80 },
+    ],
+    &[
+        Color { red: 215, green: 48, blue: 39 },
+        Color { red: 244, green: 109, blue: 67 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 254, green: 224, blue: 139 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 217, green: 239, blue: 139 },
+        Color { red: 166, green: 217, blue: 106 },
+        Color { red: 102, green: 189, blue: 99 },
+        Color { red: 26, green: 152, blue: 80 },
+    ],
+    &[
+        Color { red: 165, green: 0, blue: 38 },
+        Color { red: 215, green: 48, blue: 39 },
+        Color { red: 244, green: 109, blue: 67 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 254, green: 224, blue: 139 },
+        Color { red: 217, green: 239, blue: 139 },
+        Color { red: 166, green: 217, blue: 106 },
+        Color { red: 102, green: 189, blue: 99 },
+        Color { red: 26, green: 152, blue: 80 },
+        Color { red: 0, green: 104, blue: 55 },
+    ],
+    &[
+        Color { red: 165, green: 0, blue: 38 },
+        Color { red: 215, green: 48, blue: 39 },
+        Color { red: 244, green: 109, blue: 67 },
+        Color { red: 253, green: 174, blue: 97 },
+        Color { red: 254, green: 224, blue: 139 },
+        Color { red: 255, green: 255, blue: 191 },
+        Color { red: 217, green: 239, blue: 139 },
+        Color { red: 166, green: 217, blue: 106 },
+        Color { red: 102, green: 189, blue: 99 },
+        Color { red: 26, green: 152, blue: 80 },
+        Color { red: 0, green: 104, blue: 55 },
+    ],
+];
+
+/// Accent colors, with 3 to 8 classes.
+const ACCENT: &[&[Color]] = &[
+    &[
+        Color { red: 127, green: 201, blue: 127 },
+        Color { red: 190, green: 174, blue: 212 },
+        Color { red: 253, green: 192, blue: 134 },
+    ],
+    &[
+        Color { red: 127, green: 201, blue: 127 },
+        Color { red: 190, green: 174, blue: 212 },
+        Color { red: 253, green: 192, blue: 134 },
+        Color { red: 255, green: 255, blue: 153 },
+    ],
+    &[
+        Color { red: 127, green: 201, blue: 127 },
+        Color { red: 190, green: 174, blue: 212 },
+        Color { red: 253, green: 192, blue: 134 },
+        Color { red: 255, green: 255, blue: 153 },
+        Color { red: 56, green: 108, blue: 176 },
+    ],
+    &[
+        Color { red: 127, green: 201, blue: 127 },
+        Color { red: 190, green: 174, blue: 212 },
+        Color { red: 253, green: 192, blue: 134 },
+        Color { red: 255, green: 255, blue: 153 },
+        Color { red: 56, green: 108, blue: 176 },
+        Color { red: 240, green: 2, blue: 127 },
+    ],
+    &[
+        Color { red: 127, green: 201, blue: 127 },
+        Color { red: 190, green: 174, blue: 212 },
+        Color { red: 253, green: 192, blue: 134 },
+        Color { red: 255, green: 255, blue: 153 },
+        Color { red: 56, green: 108, blue: 176 },
+        Color { red: 240, green: 2, blue: 127 },
+        Color { red: 191, green: 91, blue: 23 },
+    ],
+    &[
+        Color { red: 127, green: 201, blue: 127 },
+        Color { red: 190, green: 174, blue: 212 },
+        Color { red: 253, green: 192, blue: 134 },
+        Color { red: 255, green: 255, blue: 153 },
+        Color { red: 56, green: 108, blue: 176 },
+        Color { red: 240, green: 2, blue: 127 },
+        Color { red: 191, green: 91, blue: 23 },
+        Color { red: 102, green: 102, blue: 102 },
+    ],
+];
+
+/// Dark colors, with 3 to 8 classes.
+const DARK2: &[&[Color]] = &[
+    &[
+        Color { red: 27, green: 158, blue: 119 },
+        Color { red: 217, green: 95, blue: 2 },
+        Color { red: 117, green: 112, blue: 179 },
+    ],
+    &[
+        Color { red: 27, green: 158, blue: 119 },
+        Color { red: 217, green: 95, blue: 2 },
+        Color { red: 117, green: 112, blue: 179 },
+        Color { red: 231, green: 41, blue: 138 },
+    ],
+    &[
+        Color { red: 27, green: 158, blue: 119 },
+        Color { red: 217, green: 95, blue: 2 },
+        Color { red: 117, green: 112, blue: 179 },
+        Color { red: 231, green: 41, blue: 138 },
+        Color { red: 102, green: 166, blue: 30 },
+    ],
+    &[
+        Color { red: 27, green: 158, blue: 119 },
+        Color { red: 217, green: 95, blue: 2 },
+        Color { red: 117, green: 112, blue: 179 },
+        Color { red: 231, green: 41, blue: 138 },
+        Color { red: 102, green: 166, blue: 30 },
+        Color { red: 230, green: 171, blue: 2 },
+    ],
+    &[
+        Color { red: 27, green: 158, blue: 119 },
+        Color { red: 217, green: 95, blue: 2 },
+        Color { red: 117, green: 112, blue: 179 },
+        Color { red: 231, green: 41, blue: 138 },
+        Color { red: 102, green: 166, blue: 30 },
+        Color { red: 230, green: 171, blue: 2 },
+        Color { red: 166, green: 118, blue: 29 },
+    ],
+    &[
+        Color { red: 27, green: 158, blue: 119 },
+        Color { red: 217, green: 95, blue: 2 },
+        Color { red: 117, green: 112, blue: 179 },
+        Color { red: 231, green: 41, blue: 138 },
+        Color { red: 102, green: 166, blue: 30 },
+        Color { red: 230, green: 171, blue: 2 },
+        Color { red: 166, green: 118, blue: 29 },
+        Color { red: 102, green: 102, blue: 102 },
+    ],
+];
+
+/// Light and dark pairs of colors, with 3 to 12 classes.
+const PAIRED: &[&[Color]] = &[
+    &[
+        Color { red: 166, green: 206, blue: 227 },
+        Color { red: 31, green: 120, blue: 180 },
+        Color { red: 178, green: 223, blue: 138 },
+    ],
+    &[
+        Color { red: 166, green: 206, blue: 227 },
+        Color { red: 31, green: 120, blue: 180 },
+        Color { red: 178, green: 223, blue: 138 },
+        Color { red: 51, green: 160, blue: 44 },
+    ],
+    &[
+        Color { red: 166, green: 206, blue: 227 },
+        Color { red: 31, green: 120, blue: 180 },
+        Color { red: 178, green: 223, blue: 138 },
+        Color { red: 51, green: 160, blue: 44 },
+        Color { red: 251, green: 154, blue: 153 },
+    ],
+    &[
+        Color { red: 166, green: 206, blue: 227 },
+        Color { red: 31, green: 120, blue: 180 },
+        Color { red: 178, green: 223, blue: 138 },
+        Color { red: 51, green: 160, blue: 44 },
+        Color { red: 251, green: 154, blue: 153 },
+        Color { red: 227, green: 26, blue: 28 },
+    ],
+    &[
+        Color { red: 166, green: 206, blue: 227 },
+        Color { red: 31, green: 120, blue: 180 },
+        Color { red: 178, green: 223, blue: 138 },
+        Color { red: 51, green: 160, blue: 44 },
+        Color { red: 251, green: 154, blue: 153 },
+        Color { red: 227, green: 26, blue: 28 },
+        Color { red: 253, green: 191, blue: 111 },
+    ],
+    &[
+        Color { red: 166, green: 206, blue: 227 },
+        Color { red: 31, green: 120, blue: 180 },
+        Color { red: 178, green: 223, blue: 138 },
+        Color { red: 51, green: 160, blue: 44 },
+        Color { red: 251, green: 154, blue: 153 },
+        Color { red: 227, green: 26, blue: 28 },
+        Color { red: 253, green: 191, blue: 111 },
+        Color { red: 255, green: 127, blue: 0 },
+    ],
+    &[
+        Color { red: 166, green: 206, blue: 227 },
+        Color { red: 31, green: 120, blue: 180 },
+        Color { red: 178, green: 223, blue: 138 },
+        Color { red: 51, green: 160, blue: 44 },
+        Color { red: 251, green: 154, blue: 153 },
+        Color { red: 227, green: 26, blue: 28 },
+        Color { red: 253, green: 191, blue: 111 },
+        Color { red: 255, green: 127, blue: 0 },
+        Color { red: 202, green: 178, blue: 214 },
+    ],
+    &[
+        Color { red: 166, green: 206, blue: 227 },
+        Color { red: 31, green: 120, blue: 180 },
+        Color { red: 178, green: 223, blue: 138 },
+        Color { red: 51, green: 160, blue: 44 },
+        Color { red: 251, green: 154, blue: 153 },
+        Color { red: 227, green: 26, blue: 28 },
+        Color { red: 253, green: 191, blue: 111 },
+        Color { red: 255, green: 127, blue: 0 },
+        Color { red: 202, green: 178, blue: 214 },
+        Color { red: 106, green: 61, blue: 154 },
+    ],
+    &[
+        Color { red: 166, green: 206, blue: 227 },
+        Color { red: 31, green: 120, blue: 180 },
+        Color { red: 178, green: 223, blue: 138 },
+        Color { red: 51, green: 160, blue: 44 },
+        Color { red: 251, green: 154, blue: 153 },
+        Color { red: 227, green: 26, blue: 28 },
+        Color { red: 253, green: 191, blue: 111 },
+        Color { red: 255, green: 127, blue: 0 },
+        Color { red: 202, green: 178, blue: 214 },
+        Color { red: 106, green: 61, blue: 154 },
+        Color { red: 255, green: 255, blue: 153 },
+    ],
+    &[
+        Color { red: 166, green: 206, blue: 227 },
+        Color { red: 31, green: 120, blue: 180 },
+        Color { red: 178, green: 223, blue: 138 },
+        Color { red: 51, green: 160, blue: 44 },
+        Color { red: 251, green: 154, blue: 153 },
+        Color { red: 227, green: 26, blue: 28 },
+        Color { red: 253, green: 191, blue: 111 },
+        Color { red: 255, green: 127, blue: 0 },
+        Color { red: 202, green: 178, blue: 214 },
+        Color { red: 106, green: 61, blue: 154 },
+        Color { red: 255, green: 255, blue: 153 },
+        Color { red: 177, green: 89, blue: 40 },
+    ],
+];
+
+/// Pastel colors, with 3 to 9 classes.
+const PASTEL1: &[&[Color]] = &[
+    &[
+        Color { red: 251, green: 180, blue: 174 },
+        Color { red: 179, green: 205, blue: 227 },
+        Color { red: 204, green: 235, blue: 197 },
+    ],
+    &[
+        Color { red: 251, green: 180, blue: 174 },
+        Color { red: 179, green: 205, blue: 227 },
+        Color { red: 204, green: 235, blue: 197 },
+        Color { red: 222, green: 203, blue: 228 },
+    ],
+    &[
+        Color { red: 251, green: 180, blue: 174 },
+        Color { red: 179, green: 205, blue: 227 },
+        Color { red: 204, green: 235, blue: 197 },
+        Color { red: 222, green: 203, blue: 228 },
+        Color { red: 254, green: 217, blue: 166 },
+    ],
+    &[
+        Color { red: 251, green: 180, blue: 174 },
+        Color { red: 179, green: 205, blue: 227 },
+        Color { red: 204, green: 235, blue: 197 },
+        Color { red: 222, green: 203, blue: 228 },
+        Color { red: 254, green: 217, blue: 166 },
+        Color { red: 255, green: 255, blue: 204 },
+    ],
+    &[
+        Color { red: 251, green: 180, blue: 174 },
+        Color { red: 179, green: 205, blue: 227 },
+        Color { red: 204, green: 235, blue: 197 },
+        Color { red: 222, green: 203, blue: 228 },
+        Color { red: 254, green: 217, blue: 166 },
+        Color { red: 255, green: 255, blue: 204 },
+        Color { red: 229, green: 216, blue: 189 },
+    ],
+    &[
+        Color { red: 251, green: 180, blue: 174 },
+        Color { red: 179, green: 205, blue: 227 },
+        Color { red: 204, green: 235, blue: 197 },
+        Color { red: 222, green: 203, blue: 228 },
+        Color { red: 254, green: 217, blue: 166 },
+        Color { red: 255, green: 255, blue: 204 },
+        Color { red: 229, green: 216, blue: 189 },
+        Color { red: 253, green: 218, blue: 236 },
+    ],
+    &[
+        Color { red: 251, green: 180, blue: 174 },
+        Color { red: 179, green: 205, blue: 227 },
+        Color { red: 204, green: 235, blue: 197 },
+        Color { red: 222, green: 203, blue: 228 },
+        Color { red: 254, green: 217, blue: 166 },
+        Color { red: 255, green: 255, blue: 204 },
+        Color { red: 229, green: 216, blue: 189 },
+        Color { red: 253, green: 218, blue: 236 },
+        Color { red: 242, green: 242, blue: 242 },
+    ],
+];
+
+/// Pastel colors, with 3 to 8 classes.
+const PASTEL2: &[&[Color]] = &[
+    &[
+        Color { red: 179, green: 226, blue: 205 },
+        Color { red: 253, green: 205, blue: 172 },
+        Color { red: 203, green: 213, blue: 232 },
+    ],
+    &[
+        Color { red: 179, green: 226, blue: 205 },
+        Color { red: 253, green: 205, blue: 172 },
+        Color { red: 203, green: 213, blue: 232 },
+        Color { red: 244, green: 202, blue: 228 },
+    ],
+    &[
+        Color { red: 179, green: 226, blue: 205 },
+        Color { red: 253, green: 205, blue: 172 },
+        Color { red: 203, green: 213, blue: 232 },
+        Color { red: 244, green: 202, blue: 228 },
+        Color { red: 230, green: 245, blue: 201 },
+    ],
+    &[
+        Color { red: 179, green: 226, blue: 205 },
+        Color { red: 253, green: 205, blue: 172 },
+        Color { red: 203, green: 213, blue: 232 },
+        Color { red: 244, green: 202, blue: 228 },
+        Color { red: 230, green: 245, blue: 201 },
+        Color { red: 255, green: 242, blue: 174 },
+    ],
+    &[
+        Color { red: 179, green: 226, blue: 205 },
+        Color { red: 253, green: 205, blue: 172 },
+        Color { red: 203, green: 213, blue: 232 },
+        Color { red: 244, green: 202, blue: 228 },
+        Color { red: 230, green: 245, blue: 201 },
+        Color { red: 255, green: 242, blue: 174 },
+        Color { red: 241, green: 226, blue: 204 },
+    ],
+    &[
+        Color { red: 179, green: 226, blue: 205 },
+        Color { red: 253, green: 205, blue: 172 },
+        Color { red: 203, green: 213, blue: 232 },
+        Color { red: 244, green: 202, blue: 228 },
+        Color { red: 230, green: 245, blue: 201 },
+        Color { red: 255, green: 242, blue: 174 },
+        Color { red: 241, green: 226, blue: 204 },
+        Color { red: 204, green: 204, blue: 204 },
+    ],
+];
+
+/// Bold colors, with 3 to 9 classes.
+const SET1: &[&[Color]] = &[
+    &[
+        Color { red: 228, green: 26, blue: 28 },
+        Color { red: 55, green: 126, blue: 184 },
+        Color { red: 77, green: 175, blue: 74 },
+    ],
+    &[
+        Color { red: 228, green: 26, blue: 28 },
+        Color { red: 55, green: 126, blue: 184 },
+        Color { red: 77, green: 175, blue: 74 },
+        Color { red: 152, green: 78, blue: 163 },
+    ],
+    &[
+        Color { red: 228, green: 26, blue: 28 },
+        Color { red: 55, green: 126, blue: 184 },
+        Color { red: 77, green: 175, blue: 74 },
+        Color { red: 152, green: 78, blue: 163 },
+        Color { red: 255, green: 127, blue: 0 },
+    ],
+    &[
+        Color { red: 228, green: 26, blue: 28 },
+        Color { red: 55, green: 126, blue: 184 },
+        Color { red: 77, green: 175, blue: 74 },
+        Color { red: 152, green: 78, blue: 163 },
+        Color { red: 255, green: 127, blue: 0 },
+        Color { red: 255, green: 255, blue: 51 },
+    ],
+    &[
+        Color { red: 228, green: 26, blue: 28 },
+        Color { red: 55, green: 126, blue: 184 },
+        Color { red: 77, green: 175, blue: 74 },
+        Color { red: 152, green: 78, blue: 163 },
+        Color { red: 255, green: 127, blue: 0 },
+        Color { red: 255, green: 255, blue: 51 },
+        Color { red: 166, green: 86, blue: 40 },
+    ],
+    &[
+        Color { red: 228, green: 26, blue: 28 },
+        Color { red: 55, green: 126, blue: 184 },
+        Color { red: 77, green: 175, blue: 74 },
+        Color { red: 152, green: 78, blue: 163 },
+        Color { red: 255, green: 127, blue: 0 },
+        Color { red: 255, green: 255, blue: 51 },
+        Color { red: 166, green: 86, blue: 40 },
+        Color { red: 247, green: 129, blue: 191 },
+    ],
+    &[
+        Color { red: 228, green: 26, blue: 28 },
+        Color { red: 55, green: 126, blue: 184 },
+        Color { red: 77, green: 175, blue: 74 },
+        Color { red: 152, green: 78, blue: 163 },
+        Color { red: 255, green: 127, blue: 0 },
+        Color { red: 255, green: 255, blue: 51 },
+        Color { red: 166, green: 86, blue: 40 },
+        Color { red: 247, green: 129, blue: 191 },
+        Color { red: 153, green: 153, blue: 153 },
+    ],
+];
+
+/// Muted colors, with 3 to 8 classes.
+const SET2: &[&[Color]] = &[
+    &[
+        Color { red: 102, green: 194, blue: 165 },
+        Color { red: 252, green: 141, blue: 98 },
+        Color { red: 141, green: 160, blue: 203 },
+    ],
+    &[
+        Color { red: 102, green: 194, blue: 165 },
+        Color { red: 252, green: 141, blue: 98 },
+        Color { red: 141, green: 160, blue: 203 },
+        Color { red: 231, green: 138, blue: 195 },
+    ],
+    &[
+        Color { red: 102, green: 194, blue: 165 },
+        Color { red: 252, green: 141, blue: 98 },
+        Color { red: 141, green: 160, blue: 203 },
+        Color { red: 231, green: 138, blue: 195 },
+        Color { red: 166, green: 216, blue: 84 },
+    ],
+    &[
+        Color { red: 102, green: 194, blue: 165 },
+        Color { red: 252, green: 141, blue: 98 },
+        Color { red: 141, green: 160, blue: 203 },
+        Color { red: 231, green: 138, blue: 195 },
+        Color { red: 166, green: 216, blue: 84 },
+        Color { red: 255, green: 217, blue: 47 },
+    ],
+    &[
+        Color { red: 102, green: 194, blue: 165 },
+        Color { red: 252, green: 141, blue: 98 },
+        Color { red: 141, green: 160, blue: 203 },
+        Color { red: 231, green: 138, blue: 195 },
+        Color { red: 166, green: 216, blue: 84 },
+        Color { red: 255, green: 217, blue: 47 },
+        Color { red: 229, green: 196, blue: 148 },
+    ],
+    &[
+        Color { red: 102, green: 194, blue: 165 },
+        Color { red: 252, green: 141, blue: 98 },
+        Color { red: 141, green: 160, blue: 203 },
+        Color { red: 231, green: 138, blue: 195 },
+        Color { red: 166, green: 216, blue: 84 },
+        Color { red: 255, green: 217, blue: 47 },
+        Color { red: 229, green: 196, blue: 148 },
+        Color { red: 179, green: 179, blue: 179 },
+    ],
+];
+
+/// Light colors, with 3 to 12 classes.
+const SET3: &[&[Color]] = &[
+    &[
+        Color { red: 141, green: 211, blue: 199 },
+        Color { red: 255, green: 255, blue: 179 },
+        Color { red: 190, green: 186, blue: 218 },
+    ],
+    &[
+        Color { red: 141, green: 211, blue: 199 },
+        Color { red: 255, green: 255, blue: 179 },
+        Color { red: 190, green: 186, blue: 218 },
+        Color { red: 251, green: 128, blue: 114 },
+    ],
+    &[
+        Color { red: 141, green: 211, blue: 199 },
+        Color { red: 255, green: 255, blue: 179 },
+        Color { red: 190, green: 186, blue: 218 },
+        Color { red: 251, green: 128, blue: 114 },
+        Color { red: 128, green: 177, blue: 211 },
+    ],
+    &[
+        Color { red: 141, green: 211, blue: 199 },
+        Color { red: 255, green: 255, blue: 179 },
+        Color { red: 190, green: 186, blue: 218 },
+        Color { red: 251, green: 128, blue: 114 },
+        Color { red: 128, green: 177, blue: 211 },
+        Color { red: 253, green: 180, blue: 98 },
+    ],
+    &[
+        Color { red: 141, green: 211, blue: 199 },
+        Color { red: 255, green: 255, blue: 179 },
+        Color { red: 190, green: 186, blue: 218 },
+        Color { red: 251, green: 128, blue: 114 },
+        Color { red: 128, green: 177, blue: 211 },
+        Color { red: 253, green: 180, blue: 98 },
+        Color { red: 179, green: 222, blue: 105 },
+    ],
+    &[
+        Color { red: 141, green: 211, blue: 199 },
+        Color { red: 255, green: 255, blue: 179 },
+        Color { red: 190, green: 186, blue: 218 },
+        Color { red: 251, green: 128, blue: 114 },
+        Color { red: 128, green: 177, blue: 211 },
+        Color { red: 253, green: 180, blue: 98 },
+        Color { red: 179, green: 222, blue: 105 },
+        Color { red: 252, green: 205, blue: 229 },
+    ],
+    &[
+        Color { red: 141, green: 211, blue: 199 },
+        Color { red: 255, green: 255, blue: 179 },
+        Color { red: 190, green: 186, blue: 218 },
+        Color { red: 251, green: 128, blue: 114 },
+        Color { red: 128, green: 177, blue: 211 },
+        Color { red: 253, green: 180, blue: 98 },
+        Color { red: 179, green: 222, blue: 105 },
+        Color { red: 252, green: 205, blue: 229 },
+        Color { red: 217, green: 217, blue: 217 },
+    ],
+    &[
+        Color { red: 141, green: 211, blue: 199 },
+        Color { red: 255, green: 255, blue: 179 },
+        Color { red: 190, green: 186, blue: 218 },
+        Color { red: 251, green: 128, blue: 114 },
+        Color { red: 128, green: 177, blue: 211 },
+        Color { red: 253, green: 180, blue: 98 },
+        Color { red: 179, green: 222, blue: 105 },
+        Color { red: 252, green: 205, blue: 229 },
+        Color { red: 217, green: 217, blue: 217 },
+        Color { red: 188, green: 128, blue: 189 },
+    ],
+    &[
+        Color { red: 141, green: 211, blue: 199 },
+        Color { red: 255, green: 255, blue: 179 },
+        Color { red: 190, green: 186, blue: 218 },
+        Color { red: 251, green: 128, blue: 114 },
+        Color { red: 128, green: 177, blue: 211 },
+        Color { red: 253, green: 180, blue: 98 },
+        Color { red: 179, green: 222, blue: 105 },
+        Color { red: 252, green: 205, blue: 229 },
+        Color { red: 217, green: 217, blue: 217 },
+        Color { red: 188, green: 128, blue: 189 },
+        Color { red: 204, green: 235, blue: 197 },
+    ],
+    &[
+        Color { red: 141, green: 211, blue: 199 },
+        Color { red: 255, green: 255, blue: 179 },
+        Color { red: 190, green: 186, blue: 218 },
+        Color { red: 251, green: 128, blue: 114 },
+        Color { red: 128, green: 177, blue: 211 },
+        Color { red: 253, green: 180, blue: 98 },
+        Color { red: 179, green: 222, blue: 105 },
+        Color { red: 252, green: 205, blue: 229 },
+        Color { red: 217, green: 217, blue: 217 },
+        Color { red: 188, green: 128, blue: 189 },
+        Color { red: 204, green: 235, blue: 197 },
+        Color { red: 255, green: 237, blue: 111 },
+    ],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn has_colors_for_each_number_of_classes() {
+        for scheme in &SCHEMES {
+            for classes in Scheme::MIN_CLASSES..=scheme.max_classes() {
+                assert_eq!(scheme.colors(classes).map(<[Color]>::len), Some(classes));
+            }
+            
+            assert_eq!(scheme.colors(2), None);
+            assert_eq!(scheme.colors(scheme.max_classes() + 1), None);
+        }
+        
+        assert_eq!(scheme(" set3 ").map(Scheme::max_classes), Some(12));
+        assert_eq!(scheme("Set3").map(Scheme::kind), Some(SchemeKind::Qualitative));
+        assert_eq!(palette("Magma", 3), None);
+    }
+}
\ No newline at end of file
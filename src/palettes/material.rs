@@ -0,0 +1,748 @@
+//! The 2014 Material Design color palette.
+//! 
+//! Each hue has 10 shades from 50 (lightest) to 900 (darkest), available as constants such as 
+//! [`RED_500`] and as arrays such as [`RED`]. Every hue except brown, grey, and blue grey also has 
+//! 4 accent shades, A100 to A700, such as [`RED_A200`].
+//! 
+//! # Examples
+//! ```
+//! use nice_colors::Color;
+//! use nice_colors::palettes::material;
+//! 
+//! assert_eq!(material::INDIGO_500, Color::from_decimal(0x3F51B5));
+//! assert_eq!(material::palette("Deep Purple").unwrap().colors(), &material::DEEP_PURPLE);
+//! assert_eq!(material::accent_palette("grey"), None);
+//! ```
+
+use crate::{Color, Palette};
+
+/// Red 50.
+pub const RED_50: Color = Color { red: 255, green: 235, blue: 238 };
+/// Red 100.
+pub const RED_100: Color = Color { red: 255, green: 205, blue: 210 };
+/// Red 200.
+pub const RED_200: Color = Color { red: 239, green: 154, blue: 154 };
+/// Red 300.
+pub const RED_300: Color = Color { red: 229, green: 115, blue: 115 };
+/// Red 400.
+pub const RED_400: Color = Color { red: 239, green: 83, blue: 80 };
+/// Red 500.
+pub const RED_500: Color = Color { red: 244, green: 67, blue: 54 };
+/// Red 600.
+pub const RED_600: Color = Color { red: 229, green: 57, blue: 53 };
+/// Red 700.
+pub const RED_700: Color = Color { red: 211, green: 47, blue: 47 };
+/// Red 800.
+pub const RED_800: Color = Color { red: 198, green: 40, blue: 40 };
+/// Red 900.
+pub const RED_900: Color = Color { red: 183, green: 28, blue: 28 };
+/// Red A100.
+pub const RED_A100: Color = Color { red: 255, green: 138, blue: 128 };
+/// Red A200.
+pub const RED_A200: Color = Color { red: 255, green: 82, blue: 82 };
+/// Red A400.
+pub const RED_A400: Color = Color { red: 255, green: 23, blue: 68 };
+/// Red A700.
+pub const RED_A700: Color = Color { red: 213, green: 0, blue: 0 };
+
+/// Pink 50.
+pub const PINK_50: Color = Color { red: 252, green: 228, blue: 236 };
+/// Pink 100.
+pub const PINK_100: Color = Color { red: 248, green: 187, blue: 208 };
+/// Pink 200.
+pub const PINK_200: Color = Color { red: 244, green: 143, blue: 177 };
+/// Pink 300.
+pub const PINK_300: Color = Color { red: 240, green: 98, blue: 146 };
+/// Pink 400.
+pub const PINK_400: Color = Color { red: 236, green: 64, blue: 122 };
+/// Pink 500.
+pub const PINK_500: Color = Color { red: 233, green: 30, blue: 99 };
+/// Pink 600.
+pub const PINK_600: Color = Color { red: 216, green: 27, blue: 96 };
+/// Pink 700.
+pub const PINK_700: Color = Color { red: 194, green: 24, blue: 91 };
+/// Pink 800.
+pub const PINK_800: Color = Color { red: 173, green: 20, blue: 87 };
+/// Pink 900.
+pub const PINK_900: Color = Color { red: 136, green: 14, blue: 79 };
+/// Pink A100.
+pub const PINK_A100: Color = Color { red: 255, green: 128, blue: 171 };
+/// Pink A200.
+pub const PINK_A200: Color = Color { red: 255, green: 64, blue: 129 };
+/// Pink A400.
+pub const PINK_A400: Color = Color { red: 245, green: 0, blue: 87 };
+/// Pink A700.
+pub const PINK_A700: Color = Color { red: 197, green: 17, blue: 98 };
+
+/// Purple 50.
+pub const PURPLE_50: Color = Color { red: 243, green: 229, blue: 245 };
+/// Purple 100.
+pub const PURPLE_100: Color = Color { red: 225, green: 190, blue: 231 };
+/// Purple 200.
+pub const PURPLE_200: Color = Color { red: 206, green: 147, blue: 216 };
+/// Purple 300.
+pub const PURPLE_300: Color = Color { red: 186, green: 104, blue: 200 };
+/// Purple 400.
+pub const PURPLE_400: Color = Color { red: 171, green: 71, blue: 188 };
+/// Purple 500.
+pub const PURPLE_500: Color = Color { red: 156, green: 39, blue: 176 };
+/// Purple 600.
+pub const PURPLE_600: Color = Color { red: 142, green: 36, blue: 170 };
+/// Purple 700.
+pub const PURPLE_700: Color = Color { red: 123, green: 31, blue: 162 };
+/// Purple 800.
+pub const PURPLE_800: Color = Color { red: 106, green: 27, blue: 154 };
+/// Purple 900.
+pub const PURPLE_900: Color = Color { red: 74, green: 20, blue: 140 };
+/// Purple A100.
+pub const PURPLE_A100: Color = Color { red: 234, green: 128, blue: 252 };
+/// Purple A200.
+pub const PURPLE_A200: Color = Color { red: 224, green: 64, blue: 251 };
+/// Purple A400.
+pub const PURPLE_A400: Color = Color { red: 213, green: 0, blue: 249 };
+/// Purple A700.
+pub const PURPLE_A700: Color = Color { red: 170, green: 0, blue: 255 };
+
+/// Deep Purple 50.
+pub const DEEP_PURPLE_50: Color = Color { red: 237, green: 231, blue: 246 };
+/// Deep Purple 100.
+pub const DEEP_PURPLE_100: Color = Color { red: 209, green: 196, blue: 233 };
+/// Deep Purple 200.
+pub const DEEP_PURPLE_200: Color = Color { red: 179, green: 157, blue: 219 };
+/// Deep Purple 300.
+pub const DEEP_PURPLE_300: Color = Color { red: 149, green: 117, blue: 205 };
+/// Deep Purple 400.
+pub const DEEP_PURPLE_400: Color = Color { red: 126, green: 87, blue: 194 };
+/// Deep Purple 500.
+pub const DEEP_PURPLE_500: Color = Color { red: 103, green: 58, blue: 183 };
+/// Deep Purple 600.
+pub const DEEP_PURPLE_600: Color = Color { red: 94, green: 53, blue: 177 };
+/// Deep Purple 700.
+pub const DEEP_PURPLE_700: Color = Color { red: 81, green: 45, blue: 168 };
+/// Deep Purple 800.
+pub const DEEP_PURPLE_800: Color = Color { red: 69, green: 39, blue: 160 };
+/// Deep Purple 900.
+pub const DEEP_PURPLE_900: Color = Color { red: 49, green: 27, blue: 146 };
+/// Deep Purple A100.
+pub const DEEP_PURPLE_A100: Color = Color { red: 179, green: 136, blue: 255 };
+/// Deep Purple A200.
+pub const DEEP_PURPLE_A200: Color = Color { red: 124, green: 77, blue: 255 };
+/// Deep Purple A400.
+pub const DEEP_PURPLE_A400: Color = Color { red: 101, green: 31, blue: 255 };
+/// Deep Purple A700.
+pub const DEEP_PURPLE_A700: Color = Color { red: 98, green: 0, blue: 234 };
+
+/// Indigo 50.
+pub const INDIGO_50: Color = Color { red: 232, green: 234, blue: 246 };
+/// Indigo 100.
+pub const INDIGO_100: Color = Color { red: 197, green: 202, blue: 233 };
+/// Indigo 200.
+pub const INDIGO_200: Color = Color { red: 159, green: 168, blue: 218 };
+/// Indigo 300.
+pub const INDIGO_300: Color = Color { red: 121, green: 134, blue: 203 };
+/// Indigo 400.
+pub const INDIGO_400: Color = Color { red: 92, green: 107, blue: 192 };
+/// Indigo 500.
+pub const INDIGO_500: Color = Color { red: 63, green: 81, blue: 181 };
+/// Indigo 600.
+pub const INDIGO_600: Color = Color { red: 57, green: 73, blue: 171 };
+/// Indigo 700.
+pub const INDIGO_700: Color = Color { red: 48, green: 63, blue: 159 };
+/// Indigo 800.
+pub const INDIGO_800: Color = Color { red: 40, green: 53, blue: 147 };
+/// Indigo 900.
+pub const INDIGO_900: Color = Color { red: 26, green: 35, blue: 126 };
+/// Indigo A100.
+pub const INDIGO_A100: Color = Color { red: 140, green: 158, blue: 255 };
+/// Indigo A200.
+pub const INDIGO_A200: Color = Color { red: 83, green: 109, blue: 254 };
+/// Indigo A400.
+pub const INDIGO_A400: Color = Color { red: 61, green: 90, blue: 254 };
+/// Indigo A700.
+pub const INDIGO_A700: Color = Color { red: 48, green: 79, blue: 254 };
+
+/// Blue 50.
+pub const BLUE_50: Color = Color { red: 227, green: 242, blue: 253 };
+/// Blue 100.
+pub const BLUE_100: Color = Color { red: 187, green: 222, blue: 251 };
+/// Blue 200.
+pub const BLUE_200: Color = Color { red: 144, green: 202, blue: 249 };
+/// Blue 300.
+pub const BLUE_300: Color = Color { red: 100, green: 181, blue: 246 };
+/// Blue 400.
+pub const BLUE_400: Color = Color { red: 66, green: 165, blue: 245 };
+/// Blue 500.
+pub const BLUE_500: Color = Color { red: 33, green: 150, blue: 243 };
+/// Blue 600.
+pub const BLUE_600: Color = Color { red: 30, green: 136, blue: 229 };
+/// Blue 700.
+pub const BLUE_700: Color = Color { red: 25, green: 118, blue: 210 };
+/// Blue 800.
+pub const BLUE_800: Color = Color { red: 21, green: 101, blue: 192 };
+/// Blue 900.
+pub const BLUE_900: Color = Color { red: 13, green: 71, blue: 161 };
+/// Blue A100.
+pub const BLUE_A100: Color = Color { red: 130, green: 177, blue: 255 };
+/// Blue A200.
+pub const BLUE_A200: Color = Color { red: 68, green: 138, blue: 255 };
+/// Blue A400.
+pub const BLUE_A400: Color = Color { red: 41, green: 121, blue: 255 };
+/// Blue A700.
+pub const BLUE_A700: Color = Color { red: 41, green: 98, blue: 255 };
+
+/// Light Blue 50.
+pub const LIGHT_BLUE_50: Color = Color { red: 225, green: 245, blue: 254 };
+/// Light Blue 100.
+pub const LIGHT_BLUE_100: Color = Color { red: 179, green: 229, blue: 252 };
+/// Light Blue 200.
+pub const LIGHT_BLUE_200: Color = Color { red: 129, green: 212, blue: 250 };
+/// Light Blue 300.
+pub const LIGHT_BLUE_300: Color = Color { red: 79, green: 195, blue: 247 };
+/// Light Blue 400.
+pub const LIGHT_BLUE_400: Color = Color { red: 41, green: 182, blue: 246 };
+/// Light Blue 500.
+pub const LIGHT_BLUE_500: Color = Color { red: 3, green: 169, blue: 244 };
+/// Light Blue 600.
+pub const LIGHT_BLUE_600: Color = Color { red: 3, green: 155, blue: 229 };
+/// Light Blue 700.
+pub const LIGHT_BLUE_700: Color = Color { red: 2, green: 136, blue: 209 };
+/// Light Blue 800.
+pub const LIGHT_BLUE_800: Color = Color { red: 2, green: 119, blue: 189 };
+/// Light Blue 900.
+pub const LIGHT_BLUE_900: Color = Color { red: 1, green: 87, blue: 155 };
+/// Light Blue A100.
+pub const LIGHT_BLUE_A100: Color = Color { red: 128, green: 216, blue: 255 };
+/// Light Blue A200.
+pub const LIGHT_BLUE_A200: Color = Color { red: 64, green: 196, blue: 255 };
+/// Light Blue A400.
+pub const LIGHT_BLUE_A400: Color = Color { red: 0, green: 176, blue: 255 };
+/// Light Blue A700.
+pub const LIGHT_BLUE_A700: Color = Color { red: 0, green: 145, blue: 234 };
+
+/// Cyan 50.
+pub const CYAN_50: Color = Color { red: 224, green: 247, blue: 250 };
+/// Cyan 100.
+pub const CYAN_100: Color = Color { red: 178, green: 235, blue: 242 };
+/// Cyan 200.
+pub const CYAN_200: Color = Color { red: 128, green: 222, blue: 234 };
+/// Cyan 300.
+pub const CYAN_300: Color = Color { red: 77, green: 208, blue: 225 };
+/// Cyan 400.
+pub const CYAN_400: Color = Color { red: 38, green: 198, blue: 218 };
+/// Cyan 500.
+pub const CYAN_500: Color = Color { red: 0, green: 188, blue: 212 };
+/// Cyan 600.
+pub const CYAN_600: Color = Color { red: 0, green: 172, blue: 193 };
+/// Cyan 700.
+pub const CYAN_700: Color = Color { red: 0, green: 151, blue: 167 };
+/// Cyan 800.
+pub const CYAN_800: Color = Color { red: 0, green: 131, blue: 143 };
+/// Cyan 900.
+pub const CYAN_900: Color = Color { red: 0, green: 96, blue: 100 };
+/// Cyan A100.
+pub const CYAN_A100: Color = Color { red: 132, green: 255, blue: 255 };
+/// Cyan A200.
+pub const CYAN_A200: Color = Color { red: 24, green: 255, blue: 255 };
+/// Cyan A400.
+pub const CYAN_A400: Color = Color { red: 0, green: 229, blue: 255 };
+/// Cyan A700.
+pub const CYAN_A700: Color = Color { red: 0, green: 184, blue: 212 };
+
+/// Teal 50.
+pub const TEAL_50: Color = Color { red: 224, green: 242, blue: 241 };
+/// Teal 100.
+pub const TEAL_100: Color = Color { red: 178, green: 223, blue: 219 };
+/// Teal 200.
+pub const TEAL_200: Color = Color { red: 128, green: 203, blue: 196 };
+/// Teal 300.
+pub const TEAL_300: Color = Color { red: 77, green: 182, blue: 172 };
+/// Teal 400.
+pub const TEAL_400: Color = Color { red: 38, green: 166, blue: 154 };
+/// Teal 500.
+pub const TEAL_500: Color = Color { red: 0, green: 150, blue: 136 };
+/// Teal 600.
+pub const TEAL_600: Color = Color { red: 0, green: 137, blue: 123 };
+/// Teal 700.
+pub const TEAL_700: Color = Color { red: 0, green: 121, blue: 107 };
+/// Teal 800.
+pub const TEAL_800: Color = Color { red: 0, green: 105, blue: 92 };
+/// Teal 900.
+pub const TEAL_900: Color = Color { red: 0, green: 77, blue: 64 };
+/// Teal A100.
+pub const TEAL_A100: Color = Color { red: 167, green: 255, blue: 235 };
+/// Teal A200.
+pub const TEAL_A200: Color = Color { red: 100, green: 255, blue: 218 };
+/// Teal A400.
+pub const TEAL_A400: Color = Color { red: 29, green: 233, blue: 182 };
+/// Teal A700.
+pub const TEAL_A700: Color = Color { red: 0, green: 191, blue: 165 };
+
+/// Green 50.
+pub const GREEN_50: Color = Color { red: 232, green: 245, blue: 233 };
+/// Green 100.
+pub const GREEN_100: Color = Color { red: 200, green: 230, blue: 201 };
+/// Green 200.
+pub const GREEN_200: Color = Color { red: 165, green: 214, blue: 167 };
+/// Green 300.
+pub const GREEN_300: Color = Color { red: 129, green: 199, blue: 132 };
+/// Green 400.
+pub const GREEN_400: Color = Color { red: 102, green: 187, blue: 106 };
+/// Green 500.
+pub const GREEN_500: Color = Color { red: 76, green: 175, blue: 80 };
+/// Green 600.
+pub const GREEN_600: Color = Color { red: 67, green: 160, blue: 71 };
+/// Green 700.
+pub const GREEN_700: Color = Color { red: 56, green: 142, blue: 60 };
+/// Green 800.
+pub const GREEN_800: Color = Color { red: 46, green: 125, blue: 50 };
+/// Green 900.
+pub const GREEN_900: Color = Color { red: 27, green: 94, blue: 32 };
+/// Green A100.
+pub const GREEN_A100: Color = Color { red: 185, green: 246, blue: 202 };
+/// Green A200.
+pub const GREEN_A200: Color = Color { red: 105, green: 240, blue: 174 };
+/// Green A400.
+pub const GREEN_A400: Color = Color { red: 0, green: 230, blue: 118 };
+/// Green A700.
+pub const GREEN_A700: Color = Color { red: 0, green: 200, blue: 83 };
+
+/// Light Green 50.
+pub const LIGHT_GREEN_50: Color = Color { red: 241, green: 248, blue: 233 };
+/// Light Green 100.
+pub const LIGHT_GREEN_100: Color = Color { red: 220, green: 237, blue: 200 };
+/// Light Green 200.
+pub const LIGHT_GREEN_200: Color = Color { red: 197, green: 225, blue: 165 };
+/// Light Green 300.
+pub const LIGHT_GREEN_300: Color = Color { red: 174, green: 213, blue: 129 };
+/// Light Green 400.
+pub const LIGHT_GREEN_400: Color = Color { red: 156, green: 204, blue: 101 };
+/// Light Green 500.
+pub const LIGHT_GREEN_500: Color = Color { red: 139, green: 195, blue: 74 };
+/// Light Green 600.
+pub const LIGHT_GREEN_600: Color = Color { red: 124, green: 179, blue: 66 };
+/// Light Green 700.
+pub const LIGHT_GREEN_700: Color = Color { red: 104, green: 159, blue: 56 };
+/// Light Green 800.
+pub const LIGHT_GREEN_800: Color = Color { red: 85, green: 139, blue: 47 };
+/// Light Green 900.
+pub const LIGHT_GREEN_900: Color = Color { red: 51, green: 105, blue: 30 };
+/// Light Green A100.
+pub const LIGHT_GREEN_A100: Color = Color { red: 204, green: 255, blue: 144 };
+/// Light Green A200.
+pub const LIGHT_GREEN_A200: Color = Color { red: 178, green: 255, blue: 89 };
+/// Light Green A400.
+pub const LIGHT_GREEN_A400: Color = Color { red: 118, green: 255, blue: 3 };
+/// Light Green A700.
+pub const LIGHT_GREEN_A700: Color = Color { red: 100, green: 221, blue: 23 };
+
+/// Lime 50.
+pub const LIME_50: Color = Color { red: 249, green: 251, blue: 231 };
+/// Lime 100.
+pub const LIME_100: Color = Color { red: 240, green: 244, blue: 195 };
+/// Lime 200.
+pub const LIME_200: Color = Color { red: 230, green: 238, blue: 156 };
+/// Lime 300.
+pub const LIME_300: Color = Color { red: 220, green: 231, blue: 117 };
+/// Lime 400.
+pub const LIME_400: Color = Color { red: 212, green: 225, blue: 87 };
+/// Lime 500.
+pub const LIME_500: Color = Color { red: 205, green: 220, blue: 57 };
+/// Lime 600.
+pub const LIME_600: Color = Color { red: 192, green: 202, blue: 51 };
+/// Lime 700.
+pub const LIME_700: Color = Color { red: 175, green: 180, blue: 43 };
+/// Lime 800.
+pub const LIME_800: Color = Color { red: 158, green: 157, blue: 36 };
+/// Lime 900.
+pub const LIME_900: Color = Color { red: 130, green: 119, blue: 23 };
+/// Lime A100.
+pub const LIME_A100: Color = Color { red: 244, green: 255, blue: 129 };
+/// Lime A200.
+pub const LIME_A200: Color = Color { red: 238, green: 255, blue: 65 };
+/// Lime A400.
+pub const LIME_A400: Color = Color { red: 198, green: 255, blue: 0 };
+/// Lime A700.
+pub const LIME_A700: Color = Color { red: 174, green: 234, blue: 0 };
+
+/// Yellow 50.
+pub const YELLOW_50: Color = Color { red: 255, green: 253, blue: 231 };
+/// Yellow 100.
+pub const YELLOW_100: Color = Color { red: 255, green: 249, blue: 196 };
+/// Yellow 200.
+pub const YELLOW_200: Color = Color { red: 255, green: 245, blue: 157 };
+/// Yellow 300.
+pub const YELLOW_300: Color = Color { red: 255, green: 241, blue: 118 };
+/// Yellow 400.
+pub const YELLOW_400: Color = Color { red: 255, green: 238, blue: 88 };
+/// Yellow 500.
+pub const YELLOW_500: Color = Color { red: 255, green: 235, blue: 59 };
+/// Yellow 600.
+pub const YELLOW_600: Color = Color { red: 253, green: 216, blue: 53 };
+/// Yellow 700.
+pub const YELLOW_700: Color = Color { red: 251, green: 192, blue: 45 };
+/// Yellow 800.
+pub const YELLOW_800: Color = Color { red: 249, green: 168, blue: 37 };
+/// Yellow 900.
+pub const YELLOW_900: Color = Color { red: 245, green: 127, blue: 23 };
+/// Yellow A100.
+pub const YELLOW_A100: Color = Color { red: 255, green: 255, blue: 141 };
+/// Yellow A200.
+pub const YELLOW_A200: Color = Color { red: 255, green: 255, blue: 0 };
+/// Yellow A400.
+pub const YELLOW_A400: Color = Color { red: 255, green: 234, blue: 0 };
+/// Yellow A700.
+pub const YELLOW_A700: Color = Color { red: 255, green: 214, blue: 0 };
+
+/// Amber 50.
+pub const AMBER_50: Color = Color { red: 255, green: 248, blue: 225 };
+/// Amber 100.
+pub const AMBER_100: Color = Color { red: 255, green: 236, blue: 179 };
+/// Amber 200.
+pub const AMBER_200: Color = Color { red: 255, green: 224, blue: 130 };
+/// Amber 300.
+pub const AMBER_300: Color = Color { red: 255, green: 213, blue: 79 };
+/// Amber 400.
+pub const AMBER_400: Color = Color { red: 255, green: 202, blue: 40 };
+/// Amber 500.
+pub const AMBER_500: Color = Color { red: 255, green: 193, blue: 7 };
+/// Amber 600.
+pub const AMBER_600: Color = Color { red: 255, green: 179, blue: 0 };
+/// Amber 700.
+pub const AMBER_700: Color = Color { red: 255, green: 160, blue: 0 };
+/// Amber 800.
+pub const AMBER_800: Color = Color { red: 255, green: 143, blue: 0 };
+/// Amber 900.
+pub const AMBER_900: Color = Color { red: 255, green: 111, blue: 0 };
+/// Amber A100.
+pub const AMBER_A100: Color = Color { red: 255, green: 229, blue: 127 };
+/// Amber A200.
+pub const AMBER_A200: Color = Color { red: 255, green: 215, blue: 64 };
+/// Amber A400.
+pub const AMBER_A400: Color = Color { red: 255, green: 196, blue: 0 };
+/// Amber A700.
+pub const AMBER_A700: Color = Color { red: 255, green: 171, blue: 0 };
+
+/// Orange 50.
+pub const ORANGE_50: Color = Color { red: 255, green: 243, blue: 224 };
+/// Orange 100.
+pub const ORANGE_100: Color = Color { red: 255, green: 224, blue: 178 };
+/// Orange 200.
+pub const ORANGE_200: Color = Color { red: 255, green: 204, blue: 128 };
+/// Orange 300.
+pub const ORANGE_300: Color = Color { red: 255, green: 183, blue: 77 };
+/// Orange 400.
+pub const ORANGE_400: Color = Color { red: 255, green: 167, blue: 38 };
+/// Orange 500.
+pub const ORANGE_500: Color = Color { red: 255, green: 152, blue: 0 };
+/// Orange 600.
+pub const ORANGE_600: Color = Color { red: 251, green: 140, blue: 0 };
+/// Orange 700.
+pub const ORANGE_700: Color = Color { red: 245, green: 124, blue: 0 };
+/// Orange 800.
+pub const ORANGE_800: Color = Color { red: 239, green: 108, blue: 0 };
+/// Orange 900.
+pub const ORANGE_900: Color = Color { red: 230, green: 81, blue: 0 };
+/// Orange A100.
+pub const ORANGE_A100: Color = Color { red: 255, green: 209, blue: 128 };
+/// Orange A200.
+pub const ORANGE_A200: Color = Color { red: 255, green: 171, blue: 64 };
+/// Orange A400.
+pub const ORANGE_A400: Color = Color { red: 255, green: 145, blue: 0 };
+/// Orange A700.
+pub const ORANGE_A700: Color = Color { red: 255, green: 109, blue: 0 };
+
+/// Deep Orange 50.
+pub const DEEP_ORANGE_50: Color = Color { red: 251, green: 233, blue: 231 };
+/// Deep Orange 100.
+pub const DEEP_ORANGE_100: Color = Color { red: 255, green: 204, blue: 188 };
+/// Deep Orange 200.
+pub const DEEP_ORANGE_200: Color = Color { red: 255, green: 171, blue: 145 };
+/// Deep Orange 300.
+pub const DEEP_ORANGE_300: Color = Color { red: 255, green: 138, blue: 101 };
+/// Deep Orange 400.
+pub const DEEP_ORANGE_400: Color = Color { red: 255, green: 112, blue: 67 };
+/// Deep Orange 500.
+pub const DEEP_ORANGE_500: Color = Color { red: 255, green: 87, blue: 34 };
+/// Deep Orange 600.
+pub const DEEP_ORANGE_600: Color = Color { red: 244, green: 81, blue: 30 };
+/// Deep Orange 700.
+pub const DEEP_ORANGE_700: Color = Color { red: 230, green: 74, blue: 25 };
+/// Deep Orange 800.
+pub const DEEP_ORANGE_800: Color = Color { red: 216, green: 67, blue: 21 };
+/// Deep Orange 900.
+pub const DEEP_ORANGE_900: Color = Color { red: 191, green: 54, blue: 12 };
+/// Deep Orange A100.
+pub const DEEP_ORANGE_A100: Color = Color { red: 255, green: 158, blue: 128 };
+/// Deep Orange A200.
+pub const DEEP_ORANGE_A200: Color = Color { red: 255, green: 110, blue: 64 };
+/// Deep Orange A400.
+pub const DEEP_ORANGE_A400: Color = Color { red: 255, green: 61, blue: 0 };
+/// Deep Orange A700.
+pub const DEEP_ORANGE_A700: Color = Color { red: 221, green: 44, blue: 0 };
+
+/// Brown 50.
+pub const BROWN_50: Color = Color { red: 239, green: 235, blue: 233 };
+/// Brown 100.
+pub const BROWN_100: Color = Color { red: 215, green: 204, blue: 200 };
+/// Brown 200.
+pub const BROWN_200: Color = Color { red: 188, green: 170, blue: 164 };
+/// Brown 300.
+pub const BROWN_300: Color = Color { red: 161, green: 136, blue: 127 };
+/// Brown 400.
+pub const BROWN_400: Color = Color { red: 141, green: 110, blue: 99 };
+/// Brown 500.
+pub const BROWN_500: Color = Color { red: 121, green: 85, blue: 72 };
+/// Brown 600.
+pub const BROWN_600: Color = Color { red: 109, green: 76, blue: 65 };
+/// Brown 700.
+pub const BROWN_700: Color = Color { red: 93, green: 64, blue: 55 };
+/// Brown 800.
+pub const BROWN_800: Color = Color { red: 78, green: 52, blue: 46 };
+/// Brown 900.
+pub const BROWN_900: Color = Color { red: 62, green: 39, blue: 35 };
+
+/// Grey 50.
+pub const GREY_50: Color = Color { red: 250, green: 250, blue: 250 };
+/// Grey 100.
+pub const GREY_100: Color = Color { red: 245, green: 245, blue: 245 };
+/// Grey 200.
+pub const GREY_200: Color = Color { red: 238, green: 238, blue: 238 };
+/// Grey 300.
+pub const GREY_300: Color = Color { red: 224, green: 224, blue: 224 };
+/// Grey 400.
+pub const GREY_400: Color = Color { red: 189, green: 189, blue: 189 };
+/// Grey 500.
+pub const GREY_500: Color = Color { red: 158, green: 158, blue: 158 };
+/// Grey 600.
+pub const GREY_600: Color = Color { red: 117, green: 117, blue: 117 };
+/// Grey 700.
+pub const GREY_700: Color = Color { red: 97, green: 97, blue: 97 };
+/// Grey 800.
+pub const GREY_800: Color = Color { red: 66, green: 66, blue: 66 };
+/// Grey 900.
+pub const GREY_900: Color = Color { red: 33, green: 33, blue: 33 };
+
+/// Blue Grey 50.
+pub const BLUE_GREY_50: Color = Color { red: 236, green: 239, blue: 241 };
+/// Blue Grey 100.
+pub const BLUE_GREY_100: Color = Color { red: 207, green: 216, blue: 220 };
+/// Blue Grey 200.
+pub const BLUE_GREY_200: Color = Color { red: 176, green: 190, blue: 197 };
+/// Blue Grey 300.
+pub const BLUE_GREY_300: Color = Color { red: 144, green: 164, blue: 174 };
+/// Blue Grey 400.
+pub const BLUE_GREY_400: Color = Color { red: 120, green: 144, blue: 156 };
+/// Blue Grey 500.
+pub const BLUE_GREY_500: Color = Color { red: 96, green: 125, blue: 139 };
+/// Blue Grey 600.
+pub const BLUE_GREY_600: Color = Color { red: 84, green: 110, blue: 122 };
+/// Blue Grey 700.
+pub const BLUE_GREY_700: Color = Color { red: 69, green: 90, blue: 100 };
+/// Blue Grey 800.
+pub const BLUE_GREY_800: Color = Color { red: 55, green: 71, blue: 79 };
+/// Blue Grey 900.
+pub const BLUE_GREY_900: Color = Color { red: 38, green: 50, blue: 56 };
+
+/// The red shades from 50 to 900.
+pub const RED: [Color; 10] = [
+    RED_50, RED_100, RED_200, RED_300, RED_400, RED_500, RED_600, RED_700, RED_800, RED_900,
+];
+/// The red accent shades from A100 to A700.
+pub const RED_ACCENTS: [Color; 4] = [RED_A100, RED_A200, RED_A400, RED_A700];
+/// The pink shades from 50 to 900.
+pub const PINK: [Color; 10] = [
+    PINK_50, PINK_100, PINK_200, PINK_300, PINK_400, PINK_500, PINK_600, PINK_700, PINK_800,
+    PINK_900,
+];
+/// The pink accent shades from A100 to A700.
+pub const PINK_ACCENTS: [Color; 4] = [PINK_A100, PINK_A200, PINK_A400, PINK_A700];
+/// The purple shades from 50 to 900.
+pub const PURPLE: [Color; 10] = [
+    PURPLE_50, PURPLE_100, PURPLE_200, PURPLE_300, PURPLE_400, PURPLE_500, PURPLE_600, PURPLE_700,
+    PURPLE_800, PURPLE_900,
+];
+/// The purple accent shades from A100 to A700.
+pub const PURPLE_ACCENTS: [Color; 4] = [PURPLE_A100, PURPLE_A200, PURPLE_A400, PURPLE_A700];
+/// The deep purple shades from 50 to 900.
+pub const DEEP_PURPLE: [Color; 10] = [
+    DEEP_PURPLE_50, DEEP_PURPLE_100, DEEP_PURPLE_200, DEEP_PURPLE_300, DEEP_PURPLE_400,
+    DEEP_PURPLE_500, DEEP_PURPLE_600, DEEP_PURPLE_700, DEEP_PURPLE_800, DEEP_PURPLE_900,
+];
+/// The deep purple accent shades from A100 to A700.
+pub const DEEP_PURPLE_ACCENTS: [Color; 4] = [
+    DEEP_PURPLE_A100, DEEP_PURPLE_A200, DEEP_PURPLE_A400, DEEP_PURPLE_A700,
+];
+/// The indigo shades from 50 to 900.
+pub const INDIGO: [Color; 10] = [
+    INDIGO_50, INDIGO_100, INDIGO_200, INDIGO_300, INDIGO_400, INDIGO_500, INDIGO_600, INDIGO_700,
+    INDIGO_800, INDIGO_900,
+];
+/// The indigo accent shades from A100 to A700.
+pub const INDIGO_ACCENTS: [Color; 4] = [INDIGO_A100, INDIGO_A200, INDIGO_A400, INDIGO_A700];
+/// The blue shades from 50 to 900.
+pub const BLUE: [Color; 10] = [
+    BLUE_50, BLUE_100, BLUE_200, BLUE_300, BLUE_400, BLUE_500, BLUE_600, BLUE_700, BLUE_800,
+    BLUE_900,
+];
+/// The blue accent shades from A100 to A700.
+pub const BLUE_ACCENTS: [Color; 4] = [BLUE_A100, BLUE_A200, BLUE_A400, BLUE_A700];
+/// The light blue shades from 50 to 900.
+pub const LIGHT_BLUE: [Color; 10] = [
+    LIGHT_BLUE_50, LIGHT_BLUE_100, LIGHT_BLUE_200, LIGHT_BLUE_300, LIGHT_BLUE_400, LIGHT_BLUE_500,
+    LIGHT_BLUE_600, LIGHT_BLUE_700, LIGHT_BLUE_800, LIGHT_BLUE_900,
+];
+/// The light blue accent shades from A100 to A700.
+pub const LIGHT_BLUE_ACCENTS: [Color; 4] = [
+    LIGHT_BLUE_A100, LIGHT_BLUE_A200, LIGHT_BLUE_A400, LIGHT_BLUE_A700,
+];
+/// The cyan shades from 50 to 900.
+pub const CYAN: [Color; 10] = [
+    CYAN_50, CYAN_100, CYAN_200, CYAN_300, CYAN_400, CYAN_500, CYAN_600, CYAN_700, CYAN_800,
+    CYAN_900,
+];
+/// The cyan accent shades from A100 to A700.
+pub const CYAN_ACCENTS: [Color; 4] = [CYAN_A100, CYAN_A200, CYAN_A400, CYAN_A700];
+/// The teal shades from 50 to 900.
+pub const TEAL: [Color; 10] = [
+    TEAL_50, TEAL_100, TEAL_200, TEAL_300, TEAL_400, TEAL_500, TEAL_600, TEAL_700, TEAL_800,
+    TEAL_900,
+];
+/// The teal accent shades from A100 to A700.
+pub const TEAL_ACCENTS: [Color; 4] = [TEAL_A100, TEAL_A200, TEAL_A400, TEAL_A700];
+/// The green shades from 50 to 900.
+pub const GREEN: [Color; 10] = [
+    GREEN_50, GREEN_100, GREEN_200, GREEN_300, GREEN_400, GREEN_500, GREEN_600, GREEN_700,
+    GREEN_800, GREEN_900,
+];
+/// The green accent shades from A100 to A700.
+pub const GREEN_ACCENTS: [Color; 4] = [GREEN_A100, GREEN_A200, GREEN_A400, GREEN_A700];
+/// The light green shades from 50 to 900.
+pub const LIGHT_GREEN: [Color; 10] = [
+    LIGHT_GREEN_50, LIGHT_GREEN_100, LIGHT_GREEN_200, LIGHT_GREEN_300, LIGHT_GREEN_400,
+    LIGHT_GREEN_500, LIGHT_GREEN_600, LIGHT_GREEN_700, LIGHT_GREEN_800, LIGHT_GREEN_900,
+];
+/// The light green accent shades from A100 to A700.
+pub const LIGHT_GREEN_ACCENTS: [Color; 4] = [
+    LIGHT_GREEN_A100, LIGHT_GREEN_A200, LIGHT_GREEN_A400, LIGHT_GREEN_A700,
+];
+/// The lime shades from 50 to 900.
+pub const LIME: [Color; 10] = [
+    LIME_50, LIME_100, LIME_200, LIME_300, LIME_400, LIME_500, LIME_600, LIME_700, LIME_800,
+    LIME_900,
+];
+/// The lime accent shades from A100 to A700.
+pub const LIME_ACCENTS: [Color; 4] = [LIME_A100, LIME_A200, LIME_A400, LIME_A700];
+/// The yellow shades from 50 to 900.
+pub const YELLOW: [Color; 10] = [
+    YELLOW_50, YELLOW_100, YELLOW_200, YELLOW_300, YELLOW_400, YELLOW_500, YELLOW_600, YELLOW_700,
+    YELLOW_800, YELLOW_900,
+];
+/// The yellow accent shades from A100 to A700.
+pub const YELLOW_ACCENTS: [Color; 4] = [YELLOW_A100, YELLOW_A200, YELLOW_A400, YELLOW_A700];
+/// The amber shades from 50 to 900.
+pub const AMBER: [Color; 10] = [
+    AMBER_50, AMBER_100, AMBER_200, AMBER_300, AMBER_400, AMBER_500, AMBER_600, AMBER_700,
+    AMBER_800, AMBER_900,
+];
+/// The amber accent shades from A100 to A700.
+pub const AMBER_ACCENTS: [Color; 4] = [AMBER_A100, AMBER_A200, AMBER_A400, AMBER_A700];
+/// The orange shades from 50 to 900.
+pub const ORANGE: [Color; 10] = [
+    ORANGE_50, ORANGE_100, ORANGE_200, ORANGE_300, ORANGE_400, ORANGE_500, ORANGE_600, ORANGE_700,
+    ORANGE_800, ORANGE_900,
+];
+/// The orange accent shades from A100 to A700.
+pub const ORANGE_ACCENTS: [Color; 4] = [ORANGE_A100, ORANGE_A200, ORANGE_A400, ORANGE_A700];
+/// The deep orange shades from 50 to 900.
+pub const DEEP_ORANGE: [Color; 10] = [
+    DEEP_ORANGE_50, DEEP_ORANGE_100, DEEP_ORANGE_200, DEEP_ORANGE_300, DEEP_ORANGE_400,
+    DEEP_ORANGE_500, DEEP_ORANGE_600, DEEP_ORANGE_700, DEEP_ORANGE_800, DEEP_ORANGE_900,
+];
+/// The deep orange accent shades from A100 to A700.
+pub const DEEP_ORANGE_ACCENTS: [Color; 4] = [
+    DEEP_ORANGE_A100, DEEP_ORANGE_A200, DEEP_ORANGE_A400, DEEP_ORANGE_A700,
+];
+/// The brown shades from 50 to 900.
+pub const BROWN: [Color; 10] = [
+    BROWN_50, BROWN_100, BROWN_200, BROWN_300, BROWN_400, BROWN_500, BROWN_600, BROWN_700,
+    BROWN_800, BROWN_900,
+];
+/// The grey shades from 50 to 900.
+pub const GREY: [Color; 10] = [
+    GREY_50, GREY_100, GREY_200, GREY_300, GREY_400, GREY_500, GREY_600, GREY_700, GREY_800,
+    GREY_900,
+];
+/// The blue grey shades from 50 to 900.
+pub const BLUE_GREY: [Color; 10] = [
+    BLUE_GREY_50, BLUE_GREY_100, BLUE_GREY_200, BLUE_GREY_300, BLUE_GREY_400, BLUE_GREY_500,
+    BLUE_GREY_600, BLUE_GREY_700, BLUE_GREY_800, BLUE_GREY_900,
+];
+
+/// Every hue name paired with its shades, in the order of the Material Design guidelines.
+pub const PALETTES: [(&str, [Color; 10]); 19] = [
+    ("red", RED),
+    ("pink", PINK),
+    ("purple", PURPLE),
+    ("deep purple", DEEP_PURPLE),
+    ("indigo", INDIGO),
+    ("blue", BLUE),
+    ("light blue", LIGHT_BLUE),
+    ("cyan", CYAN),
+    ("teal", TEAL),
+    ("green", GREEN),
+    ("light green", LIGHT_GREEN),
+    ("lime", LIME),
+    ("yellow", YELLOW),
+    ("amber", AMBER),
+    ("orange", ORANGE),
+    ("deep orange", DEEP_ORANGE),
+    ("brown", BROWN),
+    ("grey", GREY),
+    ("blue grey", BLUE_GREY),
+];
+
+/// Every hue name with accent shades paired with its accent shades.
+pub const ACCENT_PALETTES: [(&str, [Color; 4]); 16] = [
+    ("red", RED_ACCENTS),
+    ("pink", PINK_ACCENTS),
+    ("purple", PURPLE_ACCENTS),
+    ("deep purple", DEEP_PURPLE_ACCENTS),
+    ("indigo", INDIGO_ACCENTS),
+    ("blue", BLUE_ACCENTS),
+    ("light blue", LIGHT_BLUE_ACCENTS),
+    ("cyan", CYAN_ACCENTS),
+    ("teal", TEAL_ACCENTS),
+    ("green", GREEN_ACCENTS),
+    ("light green", LIGHT_GREEN_ACCENTS),
+    ("lime", LIME_ACCENTS),
+    ("yellow", YELLOW_ACCENTS),
+    ("amber", AMBER_ACCENTS),
+    ("orange", ORANGE_ACCENTS),
+    ("deep orange", DEEP_ORANGE_ACCENTS),
+];
+
+/// Gets the shades of a hue from 50 to 900 as a palette, e.g. "red" or "blue grey". Matching is 
+/// case-insensitive and leading and trailing whitespace is ignored.
+pub fn palette(name: &str) -> Option<Palette> {
+    super::find(&PALETTES, name)
+}
+
+/// Gets the accent shades of a hue from A100 to A700 as a palette. Returns `None` for brown, grey, 
+/// and blue grey, which have no accent shades.
+pub fn accent_palette(name: &str) -> Option<Palette> {
+    super::find(&ACCENT_PALETTES, name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn gets_palettes_by_name() {
+        assert_eq!(palette("blue grey").unwrap().colors(), &BLUE_GREY);
+        assert_eq!(palette(" Light Green ").unwrap().colors()[5], LIGHT_GREEN_500);
+        assert_eq!(accent_palette("red").unwrap().colors(), &RED_ACCENTS);
+        assert_eq!(accent_palette("brown"), None);
+        assert_eq!(palette("gray"), None);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,376 @@
+//! The [Open Color](https://yeun.github.io/open-color/) palette, available with the `open-color` 
+//! feature.
+//! 
+//! Each of the 13 hues has 10 steps from 0 (lightest) to 9 (darkest), available as constants such 
+//! as [`BLUE_6`] and as arrays such as [`BLUE`].
+//! 
+//! # Examples
+//! ```
+//! use nice_colors::Color;
+//! use nice_colors::palettes::open_color;
+//! 
+//! assert_eq!(open_color::BLUE_6, Color::from_decimal(0x228BE6));
+//! assert_eq!(open_color::palette("Grape").unwrap().colors(), &open_color::GRAPE);
+//! ```
+
+use crate::{Color, Palette};
+
+/// Gray 0.
+pub const GRAY_0: Color = Color { red: 248, green: 249, blue: 250 };
+/// Gray 1.
+pub const GRAY_1: Color = Color { red: 241, green: 243, blue: 245 };
+/// Gray 2.
+pub const GRAY_2: Color = Color { red: 233, green: 236, blue: 239 };
+/// Gray 3.
+pub const GRAY_3: Color = Color { red: 222, green: 226, blue: 230 };
+/// Gray 4.
+pub const GRAY_4: Color = Color { red: 206, green: 212, blue: 218 };
+/// Gray 5.
+pub const GRAY_5: Color = Color { red: 173, green: 181, blue: 189 };
+/// Gray 6.
+pub const GRAY_6: Color = Color { red: 134, green: 142, blue: 150 };
+/// Gray 7.
+pub const GRAY_7: Color = Color { red: 73, green: 80, blue: 87 };
+/// Gray 8.
+pub const GRAY_8: Color = Color { red: 52, green: 58, blue: 64 };
+/// Gray 9.
+pub const GRAY_9: Color = Color { red: 33, green: 37, blue: 41 };
+/// Red 0.
+pub const RED_0: Color = Color { red: 255, green: 245, blue: 245 };
+/// Red 1.
+pub const RED_1: Color = Color { red: 255, green: 227, blue: 227 };
+/// Red 2.
+pub const RED_2: Color = Color { red: 255, green: 201, blue: 201 };
+/// Red 3.
+pub const RED_3: Color = Color { red: 255, green: 168, blue: 168 };
+/// Red 4.
+pub const RED_4: Color = Color { red: 255, green: 135, blue: 135 };
+/// Red 5.
+pub const RED_5: Color = Color { red: 255, green: 107, blue: 107 };
+/// Red 6.
+pub const RED_6: Color = Color { red: 250, green: 82, blue: 82 };
+/// Red 7.
+pub const RED_7: Color = Color { red: 240, green: 62, blue: 62 };
+/// Red 8.
+pub const RED_8: Color = Color { red: 224, green: 49, blue: 49 };
+/// Red 9.
+pub const RED_9: Color = Color { red: 201, green: 42, blue: 42 };
+/// Pink 0.
+pub const PINK_0: Color = Color { red: 255, green: 240, blue: 246 };
+/// Pink 1.
+pub const PINK_1: Color = Color { red: 255, green: 222, blue: 235 };
+/// Pink 2.
+pub const PINK_2: Color = Color { red: 252, green: 194, blue: 215 };
+/// Pink 3.
+pub const PINK_3: Color = Color { red: 250, green: 162, blue: 193 };
+/// Pink 4.
+pub const PINK_4: Color = Color { red: 247, green: 131, blue: 172 };
+/// Pink 5.
+pub const PINK_5: Color = Color { red: 240, green: 101, blue: 149 };
+/// Pink 6.
+pub const PINK_6: Color = Color { red: 230, green: 73, blue: 128 };
+/// Pink 7.
+pub const PINK_7: Color = Color { red: 214, green: 51, blue: 108 };
+/// Pink 8.
+pub const PINK_8: Color = Color { red: 194, green: 37, blue: 92 };
+/// Pink 9.
+pub const PINK_9: Color = Color { red: 166, green: 30, blue: 77 };
+/// Grape 0.
+pub const GRAPE_0: Color = Color { red: 248, green: 240, blue: 252 };
+/// Grape 1.
+pub const GRAPE_1: Color = Color { red: 243, green: 217, blue: 250 };
+/// Grape 2.
+pub const GRAPE_2: Color = Color { red: 238, green: 190, blue: 250 };
+/// Grape 3.
+pub const GRAPE_3: Color = Color { red: 229, green: 153, blue: 247 };
+/// Grape 4.
+pub const GRAPE_4: Color = Color { red: 218, green: 119, blue: 242 };
+/// Grape 5.
+pub const GRAPE_5: Color = Color { red: 204, green: 93, blue: 232 };
+/// Grape 6.
+pub const GRAPE_6: Color = Color { red: 190, green: 75, blue: 219 };
+/// Grape 7.
+pub const GRAPE_7: Color = Color { red: 174, green: 62, blue: 201 };
+/// Grape 8.
+pub const GRAPE_8: Color = Color { red: 156, green: 54, blue: 181 };
+/// Grape 9.
+pub const GRAPE_9: Color = Color { red: 134, green: 46, blue: 156 };
+/// Violet 0.
+pub const VIOLET_0: Color = Color { red: 243, green: 240, blue: 255 };
+/// Violet 1.
+pub const VIOLET_1: Color = Color { red: 229, green: 219, blue: 255 };
+/// Violet 2.
+pub const VIOLET_2: Color = Color { red: 208, green: 191, blue: 255 };
+/// Violet 3.
+pub const VIOLET_3: Color = Color { red: 177, green: 151, blue: 252 };
+/// Violet 4.
+pub const VIOLET_4: Color = Color { red: 151, green: 117, blue: 250 };
+/// Violet 5.
+pub const VIOLET_5: Color = Color { red: 132, green: 94, blue: 247 };
+/// Violet 6.
+pub const VIOLET_6: Color = Color { red: 121, green: 80, blue: 242 };
+/// Violet 7.
+pub const VIOLET_7: Color = Color { red: 112, green: 72, blue: 232 };
+/// Violet 8.
+pub const VIOLET_8: Color = Color { red: 103, green: 65, blue: 217 };
+/// Violet 9.
+pub const VIOLET_9: Color = Color { red: 95, green: 61, blue: 196 };
+/// Indigo 0.
+pub const INDIGO_0: Color = Color { red: 237, green: 242, blue: 255 };
+/// Indigo 1.
+pub const INDIGO_1: Color = Color { red: 219, green: 228, blue: 255 };
+/// Indigo 2.
+pub const INDIGO_2: Color = Color { red: 186, green: 200, blue: 255 };
+/// Indigo 3.
+pub const INDIGO_3: Color = Color { red: 145, green: 167, blue: 255 };
+/// Indigo 4.
+pub const INDIGO_4: Color = Color { red: 116, green: 143, blue: 252 };
+/// Indigo 5.
+pub const INDIGO_5: Color = Color { red: 92, green: 124, blue: 250 };
+/// Indigo 6.
+pub const INDIGO_6: Color = Color { red: 76, green: 110, blue: 245 };
+/// Indigo 7.
+pub const INDIGO_7: Color = Color { red: 66, green: 99, blue: 235 };
+/// Indigo 8.
+pub const INDIGO_8: Color = Color { red: 59, green: 91, blue: 219 };
+/// Indigo 9.
+pub const INDIGO_9: Color = Color { red: 54, green: 79, blue: 199 };
+/// Blue 0.
+pub const BLUE_0: Color = Color { red: 231, green: 245, blue: 255 };
+/// Blue 1.
+pub const BLUE_1: Color = Color { red: 208, green: 235, blue: 255 };
+/// Blue 2.
+pub const BLUE_2: Color = Color { red: 165, green: 216, blue: 255 };
+/// Blue 3.
+pub const BLUE_3: Color = Color { red: 116, green: 192, blue: 252 };
+/// Blue 4.
+pub const BLUE_4: Color = Color { red: 77, green: 171, blue: 247 };
+/// Blue 5.
+pub const BLUE_5: Color = Color { red: 51, green: 154, blue: 240 };
+/// Blue 6.
+pub const BLUE_6: Color = Color { red: 34, green: 139, blue: 230 };
+/// Blue 7.
+pub const BLUE_7: Color = Color { red: 28, green: 126, blue: 214 };
+/// Blue 8.
+pub const BLUE_8: Color = Color { red: 25, green: 113, blue: 194 };
+/// Blue 9.
+pub const BLUE_9: Color = Color { red: 24, green: 100, blue: 171 };
+/// Cyan 0.
+pub const CYAN_0: Color = Color { red: 227, green: 250, blue: 252 };
+/// Cyan 1.
+pub const CYAN_1: Color = Color { red: 197, green: 246, blue: 250 };
+/// Cyan 2.
+pub const CYAN_2: Color = Color { red: 153, green: 233, blue: 242 };
+/// Cyan 3.
+pub const CYAN_3: Color = Color { red: 102, green: 217, blue: 232 };
+/// Cyan 4.
+pub const CYAN_4: Color = Color { red: 59, green: 201, blue: 219 };
+/// Cyan 5.
+pub const CYAN_5: Color = Color { red: 34, green: 184, blue: 207 };
+/// Cyan 6.
+pub const CYAN_6: Color = Color { red: 21, green: 170, blue: 191 };
+/// Cyan 7.
+pub const CYAN_7: Color = Color { red: 16, green: 152, blue: 173 };
+/// Cyan 8.
+pub const CYAN_8: Color = Color { red: 12, green: 133, blue: 153 };
+/// Cyan 9.
+pub const CYAN_9: Color = Color { red: 11, green: 114, blue: 133 };
+/// Teal 0.
+pub const TEAL_0: Color = Color { red: 230, green: 252, blue: 245 };
+/// Teal 1.
+pub const TEAL_1: Color = Color { red: 195, green: 250, blue: 232 };
+/// Teal 2.
+pub const TEAL_2: Color = Color { red: 150, green: 242, blue: 215 };
+/// Teal 3.
+pub const TEAL_3: Color = Color { red: 99, green: 230, blue: 190 };
+/// Teal 4.
+pub const TEAL_4: Color = Color { red: 56, green: 217, blue: 169 };
+/// Teal 5.
+pub const TEAL_5: Color = Color { red: 32, green: 201, blue: 151 };
+/// Teal 6.
+pub const TEAL_6: Color = Color { red: 18, green: 184, blue: 134 };
+/// Teal 7.
+pub const TEAL_7: Color = Color { red: 12, green: 166, blue: 120 };
+/// Teal 8.
+pub const TEAL_8: Color = Color { red: 9, green: 146, blue: 104 };
+/// Teal 9.
+pub const TEAL_9: Color = Color { red: 8, green: 127, blue: 91 };
+/// Green 0.
+pub const GREEN_0: Color = Color { red: 235, green: 251, blue: 238 };
+/// Green 1.
+pub const GREEN_1: Color = Color { red: 211, green: 249, blue: 216 };
+/// Green 2.
+pub const GREEN_2: Color = Color { red: 178, green: 242, blue: 187 };
+/// Green 3.
+pub const GREEN_3: Color = Color { red: 140, green: 233, blue: 154 };
+/// Green 4.
+pub const GREEN_4: Color = Color { red: 105, green: 219, blue: 124 };
+/// Green 5.
+pub const GREEN_5: Color = Color { red: 81, green: 207, blue: 102 };
+/// Green 6.
+pub const GREEN_6: Color = Color { red: 64, green: 192, blue: 87 };
+/// Green 7.
+pub const GREEN_7: Color = Color { red: 55, green: 178, blue: 77 };
+/// Green 8.
+pub const GREEN_8: Color = Color { red: 47, green: 158, blue: 68 };
+/// Green 9.
+pub const GREEN_9: Color = Color { red: 43, green: 138, blue: 62 };
+/// Lime 0.
+pub const LIME_0: Color = Color { red: 244, green: 252, blue: 227 };
+/// Lime 1.
+pub const LIME_1: Color = Color { red: 233, green: 250, blue: 200 };
+/// Lime 2.
+pub const LIME_2: Color = Color { red: 216, green: 245, blue: 162 };
+/// Lime 3.
+pub const LIME_3: Color = Color { red: 192, green: 235, blue: 117 };
+/// Lime 4.
+pub const LIME_4: Color = Color { red: 169, green: 227, blue: 75 };
+/// Lime 5.
+pub const LIME_5: Color = Color { red: 148, green: 216, blue: 45 };
+/// Lime 6.
+pub const LIME_6: Color = Color { red: 130, green: 201, blue: 30 };
+/// Lime 7.
+pub const LIME_7: Color = Color { red: 116, green: 184, blue: 22 };
+/// Lime 8.
+pub const LIME_8: Color = Color { red: 102, green: 168, blue: 15 };
+/// Lime 9.
+pub const LIME_9: Color = Color { red: 92, green: 148, blue: 13 };
+/// Yellow 0.
+pub const YELLOW_0: Color = Color { red: 255, green: 249, blue: 219 };
+/// Yellow 1.
+pub const YELLOW_1: Color = Color { red: 255, green: 243, blue: 191 };
+/// Yellow 2.
+pub const YELLOW_2: Color = Color { red: 255, green: 236, blue: 153 };
+/// Yellow 3.
+pub const YELLOW_3: Color = Color { red: 255, green: 224, blue: 102 };
+/// Yellow 4.
+pub const YELLOW_4: Color = Color { red: 255, green: 212, blue: 59 };
+/// Yellow 5.
+pub const YELLOW_5: Color = Color { red: 252, green: 196, blue: 25 };
+/// Yellow 6.
+pub const YELLOW_6: Color = Color { red: 250, green: 176, blue: 5 };
+/// Yellow 7.
+pub const YELLOW_7: Color = Color { red: 245, green: 159, blue: 0 };
+/// Yellow 8.
+pub const YELLOW_8: Color = Color { red: 240, green: 140, blue: 0 };
+/// Yellow 9.
+pub const YELLOW_9: Color = Color { red: 230, green: 119, blue: 0 };
+/// Orange 0.
+pub const ORANGE_0: Color = Color { red: 255, green: 244, blue: 230 };
+/// Orange 1.
+pub const ORANGE_1: Color = Color { red: 255, green: 232, blue: 204 };
+/// Orange 2.
+pub const ORANGE_2: Color = Color { red: 255, green: 216, blue: 168 };
+/// Orange 3.
+pub const ORANGE_3: Color = Color { red: 255, green: 192, blue: 120 };
+/// Orange 4.
+pub const ORANGE_4: Color = Color { red: 255, green: 169, blue: 77 };
+/// Orange 5.
+pub const ORANGE_5: Color = Color { red: 255, green: 146, blue: 43 };
+/// Orange 6.
+pub const ORANGE_6: Color = Color { red: 253, green: 126, blue: 20 };
+/// Orange 7.
+pub const ORANGE_7: Color = Color { red: 247, green: 103, blue: 7 };
+/// Orange 8.
+pub const ORANGE_8: Color = Color { red: 232, green: 89, blue: 12 };
+/// Orange 9.
+pub const ORANGE_9: Color = Color { red: 217, green: 72, blue: 15 };
+
+/// The gray steps from 0 to 9.
+pub const GRAY: [Color; 10] = [
+    GRAY_0, GRAY_1, GRAY_2, GRAY_3, GRAY_4,
+    GRAY_5, GRAY_6, GRAY_7, GRAY_8, GRAY_9,
+];
+/// The red steps from 0 to 9.
+pub const RED: [Color; 10] = [RED_0, RED_1, RED_2, RED_3, RED_4, RED_5, RED_6, RED_7, RED_8, RED_9];
+/// The pink steps from 0 to 9.
+pub const PINK: [Color; 10] = [
+    PINK_0, PINK_1, PINK_2, PINK_3, PINK_4,
+    PINK_5, PINK_6, PINK_7, PINK_8, PINK_9,
+];
+/// The grape steps from 0 to 9.
+pub const GRAPE: [Color; 10] = [
+    GRAPE_0, GRAPE_1, GRAPE_2, GRAPE_3, GRAPE_4,
+    GRAPE_5, GRAPE_6, GRAPE_7, GRAPE_8, GRAPE_9,
+];
+/// The violet steps from 0 to 9.
+pub const VIOLET: [Color; 10] = [
+    VIOLET_0, VIOLET_1, VIOLET_2, VIOLET_3, VIOLET_4,
+    VIOLET_5, VIOLET_6, VIOLET_7, VIOLET_8, VIOLET_9,
+];
+/// The indigo steps from 0 to 9.
+pub const INDIGO: [Color; 10] = [
+    INDIGO_0, INDIGO_1, INDIGO_2, INDIGO_3, INDIGO_4,
+    INDIGO_5, INDIGO_6, INDIGO_7, INDIGO_8, INDIGO_9,
+];
+/// The blue steps from 0 to 9.
+pub const BLUE: [Color; 10] = [
+    BLUE_0, BLUE_1, BLUE_2, BLUE_3, BLUE_4,
+    BLUE_5, BLUE_6, BLUE_7, BLUE_8, BLUE_9,
+];
+/// The cyan steps from 0 to 9.
+pub const CYAN: [Color; 10] = [
+    CYAN_0, CYAN_1, CYAN_2, CYAN_3, CYAN_4,
+    CYAN_5, CYAN_6, CYAN_7, CYAN_8, CYAN_9,
+];
+/// The teal steps from 0 to 9.
+pub const TEAL: [Color; 10] = [
+    TEAL_0, TEAL_1, TEAL_2, TEAL_3, TEAL_4,
+    TEAL_5, TEAL_6, TEAL_7, TEAL_8, TEAL_9,
+];
+/// The green steps from 0 to 9.
+pub const GREEN: [Color; 10] = [
+    GREEN_0, GREEN_1, GREEN_2, GREEN_3, GREEN_4,
+    GREEN_5, GREEN_6, GREEN_7, GREEN_8, GREEN_9,
+];
+/// The lime steps from 0 to 9.
+pub const LIME: [Color; 10] = [
+    LIME_0, LIME_1, LIME_2, LIME_3, LIME_4,
+    LIME_5, LIME_6, LIME_7, LIME_8, LIME_9,
+];
+/// The yellow steps from 0 to 9.
+pub const YELLOW: [Color; 10] = [
+    YELLOW_0, YELLOW_1, YELLOW_2, YELLOW_3, YELLOW_4,
+    YELLOW_5, YELLOW_6, YELLOW_7, YELLOW_8, YELLOW_9,
+];
+/// The orange steps from 0 to 9.
+pub const ORANGE: [Color; 10] = [
+    ORANGE_0, ORANGE_1, ORANGE_2, ORANGE_3, ORANGE_4,
+    ORANGE_5, ORANGE_6, ORANGE_7, ORANGE_8, ORANGE_9,
+];
+
+/// Every hue name paired with its steps, in the order of the Open Color documentation.
+pub const PALETTES: [(&str, [Color; 10]); 13] = [
+    ("gray", GRAY),
+    ("red", RED),
+    ("pink", PINK),
+    ("grape", GRAPE),
+    ("violet", VIOLET),
+    ("indigo", INDIGO),
+    ("blue", BLUE),
+    ("cyan", CYAN),
+    ("teal", TEAL),
+    ("green", GREEN),
+    ("lime", LIME),
+    ("yellow", YELLOW),
+    ("orange", ORANGE),
+];
+
+/// Gets the steps of a hue from 0 to 9 as a palette, e.g. "grape". Matching is case-insensitive 
+/// and leading and trailing whitespace is ignored.
+pub fn palette(name: &str) -> Option<Palette> {
+    super::find(&PALETTES, name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn gets_palettes_by_name() {
+        assert_eq!(palette("gray").unwrap().colors(), &GRAY);
+        assert_eq!(palette(" ORANGE ").unwrap().colors()[9], ORANGE_9);
+        assert_eq!(palette("grey"), None);
+        assert!(PALETTES.iter().all(|(_name, colors)| colors.len() == 10));
+    }
+}
\ No newline at end of file
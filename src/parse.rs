@@ -1,118 +1,248 @@
+use std::ops::Range;
 use crate::color::{SLICE_LENGTH, Alpha, Value};
+use crate::error::ParseColorError;
 use crate::helpers::{self, conversions};
+use crate::html;
 
-/// Attempts to parse a hexadecimal color string into a color.
-pub fn hex(mut hex: &str, must_include_hash: bool) -> Option<[u8; SLICE_LENGTH]> {
-    let mut len = hex.len();
-    
-    if hex.starts_with('#') {
-        hex = &hex[1..len];
-        len -= 1;
-    } else if must_include_hash {
-        return None;
-    }
-    
-    if !matches!(len, 3 | 4 | 6 | 8) {
-        return None;
-    }
-    
-    let decimal = u32::from_str_radix(hex, 16).ok()?;
-    
-    return match len {
-        3 => Some([
-            (((decimal >> 8) & 0xF) * 0x11) as Value, // Red
-            (((decimal >> 4) & 0xF) * 0x11) as Value, // Green
-            ((decimal & 0xF) * 0x11) as Value, // Blue
-        ]),
-        4 => Some([
-            (((decimal >> 12) & 0xF) * 0x11) as Value, // Red
-            (((decimal >> 8) & 0xF) * 0x11) as Value, // Green
-            (((decimal >> 4) & 0xF) * 0x11) as Value, // Blue
-            // Skip alpha
-        ]),
-        6 => Some([
-            ((decimal >> 16) & 0xFF) as Value, // Red
-            ((decimal >> 8) & 0xFF) as Value, // Green
-            (decimal & 0xFF) as Value, // Blue
-        ]),
-        8 => Some([
-            ((decimal >> 24) & 0xFF) as Value, // Red
-            ((decimal >> 16) & 0xFF) as Value, // Green
-            ((decimal >> 8) & 0xFF) as Value, // Blue
-            // Skip alpha
-        ]),
-        // Never actually reached with the "matches" check above
-        _ => None,
-    };
-}
+/// The result of parsing a color string into R, G, B color values and an alpha value.
+pub type ParseResult = Result<([u8; SLICE_LENGTH], Alpha), ParseColorError>;
 
-/// Converts an HSL color string to a slice of R, G, B color values as u8 integers.
-pub fn hsl(mut hsl: &str) -> Option<([u8; SLICE_LENGTH], Alpha)> {
-    let mut len = hsl.len();
-    let mut colors_expected = 3;
-    
-    if hsl.starts_with("hsl(") {
-        hsl = &hsl[4..len];
-        len -= 4;
-    } else if hsl.starts_with("hsla(") {
-        hsl = &hsl[5..len];
-        len -= 5;
-        colors_expected += 1;
-    } else {
-        return None;
+/// Attempts to parse any supported color string into a color. Hexadecimal color strings must 
+/// include the hash symbol. The `transparent` keyword parses as black with an alpha of `0.0`. 
+/// Alpha defaults to `1.0` if not present. Leading and trailing whitespace is ignored, and names 
+/// of functions and colors are case-insensitive.
+/// 
+/// When `strict` is `true`, components outside of their allowed range are rejected with 
+/// [`ParseColorError::ChannelOutOfRange`] rather than being clamped as per the CSS specification.
+/// 
+/// The spans carried by errors are byte ranges within `s`.
+pub fn color(s: &str, strict: bool) -> ParseResult {
+    let trimmed = s.trim();
+    
+    if trimmed.len() != s.len() {
+        return color(trimmed, strict).map_err(|error| error.offset(span(s, trimmed).start));
     }
     
-    if hsl.ends_with(')') {
-        hsl = &hsl[..(len - 1)];
-    } else {
-        return None;
+    if s.is_empty() {
+        return Err(ParseColorError::Empty);
     }
     
-    let mut hue = None;
-    let mut saturation = None;
-    let mut lightness = None;
-    let mut alpha = 1.0;
-    let mut i = 0;
+    if s.starts_with('#') {
+        return hex_with_alpha(s, true);
+    }
+    
+    if s.eq_ignore_ascii_case("transparent") {
+        return Ok(([0, 0, 0], 0.0));
+    }
     
-    for mut c in hsl.split([',', ' ']) {
-        c = c.trim();
+    if let Some((name, _arguments)) = s.split_once('(') {
+        let is = |names: &[&str]| names.iter().any(|other| name.eq_ignore_ascii_case(other));
         
-        // Skip empty strings
-        if c.is_empty() {
-            continue;
+        return if is(&["rgb", "rgba"]) {
+            rgba(s, strict)
+        } else if is(&["hsl", "hsla"]) {
+            hsl(s, strict)
+        } else if is(&["lab"]) {
+            lab(s, strict)
+        } else if is(&["lch"]) {
+            lch(s, strict)
+        } else if is(&["color"]) {
+            color_function(s, strict)
+        } else {
+            Err(ParseColorError::UnsupportedFunction {
+                name: name.into(),
+                span: span(s, name),
+            })
+        };
+    }
+    
+    if let Some(color) = html::from_html_color_name(s) {
+        return Ok((color.to_array(), 1.0));
+    }
+    
+    if hex_with_alpha(s, false).is_ok() {
+        return Err(ParseColorError::MissingHash);
+    }
+    
+    Err(ParseColorError::UnknownName(s.into()))
+}
+
+/// Finds all color tokens within a larger string, such as a stylesheet, yielding the byte range 
+/// and the parsed color of each token. Hexadecimal colors, color functions, and color names are 
+/// recognized. Color names must not be part of a larger identifier, e.g. `red` is not found within 
+/// `red-500`.
+pub fn scan(
+    text: &str,
+) -> impl Iterator<Item = (Range<usize>, ([u8; SLICE_LENGTH], Alpha))> + '_ {
+    let bytes = text.as_bytes();
+    let mut position = 0;
+    
+    std::iter::from_fn(move || {
+        while let Some(&byte) = bytes.get(position) {
+            let start = position;
+            
+            if byte == b'#' {
+                let end = token_end(bytes, start + 1, u8::is_ascii_alphanumeric);
+                
+                position = end;
+                
+                if let Ok(color) = hex_with_alpha(&text[start..end], true) {
+                    return Some((start..end, color));
+                }
+            } else if
+                byte.is_ascii_alphabetic() &&
+                (start == 0 || !is_identifier_byte(&bytes[start - 1]))
+            {
+                let mut end = token_end(bytes, start, is_identifier_byte);
+                
+                position = end;
+                
+                if bytes.get(end) == Some(&b'(') {
+                    match text[end..].find(')') {
+                        Some(close) => end += close + 1,
+                        None => continue,
+                    }
+                }
+                
+                if let Ok(color) = color(&text[start..end], false) {
+                    position = end;
+                    
+                    return Some((start..end, color));
+                }
+            } else {
+                position += 1;
+            }
         }
         
-        match i {
-            0 => {
-                let parsed = c.parse::<u16>().ok()?;
-                let value = (((parsed as f32 % 360.0) + 360.0) % 360.0) / 360.0;
-                
-                hue = Some(value);
-            },
-            1 => {
-                saturation = helpers::parse_percent(c);
-            },
-            2 => {
-                lightness = helpers::parse_percent(c);
-            },
-            3 if colors_expected == 4 => {
-                alpha = helpers::parse_percent(c)?;
-            },
-            // Too many colors - invalid color
-            _ => return None,
+        None
+    })
+}
+
+/// Gets the end of the run of bytes matching `predicate` starting at `start`.
+fn token_end(bytes: &[u8], start: usize, predicate: fn(&u8) -> bool) -> usize {
+    bytes[start..]
+        .iter()
+        .position(|byte| !predicate(byte))
+        .map_or(bytes.len(), |len| start + len)
+}
+
+/// Checks whether a byte can be part of a CSS identifier.
+fn is_identifier_byte(byte: &u8) -> bool {
+    byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_')
+}
+
+/// Parses a list of hexadecimal color strings separated by newlines or commas. Whitespace around 
+/// each color and empty entries are ignored. The hash symbol is optional.
+pub fn hex_bulk(s: &str) -> Result<Vec<[u8; SLICE_LENGTH]>, ParseColorError> {
+    s
+        .split(['\n', ','])
+        .map(str::trim)
+        .filter(|hex| !hex.is_empty())
+        .map(|hex| hex_with_alpha(hex, false)
+            .map(|(colors, _alpha)| colors)
+            .map_err(|error| error.offset(span(s, hex).start)))
+        .collect()
+}
+
+/// Attempts to parse a color byte string into a color. Any format supported by [`color`] is 
+/// accepted. Byte strings that are not valid UTF-8 are rejected with 
+/// [`ParseColorError::InvalidUtf8`].
+pub fn bytes(bytes: &[u8], strict: bool) -> ParseResult {
+    let s = std::str::from_utf8(bytes)
+        .map_err(|error| {
+            let start = error.valid_up_to();
+            let end = error.error_len().map_or(bytes.len(), |len| start + len);
+            
+            ParseColorError::InvalidUtf8 { span: start..end }
+        })?;
+    
+    color(s, strict)
+}
+
+/// Attempts to parse a hexadecimal color string into a color. The alpha value is discarded if 
+/// present.
+pub fn hex(hex: &str, must_include_hash: bool) -> Result<[u8; SLICE_LENGTH], ParseColorError> {
+    hex_with_alpha(hex, must_include_hash).map(|(colors, _alpha)| colors)
+}
+
+/// Attempts to parse a hexadecimal color string into a color with alpha. Alpha is read from the 
+/// fourth or eighth digit and defaults to `1.0` if not present.
+pub fn hex_with_alpha(
+    s: &str,
+    must_include_hash: bool,
+) -> ParseResult {
+    let hex = match s.strip_prefix('#') {
+        Some(hex) => hex,
+        None if must_include_hash => return Err(ParseColorError::MissingHash),
+        None => s,
+    };
+    let len = hex.len();
+    let mut digits = [0; 8];
+    
+    // Every digit is checked before the length so that invalid digits are reported first.
+    for (i, byte) in hex.bytes().enumerate() {
+        let digit = HEX_DIGITS[byte as usize];
+        
+        if digit == INVALID_DIGIT {
+            // All bytes before this one are ASCII, so this is always the start of a character.
+            let c = hex[i..].chars().next().unwrap_or_default();
+            
+            return Err(ParseColorError::InvalidDigit {
+                digit: c,
+                span: span(s, &hex[i..i + c.len_utf8()]),
+            });
         }
         
+        if let Some(value) = digits.get_mut(i) {
+            *value = digit;
+        }
+    }
+    
+    let [a, b, c, d, e, f, g, h] = digits;
+    
+    match len {
+        3 => Ok(([a * 0x11, b * 0x11, c * 0x11], 1.0)),
+        4 => Ok(([a * 0x11, b * 0x11, c * 0x11], alpha_from_value(d * 0x11))),
+        6 => Ok(([a << 4 | b, c << 4 | d, e << 4 | f], 1.0)),
+        8 => Ok(([a << 4 | b, c << 4 | d, e << 4 | f], alpha_from_value(g << 4 | h))),
+        _ => Err(ParseColorError::InvalidHexLength { len, span: span(s, hex) }),
+    }
+}
+
+/// Marks bytes in [`HEX_DIGITS`] that are not hexadecimal digits.
+const INVALID_DIGIT: u8 = 0xFF;
+
+/// The value of each byte as a hexadecimal digit, or [`INVALID_DIGIT`] if the byte is not a 
+/// hexadecimal digit.
+const HEX_DIGITS: [u8; 256] = {
+    let mut digits = [INVALID_DIGIT; 256];
+    let mut i = 0;
+    
+    while i < 10 {
+        digits[b'0' as usize + i] = i as u8;
         i += 1;
     }
     
-    // Check if the number of colors is valid.
-    if i != colors_expected {
-        return None;
+    i = 0;
+    
+    while i < 6 {
+        digits[b'a' as usize + i] = 10 + i as u8;
+        digits[b'A' as usize + i] = 10 + i as u8;
+        i += 1;
     }
     
-    let hue = hue?;
-    let saturation = saturation?;
-    let lightness = lightness?;
+    digits
+};
+
+/// Converts an alpha value stored as a u8 integer into a float.
+fn alpha_from_value(value: Value) -> Alpha {
+    value as Alpha / Value::MAX as Alpha
+}
+
+/// Converts an HSL color string to a slice of R, G, B color values as u8 integers.
+pub fn hsl(hsl: &str, strict: bool) -> ParseResult {
+    let ([hue, saturation, lightness], alpha) = hsl_values(hsl, strict)?;
+    let hue = hue / 360.0;
     let m2 = if lightness <= 0.5 {
         lightness * (saturation + 1.0)
     } else {
@@ -123,78 +253,291 @@ pub fn hsl(mut hsl: &str) -> Option<([u8; SLICE_LENGTH], Alpha)> {
     let g = helpers::float_to_value(conversions::hue_to_rgb(m1, m2, hue) * 255.0);
     let b = helpers::float_to_value(conversions::hue_to_rgb(m1, m2, hue - 1.0 / 3.0) * 255.0);
     
-    Some(([r, g, b], alpha))
+    Ok(([r, g, b], alpha))
+}
+
+/// Attempts to parse an HSL color string into its hue (0.0 to 360.0), saturation (0.0 to 1.0), 
+/// and lightness (0.0 to 1.0) values. Alpha defaults to `1.0` if not present.
+pub fn hsl_values(hsl: &str, strict: bool) -> Result<([f32; 3], Alpha), ParseColorError> {
+    let ([hue, saturation, lightness], alpha) = legacy_function(hsl, "hsl")?;
+    let hue = helpers::parse_hue(hue).ok_or_else(|| invalid_component(hsl, hue))?;
+    let saturation = in_range(hsl, fraction(hsl, saturation)?, 0.0, 1.0, strict, saturation)?;
+    let lightness = in_range(hsl, fraction(hsl, lightness)?, 0.0, 1.0, strict, lightness)?;
+    let alpha = match alpha {
+        Some(c) => alpha_value(hsl, c, strict)?,
+        None => 1.0,
+    };
+    
+    Ok(([hue, saturation, lightness], alpha))
 }
 
 /// Attempts to parse an rgb or rgba color string into a color. Alpha value defaults to `1.0` if 
 /// not present.
-pub fn rgba(mut rgb: &str) -> Option<([u8; SLICE_LENGTH], Alpha)> {
-    let mut len = rgb.len();
-    let mut colors_expected = SLICE_LENGTH;
-    
-    if rgb.starts_with("rgb(") {
-        rgb = &rgb[4..len];
-        len -= 4;
-    } else if rgb.starts_with("rgba(") {
-        rgb = &rgb[5..len];
-        len -= 5;
-        colors_expected += 1;
-    } else {
-        return None;
-    }
+pub fn rgba(rgb: &str, strict: bool) -> ParseResult {
+    let ([red, green, blue], alpha) = legacy_function(rgb, "rgb")?;
+    let colors = [
+        rgb_component(rgb, red, strict)?,
+        rgb_component(rgb, green, strict)?,
+        rgb_component(rgb, blue, strict)?,
+    ];
+    let alpha = match alpha {
+        Some(c) => alpha_value(rgb, c, strict)?,
+        None => 1.0,
+    };
     
-    if rgb.ends_with(')') {
-        rgb = &rgb[..(len - 1)];
+    Ok((colors, alpha))
+}
+
+/// Attempts to parse a CIE Lab color string such as `lab(52% 40 59)` into a color. Alpha 
+/// defaults to `1.0` if not present.
+pub fn lab(lab: &str, strict: bool) -> ParseResult {
+    let ([lightness, a, b], alpha) = modern_function(lab, "lab", strict)?;
+    let lightness = in_range(lab, number(lab, lightness, 100.0)?, 0.0, 100.0, strict, lightness)?;
+    let a = number(lab, a, 125.0)?;
+    let b = number(lab, b, 125.0)?;
+    let (r, g, b) = conversions::lab_to_rgb(lightness, a, b);
+    
+    Ok(([r, g, b], alpha))
+}
+
+/// Attempts to parse a CIE LCH color string such as `lch(52% 72 50)` into a color. Alpha 
+/// defaults to `1.0` if not present.
+pub fn lch(lch: &str, strict: bool) -> ParseResult {
+    let ([lightness, chroma, hue], alpha) = modern_function(lch, "lch", strict)?;
+    let lightness = in_range(lch, number(lch, lightness, 100.0)?, 0.0, 100.0, strict, lightness)?;
+    let chroma = in_range(lch, number(lch, chroma, 150.0)?, 0.0, f32::INFINITY, strict, chroma)?;
+    let hue = if hue == "none" {
+        0.0
     } else {
-        return None;
+        helpers::parse_hue(hue).ok_or_else(|| invalid_component(lch, hue))?
+    };
+    let (lightness, a, b) = conversions::lch_to_lab(lightness, chroma, hue);
+    let (r, g, b) = conversions::lab_to_rgb(lightness, a, b);
+    
+    Ok(([r, g, b], alpha))
+}
+
+/// Attempts to parse a `color()` function string such as `color(display-p3 1 0 0.5 / 0.4)` into 
+/// a color. The `srgb`, `srgb-linear`, and `display-p3` color spaces are supported. Colors outside 
+/// of the sRGB gamut are gamut mapped. Alpha defaults to `1.0` if not present.
+pub fn color_function(color: &str, strict: bool) -> ParseResult {
+    let arguments = function_arguments(color, "color")?.trim_start();
+    let (space, arguments) = arguments
+        .split_once(char::is_whitespace)
+        .ok_or(ParseColorError::InvalidComponentCount { expected: 3, found: 0 })?;
+    let ([red, green, blue], alpha) = components(color, arguments, strict)?;
+    let values = [
+        number(color, red, 1.0)?,
+        number(color, green, 1.0)?,
+        number(color, blue, 1.0)?,
+    ];
+    let linear = match space {
+        "srgb" => values.map(conversions::srgb_to_linear),
+        "srgb-linear" => values,
+        "display-p3" => conversions::display_p3_to_linear(values),
+        _ => return Err(ParseColorError::UnsupportedColorSpace {
+            name: space.into(),
+            span: span(color, space),
+        }),
+    };
+    let (r, g, b) = conversions::linear_to_rgb(conversions::gamut_map_linear(linear));
+    
+    Ok(([r, g, b], alpha))
+}
+
+/// Splits the arguments of a CSS color function that accepts either the legacy comma-separated 
+/// syntax or the space-separated syntax, e.g. `rgb(255, 0, 0)` or `rgb(255 0 0)`, into its three 
+/// components. When the name of the function is suffixed with "a", e.g. `rgba`, a fourth alpha 
+/// component is expected. With either name, alpha may also follow a `/`, e.g. 
+/// `rgb(255 0 0 / 0.5)`.
+fn legacy_function<'a>(
+    s: &'a str,
+    name: &str,
+) -> Result<([&'a str; 3], Option<&'a str>), ParseColorError> {
+    let s = helpers::remove_prefix_ignore_ascii_case(s, name)
+        .ok_or(ParseColorError::InvalidSyntax)?;
+    let (s, expected) = match s.strip_prefix(['a', 'A']) {
+        Some(s) => (s, 4),
+        None => (s, 3),
+    };
+    let arguments = function_arguments(s, "")?;
+    
+    if let Some((arguments, alpha)) = arguments.split_once('/') {
+        return Ok((space_separated(arguments)?, Some(alpha.trim())));
     }
     
-    let mut colors = [0u8; 3];
-    let mut alpha: Alpha = 1.0;
-    let mut i = 0;
+    let mut components = [""; 4];
+    let mut found = 0;
     
-    for mut c in rgb.split([',', ' ']) {
-        c = c.trim();
-        
+    for c in arguments.split([',', ' ']).map(str::trim) {
         // Skip empty strings
         if c.is_empty() {
             continue;
         }
         
-        match i {
-            0..=2 => if c.ends_with('%') {
-                // It's a percentage.
-                colors[i] = (helpers::parse_percent(c)? * 255.0).round() as u8;
-            } else if c.starts_with('-') {
-                // Remove the negative sign.
-                c = &c[1..];
-                // See if it's a number.
-                c.parse::<u32>().ok()?;
-                // Negative numbers 
-                colors[i] = 0;
-            } else {
-                // Numbers over 255 are acceptable.
-                // Casting to u8 will truncate the value.
-                colors[i] = u32::from_str_radix(c.trim(), 10).ok()? as u8;
-            },
-            3 if colors_expected == 4 => if let Ok(value) = u8::from_str_radix(c.trim(), 10) {
-                alpha = value as f32 / Value::MAX as Alpha;
-            } else {
-                alpha = c.trim().parse::<Alpha>().ok()?;
-            }
-            // Too many colors - invalid color
-            _ => return None,
+        if let Some(component) = components.get_mut(found) {
+            *component = c;
         }
         
-        i += 1;
+        found += 1;
     }
     
     // Check if the number of colors is valid.
-    if i != colors_expected {
-        return None;
+    if found != expected {
+        return Err(ParseColorError::InvalidComponentCount { expected, found });
     }
     
-    Some((colors, alpha))
+    let [a, b, c, alpha] = components;
+    
+    Ok(([a, b, c], (expected == 4).then_some(alpha)))
+}
+
+/// Splits the arguments of a CSS color function that uses the modern space-separated syntax, 
+/// e.g. `lab(52% 40 59 / 0.5)`, into its three components. Alpha defaults to `1.0` if not 
+/// present.
+fn modern_function<'a>(
+    s: &'a str,
+    name: &str,
+    strict: bool,
+) -> Result<([&'a str; 3], Alpha), ParseColorError> {
+    components(s, function_arguments(s, name)?, strict)
+}
+
+/// Returns the string between the parentheses of a CSS function with the given name. The name is 
+/// case-insensitive.
+fn function_arguments<'a>(s: &'a str, name: &str) -> Result<&'a str, ParseColorError> {
+    helpers::remove_prefix_ignore_ascii_case(s, name)
+        .and_then(|s| s.strip_prefix('('))
+        .ok_or(ParseColorError::InvalidSyntax)?
+        .strip_suffix(')')
+        .ok_or(ParseColorError::MissingParenthesis)
+}
+
+/// Splits space-separated function arguments into three components and an optional alpha 
+/// component following a `/`. Alpha defaults to `1.0` if not present.
+fn components<'a>(
+    s: &str,
+    arguments: &'a str,
+    strict: bool,
+) -> Result<([&'a str; 3], Alpha), ParseColorError> {
+    let (components, alpha) = match arguments.split_once('/') {
+        Some((components, alpha)) => {
+            let alpha = alpha.trim();
+            
+            (components, alpha_value(s, alpha, strict)?)
+        },
+        None => (arguments, 1.0),
+    };
+    
+    Ok((space_separated(components)?, alpha))
+}
+
+/// Splits space-separated components into exactly three components.
+fn space_separated(components: &str) -> Result<[&str; 3], ParseColorError> {
+    let mut values = [""; 3];
+    let mut found = 0;
+    
+    for c in components.split_whitespace() {
+        if let Some(value) = values.get_mut(found) {
+            *value = c;
+        }
+        
+        found += 1;
+    }
+    
+    if found != 3 {
+        return Err(ParseColorError::InvalidComponentCount { expected: 3, found });
+    }
+    
+    Ok(values)
+}
+
+/// Gets the byte range of `part` within `s`. `part` must be a subslice of `s`.
+fn span(s: &str, part: &str) -> Range<usize> {
+    let start = part.as_ptr() as usize - s.as_ptr() as usize;
+    
+    start..start + part.len()
+}
+
+/// Creates an error for the invalid component `c` of the color string `s`.
+fn invalid_component(s: &str, c: &str) -> ParseColorError {
+    ParseColorError::InvalidComponent {
+        component: c.into(),
+        span: span(s, c),
+    }
+}
+
+/// Parses a finite floating point number from the component `c` of the color string `s`.
+fn float(s: &str, c: &str) -> Result<f32, ParseColorError> {
+    c
+        .parse::<f32>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .ok_or_else(|| invalid_component(s, c))
+}
+
+/// Parses a number or a percentage, where `100%` resolves to `percent_reference`. The `none` 
+/// keyword resolves to `0.0`.
+fn number(s: &str, c: &str, percent_reference: f32) -> Result<f32, ParseColorError> {
+    if c == "none" {
+        return Ok(0.0);
+    }
+    
+    if let Some(percent) = helpers::remove_suffix(c, "%") {
+        return float(s, percent)
+            .map(|value| value / 100.0 * percent_reference)
+            .map_err(|_error| invalid_component(s, c));
+    }
+    
+    float(s, c)
+}
+
+/// Parses a percentage, or a fraction starting with a decimal point, into a fraction. The value 
+/// is not clamped.
+fn fraction(s: &str, c: &str) -> Result<f32, ParseColorError> {
+    if c.ends_with('%') {
+        return number(s, c, 1.0);
+    } else if c.starts_with("0.") || c.starts_with('.') {
+        return float(s, c);
+    }
+    
+    Err(invalid_component(s, c))
+}
+
+/// Parses a CSS `<alpha-value>`, which is a number from 0 to 1 or a percentage.
+fn alpha_value(s: &str, c: &str, strict: bool) -> Result<Alpha, ParseColorError> {
+    in_range(s, number(s, c, 1.0)?, 0.0, 1.0, strict, c)
+}
+
+/// Checks that a value is within a range. Values outside of the range are clamped unless 
+/// `strict` is `true`, in which case an error is returned for the component `c` of the color 
+/// string `s`.
+fn in_range(
+    s: &str,
+    value: f32,
+    min: f32,
+    max: f32,
+    strict: bool,
+    c: &str,
+) -> Result<f32, ParseColorError> {
+    if strict && !(min..=max).contains(&value) {
+        return Err(ParseColorError::ChannelOutOfRange {
+            component: c.into(),
+            span: span(s, c),
+        });
+    }
+    
+    Ok(value.clamp(min, max))
+}
+
+/// Parses a single red, green, or blue component of an rgb color string. Components may be 
+/// given as numbers or as percentages and both forms can be mixed within the same color, e.g. 
+/// `rgb(100% 0 0)`. Values outside of the range are clamped as per the CSS specification.
+fn rgb_component(s: &str, c: &str, strict: bool) -> Result<Value, ParseColorError> {
+    // Negative numbers are clamped to 0 and numbers over 255 are clamped to 255.
+    let value = in_range(s, number(s, c, 255.0)?, 0.0, 255.0, strict, c)?;
+    
+    Ok(helpers::float_to_value(value))
 }
 
 #[cfg(test)]
@@ -210,10 +553,272 @@ mod tests {
         assert_eq!(helpers::remove_suffix("100%", "%"), Some("100"));
     }
     
+    #[test]
+    fn parses_color() {
+        assert_eq!(color("#FF0000", false), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(color("rgba(255 0 0 0.5)", false), Ok(([255, 0, 0], 0.5)));
+        assert_eq!(color("hsla(0 100% 50% 0.5)", false), Ok(([255, 0, 0], 0.5)));
+        assert_eq!(color("red", false), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(color("#FF000000", false), Ok(([255, 0, 0], 0.0)));
+        assert_eq!(color("transparent", false), Ok(([0, 0, 0], 0.0)));
+        assert_eq!(color("Transparent", false), Ok(([0, 0, 0], 0.0)));
+        assert_eq!(color("FF0000", false), Err(ParseColorError::MissingHash));
+    }
+    
+    #[test]
+    fn parses_color_ignoring_whitespace_and_case() {
+        assert_eq!(color(" #fff ", false), Ok(([255, 255, 255], 1.0)));
+        assert_eq!(color("\n  Red ", false), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(color("RGB(1 2 3)", false), Ok(([1, 2, 3], 1.0)));
+        assert_eq!(color("Rgba(1, 2, 3, 0.5)", false), Ok(([1, 2, 3], 0.5)));
+        assert_eq!(color("HSL(0 100% 50%)", false), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(color("LAB(0% 0 0)", false), Ok(([0, 0, 0], 1.0)));
+        assert_eq!(color("COLOR(srgb 1 0 0)", false), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(color("   ", false), Err(ParseColorError::Empty));
+        assert_eq!(
+            color("  rgb(255 0 x)", false),
+            Err(ParseColorError::InvalidComponent { component: "x".into(), span: 12..13 }),
+        );
+    }
+    
+    #[test]
+    fn parses_color_errors() {
+        assert_eq!(color("", false), Err(ParseColorError::Empty));
+        assert_eq!(
+            color("#FF000", false),
+            Err(ParseColorError::InvalidHexLength { len: 5, span: 1..6 }),
+        );
+        assert_eq!(color("  #FF000", false).unwrap_err().span(), Some(3..8));
+        assert_eq!(
+            color("#FF00G0", false),
+            Err(ParseColorError::InvalidDigit { digit: 'G', span: 5..6 }),
+        );
+        assert_eq!(
+            color("#+FF000", false),
+            Err(ParseColorError::InvalidDigit { digit: '+', span: 1..2 }),
+        );
+        assert_eq!(color("reddish", false), Err(ParseColorError::UnknownName("reddish".into())));
+        assert_eq!(
+            color("hwb(0 0% 0%)", false),
+            Err(ParseColorError::UnsupportedFunction { name: "hwb".into(), span: 0..3 }),
+        );
+        assert_eq!(
+            color("color(rec2020 1 0 0)", false),
+            Err(ParseColorError::UnsupportedColorSpace { name: "rec2020".into(), span: 6..13 }),
+        );
+        assert_eq!(color("rgb(255 0 0", false), Err(ParseColorError::MissingParenthesis));
+        assert_eq!(
+            color("rgb(255 0)", false),
+            Err(ParseColorError::InvalidComponentCount { expected: 3, found: 2 }),
+        );
+        assert_eq!(
+            color("rgba(255 0 0)", false),
+            Err(ParseColorError::InvalidComponentCount { expected: 4, found: 3 }),
+        );
+        assert_eq!(
+            color("rgb(255 0 x)", false),
+            Err(ParseColorError::InvalidComponent { component: "x".into(), span: 10..11 }),
+        );
+    }
+    
+    #[test]
+    fn parses_color_strict() {
+        assert_eq!(color("rgb(255, 0, 999)", false), Ok(([255, 0, 255], 1.0)));
+        assert_eq!(
+            color("rgb(255, 0, 999)", true),
+            Err(ParseColorError::ChannelOutOfRange { component: "999".into(), span: 12..15 }),
+        );
+        assert_eq!(
+            color("hsl(0 120% 50%)", true),
+            Err(ParseColorError::ChannelOutOfRange { component: "120%".into(), span: 6..10 }),
+        );
+        assert_eq!(
+            color("lab(50% 0 0 / 1.5)", true),
+            Err(ParseColorError::ChannelOutOfRange { component: "1.5".into(), span: 14..17 }),
+        );
+        // Wide gamut values are not out of range.
+        assert_eq!(color("color(display-p3 1.5 0 0)", true).map(|(colors, _)| colors[0]), Ok(255));
+    }
+    
+    #[test]
+    fn scans_colors() {
+        let text = "a { color: #F00; background: rgba(0, 0, 255, 0.5); border: 1px solid Red; }";
+        let colors = scan(text).collect::<Vec<_>>();
+        
+        assert_eq!(colors, vec![
+            (11..15, ([255, 0, 0], 1.0)),
+            (29..49, ([0, 0, 255], 0.5)),
+            (69..72, ([255, 0, 0], 1.0)),
+        ]);
+        assert_eq!(&text[29..49], "rgba(0, 0, 255, 0.5)");
+    }
+    
+    #[test]
+    fn scans_colors_skipping_invalid_tokens() {
+        let text = "#header .red-500 { color: rgb(1 2); fill: #0000FF; stroke: darkred }";
+        let colors = scan(text).collect::<Vec<_>>();
+        
+        assert_eq!(colors, vec![
+            (42..49, ([0, 0, 255], 1.0)),
+            (59..66, ([139, 0, 0], 1.0)),
+        ]);
+        assert_eq!(scan("rgb(255 0 0").count(), 0);
+        assert_eq!(
+            scan("fill: RGB(0 0 255)").collect::<Vec<_>>(),
+            vec![(6..18, ([0, 0, 255], 1.0))],
+        );
+        assert_eq!(scan("café #fff").collect::<Vec<_>>(), vec![(6..10, ([255, 255, 255], 1.0))]);
+    }
+    
+    #[test]
+    fn parses_hex_bulk() {
+        assert_eq!(
+            hex_bulk("#FF0000\n#00FF00, 0000FF\r\n\n#FFF,"),
+            Ok(vec![[255, 0, 0], [0, 255, 0], [0, 0, 255], [255, 255, 255]]),
+        );
+        assert_eq!(hex_bulk(""), Ok(vec![]));
+        assert_eq!(
+            hex_bulk("#FF0000\n#00FG00"),
+            Err(ParseColorError::InvalidDigit { digit: 'G', span: 12..13 }),
+        );
+    }
+    
+    #[test]
+    fn parses_bytes() {
+        assert_eq!(bytes(b"#FF0000", false), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(bytes(b"rgba(255 0 0 50%)", false), Ok(([255, 0, 0], 0.5)));
+        assert_eq!(bytes(b"red\xFF", false), Err(ParseColorError::InvalidUtf8 { span: 3..4 }));
+        assert_eq!(bytes(b"red\xE2\x82", false), Err(ParseColorError::InvalidUtf8 { span: 3..5 }));
+    }
+    
+    #[test]
+    fn rejects_invalid_hex_digits() {
+        assert_eq!(hex("#aBc", true), Ok([170, 187, 204]));
+        assert_eq!(
+            hex("#FF 000", true),
+            Err(ParseColorError::InvalidDigit { digit: ' ', span: 3..4 }),
+        );
+        assert_eq!(
+            hex("#FF-000", true),
+            Err(ParseColorError::InvalidDigit { digit: '-', span: 3..4 }),
+        );
+        assert_eq!(
+            hex("#FFé00", true),
+            Err(ParseColorError::InvalidDigit { digit: 'é', span: 3..5 }),
+        );
+        assert_eq!(
+            hex("#FF00000000", true),
+            Err(ParseColorError::InvalidHexLength { len: 10, span: 1..11 }),
+        );
+        assert_eq!(
+            hex("FF000", false),
+            Err(ParseColorError::InvalidHexLength { len: 5, span: 0..5 }),
+        );
+    }
+    
+    #[test]
+    fn parses_hex_with_alpha() {
+        assert_eq!(hex_with_alpha("#FF0000", true), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(hex_with_alpha("#FF000080", true), Ok(([255, 0, 0], 128.0 / 255.0)));
+        assert_eq!(hex_with_alpha("F008", false), Ok(([255, 0, 0], 136.0 / 255.0)));
+        assert_eq!(hex_with_alpha("#F000", true), Ok(([255, 0, 0], 0.0)));
+        assert_eq!(hex_with_alpha("F000", true), Err(ParseColorError::MissingHash));
+        assert_eq!(hex("#FF000080", true), Ok([255, 0, 0]));
+    }
+    
     #[test]
     fn parses_hsl() {
-        assert_eq!(hsl("hsl(0, 100%, 50%)"), Some(([255, 0, 0], 1.0)));
-        assert_eq!(hsl("hsl(120, 100%, 50%)"), Some(([0, 255, 0], 1.0)));
-        assert_eq!(hsl("hsl(0 100% 50%)"), Some(([255, 0, 0], 1.0)));
+        assert_eq!(hsl("hsl(0, 100%, 50%)", false), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(hsl("hsl(120, 100%, 50%)", false), Ok(([0, 255, 0], 1.0)));
+        assert_eq!(hsl("hsl(0 100% 50%)", false), Ok(([255, 0, 0], 1.0)));
+    }
+    
+    #[test]
+    fn parses_hsl_hue_units() {
+        assert_eq!(hsl("hsl(120deg, 100%, 50%)", false), Ok(([0, 255, 0], 1.0)));
+        assert_eq!(hsl("hsl(0.5turn, 100%, 50%)", false), Ok(([0, 255, 255], 1.0)));
+        assert_eq!(hsl("hsl(400grad 100% 50%)", false), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(hsl("hsl(3.14159rad 100% 50%)", false), Ok(([0, 255, 255], 1.0)));
+        assert_eq!(hsl("hsl(-120 100% 50%)", false), Ok(([0, 0, 255], 1.0)));
+        assert_eq!(hsl("hsl(240.5 100% 50%)", false), Ok(([2, 0, 255], 1.0)));
+        assert!(hsl("hsl(120px 100% 50%)", false).is_err());
+    }
+    
+    #[test]
+    fn parses_rgb_percentages() {
+        assert_eq!(rgba("rgb(100%, 0%, 0%)", false), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(rgba("rgb(100% 50% 0%)", false), Ok(([255, 128, 0], 1.0)));
+        assert_eq!(rgba("rgb(100% 0 0)", false), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(rgba("rgb(50.5% 0 127.6)", false), Ok(([129, 0, 128], 1.0)));
+        assert_eq!(rgba("rgba(100% 0 0 50%)", false), Ok(([255, 0, 0], 0.5)));
+        assert_eq!(rgba("rgb(100% 0 0 / 50%)", false), Ok(([255, 0, 0], 0.5)));
+        assert_eq!(rgba("rgba(255 0 0 / 0.5)", false), Ok(([255, 0, 0], 0.5)));
+        assert!(rgba("rgb(255, 0 / 0.5)", false).is_err());
+        assert!(rgba("rgb(255 0 0 /)", false).is_err());
+        assert!(rgba("rgb(100 0 0%", false).is_err());
+        assert!(rgba("rgb(100 0 x%)", false).is_err());
+    }
+    
+    #[test]
+    fn parses_alpha_values() {
+        assert_eq!(rgba("rgba(255, 0, 0, 1)", true), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(rgba("rgba(255, 0, 0, 0)", true), Ok(([255, 0, 0], 0.0)));
+        assert_eq!(rgba("rgb(255 0 0 / 1)", true), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(rgba("rgb(255 0 0 / 0)", true), Ok(([255, 0, 0], 0.0)));
+        assert_eq!(hsl("hsl(0 100% 50% / 1)", true), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(hsl("hsla(0, 100%, 50%, 0)", true), Ok(([255, 0, 0], 0.0)));
+        assert_eq!(hsl("hsl(0 100% 50% / 25%)", true), Ok(([255, 0, 0], 0.25)));
+        assert_eq!(rgba("rgba(255, 0, 0, 2)", false), Ok(([255, 0, 0], 1.0)));
+        assert!(rgba("rgba(255, 0, 0, 2)", true).is_err());
+    }
+    
+    #[test]
+    fn clamps_rgb_components() {
+        assert_eq!(rgba("rgb(300 -5 150%)", false), Ok(([255, 0, 255], 1.0)));
+        assert_eq!(rgba("rgb(-5% 0 0)", false), Ok(([0, 0, 0], 1.0)));
+        assert!(rgba("rgb(inf 0 0)", false).is_err());
+    }
+    
+    #[test]
+    fn parses_lab() {
+        assert_eq!(lab("lab(54.29% 80.8 69.89)", false), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(lab("lab(100 0 0)", false), Ok(([255, 255, 255], 1.0)));
+        assert_eq!(lab("lab(0% 0 0 / 0.5)", false), Ok(([0, 0, 0], 0.5)));
+        assert_eq!(lab("lab(50% none none / 25%)", false), Ok(([119, 119, 119], 0.25)));
+        assert_eq!(lab("lab(52% 40 59)", false).map(|(color, _)| color), Ok([197, 92, 10]));
+        assert!(lab("lab(52% 40)", false).is_err());
+        assert!(lab("lab(52% 40 59 1)", false).is_err());
+    }
+    
+    #[test]
+    fn parses_lch() {
+        assert_eq!(lch("lch(54.29% 106.84 40.85)", false), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(lch("lch(54.29% 106.84 0.1135turn)", false), Ok(([255, 0, 0], 1.0)));
+        assert_eq!(lch("lch(100% 0 none)", false), Ok(([255, 255, 255], 1.0)));
+        assert_eq!(
+            lch("lch(52% 72 50)", false).map(|(color, _)| color),
+            lab("lab(52% 46.28 55.16)", false).map(|(color, _)| color),
+        );
+        assert!(lch("lch(52% 72 50px)", false).is_err());
+    }
+    
+    #[test]
+    fn parses_color_function() {
+        assert_eq!(color_function("color(srgb 1 0 0.5)", false), Ok(([255, 0, 128], 1.0)));
+        assert_eq!(
+            color_function("color(srgb 100% 0% 50% / 50%)", false),
+            Ok(([255, 0, 128], 0.5)),
+        );
+        assert_eq!(
+            color_function("color(srgb-linear 1 0.2159 0)", false),
+            Ok(([255, 128, 0], 1.0)),
+        );
+        assert_eq!(color_function("color(display-p3 1 1 1)", false), Ok(([255, 255, 255], 1.0)));
+        assert_eq!(
+            color_function("color(display-p3 1 0 0 / 0.4)", false),
+            Ok(([255, 11, 12], 0.4)),
+        );
+        assert!(color_function("color(rec2020 1 0 0)", false).is_err());
+        assert!(color_function("color(srgb 1 0)", false).is_err());
     }
 }
\ No newline at end of file
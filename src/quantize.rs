@@ -0,0 +1,432 @@
+//! Reducing images to the colors of a [`Palette`], for GIF encoders and e-ink displays.
+//! 
+//! Pixels are given as a slice of colors in row-major order along with the width of the image.
+
+use crate::{Color, Palette};
+use crate::color::{Value, SLICE_LENGTH};
+
+/// The size of the threshold matrix used for ordered dithering by [`dither_ordered`]. Larger 
+/// matrices produce more intermediate shades with a less visible pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BayerMatrix {
+    /// A 2×2 matrix with 4 thresholds.
+    Size2,
+    /// A 4×4 matrix with 16 thresholds.
+    #[default]
+    Size4,
+    /// An 8×8 matrix with 64 thresholds.
+    Size8,
+}
+
+impl BayerMatrix {
+    /// Gets the number of rows and columns in this matrix.
+    pub fn size(&self) -> usize {
+        match self {
+            Self::Size2 => 2,
+            Self::Size4 => 4,
+            Self::Size8 => 8,
+        }
+    }
+    
+    /// Gets the threshold for a pixel, from 0 up to the number of cells in this matrix. The 
+    /// matrix is tiled across the image.
+    pub fn threshold(&self, x: usize, y: usize) -> usize {
+        let size = self.size();
+        
+        bayer_threshold(x % size, y % size, size)
+    }
+}
+
+/// Quantizes `pixels` to the colors in `palette` in place using Floyd–Steinberg error diffusion. 
+/// The difference between each pixel and its nearest palette color is spread to the neighboring 
+/// pixels that have not yet been quantized, which preserves the average color of areas that the 
+/// palette cannot represent exactly.
+/// 
+/// `pixels` is an image with `width` pixels per row. If the length of `pixels` is not a multiple 
+/// of `width`, the last row is shorter. Nothing is changed if `palette` is empty or `width` is 0.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, Palette};
+/// use nice_colors::quantize;
+/// 
+/// let black = Color { red: 0, green: 0, blue: 0 };
+/// let white = Color { red: 255, green: 255, blue: 255 };
+/// let mut pixels = [Color { red: 128, green: 128, blue: 128 }; 16];
+/// 
+/// quantize::dither_floyd_steinberg(&mut pixels, 4, &Palette::new(vec![black, white]));
+/// 
+/// assert_eq!(pixels.iter().filter(|color| **color == white).count(), 8);
+/// ```
+pub fn dither_floyd_steinberg(pixels: &mut [Color], width: usize, palette: &Palette) {
+    if palette.is_empty() || width == 0 {
+        return;
+    }
+    
+    // Errors for the current row and the next row, offset by one so that the pixels either side
+    // of each row can be written without bounds checks.
+    let mut current = vec![[0.0; SLICE_LENGTH]; width + 2];
+    let mut next = vec![[0.0; SLICE_LENGTH]; width + 2];
+    
+    for row in pixels.chunks_mut(width) {
+        for (x, pixel) in row.iter_mut().enumerate() {
+            let error = current[x + 1];
+            let mut values = [0.0; SLICE_LENGTH];
+            
+            for (i, value) in values.iter_mut().enumerate() {
+                *value = (pixel[i] as f32 + error[i]).clamp(0.0, Value::MAX as f32);
+            }
+            
+            let wanted = Color::from(values.map(|value| value.round() as Value));
+            let Some(nearest) = palette.nearest(wanted) else {
+                return;
+            };
+            
+            for (i, value) in values.iter().enumerate() {
+                let error = value - nearest[i] as f32;
+                
+                current[x + 2][i] += error * 7.0 / 16.0;
+                next[x][i] += error * 3.0 / 16.0;
+                next[x + 1][i] += error * 5.0 / 16.0;
+                next[x + 2][i] += error / 16.0;
+            }
+            
+            *pixel = nearest;
+        }
+        
+        std::mem::swap(&mut current, &mut next);
+        next.fill([0.0; SLICE_LENGTH]);
+    }
+}
+
+/// Quantizes `pixels` to the colors in `palette` in place using ordered dithering with a tiled 
+/// Bayer matrix. Each pixel is offset by the threshold at its position before being replaced with 
+/// its nearest palette color. Unlike [`dither_floyd_steinberg`], each pixel is quantized 
+/// independently of the others, which is faster and keeps the pattern stable between frames of 
+/// an animation.
+/// 
+/// Offsets are scaled to the distance between neighboring colors of a palette with the same 
+/// number of colors spread evenly over red, green, and blue.
+/// 
+/// `pixels` is an image with `width` pixels per row. If the length of `pixels` is not a multiple 
+/// of `width`, the last row is shorter. Nothing is changed if `palette` is empty or `width` is 0.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, Palette};
+/// use nice_colors::quantize::{self, BayerMatrix};
+/// 
+/// let black = Color { red: 0, green: 0, blue: 0 };
+/// let white = Color { red: 255, green: 255, blue: 255 };
+/// let mut pixels = [Color { red: 128, green: 128, blue: 128 }; 16];
+/// 
+/// quantize::dither_ordered(&mut pixels, 4, &Palette::new(vec![black, white]), BayerMatrix::Size4);
+/// 
+/// assert_eq!(pixels.iter().filter(|color| **color == white).count(), 8);
+/// ```
+pub fn dither_ordered(pixels: &mut [Color], width: usize, palette: &Palette, matrix: BayerMatrix) {
+    if palette.is_empty() || width == 0 {
+        return;
+    }
+    
+    let cells = matrix.size().pow(2) as f32;
+    let spread = Value::MAX as f32 / ((palette.len() as f32).cbrt() - 1.0).max(1.0);
+    
+    for (y, row) in pixels.chunks_mut(width).enumerate() {
+        for (x, pixel) in row.iter_mut().enumerate() {
+            let offset = ((matrix.threshold(x, y) as f32 + 0.5) / cells - 0.5) * spread;
+            let wanted = pixel.to_array().map(|value| {
+                (value as f32 + offset).round().clamp(0.0, Value::MAX as f32) as Value
+            });
+            
+            if let Some(nearest) = palette.nearest(Color::from(wanted)) {
+                *pixel = nearest;
+            }
+        }
+    }
+}
+
+/// Builds a palette of at most `max_colors` colors that represent `pixels` using an octree 
+/// quantizer. Colors are sorted into a tree by the bits of their values, and whenever the tree has 
+/// more than `max_colors` leaves, the node with the fewest pixels among the deepest nodes is 
+/// merged into a single leaf. Slots freed by merging are reused, so memory use is bounded by the 
+/// size of the tree rather than the number of pixels, which suits very large images.
+/// 
+/// Each palette color is the average of the pixels merged into it. The palette may have fewer 
+/// than `max_colors` colors, since merging a node can remove up to 7 leaves at once.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::Color;
+/// use nice_colors::quantize;
+/// 
+/// let red = Color { red: 255, green: 0, blue: 0 };
+/// let dark_red = Color { red: 250, green: 0, blue: 0 };
+/// let blue = Color { red: 0, green: 0, blue: 255 };
+/// let palette = quantize::octree_palette(&[red, dark_red, blue, blue], 2);
+/// 
+/// assert_eq!(palette.colors(), [Color { red: 253, green: 0, blue: 0 }, blue]);
+/// ```
+pub fn octree_palette(pixels: &[Color], max_colors: usize) -> Palette {
+    if max_colors == 0 {
+        return Palette::default();
+    }
+    
+    let mut octree = Octree::new();
+    
+    for pixel in pixels {
+        octree.insert(*pixel);
+        
+        while octree.leaves > max_colors {
+            octree.reduce();
+        }
+    }
+    
+    octree
+        .nodes
+        .iter()
+        .filter(|node| node.leaf && node.count > 0)
+        .map(|node| Color::from(node.sum.map(|sum| ((sum + node.count / 2) / node.count) as Value)))
+        .collect()
+}
+
+/// The number of levels below the root of an [`Octree`], one for each bit of a value.
+const OCTREE_DEPTH: usize = 8;
+
+/// A node in an [`Octree`].
+#[derive(Debug, Default)]
+struct OctreeNode {
+    /// The indexes of the children of this node.
+    children: [Option<usize>; 8],
+    /// The sums of the red, green, and blue values of the colors in this node.
+    sum: [u64; SLICE_LENGTH],
+    /// The number of colors in this node and its descendants.
+    count: u64,
+    /// Whether this node is a leaf.
+    leaf: bool,
+}
+
+/// An octree for quantizing colors, with nodes stored in a single list.
+#[derive(Debug)]
+struct Octree {
+    nodes: Vec<OctreeNode>,
+    /// The indexes of the nodes that are not leaves at each level.
+    reducible: [Vec<usize>; OCTREE_DEPTH],
+    /// The indexes of the nodes freed by merging, which are reused before adding new nodes.
+    free: Vec<usize>,
+    leaves: usize,
+}
+
+impl Octree {
+    /// Creates an octree with only a root node.
+    fn new() -> Self {
+        let mut reducible: [Vec<usize>; OCTREE_DEPTH] = Default::default();
+        
+        reducible[0].push(0);
+        
+        Self {
+            nodes: vec![OctreeNode::default()],
+            reducible,
+            free: Vec::new(),
+            leaves: 0,
+        }
+    }
+    
+    /// Adds a color to the leaf it belongs to, creating nodes as needed.
+    fn insert(&mut self, color: Color) {
+        let values = color.to_array();
+        let mut index = 0;
+        
+        for level in 0..OCTREE_DEPTH {
+            if self.nodes[index].leaf {
+                break;
+            }
+            
+            self.nodes[index].count += 1;
+            
+            let bit = OCTREE_DEPTH - 1 - level;
+            let child = values
+                .iter()
+                .fold(0, |child, value| child << 1 | usize::from(value >> bit & 1));
+            
+            index = match self.nodes[index].children[child] {
+                Some(existing) => existing,
+                None => {
+                    let leaf = level + 1 == OCTREE_DEPTH;
+                    let node = OctreeNode { leaf, ..OctreeNode::default() };
+                    let created = match self.free.pop() {
+                        Some(created) => {
+                            self.nodes[created] = node;
+                            created
+                        },
+                        None => {
+                            self.nodes.push(node);
+                            self.nodes.len() - 1
+                        },
+                    };
+                    
+                    if leaf {
+                        self.leaves += 1;
+                    } else {
+                        self.reducible[level + 1].push(created);
+                    }
+                    
+                    self.nodes[index].children[child] = Some(created);
+                    created
+                },
+            };
+        }
+        
+        let node = &mut self.nodes[index];
+        
+        for (sum, value) in node.sum.iter_mut().zip(values) {
+            *sum += u64::from(value);
+        }
+        
+        node.count += 1;
+    }
+    
+    /// Merges the children of the node with the fewest colors at the deepest level that has 
+    /// nodes which are not leaves, turning it into a leaf. The slots of its children are freed.
+    fn reduce(&mut self) {
+        let Some(nodes) = self.reducible.iter_mut().rev().find(|nodes| !nodes.is_empty()) else {
+            return;
+        };
+        let position = (0..nodes.len())
+            .min_by_key(|&position| self.nodes[nodes[position]].count)
+            .unwrap_or_default();
+        let index = nodes.swap_remove(position);
+        let children = std::mem::take(&mut self.nodes[index].children);
+        
+        for child in children.into_iter().flatten() {
+            let child_sum = std::mem::take(&mut self.nodes[child]).sum;
+            
+            for (sum, child_sum) in self.nodes[index].sum.iter_mut().zip(child_sum) {
+                *sum += child_sum;
+            }
+            
+            self.free.push(child);
+            self.leaves -= 1;
+        }
+        
+        self.nodes[index].leaf = true;
+        self.leaves += 1;
+    }
+}
+
+/// Gets the threshold at a position within a Bayer matrix of `size` rows and columns, where 
+/// `size` is a power of 2. Each matrix is built from 4 copies of the matrix half its size.
+fn bayer_threshold(x: usize, y: usize, size: usize) -> usize {
+    if size == 1 {
+        return 0;
+    }
+    
+    let half = size / 2;
+    let quadrant = match (x / half, y / half) {
+        (0, 0) => 0,
+        (1, 0) => 2,
+        (0, _) => 3,
+        _ => 1,
+    };
+    
+    4 * bayer_threshold(x % half, y % half, half) + quadrant
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn dithers_to_palette() {
+        let red = Color { red: 255, green: 0, blue: 0 };
+        let blue = Color { red: 0, green: 0, blue: 255 };
+        let palette = Palette::new(vec![red, blue]);
+        let mut pixels = [Color { red: 64, green: 0, blue: 191 }; 64];
+        
+        dither_floyd_steinberg(&mut pixels, 8, &palette);
+        
+        let reds = pixels.iter().filter(|color| **color == red).count();
+        
+        assert!(pixels.iter().all(|color| *color == red || *color == blue));
+        assert!((14..=18).contains(&reds), "{reds}");
+        
+        let mut pixels = [red, blue, red];
+        
+        dither_floyd_steinberg(&mut pixels, 2, &Palette::default());
+        dither_floyd_steinberg(&mut pixels, 0, &palette);
+        assert_eq!(pixels, [red, blue, red]);
+    }
+    
+    #[test]
+    fn builds_octree_palettes() {
+        let pixels = (0..=255)
+            .flat_map(|red| (0..=255).step_by(15).map(move |blue| Color { red, green: 0, blue }))
+            .collect::<Vec<_>>();
+        
+        for max_colors in [1, 2, 16, 100] {
+            let palette = octree_palette(&pixels, max_colors);
+            
+            assert!(!palette.is_empty() && palette.len() <= max_colors, "{max_colors}");
+        }
+        
+        assert_eq!(octree_palette(&pixels, 1).colors(), [Color { red: 128, green: 0, blue: 128 }]);
+        assert!(octree_palette(&pixels, 0).is_empty());
+        assert!(octree_palette(&[], 16).is_empty());
+    }
+    
+    #[test]
+    fn reduces_least_common_octree_nodes() {
+        let [c0, c1, c2, c3] = [0, 1, 2, 3].map(|blue| Color { red: 0, green: 0, blue });
+        let palette = octree_palette(&[c0, c1, c2, c2, c2, c2, c2, c3], 3);
+        
+        assert_eq!(palette.colors(), [Color { red: 0, green: 0, blue: 1 }, c2, c3]);
+        
+        let mut octree = Octree::new();
+        
+        for red in 0..=255 {
+            for blue in 0..=255 {
+                octree.insert(Color { red, green: 0, blue });
+                
+                while octree.leaves > 16 {
+                    octree.reduce();
+                }
+            }
+        }
+        
+        assert!(octree.nodes.len() <= 17 * (OCTREE_DEPTH + 1), "{}", octree.nodes.len());
+    }
+    
+    #[test]
+    fn builds_bayer_matrices() {
+        let matrix = BayerMatrix::Size4;
+        let rows = (0..4)
+            .map(|y| (0..4).map(|x| matrix.threshold(x, y)).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        
+        assert_eq!(rows, [[0, 8, 2, 10], [12, 4, 14, 6], [3, 11, 1, 9], [15, 7, 13, 5]]);
+        assert_eq!(matrix.threshold(5, 6), matrix.threshold(1, 2));
+        
+        let mut thresholds = (0..64)
+            .map(|i| BayerMatrix::Size8.threshold(i % 8, i / 8))
+            .collect::<Vec<_>>();
+        
+        thresholds.sort();
+        assert_eq!(thresholds, (0..64).collect::<Vec<_>>());
+    }
+    
+    #[test]
+    fn dithers_with_stable_pattern() {
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let palette = Palette::new(vec![black, white]);
+        let mut pixels = [Color { red: 64, green: 64, blue: 64 }; 16];
+        let mut shifted = pixels;
+        
+        shifted[..4].fill(white);
+        dither_ordered(&mut pixels, 4, &palette, BayerMatrix::Size4);
+        dither_ordered(&mut shifted, 4, &palette, BayerMatrix::Size4);
+        
+        assert_eq!(pixels.iter().filter(|color| **color == white).count(), 4);
+        assert_eq!(pixels[4..], shifted[4..]);
+    }
+}
\ No newline at end of file
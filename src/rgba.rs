@@ -0,0 +1,291 @@
+use crate::color::{Alpha, Value};
+use crate::{helpers, parse, ChannelOrder, Color, ColorWithAlpha, ParseColorError};
+use std::fmt;
+use std::str::FromStr;
+
+/// A color containing values for red, green, blue, and alpha.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "rkyv", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
+#[cfg_attr(feature = "borsh", derive(borsh::BorshSerialize, borsh::BorshDeserialize))]
+pub struct Rgba {
+    /// The red value.
+    pub red: Value,
+    /// The green value.
+    pub green: Value,
+    /// The blue value.
+    pub blue: Value,
+    /// The alpha value (0.0 to 1.0).
+    pub alpha: Alpha,
+}
+
+impl Rgba {
+    /// Fully transparent black, the value of the CSS `transparent` keyword.
+    pub const TRANSPARENT: Self = Self { red: 0, green: 0, blue: 0, alpha: 0.0 };
+    /// Opaque black.
+    pub const BLACK: Self = Self { red: 0, green: 0, blue: 0, alpha: 1.0 };
+    /// Opaque white.
+    pub const WHITE: Self = Self { red: 255, green: 255, blue: 255, alpha: 1.0 };
+    
+    /// Creates a new [`Rgba`]. This defaults to opaque black and is equivalent to 
+    /// [`Rgba::default()`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Rgba;
+    /// 
+    /// let color = Rgba::new().red(100).alpha(0.5);
+    /// 
+    /// assert_eq!(color, Rgba { red: 100, green: 0, blue: 0, alpha: 0.5 });
+    /// ```
+    pub fn new() -> Self {
+        Self::BLACK
+    }
+    
+    /// Sets the red value of this color.
+    pub fn red(self, red: Value) -> Self {
+        Self { red, ..self }
+    }
+    
+    /// Sets the green value of this color.
+    pub fn green(self, green: Value) -> Self {
+        Self { green, ..self }
+    }
+    
+    /// Sets the blue value of this color.
+    pub fn blue(self, blue: Value) -> Self {
+        Self { blue, ..self }
+    }
+    
+    /// Sets the alpha value of this color.
+    /// 
+    /// The alpha value is a float between 0.0 and 1.0:
+    /// - If the value is less than 0.0, it will be set to 0.0.
+    /// - If the value is greater than 1.0, it will be set to 1.0.
+    pub fn alpha(self, alpha: Alpha) -> Self {
+        Self { alpha: alpha.clamp(0.0, 1.0), ..self }
+    }
+    
+    /// Gets the color of this value without its alpha.
+    pub fn to_color(&self) -> Color {
+        Color {
+            red: self.red,
+            green: self.green,
+            blue: self.blue,
+        }
+    }
+    
+    /// Converts this color into an 8-digit hexadecimal color string, where the last two digits 
+    /// are the alpha value.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Rgba;
+    /// 
+    /// assert_eq!(Rgba { red: 255, green: 0, blue: 0, alpha: 0.5 }.to_hex_string(), "#FF000080");
+    /// ```
+    pub fn to_hex_string(&self) -> String {
+        self.to_color().to_hex_alpha(self.alpha)
+    }
+    
+    /// Converts this color into a hexadecimal color string in its shortest form. See 
+    /// [`Color::to_hex_alpha_short`].
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Rgba;
+    /// 
+    /// assert_eq!(Rgba { red: 255, green: 0, blue: 0, alpha: 1.0 }.to_hex_short(), "#F00");
+    /// assert_eq!(Rgba { red: 255, green: 0, blue: 0, alpha: 0.0 }.to_hex_short(), "#F000");
+    /// ```
+    pub fn to_hex_short(&self) -> String {
+        self.to_color().to_hex_alpha_short(self.alpha)
+    }
+    
+    /// Converts this color into an rgba color string.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::Rgba;
+    /// 
+    /// assert_eq!(
+    ///     Rgba { red: 255, green: 0, blue: 0, alpha: 0.5 }.to_rgba_string(),
+    ///     "rgba(255 0 0 0.5)",
+    /// );
+    /// ```
+    pub fn to_rgba_string(&self) -> String {
+        self.to_color().to_rgba_string(self.alpha)
+    }
+    
+    /// Packs this color into a `u32` with the given channel order.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{ChannelOrder, Rgba};
+    /// 
+    /// let color = Rgba { red: 255, green: 0, blue: 0, alpha: 0.5 };
+    /// 
+    /// assert_eq!(color.to_u32(ChannelOrder::Argb), 0x80FF0000);
+    /// assert_eq!(color.to_u32(ChannelOrder::Rgba), 0xFF000080);
+    /// ```
+    pub fn to_u32(&self, order: ChannelOrder) -> u32 {
+        let alpha = helpers::float_to_value(self.alpha * Value::MAX as Alpha);
+        
+        order.pack(self.to_color().to_array(), alpha)
+    }
+    
+    /// Unpacks a color from a `u32` with the given channel order.
+    /// 
+    /// # Examples
+    /// ```
+    /// use nice_colors::{ChannelOrder, Rgba};
+    /// 
+    /// let color = Rgba::from_u32(0x00FF0000, ChannelOrder::Argb);
+    /// 
+    /// assert_eq!(color, Rgba { red: 255, green: 0, blue: 0, alpha: 0.0 });
+    /// ```
+    pub fn from_u32(value: u32, order: ChannelOrder) -> Self {
+        let ([red, green, blue], alpha) = order.unpack(value);
+        
+        Self {
+            red,
+            green,
+            blue,
+            alpha: alpha as Alpha / Value::MAX as Alpha,
+        }
+    }
+}
+
+impl Default for Rgba {
+    fn default() -> Self {
+        Self::BLACK
+    }
+}
+
+impl From<Color> for Rgba {
+    fn from(color: Color) -> Self {
+        (color, 1.0).into()
+    }
+}
+
+impl From<ColorWithAlpha> for Rgba {
+    fn from((color, alpha): ColorWithAlpha) -> Self {
+        Self {
+            red: color.red,
+            green: color.green,
+            blue: color.blue,
+            alpha,
+        }
+    }
+}
+
+impl From<Rgba> for ColorWithAlpha {
+    fn from(rgba: Rgba) -> Self {
+        (rgba.to_color(), rgba.alpha)
+    }
+}
+
+impl From<Rgba> for Color {
+    fn from(rgba: Rgba) -> Self {
+        rgba.to_color()
+    }
+}
+
+impl fmt::Display for Rgba {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.to_hex_string())
+    }
+}
+
+impl FromStr for Rgba {
+    type Err = ParseColorError;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::color(s, false).map(|(colors, alpha)| (Color::from(colors), alpha).into())
+    }
+}
+
+/// Serializes to an 8-digit hexadecimal color string for human-readable formats such as JSON, 
+/// and to the red, green, blue, and alpha values for binary formats such as bincode.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rgba {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.collect_str(&self.to_hex_string())
+        } else {
+            (self.red, self.green, self.blue, self.alpha).serialize(serializer)
+        }
+    }
+}
+
+/// Deserializes from any supported color string for human-readable formats such as JSON, and 
+/// from the red, green, blue, and alpha values for binary formats such as bincode.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rgba {
+    fn deserialize<D>(deserializer: D) -> Result<Rgba, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(crate::serializers::ColorAlphaVisitor).map(Self::from)
+        } else {
+            let (red, green, blue, alpha) = serde::Deserialize::deserialize(deserializer)?;
+            
+            Ok(Self { red, green, blue, alpha })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn converts_from_str() {
+        assert_eq!(
+            "#FF000080".parse::<Rgba>(),
+            Ok(Rgba { red: 255, green: 0, blue: 0, alpha: 128.0 / 255.0 }),
+        );
+        assert_eq!(
+            "rgba(255 0 0 0.5)".parse::<Rgba>(),
+            Ok(Rgba { red: 255, green: 0, blue: 0, alpha: 0.5 }),
+        );
+        assert_eq!("transparent".parse::<Rgba>(), Ok(Rgba::TRANSPARENT));
+        assert_eq!("white".parse::<Rgba>(), Ok(Rgba::WHITE));
+    }
+    
+    #[test]
+    fn converts_to_string() {
+        let color = Rgba { red: 255, green: 0, blue: 0, alpha: 0.5 };
+        
+        assert_eq!(color.to_string(), "#FF000080");
+        assert_eq!(Rgba::TRANSPARENT.to_string(), "#00000000");
+        assert_eq!(
+            color.to_string().parse::<Rgba>().map(|color| color.to_color()),
+            Ok(color.to_color()),
+        );
+    }
+    
+    #[test]
+    fn packs_u32() {
+        let color = Rgba { red: 1, green: 2, blue: 3, alpha: 1.0 };
+        let orders = [ChannelOrder::Argb, ChannelOrder::Rgba, ChannelOrder::Abgr, ChannelOrder::Bgra];
+        
+        for order in orders {
+            assert_eq!(Rgba::from_u32(color.to_u32(order), order), color);
+            assert_eq!(Color::from_u32(color.to_u32(order), order), color.to_color());
+        }
+    }
+    
+    #[test]
+    fn converts_to_and_from_color_with_alpha() {
+        let color = Color { red: 255, green: 0, blue: 0 };
+        let rgba = Rgba::from((color, 0.5));
+        
+        assert_eq!(rgba, Rgba { red: 255, green: 0, blue: 0, alpha: 0.5 });
+        assert_eq!(ColorWithAlpha::from(rgba), (color, 0.5));
+        assert_eq!(Rgba::from(color).alpha, 1.0);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,172 @@
+use crate::{Color, Gradient};
+
+/// A transform applied to values and the domain of a [`Scale`] before they are mapped onto its 
+/// gradient.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ScaleTransform {
+    /// No transform.
+    #[default]
+    Linear,
+    /// The natural logarithm, for data spanning several orders of magnitude. Only positive 
+    /// values can be mapped.
+    Log,
+    /// Raises values to the given exponent, keeping their sign, e.g. 0.5 for a square root scale.
+    Pow(f32),
+}
+
+impl ScaleTransform {
+    /// Applies this transform to a value.
+    fn apply(&self, value: f32) -> f32 {
+        match *self {
+            Self::Linear => value,
+            Self::Log => value.ln(),
+            Self::Pow(exponent) => value.signum() * value.abs().powf(exponent),
+        }
+    }
+}
+
+/// A [`Gradient`] mapped onto a numeric domain, for coloring data.
+/// 
+/// The domain defaults to 0.0 to 1.0. A domain with more than 2 values maps each value onto 
+/// positions spaced evenly along the gradient, so `[0.0, 50.0, 100.0]` maps 50.0 to the middle 
+/// of the gradient however the values are spread. Values outside of the domain are clamped to it 
+/// unless clamping is turned off.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, Gradient, Scale, ScaleTransform};
+/// 
+/// let black = Color { red: 0, green: 0, blue: 0 };
+/// let white = Color { red: 255, green: 255, blue: 255 };
+/// let gray = Color { red: 128, green: 128, blue: 128 };
+/// let scale = Scale::new(Gradient::from_colors(&[black, white])).domain(&[0.0, 10.0, 1000.0]);
+/// 
+/// assert_eq!(scale.sample(10.0), Some(gray));
+/// assert_eq!(scale.sample(2000.0), Some(white));
+/// assert_eq!(scale.clone().clamp(false).sample(2000.0), None);
+/// 
+/// let scale = scale.domain(&[1.0, 1000.0]).transform(ScaleTransform::Log);
+/// 
+/// assert_eq!(scale.position(100.0), Some(2.0 / 3.0));
+/// assert_eq!(scale.sample(-1.0), None);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scale {
+    gradient: Gradient,
+    domain: Vec<f32>,
+    clamp: bool,
+    transform: ScaleTransform,
+}
+
+impl Scale {
+    /// Creates a scale over a gradient, with a domain of 0.0 to 1.0.
+    pub fn new(gradient: Gradient) -> Self {
+        Self {
+            gradient,
+            domain: vec![0.0, 1.0],
+            clamp: true,
+            transform: ScaleTransform::Linear,
+        }
+    }
+    
+    /// Sets the domain. The values should be in ascending or descending order. Domains with fewer 
+    /// than 2 values are ignored.
+    pub fn domain(self, domain: &[f32]) -> Self {
+        if domain.len() < 2 {
+            return self;
+        }
+        
+        Self {
+            domain: domain.to_vec(),
+            ..self
+        }
+    }
+    
+    /// Sets whether values outside of the domain are clamped to it. If not, sampling them returns 
+    /// `None`. Clamping is on by default.
+    pub fn clamp(self, clamp: bool) -> Self {
+        Self { clamp, ..self }
+    }
+    
+    /// Sets the transform applied to values and the domain.
+    pub fn transform(self, transform: ScaleTransform) -> Self {
+        Self { transform, ..self }
+    }
+    
+    /// Gets the gradient.
+    pub fn gradient(&self) -> &Gradient {
+        &self.gradient
+    }
+    
+    /// Gets the position along the gradient that a value maps to, where 0.0 is the start of the 
+    /// gradient and 1.0 is the end. Returns `None` if the value is outside of the domain and 
+    /// clamping is off, or if the transform is not defined for it or the domain, such as 
+    /// [`ScaleTransform::Log`] for values that are not positive.
+    pub fn position(&self, value: f32) -> Option<f32> {
+        let transform = |value: f32| Some(self.transform.apply(value)).filter(|v| v.is_finite());
+        let mut value = transform(value)?;
+        let first = transform(self.domain[0])?;
+        let last = transform(self.domain[self.domain.len() - 1])?;
+        let (min, max) = (first.min(last), first.max(last));
+        
+        if value < min || value > max {
+            if !self.clamp {
+                return None;
+            }
+            
+            value = value.clamp(min, max);
+        }
+        
+        let segments = (self.domain.len() - 1) as f32;
+        
+        for (index, pair) in self.domain.windows(2).enumerate() {
+            let start = transform(pair[0])?;
+            let end = transform(pair[1])?;
+            
+            if value >= start.min(end) && value <= start.max(end) {
+                let progress = if start == end {
+                    0.0
+                } else {
+                    (value - start) / (end - start)
+                };
+                
+                return Some((index as f32 + progress) / segments);
+            }
+        }
+        
+        None
+    }
+    
+    /// Samples the color a value maps to. See [`Scale::position`].
+    pub fn sample(&self, value: f32) -> Option<Color> {
+        self.gradient.sample(self.position(value)?)
+    }
+}
+
+impl From<Gradient> for Scale {
+    fn from(gradient: Gradient) -> Self {
+        Self::new(gradient)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn maps_values_onto_gradient() {
+        let scale = Scale::new(Gradient::new()).domain(&[100.0, 0.0]);
+        
+        assert_eq!(scale.position(25.0), Some(0.75));
+        assert_eq!(scale.position(-5.0), Some(1.0));
+        assert_eq!(scale.clone().domain(&[5.0]), scale);
+        assert_eq!(scale.sample(25.0), None);
+        
+        let scale = scale.domain(&[-4.0, 0.0, 4.0]).transform(ScaleTransform::Pow(0.5));
+        
+        assert_eq!(scale.position(-1.0), Some(0.25));
+        assert_eq!(scale.position(1.0), Some(0.75));
+        assert_eq!(scale.clone().transform(ScaleTransform::Log).position(1.0), None);
+        assert_eq!(scale.clamp(false).position(f32::NAN), None);
+    }
+}
\ No newline at end of file
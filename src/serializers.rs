@@ -1,17 +1,47 @@
 //! Serializers for the `Color` type.
 
-use crate::{Color, ColorWithAlpha};
-use serde::de;
+use crate::{helpers, parse, Color, ColorWithAlpha, HSLAColor, HSLColor};
+use crate::color::{Alpha, Value};
+use serde::{de, Serialize, Deserialize};
 use std::fmt;
 
-/// Deserializes from hexademical and rgb color strings.
+/// A color represented as a map of its channels, e.g. `{ "r": 255, "g": 0, "b": 0, "a": 0.5 }`.
+#[derive(Serialize, Deserialize)]
+struct ColorMap {
+    r: Value,
+    g: Value,
+    b: Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    a: Option<Alpha>,
+}
+
+/// The largest decimal color value, white.
+const MAX_DECIMAL: u64 = 0xFFFFFF;
+
+/// Converts a decimal color value into a color, checking that it is in range.
+fn color_from_decimal<E>(value: u64) -> Result<Color, E>
+where
+    E: de::Error,
+{
+    if value > MAX_DECIMAL {
+        return Err(de::Error::invalid_value(
+            de::Unexpected::Unsigned(value),
+            &"a decimal color value no greater than 16777215",
+        ));
+    }
+    
+    Ok(Color::from_decimal(value as u32))
+}
+
+/// Deserializes from color strings, decimal color values, and arrays of red, green, and blue 
+/// values.
 pub(crate) struct ColorVisitor;
 
 impl<'de> de::Visitor<'de> for ColorVisitor {
     type Value = Color;
     
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("a hexadecimal, rgb, or hsl color string")
+        formatter.write_str("a color string, a decimal color value, or an array of 3 values")
     }
     
     /// Deserializes from a color string.
@@ -21,6 +51,107 @@ impl<'de> de::Visitor<'de> for ColorVisitor {
     {
         s.parse::<Self::Value>().map_err(serde::de::Error::custom)
     }
+    
+    /// Deserializes from a decimal color value.
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        color_from_decimal(value)
+    }
+    
+    /// Deserializes from a decimal color value.
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let value = u64::try_from(value)
+            .map_err(|_error| de::Error::invalid_value(de::Unexpected::Signed(value), &self))?;
+        
+        color_from_decimal(value)
+    }
+    
+    /// Deserializes from an array of red, green, and blue values.
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: de::SeqAccess<'de>,
+    {
+        let mut values = [0; 3];
+        
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(4, &self));
+        }
+        
+        Ok(values.into())
+    }
+}
+
+/// A color deserialized using [`ColorVisitor`] regardless of the format, for use within 
+/// collections.
+struct AnyColor(Color);
+
+impl<'de> Deserialize<'de> for AnyColor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ColorVisitor).map(Self)
+    }
+}
+
+/// Deserializes HSL colors from color strings. hsl color strings are parsed without converting 
+/// through RGB so that no precision is lost.
+pub(crate) struct HSLColorVisitor;
+
+impl<'de> de::Visitor<'de> for HSLColorVisitor {
+    type Value = HSLColor;
+    
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an hsl, hexadecimal, or rgb color string")
+    }
+    
+    /// Deserializes from a color string.
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let s = s.trim();
+        
+        if helpers::remove_prefix_ignore_ascii_case(s, "hsl").is_some() {
+            let ([hue, saturation, lightness], _alpha) = parse::hsl_values(s, false)
+                .map_err(serde::de::Error::custom)?;
+            
+            return Ok(HSLColor { hue, saturation, lightness });
+        }
+        
+        s.parse::<Color>().map(HSLColor::from).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Deserializes HSLA colors from color strings. hsl color strings are parsed without converting 
+/// through RGB so that no precision is lost.
+pub(crate) struct HSLAColorVisitor;
+
+impl<'de> de::Visitor<'de> for HSLAColorVisitor {
+    type Value = HSLAColor;
+    
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an hsl, hexadecimal, or rgb color string")
+    }
+    
+    /// Deserializes from a color string.
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        s.parse::<HSLAColor>().map_err(serde::de::Error::custom)
+    }
 }
 
 /// Deserializes from optional hexademical and rgb color strings.
@@ -59,7 +190,7 @@ impl<'de> de::Visitor<'de> for OptionColorVisitor {
 }
 
 /// Deserializes from hexademical and rgb color strings with alpha.
-struct ColorAlphaVisitor;
+pub(crate) struct ColorAlphaVisitor;
 
 impl<'de> de::Visitor<'de> for ColorAlphaVisitor {
     type Value = ColorWithAlpha;
@@ -73,16 +204,9 @@ impl<'de> de::Visitor<'de> for ColorAlphaVisitor {
     where
         E: de::Error,
     {
-        if v.starts_with("rgb") {
-            let (color, alpha) = Color::from_rgba_str(v)
-                .ok_or(serde::de::Error::custom("Not a valid rgb color string."))?;
-            
-            return Ok((color, alpha));
-        }
-        
-        v.parse::<Color>().map_err(serde::de::Error::custom).and_then(|color| {
-            Ok((color, 1.0))
-        })
+        parse::color(v, false)
+            .map(|(colors, alpha)| (colors.into(), alpha))
+            .map_err(serde::de::Error::custom)
     }
 }
 
@@ -133,7 +257,7 @@ pub mod hex {
     where
         S: Serializer,
     {
-        serializer.collect_str(&format!("{}", value.to_hex_string()))
+        serializer.collect_str(&value.to_hex_string())
     }
     
     /// Deserializes a color from a hex string.
@@ -158,7 +282,7 @@ pub mod hex_option {
         S: Serializer,
     {
         if let Some(value) = value {
-            serializer.collect_str(&format!("{}", value.to_hex_string()))
+            serializer.collect_str(&value.to_hex_string())
         } else {
             serializer.serialize_none()
         }
@@ -173,6 +297,58 @@ pub mod hex_option {
     }
 }
 
+/// Serializes and deserializes to and from lowercase hexademical color strings, e.g. `#ff0000`. 
+/// Deserialization also supports uppercase hexadecimal and rgb color strings.
+pub mod hex_lower {
+    use super::ColorVisitor;
+    use crate::Color;
+    use serde::{Serializer, Deserializer};
+    
+    /// Serializes a color to a lowercase hex string.
+    pub fn serialize<S>(value: &Color, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&value.to_hex_lower())
+    }
+    
+    /// Deserializes a color from a hex string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ColorVisitor)
+    }
+}
+
+/// Serializes and deserializes to and from color names, e.g. `red`, falling back to hexademical 
+/// color strings for colors without a name. Deserialization also supports rgb color strings.
+pub mod named_or_hex {
+    use super::ColorVisitor;
+    use crate::{html, Color};
+    use serde::{Serializer, Deserializer};
+    
+    /// Serializes a color to a color name or a hex string.
+    pub fn serialize<S>(value: &Color, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if let Some(name) = html::to_html_color_name(value) {
+            serializer.serialize_str(name)
+        } else {
+            serializer.collect_str(&value.to_hex_string())
+        }
+    }
+    
+    /// Deserializes a color from a color name or a hex string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ColorVisitor)
+    }
+}
+
 /// Serializes and deserializes to and from rgb color strings. Deserialization also supports 
 /// hexadecimal color strings.
 pub mod rgb {
@@ -185,7 +361,7 @@ pub mod rgb {
     where
         S: Serializer,
     {
-        serializer.collect_str(&format!("{}", value.to_rgb_string()))
+        serializer.collect_str(&value.to_rgb_string())
     }
     
     /// Deserializes a color from an rgb string.
@@ -210,7 +386,7 @@ pub mod rgb_option {
         S: Serializer,
     {
         if let Some(value) = value {
-            serializer.collect_str(&format!("{}", value.to_rgb_string()))
+            serializer.collect_str(&value.to_rgb_string())
         } else {
             serializer.serialize_none()
         }
@@ -227,6 +403,9 @@ pub mod rgb_option {
 
 /// Serializes and deserializes to and from rgb color strings with alpha. Deserialization also 
 /// supports hexadecimal color strings.
+/// 
+/// Consider using [`Rgba`](crate::Rgba) rather than `(Color, f32)` with this module, which 
+/// (de)serializes with alpha without any attributes.
 pub mod rgba {
     use super::ColorAlphaVisitor;
     use crate::Color;
@@ -238,9 +417,9 @@ pub mod rgba {
         S: Serializer,
     {
         if value.1 <= 1.0 {
-            serializer.collect_str(&format!("{}", value.0.to_rgba_string(value.1)))
+            serializer.collect_str(&value.0.to_rgba_string(value.1))
         } else {
-            serializer.collect_str(&format!("{}", value.0.to_rgb_string()))
+            serializer.collect_str(&value.0.to_rgb_string())
         }
     }
     
@@ -267,9 +446,9 @@ pub mod rgba_option {
     {
         if let Some(value) = value {
             if value.1 <= 1.0 {
-                serializer.collect_str(&format!("{}", value.0.to_rgba_string(value.1)))
+                serializer.collect_str(&value.0.to_rgba_string(value.1))
             } else {
-                serializer.collect_str(&format!("{}", value.0.to_rgb_string()))
+                serializer.collect_str(&value.0.to_rgb_string())
             }
         } else {
             serializer.serialize_none()
@@ -285,9 +464,226 @@ pub mod rgba_option {
     }
 }
 
+/// Serializes and deserializes to and from hsl color strings. Deserialization also supports 
+/// hexadecimal and rgb color strings.
+pub mod hsl {
+    use super::ColorVisitor;
+    use crate::Color;
+    use serde::{Serializer, Deserializer};
+    
+    /// Serializes a color to an hsl string.
+    pub fn serialize<S>(value: &Color, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&value.to_hsl_string())
+    }
+    
+    /// Deserializes a color from an hsl string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ColorVisitor)
+    }
+}
+
+/// Serializes and deserializes to and from hsl color strings with alpha. Deserialization also 
+/// supports hexadecimal and rgb color strings.
+pub mod hsla {
+    use super::ColorAlphaVisitor;
+    use crate::ColorWithAlpha;
+    use serde::{Serializer, Deserializer};
+    
+    /// Serializes a color to an hsla string.
+    pub fn serialize<S>(value: &ColorWithAlpha, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(&value.0.to_hsla_string(value.1))
+    }
+    
+    /// Deserializes a color from an hsla string.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<ColorWithAlpha, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ColorAlphaVisitor)
+    }
+}
+
+/// Serializes and deserializes vectors of colors to and from arrays of hexademical color strings. 
+/// Deserialization also supports the other color formats.
+pub mod hex_vec {
+    use super::AnyColor;
+    use crate::Color;
+    use serde::{Serializer, Deserialize, Deserializer};
+    
+    /// Serializes colors to an array of hex strings.
+    pub fn serialize<S>(value: &[Color], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_seq(value.iter().map(Color::to_hex_string))
+    }
+    
+    /// Deserializes colors from an array of hex strings.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<Color>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let colors = Vec::<AnyColor>::deserialize(deserializer)?;
+        
+        Ok(colors.into_iter().map(|AnyColor(color)| color).collect())
+    }
+}
+
+/// Serializes and deserializes maps of colors to and from maps of rgb color strings. 
+/// Deserialization also supports the other color formats.
+pub mod rgb_map {
+    use super::AnyColor;
+    use crate::Color;
+    use std::collections::HashMap;
+    use serde::{Serializer, Deserialize, Deserializer};
+    
+    /// Serializes colors to a map of rgb strings.
+    pub fn serialize<S>(value: &HashMap<String, Color>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_map(value.iter().map(|(key, color)| (key, color.to_rgb_string())))
+    }
+    
+    /// Deserializes colors from a map of rgb strings.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<HashMap<String, Color>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let colors = HashMap::<String, AnyColor>::deserialize(deserializer)?;
+        
+        Ok(colors.into_iter().map(|(key, AnyColor(color))| (key, color)).collect())
+    }
+}
+
+/// Serializes and deserializes to and from arrays of red, green, and blue values, e.g. 
+/// `[255, 0, 0]`.
+pub mod array {
+    use crate::Color;
+    use crate::color::{SLICE_LENGTH, Value};
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    
+    /// Serializes a color to an array.
+    pub fn serialize<S>(value: &Color, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        value.to_array().serialize(serializer)
+    }
+    
+    /// Deserializes a color from an array.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        <[Value; SLICE_LENGTH]>::deserialize(deserializer).map(Color::from)
+    }
+}
+
+/// Serializes and deserializes to and from decimal color values, e.g. `16711680` for red.
+pub mod decimal {
+    use crate::Color;
+    use crate::color::DecimalValue;
+    use serde::{Serializer, Deserialize, Deserializer};
+    
+    /// Serializes a color to a decimal value.
+    pub fn serialize<S>(value: &Color, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_u32(value.to_decimal())
+    }
+    
+    /// Deserializes a color from a decimal value.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let decimal = DecimalValue::deserialize(deserializer)?;
+        
+        super::color_from_decimal(decimal.into())
+    }
+}
+
+/// Serializes and deserializes to and from maps of red, green, and blue values, e.g. 
+/// `{ "r": 255, "g": 0, "b": 0 }`. An alpha value under the `"a"` key is ignored when 
+/// deserializing.
+pub mod map {
+    use super::ColorMap;
+    use crate::Color;
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    
+    /// Serializes a color to a map.
+    pub fn serialize<S>(value: &Color, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        ColorMap {
+            r: value.red,
+            g: value.green,
+            b: value.blue,
+            a: None,
+        }.serialize(serializer)
+    }
+    
+    /// Deserializes a color from a map.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Color, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ColorMap { r, g, b, .. } = ColorMap::deserialize(deserializer)?;
+        
+        Ok(Color { red: r, green: g, blue: b })
+    }
+}
+
+/// Serializes and deserializes to and from maps of red, green, blue, and alpha values, e.g. 
+/// `{ "r": 255, "g": 0, "b": 0, "a": 0.5 }`. Alpha defaults to `1.0` if not present when 
+/// deserializing.
+pub mod map_with_alpha {
+    use super::ColorMap;
+    use crate::{Color, ColorWithAlpha};
+    use serde::{Serialize, Serializer, Deserialize, Deserializer};
+    
+    /// Serializes a color with alpha to a map.
+    pub fn serialize<S>(value: &ColorWithAlpha, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (color, alpha) = value;
+        
+        ColorMap {
+            r: color.red,
+            g: color.green,
+            b: color.blue,
+            a: Some(*alpha),
+        }.serialize(serializer)
+    }
+    
+    /// Deserializes a color with alpha from a map.
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<ColorWithAlpha, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let ColorMap { r, g, b, a } = ColorMap::deserialize(deserializer)?;
+        
+        Ok((Color { red: r, green: g, blue: b }, a.unwrap_or(1.0)))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::Rgba;
     use serde::{Deserialize, Serialize};
     
     #[test]
@@ -306,6 +702,303 @@ mod tests {
         assert_eq!(color, Color { red: 255, green: 0, blue: 0 });
     }
     
+    #[test]
+    fn test_compact_serialize() {
+        use serde_test::{assert_tokens, Configure, Token};
+        
+        let color = Color { red: 255, green: 128, blue: 0 };
+        
+        assert_tokens(&color.readable(), &[Token::Str("#FF8000")]);
+        assert_tokens(&color.compact(), &[
+            Token::Tuple { len: 3 },
+            Token::U8(255),
+            Token::U8(128),
+            Token::U8(0),
+            Token::TupleEnd,
+        ]);
+    }
+    
+    #[test]
+    fn test_hsl_color_serialize() {
+        use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+        
+        let color = HSLColor { hue: 210.5, saturation: 0.25, lightness: 0.4 };
+        
+        assert_tokens(&color.readable(), &[Token::Str("hsl(210.5 25% 40%)")]);
+        assert_tokens(&color.compact(), &[
+            Token::Tuple { len: 3 },
+            Token::F32(210.5),
+            Token::F32(0.25),
+            Token::F32(0.4),
+            Token::TupleEnd,
+        ]);
+        assert_de_tokens(
+            &HSLColor { hue: 0.0, saturation: 1.0, lightness: 0.5 }.readable(),
+            &[Token::Str("#FF0000")],
+        );
+    }
+    
+    #[test]
+    fn test_rgba_type_serialize() {
+        use serde_test::{assert_de_tokens, assert_tokens, Configure, Token};
+        
+        let color = Rgba { red: 255, green: 0, blue: 0, alpha: 0.0 };
+        
+        assert_tokens(&color.readable(), &[Token::Str("#FF000000")]);
+        assert_de_tokens(&color.readable(), &[Token::Str("rgba(255 0 0 0)")]);
+        assert_tokens(&color.compact(), &[
+            Token::Tuple { len: 4 },
+            Token::U8(255),
+            Token::U8(0),
+            Token::U8(0),
+            Token::F32(0.0),
+            Token::TupleEnd,
+        ]);
+    }
+    
+    #[test]
+    fn test_collection_serializers() {
+        use std::collections::HashMap;
+        
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Theme {
+            #[serde(with = "hex_vec")]
+            palette: Vec<Color>,
+            #[serde(with = "rgb_map")]
+            named: HashMap<String, Color>,
+        }
+        
+        let s = serde_json::to_string(&Theme {
+            palette: vec![
+                Color { red: 255, green: 0, blue: 0 },
+                Color { red: 0, green: 0, blue: 255 },
+            ],
+            named: HashMap::from([("accent".into(), Color { red: 0, green: 128, blue: 0 })]),
+        }).unwrap();
+        
+        assert_eq!(
+            s,
+            "{\"palette\":[\"#FF0000\",\"#0000FF\"],\"named\":{\"accent\":\"rgb(0 128 0)\"}}",
+        );
+        
+        let theme = serde_json::from_str::<Theme>(
+            "{\"palette\":[\"red\",\"#0000FF\"],\"named\":{\"a\":\"#008000\",\"b\":\"blue\"}}",
+        ).unwrap();
+        
+        assert_eq!(theme.palette, vec![
+            Color { red: 255, green: 0, blue: 0 },
+            Color { red: 0, green: 0, blue: 255 },
+        ]);
+        assert_eq!(theme.named["a"], Color { red: 0, green: 128, blue: 0 });
+        assert_eq!(theme.named["b"], Color { red: 0, green: 0, blue: 255 });
+    }
+    
+    #[test]
+    fn test_deserialize_decimal_and_array() {
+        assert_eq!(
+            serde_json::from_str::<Color>("16711680").unwrap(),
+            Color { red: 255, green: 0, blue: 0 },
+        );
+        assert_eq!(
+            serde_json::from_str::<Color>("[255, 128, 0]").unwrap(),
+            Color { red: 255, green: 128, blue: 0 },
+        );
+        assert!(serde_json::from_str::<Color>("16777216").is_err());
+        assert!(serde_json::from_str::<Color>("-1").is_err());
+        assert!(serde_json::from_str::<Color>("[255, 128]").is_err());
+        assert!(serde_json::from_str::<Color>("[255, 128, 0, 0]").is_err());
+        assert!(serde_json::from_str::<Color>("[256, 128, 0]").is_err());
+    }
+    
+    #[test]
+    fn test_rgba_deserialize_alpha() {
+        #[derive(Debug, Deserialize)]
+        struct Background {
+            #[serde(with = "rgba")]
+            color: (Color, f32),
+        }
+        
+        let background = serde_json::from_str::<Background>("{\"color\":\"transparent\"}").unwrap();
+        
+        assert_eq!(background.color, (Color { red: 0, green: 0, blue: 0 }, 0.0));
+        
+        let background = serde_json::from_str::<Background>("{\"color\":\"#FF000080\"}").unwrap();
+        
+        assert_eq!(background.color, (Color { red: 255, green: 0, blue: 0 }, 128.0 / 255.0));
+        
+        let background = serde_json::from_str::<Background>("{\"color\":\"red\"}").unwrap();
+        
+        assert_eq!(background.color, (Color { red: 255, green: 0, blue: 0 }, 1.0));
+    }
+    
+    #[test]
+    fn test_alpha_round_trip() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Layer {
+            #[serde(with = "rgba")]
+            rgba: (Color, f32),
+            #[serde(with = "hsla")]
+            hsla: (Color, f32),
+        }
+        
+        let color = Color { red: 255, green: 0, blue: 0 };
+        
+        for alpha in [0.0, 0.5, 1.0] {
+            let layer = Layer {
+                rgba: (color, alpha),
+                hsla: (color, alpha),
+            };
+            let serialized = serde_json::to_string(&layer).unwrap();
+            
+            assert_eq!(serde_json::from_str::<Layer>(&serialized).unwrap(), layer, "{serialized}");
+        }
+    }
+    
+    #[test]
+    fn test_array_serialize() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Background {
+            #[serde(with = "array")]
+            color: Color,
+        }
+        
+        let s = serde_json::to_string(&Background {
+            color: Color { red: 255, green: 128, blue: 0 },
+        }).unwrap();
+        
+        assert_eq!(s, "{\"color\":[255,128,0]}");
+        
+        let background = serde_json::from_str::<Background>(&s).unwrap();
+        
+        assert_eq!(background.color, Color { red: 255, green: 128, blue: 0 });
+        assert!(serde_json::from_str::<Background>("{\"color\":[255,128]}").is_err());
+        assert!(serde_json::from_str::<Background>("{\"color\":[256,0,0]}").is_err());
+    }
+    
+    #[test]
+    fn test_decimal_serialize() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Embed {
+            #[serde(with = "decimal")]
+            color: Color,
+        }
+        
+        let s = serde_json::to_string(&Embed {
+            color: Color { red: 255, green: 0, blue: 0 },
+        }).unwrap();
+        
+        assert_eq!(s, "{\"color\":16711680}");
+        
+        let embed = serde_json::from_str::<Embed>("{\"color\":1127219}").unwrap();
+        
+        assert_eq!(embed.color, Color { red: 17, green: 51, blue: 51 });
+        assert!(serde_json::from_str::<Embed>("{\"color\":16777216}").is_err());
+        assert!(serde_json::from_str::<Embed>("{\"color\":-1}").is_err());
+    }
+    
+    #[test]
+    fn test_hex_lower_serialize() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Attachment {
+            #[serde(with = "hex_lower")]
+            color: Color,
+        }
+        
+        let s = serde_json::to_string(&Attachment {
+            color: Color { red: 255, green: 0, blue: 171 },
+        }).unwrap();
+        
+        assert_eq!(s, "{\"color\":\"#ff00ab\"}");
+        
+        let attachment = serde_json::from_str::<Attachment>(&s).unwrap();
+        
+        assert_eq!(attachment.color, Color { red: 255, green: 0, blue: 171 });
+    }
+    
+    #[test]
+    fn test_named_or_hex_serialize() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Theme {
+            #[serde(with = "named_or_hex")]
+            foreground: Color,
+            #[serde(with = "named_or_hex")]
+            background: Color,
+        }
+        
+        let s = serde_json::to_string(&Theme {
+            foreground: Color { red: 255, green: 0, blue: 0 },
+            background: Color { red: 18, green: 52, blue: 86 },
+        }).unwrap();
+        
+        assert_eq!(s, "{\"foreground\":\"red\",\"background\":\"#123456\"}");
+        
+        let theme = serde_json::from_str::<Theme>(&s).unwrap();
+        
+        assert_eq!(theme.foreground, Color { red: 255, green: 0, blue: 0 });
+        assert_eq!(theme.background, Color { red: 18, green: 52, blue: 86 });
+    }
+    
+    #[test]
+    fn test_hsl_serialize() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Theme {
+            #[serde(with = "hsl")]
+            foreground: Color,
+            #[serde(with = "hsla")]
+            background: ColorWithAlpha,
+        }
+        
+        let s = serde_json::to_string(&Theme {
+            foreground: Color { red: 255, green: 0, blue: 0 },
+            background: (Color { red: 0, green: 0, blue: 255 }, 0.5),
+        }).unwrap();
+        
+        assert_eq!(
+            s,
+            "{\"foreground\":\"hsl(0 100% 50%)\",\"background\":\"hsla(240 100% 50% 0.5)\"}",
+        );
+        
+        let theme = serde_json::from_str::<Theme>(&s).unwrap();
+        
+        assert_eq!(theme.foreground, Color { red: 255, green: 0, blue: 0 });
+        assert_eq!(theme.background, (Color { red: 0, green: 0, blue: 255 }, 0.5));
+        
+        let theme = serde_json::from_str::<Theme>(
+            "{\"foreground\":\"#FF0000\",\"background\":\"rgba(0 0 255 0.5)\"}",
+        ).unwrap();
+        
+        assert_eq!(theme.foreground, Color { red: 255, green: 0, blue: 0 });
+        assert_eq!(theme.background, (Color { red: 0, green: 0, blue: 255 }, 0.5));
+    }
+    
+    #[test]
+    fn test_map_serialize() {
+        #[derive(Debug, Serialize, Deserialize)]
+        struct Theme {
+            #[serde(with = "map")]
+            foreground: Color,
+            #[serde(with = "map_with_alpha")]
+            background: ColorWithAlpha,
+        }
+        
+        let s = serde_json::to_string(&Theme {
+            foreground: Color { red: 255, green: 0, blue: 0 },
+            background: (Color { red: 0, green: 0, blue: 255 }, 0.5),
+        }).unwrap();
+        
+        assert_eq!(
+            s,
+            "{\"foreground\":{\"r\":255,\"g\":0,\"b\":0},\"background\":{\"r\":0,\"g\":0,\"b\":255,\"a\":0.5}}",
+        );
+        
+        let theme = serde_json::from_str::<Theme>(
+            "{\"foreground\":{\"r\":255,\"g\":0,\"b\":0,\"a\":0.5},\"background\":{\"r\":0,\"g\":0,\"b\":255}}",
+        ).unwrap();
+        
+        assert_eq!(theme.foreground, Color { red: 255, green: 0, blue: 0 });
+        assert_eq!(theme.background, (Color { red: 0, green: 0, blue: 255 }, 1.0));
+    }
+    
     #[test]
     fn test_all_serializers() {
         #[derive(Debug, Clone, Serialize, Deserialize)]
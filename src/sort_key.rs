@@ -0,0 +1,141 @@
+use crate::{Color, HSLColor, HSVColor};
+use std::cmp::Ordering;
+
+/// The property colors are ordered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Order {
+    Hue,
+    Lightness,
+    Luminance,
+    Step(u8),
+}
+
+/// A way of ordering colors, for sorting swatches and palettes. Use [`SortKey::compare`] with 
+/// [`slice::sort_by`] or sort a slice directly with [`SortKey::sort`].
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, SortKey};
+/// 
+/// let red = Color { red: 255, green: 0, blue: 0 };
+/// let green = Color { red: 0, green: 255, blue: 0 };
+/// let navy = Color { red: 0, green: 0, blue: 128 };
+/// let mut colors = [navy, green, red];
+/// 
+/// SortKey::hue().sort(&mut colors);
+/// assert_eq!(colors, [red, green, navy]);
+/// 
+/// colors.sort_by(|a, b| SortKey::lightness().reverse().compare(a, b));
+/// assert_eq!(colors, [green, red, navy]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    order: Order,
+    reverse: bool,
+}
+
+impl SortKey {
+    /// Orders colors by their hue in HSL, from red through green and blue back to red.
+    pub fn hue() -> Self {
+        Self::new(Order::Hue)
+    }
+    
+    /// Orders colors by their perceived lightness in Oklab, from dark to light.
+    pub fn lightness() -> Self {
+        Self::new(Order::Lightness)
+    }
+    
+    /// Orders colors by their WCAG 2 relative luminance, from dark to light.
+    pub fn luminance() -> Self {
+        Self::new(Order::Luminance)
+    }
+    
+    /// Orders colors into `bands` bands of hue, then by luminance within each band, alternating 
+    /// between dark to light and light to dark so that neighboring bands meet at a similar 
+    /// luminance. This produces a smooth ordering for swatches, unlike sorting by hue alone which 
+    /// scatters light and dark colors. Fewer than 1 band is treated as 1 band.
+    pub fn step(bands: u8) -> Self {
+        Self::new(Order::Step(bands.max(1)))
+    }
+    
+    /// Reverses the ordering.
+    pub fn reverse(self) -> Self {
+        Self {
+            reverse: !self.reverse,
+            ..self
+        }
+    }
+    
+    /// Compares two colors using this ordering.
+    pub fn compare(&self, a: &Color, b: &Color) -> Ordering {
+        let ordering = match self.order {
+            Order::Hue => HSLColor::from(*a).hue.total_cmp(&HSLColor::from(*b).hue),
+            Order::Lightness => a.to_oklab()[0].total_cmp(&b.to_oklab()[0]),
+            Order::Luminance => a.relative_luminance().total_cmp(&b.relative_luminance()),
+            Order::Step(bands) => step_key(a, bands).cmp(&step_key(b, bands)),
+        };
+        
+        if self.reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    }
+    
+    /// Sorts colors using this ordering. The sort is stable.
+    pub fn sort(&self, colors: &mut [Color]) {
+        colors.sort_by(|a, b| self.compare(a, b));
+    }
+    
+    /// Creates a key for an ordering, from low to high.
+    fn new(order: Order) -> Self {
+        Self {
+            order,
+            reverse: false,
+        }
+    }
+}
+
+/// Gets the hue band, luminance step, and value step of a color for step sorting. Luminance and 
+/// value are divided into as many steps as there are hue bands.
+fn step_key(color: &Color, bands: u8) -> (u8, u8, u8) {
+    let hsv = HSVColor::from(*color);
+    let [red, green, blue] = color.to_array().map(|value| value as f32 / 255.0);
+    // A fast approximation of perceived brightness.
+    let luminance = (0.241 * red + 0.691 * green + 0.068 * blue).sqrt();
+    let step = |value: f32| (value * bands as f32).clamp(0.0, (bands - 1) as f32) as u8;
+    let band = step(hsv.hue / 360.0);
+    let luminance = step(luminance);
+    let value = step(hsv.value);
+    
+    if band % 2 == 1 {
+        (band, bands - 1 - luminance, bands - 1 - value)
+    } else {
+        (band, luminance, value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn sorts_by_step() {
+        let dark_red = Color { red: 100, green: 0, blue: 0 };
+        let light_red = Color { red: 255, green: 150, blue: 150 };
+        let dark_yellow = Color { red: 100, green: 100, blue: 0 };
+        let light_yellow = Color { red: 255, green: 255, blue: 150 };
+        let mut colors = [light_yellow, light_red, dark_yellow, dark_red];
+        
+        SortKey::step(8).sort(&mut colors);
+        
+        // Red is in the first band, sorted dark to light, and yellow is in the second band, 
+        // sorted light to dark.
+        assert_eq!(colors, [dark_red, light_red, light_yellow, dark_yellow]);
+        
+        SortKey::luminance().sort(&mut colors);
+        
+        assert_eq!(colors, [dark_red, dark_yellow, light_red, light_yellow]);
+        assert_eq!(SortKey::step(0), SortKey::step(1));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+//! [proptest](https://docs.rs/proptest) strategies for generating colors and color strings in 
+//! property tests.
+//! 
+//! # Examples
+//! ```
+//! use nice_colors::Color;
+//! use nice_colors::strategies::any_color;
+//! use proptest::prelude::*;
+//! 
+//! proptest!(|(color in any_color())| {
+//!     prop_assert_eq!(Color::parse(&color.to_hex_string()), Ok(color));
+//! });
+//! ```
+
+use crate::{html, Color, FormatOptions, InterpolationSpace, Notation};
+use proptest::prelude::*;
+use proptest::sample::select;
+
+/// Generates any color, shrinking towards black.
+pub fn any_color() -> impl Strategy<Value = Color> {
+    any::<[u8; 3]>().prop_map(Color::from)
+}
+
+/// Generates colors that convert to the given color space and back without changing, for 
+/// testing round trips through that space.
+pub fn color_in_gamut(space: InterpolationSpace) -> impl Strategy<Value = Color> {
+    any_color().prop_filter("color does not round trip", move |color| {
+        space.lerp(*color, *color, 0.0) == *color
+    })
+}
+
+/// Generates valid CSS color strings, using hexadecimal, rgb, and hsl notation in both the modern 
+/// and legacy syntaxes, with and without alpha, as well as color names.
+pub fn valid_css_color_string() -> impl Strategy<Value = String> {
+    let notation = select(vec![Notation::Hex, Notation::Rgb, Notation::Hsl]);
+    let alpha = proptest::option::of(0.0..=1.0f32);
+    let formatted = (any_color(), notation, any::<bool>(), alpha, any::<bool>(), any::<bool>())
+        .prop_map(|(color, notation, legacy, alpha, percentages, uppercase)| {
+            let options = FormatOptions {
+                notation,
+                legacy,
+                alpha,
+                percentages,
+                uppercase,
+            };
+            
+            color.to_css(options)
+        });
+    let named = select(html::COLOR_NAMES.map(|(name, _color)| name).to_vec())
+        .prop_map(String::from);
+    
+    prop_oneof![4 => formatted, 1 => named]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    proptest! {
+        #[test]
+        fn parses_valid_css_color_strings(s in valid_css_color_string()) {
+            prop_assert!(s.parse::<Color>().is_ok(), "{}", s);
+        }
+        
+        #[test]
+        fn round_trips_hsl(color in color_in_gamut(InterpolationSpace::Hsl)) {
+            let hsl = crate::HSLColor::from(color);
+            
+            prop_assert_eq!(Color::from(hsl), color);
+        }
+    }
+}
\ No newline at end of file
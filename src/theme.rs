@@ -0,0 +1,222 @@
+use crate::{format, Color};
+use crate::helpers::conversions;
+
+/// A set of colors for the semantic roles of a user interface. Each `on_` color is used for text 
+/// and icons drawn on top of the color it is named after.
+/// 
+/// # Examples
+/// ```
+/// use nice_colors::{Color, Theme};
+/// 
+/// let light = Theme {
+///     background: Color::from_decimal(0xFFFFFF),
+///     on_background: Color::from_decimal(0x1C1B1F),
+///     surface: Color::from_decimal(0xF4F1F8),
+///     on_surface: Color::from_decimal(0x1C1B1F),
+///     primary: Color::from_decimal(0x6750A4),
+///     on_primary: Color::from_decimal(0xFFFFFF),
+///     secondary: Color::from_decimal(0x625B71),
+///     on_secondary: Color::from_decimal(0xFFFFFF),
+///     error: Color::from_decimal(0xB3261E),
+///     on_error: Color::from_decimal(0xFFFFFF),
+/// };
+/// let dark = light.derive_dark();
+/// 
+/// assert!(!light.is_dark());
+/// assert!(dark.is_dark());
+/// assert!(dark.on_primary.contrast_ratio(dark.primary) >= 4.5);
+/// assert_eq!(dark.derive_dark(), dark);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Theme {
+    /// The color behind all content.
+    pub background: Color,
+    /// The color of content on the background.
+    pub on_background: Color,
+    /// The color of components such as cards, sheets, and menus.
+    pub surface: Color,
+    /// The color of content on surfaces.
+    pub on_surface: Color,
+    /// The color used most often for prominent components such as buttons.
+    pub primary: Color,
+    /// The color of content on the primary color.
+    pub on_primary: Color,
+    /// The color used for less prominent components.
+    pub secondary: Color,
+    /// The color of content on the secondary color.
+    pub on_secondary: Color,
+    /// The color used to indicate errors.
+    pub error: Color,
+    /// The color of content on the error color.
+    pub on_error: Color,
+}
+
+impl Theme {
+    /// Checks whether this theme has a dark background, i.e. a background with a lightness below 
+    /// 50% in Oklch.
+    pub fn is_dark(&self) -> bool {
+        self.background.to_oklab()[0] < 0.5
+    }
+    
+    /// Derives a dark theme from this theme by flipping the lightness of every color in Oklch, 
+    /// keeping hue and chroma. Each `on_` color is then adjusted so that its contrast with the 
+    /// color it is drawn on is at least what it was in this theme, or as close to it as black or 
+    /// white can get. Returns this theme if it is already dark.
+    pub fn derive_dark(&self) -> Self {
+        if self.is_dark() {
+            *self
+        } else {
+            self.flip()
+        }
+    }
+    
+    /// Derives a light theme from this theme. This is the inverse of [`Theme::derive_dark`] and 
+    /// returns this theme if it is already light.
+    pub fn derive_light(&self) -> Self {
+        if self.is_dark() {
+            self.flip()
+        } else {
+            *self
+        }
+    }
+    
+    /// Gets the name and color of each role, in the order the fields are declared. Names are 
+    /// written in kebab case, e.g. "on-primary".
+    pub fn roles(&self) -> [(&'static str, Color); 10] {
+        [
+            ("background", self.background),
+            ("on-background", self.on_background),
+            ("surface", self.surface),
+            ("on-surface", self.on_surface),
+            ("primary", self.primary),
+            ("on-primary", self.on_primary),
+            ("secondary", self.secondary),
+            ("on-secondary", self.on_secondary),
+            ("error", self.error),
+            ("on-error", self.on_error),
+        ]
+    }
+    
+    /// Generates a `:root` block declaring a CSS custom property for each role, e.g. 
+    /// `--color-on-primary: #FFFFFF;`.
+    pub fn to_css_root(&self) -> String {
+        format::css_root(self.roles().map(|(name, color)| (format!("color-{name}"), color)))
+    }
+    
+    /// Flips the lightness of every color, then restores the contrast of each `on_` color.
+    fn flip(&self) -> Self {
+        let pair = |color: Color, on: Color| {
+            let flipped = flip_lightness(color);
+            let on_flipped = flip_lightness(on).adjust_for_contrast(
+                flipped,
+                on.contrast_ratio(color),
+            );
+            
+            (flipped, on_flipped)
+        };
+        let (background, on_background) = pair(self.background, self.on_background);
+        let (surface, on_surface) = pair(self.surface, self.on_surface);
+        let (primary, on_primary) = pair(self.primary, self.on_primary);
+        let (secondary, on_secondary) = pair(self.secondary, self.on_secondary);
+        let (error, on_error) = pair(self.error, self.on_error);
+        
+        Self {
+            background,
+            on_background,
+            surface,
+            on_surface,
+            primary,
+            on_primary,
+            secondary,
+            on_secondary,
+            error,
+            on_error,
+        }
+    }
+}
+
+/// Flips the lightness of a color in Oklch, keeping hue and chroma where the sRGB gamut allows.
+fn flip_lightness(color: Color) -> Color {
+    let [lightness, a, b] = color.to_oklab();
+    let linear = conversions::oklab_to_linear([1.0 - lightness, a, b]);
+    
+    Color::from(conversions::linear_to_rgb(conversions::gamut_map_linear(linear)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn derives_dark_and_light_themes() {
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let black = Color { red: 0, green: 0, blue: 0 };
+        let blue = Color { red: 30, green: 80, blue: 200 };
+        let light = Theme {
+            background: white,
+            on_background: black,
+            surface: Color { red: 240, green: 240, blue: 245 },
+            on_surface: Color { red: 30, green: 30, blue: 35 },
+            primary: blue,
+            on_primary: white,
+            secondary: Color { red: 90, green: 90, blue: 110 },
+            on_secondary: white,
+            error: Color { red: 180, green: 30, blue: 30 },
+            on_error: white,
+        };
+        let dark = light.derive_dark();
+        
+        assert!(dark.is_dark());
+        assert_eq!(dark.background, black);
+        assert_eq!(dark.on_background, white);
+        
+        let [_lightness, a, b] = blue.to_oklab();
+        let [_lightness, dark_a, dark_b] = dark.primary.to_oklab();
+        
+        assert!((a.atan2(b) - dark_a.atan2(dark_b)).abs() < 0.05, "{:?}", dark.primary);
+        
+        for ((color, on), (dark_color, dark_on)) in [
+            ((light.surface, light.on_surface), (dark.surface, dark.on_surface)),
+            ((light.primary, light.on_primary), (dark.primary, dark.on_primary)),
+            ((light.secondary, light.on_secondary), (dark.secondary, dark.on_secondary)),
+            ((light.error, light.on_error), (dark.error, dark.on_error)),
+        ] {
+            let ratio = on.contrast_ratio(color);
+            let dark_ratio = dark_on.contrast_ratio(dark_color);
+            
+            let best = white.contrast_ratio(dark_color).max(black.contrast_ratio(dark_color));
+            
+            assert!(dark_ratio >= ratio.min(best), "{dark_on:?} on {dark_color:?}");
+            
+            if ratio >= 4.5 {
+                assert!(dark_ratio >= 4.5, "{dark_on:?} on {dark_color:?}");
+            }
+        }
+        
+        assert!(dark.derive_light().background.relative_luminance() > 0.95);
+        assert_eq!(light.derive_light(), light);
+    }
+    
+    #[test]
+    fn generates_css_root() {
+        let white = Color { red: 255, green: 255, blue: 255 };
+        let theme = Theme {
+            background: white,
+            on_background: Color { red: 0, green: 0, blue: 0 },
+            surface: white,
+            on_surface: Color { red: 17, green: 17, blue: 17 },
+            primary: Color { red: 0, green: 102, blue: 204 },
+            on_primary: white,
+            secondary: Color { red: 85, green: 85, blue: 85 },
+            on_secondary: white,
+            error: Color { red: 204, green: 0, blue: 0 },
+            on_error: white,
+        };
+        let css = theme.to_css_root();
+        
+        assert!(css.starts_with(":root {\n  --color-background: #FFFFFF;\n"));
+        assert!(css.contains("\n  --color-primary: #0066CC;\n  --color-on-primary: #FFFFFF;\n"));
+        assert!(css.ends_with("  --color-on-error: #FFFFFF;\n}"));
+        assert_eq!(css.lines().count(), 12);
+    }
+}
\ No newline at end of file
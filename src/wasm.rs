@@ -0,0 +1,131 @@
+//! Bindings for JavaScript using [wasm-bindgen](https://docs.rs/wasm-bindgen), so web projects can 
+//! use the same color math in JavaScript and Rust.
+//! 
+//! ```js
+//! import { JsColor } from "nice-colors";
+//! 
+//! const color = JsColor.parse("rebeccapurple");
+//! 
+//! console.log(color.blend(new JsColor(255, 255, 255), 0.5).toHex());
+//! ```
+
+use crate::Color;
+use wasm_bindgen::prelude::*;
+
+/// A color for use in JavaScript.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsColor(Color);
+
+#[wasm_bindgen]
+impl JsColor {
+    /// Creates a color from red, green, and blue values.
+    #[wasm_bindgen(constructor)]
+    pub fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self(Color { red, green, blue })
+    }
+    
+    /// Parses any supported color string. Throws an error describing why the string could not 
+    /// be parsed.
+    pub fn parse(s: &str) -> Result<JsColor, JsError> {
+        s.parse::<Color>().map(Self).map_err(|error| JsError::new(&error.to_string()))
+    }
+    
+    /// Creates a color from a decimal color value.
+    #[wasm_bindgen(js_name = fromDecimal)]
+    pub fn from_decimal(decimal: u32) -> Self {
+        Self(Color::from_decimal(decimal))
+    }
+    
+    /// The red value.
+    #[wasm_bindgen(getter)]
+    pub fn red(&self) -> u8 {
+        self.0.red
+    }
+    
+    /// The green value.
+    #[wasm_bindgen(getter)]
+    pub fn green(&self) -> u8 {
+        self.0.green
+    }
+    
+    /// The blue value.
+    #[wasm_bindgen(getter)]
+    pub fn blue(&self) -> u8 {
+        self.0.blue
+    }
+    
+    /// Converts this color into a hexadecimal color string, e.g. `#FF0000`.
+    #[wasm_bindgen(js_name = toHex)]
+    pub fn to_hex(&self) -> String {
+        self.0.to_hex_string()
+    }
+    
+    /// Converts this color into an rgb color string, e.g. `rgb(255 0 0)`.
+    #[wasm_bindgen(js_name = toRgb)]
+    pub fn to_rgb(&self) -> String {
+        self.0.to_rgb_string()
+    }
+    
+    /// Converts this color into a decimal color value.
+    #[wasm_bindgen(js_name = toDecimal)]
+    pub fn to_decimal(&self) -> u32 {
+        self.0.to_decimal()
+    }
+    
+    /// Blends this color with another color. See [`Color::blend`].
+    pub fn blend(&self, other: &JsColor, amount: f32) -> JsColor {
+        Self(self.0.blend(other.0, amount))
+    }
+    
+    /// Lightens this color. See [`Color::lighten`].
+    pub fn lighten(&self, amount: f32) -> JsColor {
+        Self(self.0.lighten(amount))
+    }
+    
+    /// Darkens this color. See [`Color::darken`].
+    pub fn darken(&self, amount: f32) -> JsColor {
+        Self(self.0.darken(amount))
+    }
+    
+    /// Gets the WCAG 2 relative luminance of this color. See [`Color::relative_luminance`].
+    #[wasm_bindgen(js_name = relativeLuminance)]
+    pub fn relative_luminance(&self) -> f32 {
+        self.0.relative_luminance()
+    }
+    
+    /// Gets the WCAG 2 contrast ratio between this color and another color. See 
+    /// [`Color::contrast_ratio`].
+    #[wasm_bindgen(js_name = contrastRatio)]
+    pub fn contrast_ratio(&self, other: &JsColor) -> f32 {
+        self.0.contrast_ratio(other.0)
+    }
+}
+
+impl From<Color> for JsColor {
+    fn from(color: Color) -> Self {
+        Self(color)
+    }
+}
+
+impl From<JsColor> for Color {
+    fn from(color: JsColor) -> Self {
+        color.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn wraps_color() {
+        let red = JsColor::new(255, 0, 0);
+        let white = JsColor::from_decimal(0xFFFFFF);
+        
+        assert_eq!(red.to_hex(), "#FF0000");
+        assert_eq!(red.blend(&white, 0.5).to_rgb(), "rgb(255 128 128)");
+        assert_eq!(Color::from(red.darken(0.5)), Color { red: 128, green: 0, blue: 0 });
+        assert!((red.contrast_ratio(&white) - 4.0).abs() < 0.01);
+    }
+}
\ No newline at end of file
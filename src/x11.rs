@@ -0,0 +1,822 @@
+//! X11 color names, as listed in the `rgb.txt` file shipped with the X Window System.
+//! 
+//! These are still used by legacy tooling and terminal emulator themes. Some names share a name 
+//! with a CSS color but not its value, e.g. "gray" is `#BEBEBE` and "green" is `#00FF00`. Names 
+//! are listed in lowercase, and names written both with and without spaces (e.g. "ghost white" and 
+//! "GhostWhite") are listed both ways.
+//! 
+//! # Examples
+//! ```
+//! use nice_colors::Color;
+//! use nice_colors::x11;
+//! 
+//! assert_eq!(x11::from_x11_color_name("Gray"), Some(Color { red: 190, green: 190, blue: 190 }));
+//! assert_eq!(x11::from_x11_color_name("DarkSlateGray4"), Some(Color::from_decimal(0x528B8B)));
+//! assert_eq!(x11::to_x11_color_name(&Color::from_decimal(0xF8F8FF)), Some("ghost white"));
+//! ```
+
+use super::Color;
+use crate::named;
+
+/// Every color name paired with its color, sorted by name.
+pub static COLOR_NAMES: [(&str, Color); 753] = [
+    ("alice blue", Color { red: 240, green: 248, blue: 255 }),
+    ("aliceblue", Color { red: 240, green: 248, blue: 255 }),
+    ("antique white", Color { red: 250, green: 235, blue: 215 }),
+    ("antiquewhite", Color { red: 250, green: 235, blue: 215 }),
+    ("antiquewhite1", Color { red: 255, green: 239, blue: 219 }),
+    ("antiquewhite2", Color { red: 238, green: 223, blue: 204 }),
+    ("antiquewhite3", Color { red: 205, green: 192, blue: 176 }),
+    ("antiquewhite4", Color { red: 139, green: 131, blue: 120 }),
+    ("aquamarine", Color { red: 127, green: 255, blue: 212 }),
+    ("aquamarine1", Color { red: 127, green: 255, blue: 212 }),
+    ("aquamarine2", Color { red: 118, green: 238, blue: 198 }),
+    ("aquamarine3", Color { red: 102, green: 205, blue: 170 }),
+    ("aquamarine4", Color { red: 69, green: 139, blue: 116 }),
+    ("azure", Color { red: 240, green: 255, blue: 255 }),
+    ("azure1", Color { red: 240, green: 255, blue: 255 }),
+    ("azure2", Color { red: 224, green: 238, blue: 238 }),
+    ("azure3", Color { red: 193, green: 205, blue: 205 }),
+    ("azure4", Color { red: 131, green: 139, blue: 139 }),
+    ("beige", Color { red: 245, green: 245, blue: 220 }),
+    ("bisque", Color { red: 255, green: 228, blue: 196 }),
+    ("bisque1", Color { red: 255, green: 228, blue: 196 }),
+    ("bisque2", Color { red: 238, green: 213, blue: 183 }),
+    ("bisque3", Color { red: 205, green: 183, blue: 158 }),
+    ("bisque4", Color { red: 139, green: 125, blue: 107 }),
+    ("black", Color { red: 0, green: 0, blue: 0 }),
+    ("blanched almond", Color { red: 255, green: 235, blue: 205 }),
+    ("blanchedalmond", Color { red: 255, green: 235, blue: 205 }),
+    ("blue", Color { red: 0, green: 0, blue: 255 }),
+    ("blue violet", Color { red: 138, green: 43, blue: 226 }),
+    ("blue1", Color { red: 0, green: 0, blue: 255 }),
+    ("blue2", Color { red: 0, green: 0, blue: 238 }),
+    ("blue3", Color { red: 0, green: 0, blue: 205 }),
+    ("blue4", Color { red: 0, green: 0, blue: 139 }),
+    ("blueviolet", Color { red: 138, green: 43, blue: 226 }),
+    ("brown", Color { red: 165, green: 42, blue: 42 }),
+    ("brown1", Color { red: 255, green: 64, blue: 64 }),
+    ("brown2", Color { red: 238, green: 59, blue: 59 }),
+    ("brown3", Color { red: 205, green: 51, blue: 51 }),
+    ("brown4", Color { red: 139, green: 35, blue: 35 }),
+    ("burlywood", Color { red: 222, green: 184, blue: 135 }),
+    ("burlywood1", Color { red: 255, green: 211, blue: 155 }),
+    ("burlywood2", Color { red: 238, green: 197, blue: 145 }),
+    ("burlywood3", Color { red: 205, green: 170, blue: 125 }),
+    ("burlywood4", Color { red: 139, green: 115, blue: 85 }),
+    ("cadet blue", Color { red: 95, green: 158, blue: 160 }),
+    ("cadetblue", Color { red: 95, green: 158, blue: 160 }),
+    ("cadetblue1", Color { red: 152, green: 245, blue: 255 }),
+    ("cadetblue2", Color { red: 142, green: 229, blue: 238 }),
+    ("cadetblue3", Color { red: 122, green: 197, blue: 205 }),
+    ("cadetblue4", Color { red: 83, green: 134, blue: 139 }),
+    ("chartreuse", Color { red: 127, green: 255, blue: 0 }),
+    ("chartreuse1", Color { red: 127, green: 255, blue: 0 }),
+    ("chartreuse2", Color { red: 118, green: 238, blue: 0 }),
+    ("chartreuse3", Color { red: 102, green: 205, blue: 0 }),
+    ("chartreuse4", Color { red: 69, green: 139, blue: 0 }),
+    ("chocolate", Color { red: 210, green: 105, blue: 30 }),
+    ("chocolate1", Color { red: 255, green: 127, blue: 36 }),
+    ("chocolate2", Color { red: 238, green: 118, blue: 33 }),
+    ("chocolate3", Color { red: 205, green: 102, blue: 29 }),
+    ("chocolate4", Color { red: 139, green: 69, blue: 19 }),
+    ("coral", Color { red: 255, green: 127, blue: 80 }),
+    ("coral1", Color { red: 255, green: 114, blue: 86 }),
+    ("coral2", Color { red: 238, green: 106, blue: 80 }),
+    ("coral3", Color { red: 205, green: 91, blue: 69 }),
+    ("coral4", Color { red: 139, green: 62, blue: 47 }),
+    ("cornflower blue", Color { red: 100, green: 149, blue: 237 }),
+    ("cornflowerblue", Color { red: 100, green: 149, blue: 237 }),
+    ("cornsilk", Color { red: 255, green: 248, blue: 220 }),
+    ("cornsilk1", Color { red: 255, green: 248, blue: 220 }),
+    ("cornsilk2", Color { red: 238, green: 232, blue: 205 }),
+    ("cornsilk3", Color { red: 205, green: 200, blue: 177 }),
+    ("cornsilk4", Color { red: 139, green: 136, blue: 120 }),
+    ("cyan", Color { red: 0, green: 255, blue: 255 }),
+    ("cyan1", Color { red: 0, green: 255, blue: 255 }),
+    ("cyan2", Color { red: 0, green: 238, blue: 238 }),
+    ("cyan3", Color { red: 0, green: 205, blue: 205 }),
+    ("cyan4", Color { red: 0, green: 139, blue: 139 }),
+    ("dark blue", Color { red: 0, green: 0, blue: 139 }),
+    ("dark cyan", Color { red: 0, green: 139, blue: 139 }),
+    ("dark goldenrod", Color { red: 184, green: 134, blue: 11 }),
+    ("dark gray", Color { red: 169, green: 169, blue: 169 }),
+    ("dark green", Color { red: 0, green: 100, blue: 0 }),
+    ("dark grey", Color { red: 169, green: 169, blue: 169 }),
+    ("dark khaki", Color { red: 189, green: 183, blue: 107 }),
+    ("dark magenta", Color { red: 139, green: 0, blue: 139 }),
+    ("dark olive green", Color { red: 85, green: 107, blue: 47 }),
+    ("dark orange", Color { red: 255, green: 140, blue: 0 }),
+    ("dark orchid", Color { red: 153, green: 50, blue: 204 }),
+    ("dark red", Color { red: 139, green: 0, blue: 0 }),
+    ("dark salmon", Color { red: 233, green: 150, blue: 122 }),
+    ("dark sea green", Color { red: 143, green: 188, blue: 143 }),
+    ("dark slate blue", Color { red: 72, green: 61, blue: 139 }),
+    ("dark slate gray", Color { red: 47, green: 79, blue: 79 }),
+    ("dark slate grey", Color { red: 47, green: 79, blue: 79 }),
+    ("dark turquoise", Color { red: 0, green: 206, blue: 209 }),
+    ("dark violet", Color { red: 148, green: 0, blue: 211 }),
+    ("darkblue", Color { red: 0, green: 0, blue: 139 }),
+    ("darkcyan", Color { red: 0, green: 139, blue: 139 }),
+    ("darkgoldenrod", Color { red: 184, green: 134, blue: 11 }),
+    ("darkgoldenrod1", Color { red: 255, green: 185, blue: 15 }),
+    ("darkgoldenrod2", Color { red: 238, green: 173, blue: 14 }),
+    ("darkgoldenrod3", Color { red: 205, green: 149, blue: 12 }),
+    ("darkgoldenrod4", Color { red: 139, green: 101, blue: 8 }),
+    ("darkgray", Color { red: 169, green: 169, blue: 169 }),
+    ("darkgreen", Color { red: 0, green: 100, blue: 0 }),
+    ("darkgrey", Color { red: 169, green: 169, blue: 169 }),
+    ("darkkhaki", Color { red: 189, green: 183, blue: 107 }),
+    ("darkmagenta", Color { red: 139, green: 0, blue: 139 }),
+    ("darkolivegreen", Color { red: 85, green: 107, blue: 47 }),
+    ("darkolivegreen1", Color { red: 202, green: 255, blue: 112 }),
+    ("darkolivegreen2", Color { red: 188, green: 238, blue: 104 }),
+    ("darkolivegreen3", Color { red: 162, green: 205, blue: 90 }),
+    ("darkolivegreen4", Color { red: 110, green: 139, blue: 61 }),
+    ("darkorange", Color { red: 255, green: 140, blue: 0 }),
+    ("darkorange1", Color { red: 255, green: 127, blue: 0 }),
+    ("darkorange2", Color { red: 238, green: 118, blue: 0 }),
+    ("darkorange3", Color { red: 205, green: 102, blue: 0 }),
+    ("darkorange4", Color { red: 139, green: 69, blue: 0 }),
+    ("darkorchid", Color { red: 153, green: 50, blue: 204 }),
+    ("darkorchid1", Color { red: 191, green: 62, blue: 255 }),
+    ("darkorchid2", Color { red: 178, green: 58, blue: 238 }),
+    ("darkorchid3", Color { red: 154, green: 50, blue: 205 }),
+    ("darkorchid4", Color { red: 104, green: 34, blue: 139 }),
+    ("darkred", Color { red: 139, green: 0, blue: 0 }),
+    ("darksalmon", Color { red: 233, green: 150, blue: 122 }),
+    ("darkseagreen", Color { red: 143, green: 188, blue: 143 }),
+    ("darkseagreen1", Color { red: 193, green: 255, blue: 193 }),
+    ("darkseagreen2", Color { red: 180, green: 238, blue: 180 }),
+    ("darkseagreen3", Color { red: 155, green: 205, blue: 155 }),
+    ("darkseagreen4", Color { red: 105, green: 139, blue: 105 }),
+    ("darkslateblue", Color { red: 72, green: 61, blue: 139 }),
+    ("darkslategray", Color { red: 47, green: 79, blue: 79 }),
+    ("darkslategray1", Color { red: 151, green: 255, blue: 255 }),
+    ("darkslategray2", Color { red: 141, green: 238, blue: 238 }),
+    ("darkslategray3", Color { red: 121, green: 205, blue: 205 }),
+    ("darkslategray4", Color { red: 82, green: 139, blue: 139 }),
+    ("darkslategrey", Color { red: 47, green: 79, blue: 79 }),
+    ("darkturquoise", Color { red: 0, green: 206, blue: 209 }),
+    ("darkviolet", Color { red: 148, green: 0, blue: 211 }),
+    ("debianred", Color { red: 215, green: 7, blue: 81 }),
+    ("deep pink", Color { red: 255, green: 20, blue: 147 }),
+    ("deep sky blue", Color { red: 0, green: 191, blue: 255 }),
+    ("deeppink", Color { red: 255, green: 20, blue: 147 }),
+    ("deeppink1", Color { red: 255, green: 20, blue: 147 }),
+    ("deeppink2", Color { red: 238, green: 18, blue: 137 }),
+    ("deeppink3", Color { red: 205, green: 16, blue: 118 }),
+    ("deeppink4", Color { red: 139, green: 10, blue: 80 }),
+    ("deepskyblue", Color { red: 0, green: 191, blue: 255 }),
+    ("deepskyblue1", Color { red: 0, green: 191, blue: 255 }),
+    ("deepskyblue2", Color { red: 0, green: 178, blue: 238 }),
+    ("deepskyblue3", Color { red: 0, green: 154, blue: 205 }),
+    ("deepskyblue4", Color { red: 0, green: 104, blue: 139 }),
+    ("dim gray", Color { red: 105, green: 105, blue: 105 }),
+    ("dim grey", Color { red: 105, green: 105, blue: 105 }),
+    ("dimgray", Color { red: 105, green: 105, blue: 105 }),
+    ("dimgrey", Color { red: 105, green: 105, blue: 105 }),
+    ("dodger blue", Color { red: 30, green: 144, blue: 255 }),
+    ("dodgerblue", Color { red: 30, green: 144, blue: 255 }),
+    ("dodgerblue1", Color { red: 30, green: 144, blue: 255 }),
+    ("dodgerblue2", Color { red: 28, green: 134, blue: 238 }),
+    ("dodgerblue3", Color { red: 24, green: 116, blue: 205 }),
+    ("dodgerblue4", Color { red: 16, green: 78, blue: 139 }),
+    ("firebrick", Color { red: 178, green: 34, blue: 34 }),
+    ("firebrick1", Color { red: 255, green: 48, blue: 48 }),
+    ("firebrick2", Color { red: 238, green: 44, blue: 44 }),
+    ("firebrick3", Color { red: 205, green: 38, blue: 38 }),
+    ("firebrick4", Color { red: 139, green: 26, blue: 26 }),
+    ("floral white", Color { red: 255, green: 250, blue: 240 }),
+    ("floralwhite", Color { red: 255, green: 250, blue: 240 }),
+    ("forest green", Color { red: 34, green: 139, blue: 34 }),
+    ("forestgreen", Color { red: 34, green: 139, blue: 34 }),
+    ("gainsboro", Color { red: 220, green: 220, blue: 220 }),
+    ("ghost white", Color { red: 248, green: 248, blue: 255 }),
+    ("ghostwhite", Color { red: 248, green: 248, blue: 255 }),
+    ("gold", Color { red: 255, green: 215, blue: 0 }),
+    ("gold1", Color { red: 255, green: 215, blue: 0 }),
+    ("gold2", Color { red: 238, green: 201, blue: 0 }),
+    ("gold3", Color { red: 205, green: 173, blue: 0 }),
+    ("gold4", Color { red: 139, green: 117, blue: 0 }),
+    ("goldenrod", Color { red: 218, green: 165, blue: 32 }),
+    ("goldenrod1", Color { red: 255, green: 193, blue: 37 }),
+    ("goldenrod2", Color { red: 238, green: 180, blue: 34 }),
+    ("goldenrod3", Color { red: 205, green: 155, blue: 29 }),
+    ("goldenrod4", Color { red: 139, green: 105, blue: 20 }),
+    ("gray", Color { red: 190, green: 190, blue: 190 }),
+    ("gray0", Color { red: 0, green: 0, blue: 0 }),
+    ("gray1", Color { red: 3, green: 3, blue: 3 }),
+    ("gray10", Color { red: 26, green: 26, blue: 26 }),
+    ("gray100", Color { red: 255, green: 255, blue: 255 }),
+    ("gray11", Color { red: 28, green: 28, blue: 28 }),
+    ("gray12", Color { red: 31, green: 31, blue: 31 }),
+    ("gray13", Color { red: 33, green: 33, blue: 33 }),
+    ("gray14", Color { red: 36, green: 36, blue: 36 }),
+    ("gray15", Color { red: 38, green: 38, blue: 38 }),
+    ("gray16", Color { red: 41, green: 41, blue: 41 }),
+    ("gray17", Color { red: 43, green: 43, blue: 43 }),
+    ("gray18", Color { red: 46, green: 46, blue: 46 }),
+    ("gray19", Color { red: 48, green: 48, blue: 48 }),
+    ("gray2", Color { red: 5, green: 5, blue: 5 }),
+    ("gray20", Color { red: 51, green: 51, blue: 51 }),
+    ("gray21", Color { red: 54, green: 54, blue: 54 }),
+    ("gray22", Color { red: 56, green: 56, blue: 56 }),
+    ("gray23", Color { red: 59, green: 59, blue: 59 }),
+    ("gray24", Color { red: 61, green: 61, blue: 61 }),
+    ("gray25", Color { red: 64, green: 64, blue: 64 }),
+    ("gray26", Color { red: 66, green: 66, blue: 66 }),
+    ("gray27", Color { red: 69, green: 69, blue: 69 }),
+    ("gray28", Color { red: 71, green: 71, blue: 71 }),
+    ("gray29", Color { red: 74, green: 74, blue: 74 }),
+    ("gray3", Color { red: 8, green: 8, blue: 8 }),
+    ("gray30", Color { red: 77, green: 77, blue: 77 }),
+    ("gray31", Color { red: 79, green: 79, blue: 79 }),
+    ("gray32", Color { red: 82, green: 82, blue: 82 }),
+    ("gray33", Color { red: 84, green: 84, blue: 84 }),
+    ("gray34", Color { red: 87, green: 87, blue: 87 }),
+    ("gray35", Color { red: 89, green: 89, blue: 89 }),
+    ("gray36", Color { red: 92, green: 92, blue: 92 }),
+    ("gray37", Color { red: 94, green: 94, blue: 94 }),
+    ("gray38", Color { red: 97, green: 97, blue: 97 }),
+    ("gray39", Color { red: 99, green: 99, blue: 99 }),
+    ("gray4", Color { red: 10, green: 10, blue: 10 }),
+    ("gray40", Color { red: 102, green: 102, blue: 102 }),
+    ("gray41", Color { red: 105, green: 105, blue: 105 }),
+    ("gray42", Color { red: 107, green: 107, blue: 107 }),
+    ("gray43", Color { red: 110, green: 110, blue: 110 }),
+    ("gray44", Color { red: 112, green: 112, blue: 112 }),
+    ("gray45", Color { red: 115, green: 115, blue: 115 }),
+    ("gray46", Color { red: 117, green: 117, blue: 117 }),
+    ("gray47", Color { red: 120, green: 120, blue: 120 }),
+    ("gray48", Color { red: 122, green: 122, blue: 122 }),
+    ("gray49", Color { red: 125, green: 125, blue: 125 }),
+    ("gray5", Color { red: 13, green: 13, blue: 13 }),
+    ("gray50", Color { red: 127, green: 127, blue: 127 }),
+    ("gray51", Color { red: 130, green: 130, blue: 130 }),
+    ("gray52", Color { red: 133, green: 133, blue: 133 }),
+    ("gray53", Color { red: 135, green: 135, blue: 135 }),
+    ("gray54", Color { red: 138, green: 138, blue: 138 }),
+    ("gray55", Color { red: 140, green: 140, blue: 140 }),
+    ("gray56", Color { red: 143, green: 143, blue: 143 }),
+    ("gray57", Color { red: 145, green: 145, blue: 145 }),
+    ("gray58", Color { red: 148, green: 148, blue: 148 }),
+    ("gray59", Color { red: 150, green: 150, blue: 150 }),
+    ("gray6", Color { red: 15, green: 15, blue: 15 }),
+    ("gray60", Color { red: 153, green: 153, blue: 153 }),
+    ("gray61", Color { red: 156, green: 156, blue: 156 }),
+    ("gray62", Color { red: 158, green: 158, blue: 158 }),
+    ("gray63", Color { red: 161, green: 161, blue: 161 }),
+    ("gray64", Color { red: 163, green: 163, blue: 163 }),
+    ("gray65", Color { red: 166, green: 166, blue: 166 }),
+    ("gray66", Color { red: 168, green: 168, blue: 168 }),
+    ("gray67", Color { red: 171, green: 171, blue: 171 }),
+    ("gray68", Color { red: 173, green: 173, blue: 173 }),
+    ("gray69", Color { red: 176, green: 176, blue: 176 }),
+    ("gray7", Color { red: 18, green: 18, blue: 18 }),
+    ("gray70", Color { red: 179, green: 179, blue: 179 }),
+    ("gray71", Color { red: 181, green: 181, blue: 181 }),
+    ("gray72", Color { red: 184, green: 184, blue: 184 }),
+    ("gray73", Color { red: 186, green: 186, blue: 186 }),
+    ("gray74", Color { red: 189, green: 189, blue: 189 }),
+    ("gray75", Color { red: 191, green: 191, blue: 191 }),
+    ("gray76", Color { red: 194, green: 194, blue: 194 }),
+    ("gray77", Color { red: 196, green: 196, blue: 196 }),
+    ("gray78", Color { red: 199, green: 199, blue: 199 }),
+    ("gray79", Color { red: 201, green: 201, blue: 201 }),
+    ("gray8", Color { red: 20, green: 20, blue: 20 }),
+    ("gray80", Color { red: 204, green: 204, blue: 204 }),
+    ("gray81", Color { red: 207, green: 207, blue: 207 }),
+    ("gray82", Color { red: 209, green: 209, blue: 209 }),
+    ("gray83", Color { red: 212, green: 212, blue: 212 }),
+    ("gray84", Color { red: 214, green: 214, blue: 214 }),
+    ("gray85", Color { red: 217, green: 217, blue: 217 }),
+    ("gray86", Color { red: 219, green: 219, blue: 219 }),
+    ("gray87", Color { red: 222, green: 222, blue: 222 }),
+    ("gray88", Color { red: 224, green: 224, blue: 224 }),
+    ("gray89", Color { red: 227, green: 227, blue: 227 }),
+    ("gray9", Color { red: 23, green: 23, blue: 23 }),
+    ("gray90", Color { red: 229, green: 229, blue: 229 }),
+    ("gray91", Color { red: 232, green: 232, blue: 232 }),
+    ("gray92", Color { red: 235, green: 235, blue: 235 }),
+    ("gray93", Color { red: 237, green: 237, blue: 237 }),
+    ("gray94", Color { red: 240, green: 240, blue: 240 }),
+    ("gray95", Color { red: 242, green: 242, blue: 242 }),
+    ("gray96", Color { red: 245, green: 245, blue: 245 }),
+    ("gray97", Color { red: 247, green: 247, blue: 247 }),
+    ("gray98", Color { red: 250, green: 250, blue: 250 }),
+    ("gray99", Color { red: 252, green: 252, blue: 252 }),
+    ("green", Color { red: 0, green: 255, blue: 0 }),
+    ("green yellow", Color { red: 173, green: 255, blue: 47 }),
+    ("green1", Color { red: 0, green: 255, blue: 0 }),
+    ("green2", Color { red: 0, green: 238, blue: 0 }),
+    ("green3", Color { red: 0, green: 205, blue: 0 }),
+    ("green4", Color { red: 0, green: 139, blue: 0 }),
+    ("greenyellow", Color { red: 173, green: 255, blue: 47 }),
+    ("grey", Color { red: 190, green: 190, blue: 190 }),
+    ("grey0", Color { red: 0, green: 0, blue: 0 }),
+    ("grey1", Color { red: 3, green: 3, blue: 3 }),
+    ("grey10", Color { red: 26, green: 26, blue: 26 }),
+    ("grey100", Color { red: 255, green: 255, blue: 255 }),
+    ("grey11", Color { red: 28, green: 28, blue: 28 }),
+    ("grey12", Color { red: 31, green: 31, blue: 31 }),
+    ("grey13", Color { red: 33, green: 33, blue: 33 }),
+    ("grey14", Color { red: 36, green: 36, blue: 36 }),
+    ("grey15", Color { red: 38, green: 38, blue: 38 }),
+    ("grey16", Color { red: 41, green: 41, blue: 41 }),
+    ("grey17", Color { red: 43, green: 43, blue: 43 }),
+    ("grey18", Color { red: 46, green: 46, blue: 46 }),
+    ("grey19", Color { red: 48, green: 48, blue: 48 }),
+    ("grey2", Color { red: 5, green: 5, blue: 5 }),
+    ("grey20", Color { red: 51, green: 51, blue: 51 }),
+    ("grey21", Color { red: 54, green: 54, blue: 54 }),
+    ("grey22", Color { red: 56, green: 56, blue: 56 }),
+    ("grey23", Color { red: 59, green: 59, blue: 59 }),
+    ("grey24", Color { red: 61, green: 61, blue: 61 }),
+    ("grey25", Color { red: 64, green: 64, blue: 64 }),
+    ("grey26", Color { red: 66, green: 66, blue: 66 }),
+    ("grey27", Color { red: 69, green: 69, blue: 69 }),
+    ("grey28", Color { red: 71, green: 71, blue: 71 }),
+    ("grey29", Color { red: 74, green: 74, blue: 74 }),
+    ("grey3", Color { red: 8, green: 8, blue: 8 }),
+    ("grey30", Color { red: 77, green: 77, blue: 77 }),
+    ("grey31", Color { red: 79, green: 79, blue: 79 }),
+    ("grey32", Color { red: 82, green: 82, blue: 82 }),
+    ("grey33", Color { red: 84, green: 84, blue: 84 }),
+    ("grey34", Color { red: 87, green: 87, blue: 87 }),
+    ("grey35", Color { red: 89, green: 89, blue: 89 }),
+    ("grey36", Color { red: 92, green: 92, blue: 92 }),
+    ("grey37", Color { red: 94, green: 94, blue: 94 }),
+    ("grey38", Color { red: 97, green: 97, blue: 97 }),
+    ("grey39", Color { red: 99, green: 99, blue: 99 }),
+    ("grey4", Color { red: 10, green: 10, blue: 10 }),
+    ("grey40", Color { red: 102, green: 102, blue: 102 }),
+    ("grey41", Color { red: 105, green: 105, blue: 105 }),
+    ("grey42", Color { red: 107, green: 107, blue: 107 }),
+    ("grey43", Color { red: 110, green: 110, blue: 110 }),
+    ("grey44", Color { red: 112, green: 112, blue: 112 }),
+    ("grey45", Color { red: 115, green: 115, blue: 115 }),
+    ("grey46", Color { red: 117, green: 117, blue: 117 }),
+    ("grey47", Color { red: 120, green: 120, blue: 120 }),
+    ("grey48", Color { red: 122, green: 122, blue: 122 }),
+    ("grey49", Color { red: 125, green: 125, blue: 125 }),
+    ("grey5", Color { red: 13, green: 13, blue: 13 }),
+    ("grey50", Color { red: 127, green: 127, blue: 127 }),
+    ("grey51", Color { red: 130, green: 130, blue: 130 }),
+    ("grey52", Color { red: 133, green: 133, blue: 133 }),
+    ("grey53", Color { red: 135, green: 135, blue: 135 }),
+    ("grey54", Color { red: 138, green: 138, blue: 138 }),
+    ("grey55", Color { red: 140, green: 140, blue: 140 }),
+    ("grey56", Color { red: 143, green: 143, blue: 143 }),
+    ("grey57", Color { red: 145, green: 145, blue: 145 }),
+    ("grey58", Color { red: 148, green: 148, blue: 148 }),
+    ("grey59", Color { red: 150, green: 150, blue: 150 }),
+    ("grey6", Color { red: 15, green: 15, blue: 15 }),
+    ("grey60", Color { red: 153, green: 153, blue: 153 }),
+    ("grey61", Color { red: 156, green: 156, blue: 156 }),
+    ("grey62", Color { red: 158, green: 158, blue: 158 }),
+    ("grey63", Color { red: 161, green: 161, blue: 161 }),
+    ("grey64", Color { red: 163, green: 163, blue: 163 }),
+    ("grey65", Color { red: 166, green: 166, blue: 166 }),
+    ("grey66", Color { red: 168, green: 168, blue: 168 }),
+    ("grey67", Color { red: 171, green: 171, blue: 171 }),
+    ("grey68", Color { red: 173, green: 173, blue: 173 }),
+    ("grey69", Color { red: 176, green: 176, blue: 176 }),
+    ("grey7", Color { red: 18, green: 18, blue: 18 }),
+    ("grey70", Color { red: 179, green: 179, blue: 179 }),
+    ("grey71", Color { red: 181, green: 181, blue: 181 }),
+    ("grey72", Color { red: 184, green: 184, blue: 184 }),
+    ("grey73", Color { red: 186, green: 186, blue: 186 }),
+    ("grey74", Color { red: 189, green: 189, blue: 189 }),
+    ("grey75", Color { red: 191, green: 191, blue: 191 }),
+    ("grey76", Color { red: 194, green: 194, blue: 194 }),
+    ("grey77", Color { red: 196, green: 196, blue: 196 }),
+    ("grey78", Color { red: 199, green: 199, blue: 199 }),
+    ("grey79", Color { red: 201, green: 201, blue: 201 }),
+    ("grey8", Color { red: 20, green: 20, blue: 20 }),
+    ("grey80", Color { red: 204, green: 204, blue: 204 }),
+    ("grey81", Color { red: 207, green: 207, blue: 207 }),
+    ("grey82", Color { red: 209, green: 209, blue: 209 }),
+    ("grey83", Color { red: 212, green: 212, blue: 212 }),
+    ("grey84", Color { red: 214, green: 214, blue: 214 }),
+    ("grey85", Color { red: 217, green: 217, blue: 217 }),
+    ("grey86", Color { red: 219, green: 219, blue: 219 }),
+    ("grey87", Color { red: 222, green: 222, blue: 222 }),
+    ("grey88", Color { red: 224, green: 224, blue: 224 }),
+    ("grey89", Color { red: 227, green: 227, blue: 227 }),
+    ("grey9", Color { red: 23, green: 23, blue: 23 }),
+    ("grey90", Color { red: 229, green: 229, blue: 229 }),
+    ("grey91", Color { red: 232, green: 232, blue: 232 }),
+    ("grey92", Color { red: 235, green: 235, blue: 235 }),
+    ("grey93", Color { red: 237, green: 237, blue: 237 }),
+    ("grey94", Color { red: 240, green: 240, blue: 240 }),
+    ("grey95", Color { red: 242, green: 242, blue: 242 }),
+    ("grey96", Color { red: 245, green: 245, blue: 245 }),
+    ("grey97", Color { red: 247, green: 247, blue: 247 }),
+    ("grey98", Color { red: 250, green: 250, blue: 250 }),
+    ("grey99", Color { red: 252, green: 252, blue: 252 }),
+    ("honeydew", Color { red: 240, green: 255, blue: 240 }),
+    ("honeydew1", Color { red: 240, green: 255, blue: 240 }),
+    ("honeydew2", Color { red: 224, green: 238, blue: 224 }),
+    ("honeydew3", Color { red: 193, green: 205, blue: 193 }),
+    ("honeydew4", Color { red: 131, green: 139, blue: 131 }),
+    ("hot pink", Color { red: 255, green: 105, blue: 180 }),
+    ("hotpink", Color { red: 255, green: 105, blue: 180 }),
+    ("hotpink1", Color { red: 255, green: 110, blue: 180 }),
+    ("hotpink2", Color { red: 238, green: 106, blue: 167 }),
+    ("hotpink3", Color { red: 205, green: 96, blue: 144 }),
+    ("hotpink4", Color { red: 139, green: 58, blue: 98 }),
+    ("indian red", Color { red: 205, green: 92, blue: 92 }),
+    ("indianred", Color { red: 205, green: 92, blue: 92 }),
+    ("indianred1", Color { red: 255, green: 106, blue: 106 }),
+    ("indianred2", Color { red: 238, green: 99, blue: 99 }),
+    ("indianred3", Color { red: 205, green: 85, blue: 85 }),
+    ("indianred4", Color { red: 139, green: 58, blue: 58 }),
+    ("ivory", Color { red: 255, green: 255, blue: 240 }),
+    ("ivory1", Color { red: 255, green: 255, blue: 240 }),
+    ("ivory2", Color { red: 238, green: 238, blue: 224 }),
+    ("ivory3", Color { red: 205, green: 205, blue: 193 }),
+    ("ivory4", Color { red: 139, green: 139, blue: 131 }),
+    ("khaki", Color { red: 240, green: 230, blue: 140 }),
+    ("khaki1", Color { red: 255, green: 246, blue: 143 }),
+    ("khaki2", Color { red: 238, green: 230, blue: 133 }),
+    ("khaki3", Color { red: 205, green: 198, blue: 115 }),
+    ("khaki4", Color { red: 139, green: 134, blue: 78 }),
+    ("lavender", Color { red: 230, green: 230, blue: 250 }),
+    ("lavender blush", Color { red: 255, green: 240, blue: 245 }),
+    ("lavenderblush", Color { red: 255, green: 240, blue: 245 }),
+    ("lavenderblush1", Color { red: 255, green: 240, blue: 245 }),
+    ("lavenderblush2", Color { red: 238, green: 224, blue: 229 }),
+    ("lavenderblush3", Color { red: 205, green: 193, blue: 197 }),
+    ("lavenderblush4", Color { red: 139, green: 131, blue: 134 }),
+    ("lawn green", Color { red: 124, green: 252, blue: 0 }),
+    ("lawngreen", Color { red: 124, green: 252, blue: 0 }),
+    ("lemon chiffon", Color { red: 255, green: 250, blue: 205 }),
+    ("lemonchiffon", Color { red: 255, green: 250, blue: 205 }),
+    ("lemonchiffon1", Color { red: 255, green: 250, blue: 205 }),
+    ("lemonchiffon2", Color { red: 238, green: 233, blue: 191 }),
+    ("lemonchiffon3", Color { red: 205, green: 201, blue: 165 }),
+    ("lemonchiffon4", Color { red: 139, green: 137, blue: 112 }),
+    ("light blue", Color { red: 173, green: 216, blue: 230 }),
+    ("light coral", Color { red: 240, green: 128, blue: 128 }),
+    ("light cyan", Color { red: 224, green: 255, blue: 255 }),
+    ("light goldenrod", Color { red: 238, green: 221, blue: 130 }),
+    ("light goldenrod yellow", Color { red: 250, green: 250, blue: 210 }),
+    ("light gray", Color { red: 211, green: 211, blue: 211 }),
+    ("light green", Color { red: 144, green: 238, blue: 144 }),
+    ("light grey", Color { red: 211, green: 211, blue: 211 }),
+    ("light pink", Color { red: 255, green: 182, blue: 193 }),
+    ("light salmon", Color { red: 255, green: 160, blue: 122 }),
+    ("light sea green", Color { red: 32, green: 178, blue: 170 }),
+    ("light sky blue", Color { red: 135, green: 206, blue: 250 }),
+    ("light slate blue", Color { red: 132, green: 112, blue: 255 }),
+    ("light slate gray", Color { red: 119, green: 136, blue: 153 }),
+    ("light slate grey", Color { red: 119, green: 136, blue: 153 }),
+    ("light steel blue", Color { red: 176, green: 196, blue: 222 }),
+    ("light yellow", Color { red: 255, green: 255, blue: 224 }),
+    ("lightblue", Color { red: 173, green: 216, blue: 230 }),
+    ("lightblue1", Color { red: 191, green: 239, blue: 255 }),
+    ("lightblue2", Color { red: 178, green: 223, blue: 238 }),
+    ("lightblue3", Color { red: 154, green: 192, blue: 205 }),
+    ("lightblue4", Color { red: 104, green: 131, blue: 139 }),
+    ("lightcoral", Color { red: 240, green: 128, blue: 128 }),
+    ("lightcyan", Color { red: 224, green: 255, blue: 255 }),
+    ("lightcyan1", Color { red: 224, green: 255, blue: 255 }),
+    ("lightcyan2", Color { red: 209, green: 238, blue: 238 }),
+    ("lightcyan3", Color { red: 180, green: 205, blue: 205 }),
+    ("lightcyan4", Color { red: 122, green: 139, blue: 139 }),
+    ("lightgoldenrod", Color { red: 238, green: 221, blue: 130 }),
+    ("lightgoldenrod1", Color { red: 255, green: 236, blue: 139 }),
+    ("lightgoldenrod2", Color { red: 238, green: 220, blue: 130 }),
+    ("lightgoldenrod3", Color { red: 205, green: 190, blue: 112 }),
+    ("lightgoldenrod4", Color { red: 139, green: 129, blue: 76 }),
+    ("lightgoldenrodyellow", Color { red: 250, green: 250, blue: 210 }),
+    ("lightgray", Color { red: 211, green: 211, blue: 211 }),
+    ("lightgreen", Color { red: 144, green: 238, blue: 144 }),
+    ("lightgrey", Color { red: 211, green: 211, blue: 211 }),
+    ("lightpink", Color { red: 255, green: 182, blue: 193 }),
+    ("lightpink1", Color { red: 255, green: 174, blue: 185 }),
+    ("lightpink2", Color { red: 238, green: 162, blue: 173 }),
+    ("lightpink3", Color { red: 205, green: 140, blue: 149 }),
+    ("lightpink4", Color { red: 139, green: 95, blue: 101 }),
+    ("lightsalmon", Color { red: 255, green: 160, blue: 122 }),
+    ("lightsalmon1", Color { red: 255, green: 160, blue: 122 }),
+    ("lightsalmon2", Color { red: 238, green: 149, blue: 114 }),
+    ("lightsalmon3", Color { red: 205, green: 129, blue: 98 }),
+    ("lightsalmon4", Color { red: 139, green: 87, blue: 66 }),
+    ("lightseagreen", Color { red: 32, green: 178, blue: 170 }),
+    ("lightskyblue", Color { red: 135, green: 206, blue: 250 }),
+    ("lightskyblue1", Color { red: 176, green: 226, blue: 255 }),
+    ("lightskyblue2", Color { red: 164, green: 211, blue: 238 }),
+    ("lightskyblue3", Color { red: 141, green: 182, blue: 205 }),
+    ("lightskyblue4", Color { red: 96, green: 123, blue: 139 }),
+    ("lightslateblue", Color { red: 132, green: 112, blue: 255 }),
+    ("lightslategray", Color { red: 119, green: 136, blue: 153 }),
+    ("lightslategrey", Color { red: 119, green: 136, blue: 153 }),
+    ("lightsteelblue", Color { red: 176, green: 196, blue: 222 }),
+    ("lightsteelblue1", Color { red: 202, green: 225, blue: 255 }),
+    ("lightsteelblue2", Color { red: 188, green: 210, blue: 238 }),
+    ("lightsteelblue3", Color { red: 162, green: 181, blue: 205 }),
+    ("lightsteelblue4", Color { red: 110, green: 123, blue: 139 }),
+    ("lightyellow", Color { red: 255, green: 255, blue: 224 }),
+    ("lightyellow1", Color { red: 255, green: 255, blue: 224 }),
+    ("lightyellow2", Color { red: 238, green: 238, blue: 209 }),
+    ("lightyellow3", Color { red: 205, green: 205, blue: 180 }),
+    ("lightyellow4", Color { red: 139, green: 139, blue: 122 }),
+    ("lime green", Color { red: 50, green: 205, blue: 50 }),
+    ("limegreen", Color { red: 50, green: 205, blue: 50 }),
+    ("linen", Color { red: 250, green: 240, blue: 230 }),
+    ("magenta", Color { red: 255, green: 0, blue: 255 }),
+    ("magenta1", Color { red: 255, green: 0, blue: 255 }),
+    ("magenta2", Color { red: 238, green: 0, blue: 238 }),
+    ("magenta3", Color { red: 205, green: 0, blue: 205 }),
+    ("magenta4", Color { red: 139, green: 0, blue: 139 }),
+    ("maroon", Color { red: 176, green: 48, blue: 96 }),
+    ("maroon1", Color { red: 255, green: 52, blue: 179 }),
+    ("maroon2", Color { red: 238, green: 48, blue: 167 }),
+    ("maroon3", Color { red: 205, green: 41, blue: 144 }),
+    ("maroon4", Color { red: 139, green: 28, blue: 98 }),
+    ("medium aquamarine", Color { red: 102, green: 205, blue: 170 }),
+    ("medium blue", Color { red: 0, green: 0, blue: 205 }),
+    ("medium orchid", Color { red: 186, green: 85, blue: 211 }),
+    ("medium purple", Color { red: 147, green: 112, blue: 219 }),
+    ("medium sea green", Color { red: 60, green: 179, blue: 113 }),
+    ("medium slate blue", Color { red: 123, green: 104, blue: 238 }),
+    ("medium spring green", Color { red: 0, green: 250, blue: 154 }),
+    ("medium turquoise", Color { red: 72, green: 209, blue: 204 }),
+    ("medium violet red", Color { red: 199, green: 21, blue: 133 }),
+    ("mediumaquamarine", Color { red: 102, green: 205, blue: 170 }),
+    ("mediumblue", Color { red: 0, green: 0, blue: 205 }),
+    ("mediumorchid", Color { red: 186, green: 85, blue: 211 }),
+    ("mediumorchid1", Color { red: 224, green: 102, blue: 255 }),
+    ("mediumorchid2", Color { red: 209, green: 95, blue: 238 }),
+    ("mediumorchid3", Color { red: 180, green: 82, blue: 205 }),
+    ("mediumorchid4", Color { red: 122, green: 55, blue: 139 }),
+    ("mediumpurple", Color { red: 147, green: 112, blue: 219 }),
+    ("mediumpurple1", Color { red: 171, green: 130, blue: 255 }),
+    ("mediumpurple2", Color { red: 159, green: 121, blue: 238 }),
+    ("mediumpurple3", Color { red: 137, green: 104, blue: 205 }),
+    ("mediumpurple4", Color { red: 93, green: 71, blue: 139 }),
+    ("mediumseagreen", Color { red: 60, green: 179, blue: 113 }),
+    ("mediumslateblue", Color { red: 123, green: 104, blue: 238 }),
+    ("mediumspringgreen", Color { red: 0, green: 250, blue: 154 }),
+    ("mediumturquoise", Color { red: 72, green: 209, blue: 204 }),
+    ("mediumvioletred", Color { red: 199, green: 21, blue: 133 }),
+    ("midnight blue", Color { red: 25, green: 25, blue: 112 }),
+    ("midnightblue", Color { red: 25, green: 25, blue: 112 }),
+    ("mint cream", Color { red: 245, green: 255, blue: 250 }),
+    ("mintcream", Color { red: 245, green: 255, blue: 250 }),
+    ("misty rose", Color { red: 255, green: 228, blue: 225 }),
+    ("mistyrose", Color { red: 255, green: 228, blue: 225 }),
+    ("mistyrose1", Color { red: 255, green: 228, blue: 225 }),
+    ("mistyrose2", Color { red: 238, green: 213, blue: 210 }),
+    ("mistyrose3", Color { red: 205, green: 183, blue: 181 }),
+    ("mistyrose4", Color { red: 139, green: 125, blue: 123 }),
+    ("moccasin", Color { red: 255, green: 228, blue: 181 }),
+    ("navajo white", Color { red: 255, green: 222, blue: 173 }),
+    ("navajowhite", Color { red: 255, green: 222, blue: 173 }),
+    ("navajowhite1", Color { red: 255, green: 222, blue: 173 }),
+    ("navajowhite2", Color { red: 238, green: 207, blue: 161 }),
+    ("navajowhite3", Color { red: 205, green: 179, blue: 139 }),
+    ("navajowhite4", Color { red: 139, green: 121, blue: 94 }),
+    ("navy", Color { red: 0, green: 0, blue: 128 }),
+    ("navy blue", Color { red: 0, green: 0, blue: 128 }),
+    ("navyblue", Color { red: 0, green: 0, blue: 128 }),
+    ("old lace", Color { red: 253, green: 245, blue: 230 }),
+    ("oldlace", Color { red: 253, green: 245, blue: 230 }),
+    ("olive drab", Color { red: 107, green: 142, blue: 35 }),
+    ("olivedrab", Color { red: 107, green: 142, blue: 35 }),
+    ("olivedrab1", Color { red: 192, green: 255, blue: 62 }),
+    ("olivedrab2", Color { red: 179, green: 238, blue: 58 }),
+    ("olivedrab3", Color { red: 154, green: 205, blue: 50 }),
+    ("olivedrab4", Color { red: 105, green: 139, blue: 34 }),
+    ("orange", Color { red: 255, green: 165, blue: 0 }),
+    ("orange red", Color { red: 255, green: 69, blue: 0 }),
+    ("orange1", Color { red: 255, green: 165, blue: 0 }),
+    ("orange2", Color { red: 238, green: 154, blue: 0 }),
+    ("orange3", Color { red: 205, green: 133, blue: 0 }),
+    ("orange4", Color { red: 139, green: 90, blue: 0 }),
+    ("orangered", Color { red: 255, green: 69, blue: 0 }),
+    ("orangered1", Color { red: 255, green: 69, blue: 0 }),
+    ("orangered2", Color { red: 238, green: 64, blue: 0 }),
+    ("orangered3", Color { red: 205, green: 55, blue: 0 }),
+    ("orangered4", Color { red: 139, green: 37, blue: 0 }),
+    ("orchid", Color { red: 218, green: 112, blue: 214 }),
+    ("orchid1", Color { red: 255, green: 131, blue: 250 }),
+    ("orchid2", Color { red: 238, green: 122, blue: 233 }),
+    ("orchid3", Color { red: 205, green: 105, blue: 201 }),
+    ("orchid4", Color { red: 139, green: 71, blue: 137 }),
+    ("pale goldenrod", Color { red: 238, green: 232, blue: 170 }),
+    ("pale green", Color { red: 152, green: 251, blue: 152 }),
+    ("pale turquoise", Color { red: 175, green: 238, blue: 238 }),
+    ("pale violet red", Color { red: 219, green: 112, blue: 147 }),
+    ("palegoldenrod", Color { red: 238, green: 232, blue: 170 }),
+    ("palegreen", Color { red: 152, green: 251, blue: 152 }),
+    ("palegreen1", Color { red: 154, green: 255, blue: 154 }),
+    ("palegreen2", Color { red: 144, green: 238, blue: 144 }),
+    ("palegreen3", Color { red: 124, green: 205, blue: 124 }),
+    ("palegreen4", Color { red: 84, green: 139, blue: 84 }),
+    ("paleturquoise", Color { red: 175, green: 238, blue: 238 }),
+    ("paleturquoise1", Color { red: 187, green: 255, blue: 255 }),
+    ("paleturquoise2", Color { red: 174, green: 238, blue: 238 }),
+    ("paleturquoise3", Color { red: 150, green: 205, blue: 205 }),
+    ("paleturquoise4", Color { red: 102, green: 139, blue: 139 }),
+    ("palevioletred", Color { red: 219, green: 112, blue: 147 }),
+    ("palevioletred1", Color { red: 255, green: 130, blue: 171 }),
+    ("palevioletred2", Color { red: 238, green: 121, blue: 159 }),
+    ("palevioletred3", Color { red: 205, green: 104, blue: 137 }),
+    ("palevioletred4", Color { red: 139, green: 71, blue: 93 }),
+    ("papaya whip", Color { red: 255, green: 239, blue: 213 }),
+    ("papayawhip", Color { red: 255, green: 239, blue: 213 }),
+    ("peach puff", Color { red: 255, green: 218, blue: 185 }),
+    ("peachpuff", Color { red: 255, green: 218, blue: 185 }),
+    ("peachpuff1", Color { red: 255, green: 218, blue: 185 }),
+    ("peachpuff2", Color { red: 238, green: 203, blue: 173 }),
+    ("peachpuff3", Color { red: 205, green: 175, blue: 149 }),
+    ("peachpuff4", Color { red: 139, green: 119, blue: 101 }),
+    ("peru", Color { red: 205, green: 133, blue: 63 }),
+    ("pink", Color { red: 255, green: 192, blue: 203 }),
+    ("pink1", Color { red: 255, green: 181, blue: 197 }),
+    ("pink2", Color { red: 238, green: 169, blue: 184 }),
+    ("pink3", Color { red: 205, green: 145, blue: 158 }),
+    ("pink4", Color { red: 139, green: 99, blue: 108 }),
+    ("plum", Color { red: 221, green: 160, blue: 221 }),
+    ("plum1", Color { red: 255, green: 187, blue: 255 }),
+    ("plum2", Color { red: 238, green: 174, blue: 238 }),
+    ("plum3", Color { red: 205, green: 150, blue: 205 }),
+    ("plum4", Color { red: 139, green: 102, blue: 139 }),
+    ("powder blue", Color { red: 176, green: 224, blue: 230 }),
+    ("powderblue", Color { red: 176, green: 224, blue: 230 }),
+    ("purple", Color { red: 160, green: 32, blue: 240 }),
+    ("purple1", Color { red: 155, green: 48, blue: 255 }),
+    ("purple2", Color { red: 145, green: 44, blue: 238 }),
+    ("purple3", Color { red: 125, green: 38, blue: 205 }),
+    ("purple4", Color { red: 85, green: 26, blue: 139 }),
+    ("red", Color { red: 255, green: 0, blue: 0 }),
+    ("red1", Color { red: 255, green: 0, blue: 0 }),
+    ("red2", Color { red: 238, green: 0, blue: 0 }),
+    ("red3", Color { red: 205, green: 0, blue: 0 }),
+    ("red4", Color { red: 139, green: 0, blue: 0 }),
+    ("rosy brown", Color { red: 188, green: 143, blue: 143 }),
+    ("rosybrown", Color { red: 188, green: 143, blue: 143 }),
+    ("rosybrown1", Color { red: 255, green: 193, blue: 193 }),
+    ("rosybrown2", Color { red: 238, green: 180, blue: 180 }),
+    ("rosybrown3", Color { red: 205, green: 155, blue: 155 }),
+    ("rosybrown4", Color { red: 139, green: 105, blue: 105 }),
+    ("royal blue", Color { red: 65, green: 105, blue: 225 }),
+    ("royalblue", Color { red: 65, green: 105, blue: 225 }),
+    ("royalblue1", Color { red: 72, green: 118, blue: 255 }),
+    ("royalblue2", Color { red: 67, green: 110, blue: 238 }),
+    ("royalblue3", Color { red: 58, green: 95, blue: 205 }),
+    ("royalblue4", Color { red: 39, green: 64, blue: 139 }),
+    ("saddle brown", Color { red: 139, green: 69, blue: 19 }),
+    ("saddlebrown", Color { red: 139, green: 69, blue: 19 }),
+    ("salmon", Color { red: 250, green: 128, blue: 114 }),
+    ("salmon1", Color { red: 255, green: 140, blue: 105 }),
+    ("salmon2", Color { red: 238, green: 130, blue: 98 }),
+    ("salmon3", Color { red: 205, green: 112, blue: 84 }),
+    ("salmon4", Color { red: 139, green: 76, blue: 57 }),
+    ("sandy brown", Color { red: 244, green: 164, blue: 96 }),
+    ("sandybrown", Color { red: 244, green: 164, blue: 96 }),
+    ("sea green", Color { red: 46, green: 139, blue: 87 }),
+    ("seagreen", Color { red: 46, green: 139, blue: 87 }),
+    ("seagreen1", Color { red: 84, green: 255, blue: 159 }),
+    ("seagreen2", Color { red: 78, green: 238, blue: 148 }),
+    ("seagreen3", Color { red: 67, green: 205, blue: 128 }),
+    ("seagreen4", Color { red: 46, green: 139, blue: 87 }),
+    ("seashell", Color { red: 255, green: 245, blue: 238 }),
+    ("seashell1", Color { red: 255, green: 245, blue: 238 }),
+    ("seashell2", Color { red: 238, green: 229, blue: 222 }),
+    ("seashell3", Color { red: 205, green: 197, blue: 191 }),
+    ("seashell4", Color { red: 139, green: 134, blue: 130 }),
+    ("sienna", Color { red: 160, green: 82, blue: 45 }),
+    ("sienna1", Color { red: 255, green: 130, blue: 71 }),
+    ("sienna2", Color { red: 238, green: 121, blue: 66 }),
+    ("sienna3", Color { red: 205, green: 104, blue: 57 }),
+    ("sienna4", Color { red: 139, green: 71, blue: 38 }),
+    ("sky blue", Color { red: 135, green: 206, blue: 235 }),
+    ("skyblue", Color { red: 135, green: 206, blue: 235 }),
+    ("skyblue1", Color { red: 135, green: 206, blue: 255 }),
+    ("skyblue2", Color { red: 126, green: 192, blue: 238 }),
+    ("skyblue3", Color { red: 108, green: 166, blue: 205 }),
+    ("skyblue4", Color { red: 74, green: 112, blue: 139 }),
+    ("slate blue", Color { red: 106, green: 90, blue: 205 }),
+    ("slate gray", Color { red: 112, green: 128, blue: 144 }),
+    ("slate grey", Color { red: 112, green: 128, blue: 144 }),
+    ("slateblue", Color { red: 106, green: 90, blue: 205 }),
+    ("slateblue1", Color { red: 131, green: 111, blue: 255 }),
+    ("slateblue2", Color { red: 122, green: 103, blue: 238 }),
+    ("slateblue3", Color { red: 105, green: 89, blue: 205 }),
+    ("slateblue4", Color { red: 71, green: 60, blue: 139 }),
+    ("slategray", Color { red: 112, green: 128, blue: 144 }),
+    ("slategray1", Color { red: 198, green: 226, blue: 255 }),
+    ("slategray2", Color { red: 185, green: 211, blue: 238 }),
+    ("slategray3", Color { red: 159, green: 182, blue: 205 }),
+    ("slategray4", Color { red: 108, green: 123, blue: 139 }),
+    ("slategrey", Color { red: 112, green: 128, blue: 144 }),
+    ("snow", Color { red: 255, green: 250, blue: 250 }),
+    ("snow1", Color { red: 255, green: 250, blue: 250 }),
+    ("snow2", Color { red: 238, green: 233, blue: 233 }),
+    ("snow3", Color { red: 205, green: 201, blue: 201 }),
+    ("snow4", Color { red: 139, green: 137, blue: 137 }),
+    ("spring green", Color { red: 0, green: 255, blue: 127 }),
+    ("springgreen", Color { red: 0, green: 255, blue: 127 }),
+    ("springgreen1", Color { red: 0, green: 255, blue: 127 }),
+    ("springgreen2", Color { red: 0, green: 238, blue: 118 }),
+    ("springgreen3", Color { red: 0, green: 205, blue: 102 }),
+    ("springgreen4", Color { red: 0, green: 139, blue: 69 }),
+    ("steel blue", Color { red: 70, green: 130, blue: 180 }),
+    ("steelblue", Color { red: 70, green: 130, blue: 180 }),
+    ("steelblue1", Color { red: 99, green: 184, blue: 255 }),
+    ("steelblue2", Color { red: 92, green: 172, blue: 238 }),
+    ("steelblue3", Color { red: 79, green: 148, blue: 205 }),
+    ("steelblue4", Color { red: 54, green: 100, blue: 139 }),
+    ("tan", Color { red: 210, green: 180, blue: 140 }),
+    ("tan1", Color { red: 255, green: 165, blue: 79 }),
+    ("tan2", Color { red: 238, green: 154, blue: 73 }),
+    ("tan3", Color { red: 205, green: 133, blue: 63 }),
+    ("tan4", Color { red: 139, green: 90, blue: 43 }),
+    ("thistle", Color { red: 216, green: 191, blue: 216 }),
+    ("thistle1", Color { red: 255, green: 225, blue: 255 }),
+    ("thistle2", Color { red: 238, green: 210, blue: 238 }),
+    ("thistle3", Color { red: 205, green: 181, blue: 205 }),
+    ("thistle4", Color { red: 139, green: 123, blue: 139 }),
+    ("tomato", Color { red: 255, green: 99, blue: 71 }),
+    ("tomato1", Color { red: 255, green: 99, blue: 71 }),
+    ("tomato2", Color { red: 238, green: 92, blue: 66 }),
+    ("tomato3", Color { red: 205, green: 79, blue: 57 }),
+    ("tomato4", Color { red: 139, green: 54, blue: 38 }),
+    ("turquoise", Color { red: 64, green: 224, blue: 208 }),
+    ("turquoise1", Color { red: 0, green: 245, blue: 255 }),
+    ("turquoise2", Color { red: 0, green: 229, blue: 238 }),
+    ("turquoise3", Color { red: 0, green: 197, blue: 205 }),
+    ("turquoise4", Color { red: 0, green: 134, blue: 139 }),
+    ("violet", Color { red: 238, green: 130, blue: 238 }),
+    ("violet red", Color { red: 208, green: 32, blue: 144 }),
+    ("violetred", Color { red: 208, green: 32, blue: 144 }),
+    ("violetred1", Color { red: 255, green: 62, blue: 150 }),
+    ("violetred2", Color { red: 238, green: 58, blue: 140 }),
+    ("violetred3", Color { red: 205, green: 50, blue: 120 }),
+    ("violetred4", Color { red: 139, green: 34, blue: 82 }),
+    ("wheat", Color { red: 245, green: 222, blue: 179 }),
+    ("wheat1", Color { red: 255, green: 231, blue: 186 }),
+    ("wheat2", Color { red: 238, green: 216, blue: 174 }),
+    ("wheat3", Color { red: 205, green: 186, blue: 150 }),
+    ("wheat4", Color { red: 139, green: 126, blue: 102 }),
+    ("white", Color { red: 255, green: 255, blue: 255 }),
+    ("white smoke", Color { red: 245, green: 245, blue: 245 }),
+    ("whitesmoke", Color { red: 245, green: 245, blue: 245 }),
+    ("yellow", Color { red: 255, green: 255, blue: 0 }),
+    ("yellow green", Color { red: 154, green: 205, blue: 50 }),
+    ("yellow1", Color { red: 255, green: 255, blue: 0 }),
+    ("yellow2", Color { red: 238, green: 238, blue: 0 }),
+    ("yellow3", Color { red: 205, green: 205, blue: 0 }),
+    ("yellow4", Color { red: 139, green: 139, blue: 0 }),
+    ("yellowgreen", Color { red: 154, green: 205, blue: 50 }),
+];
+
+/// Every color paired with its name, keyed by its decimal value and sorted by key.
+static COLOR_VALUES: [(u32, &str); COLOR_NAMES.len()] = named::sort_by_value(COLOR_NAMES);
+
+/// Attempts to get a color from an X11 color name. Matching is case-insensitive and leading and 
+/// trailing whitespace is ignored.
+pub fn from_x11_color_name(s: &str) -> Option<Color> {
+    named::find_by_name(&COLOR_NAMES, s)
+}
+
+/// Converts a color to an X11 color name if possible. For colors with more than one name, the 
+/// first name in alphabetical order is used, e.g. "gray100" rather than "white".
+pub fn to_x11_color_name(color: &Color) -> Option<&'static str> {
+    named::find_by_value(&COLOR_VALUES, color)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn parses_x11_color_name() {
+        assert_eq!(from_x11_color_name("maroon"), Some(Color { red: 176, green: 48, blue: 96 }));
+        assert_eq!(from_x11_color_name("  Ghost White\n"), from_x11_color_name("GhostWhite"));
+        assert_eq!(from_x11_color_name("grey100"), Some(Color { red: 255, green: 255, blue: 255 }));
+        assert_eq!(from_x11_color_name("rebeccapurple"), None);
+        assert_eq!(from_x11_color_name(""), None);
+    }
+    
+    #[test]
+    fn converts_to_x11_color_name() {
+        assert_eq!(to_x11_color_name(&Color::from_decimal(0xF8F8FF)), Some("ghost white"));
+        assert_eq!(to_x11_color_name(&Color { red: 1, green: 2, blue: 3 }), None);
+        
+        for (name, color) in COLOR_NAMES {
+            let found = to_x11_color_name(&color).and_then(from_x11_color_name);
+            
+            assert_eq!(found, Some(color), "{name}");
+        }
+    }
+    
+    #[test]
+    fn color_names_are_sorted() {
+        assert!(COLOR_NAMES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
\ No newline at end of file
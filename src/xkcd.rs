@@ -0,0 +1,1019 @@
+//! Color names from the [xkcd color survey](https://xkcd.com/color/rgb/), available with the 
+//! `xkcd` feature.
+//! 
+//! The survey names are written in lowercase with spaces, e.g. "light blue", exactly as they 
+//! appear in the survey's published `rgb.txt`.
+//! 
+//! # Examples
+//! ```
+//! use nice_colors::Color;
+//! use nice_colors::xkcd;
+//! 
+//! let light_blue = Color { red: 149, green: 208, blue: 252 };
+//! 
+//! assert_eq!(xkcd::from_xkcd_color_name("Light Blue"), Some(light_blue));
+//! assert_eq!(xkcd::nearest_xkcd_color_name(&Color { red: 230, green: 2, blue: 5 }), "red");
+//! ```
+
+use super::Color;
+use crate::named;
+
+/// Every survey color name paired with its color, sorted by name.
+pub static COLOR_NAMES: [(&str, Color); 949] = [
+    ("acid green", Color { red: 143, green: 254, blue: 9 }),
+    ("adobe", Color { red: 189, green: 108, blue: 72 }),
+    ("algae", Color { red: 84, green: 172, blue: 104 }),
+    ("algae green", Color { red: 33, green: 195, blue: 111 }),
+    ("almost black", Color { red: 7, green: 13, blue: 13 }),
+    ("amber", Color { red: 254, green: 179, blue: 8 }),
+    ("amethyst", Color { red: 155, green: 95, blue: 192 }),
+    ("apple", Color { red: 110, green: 203, blue: 60 }),
+    ("apple green", Color { red: 118, green: 205, blue: 38 }),
+    ("apricot", Color { red: 255, green: 177, blue: 109 }),
+    ("aqua", Color { red: 19, green: 234, blue: 201 }),
+    ("aqua blue", Color { red: 2, green: 216, blue: 233 }),
+    ("aqua green", Color { red: 18, green: 225, blue: 147 }),
+    ("aqua marine", Color { red: 46, green: 232, blue: 187 }),
+    ("aquamarine", Color { red: 4, green: 216, blue: 178 }),
+    ("army green", Color { red: 75, green: 93, blue: 22 }),
+    ("asparagus", Color { red: 119, green: 171, blue: 86 }),
+    ("aubergine", Color { red: 61, green: 7, blue: 52 }),
+    ("auburn", Color { red: 154, green: 48, blue: 1 }),
+    ("avocado", Color { red: 144, green: 177, blue: 52 }),
+    ("avocado green", Color { red: 135, green: 169, blue: 34 }),
+    ("azul", Color { red: 29, green: 93, blue: 236 }),
+    ("azure", Color { red: 6, green: 154, blue: 243 }),
+    ("baby blue", Color { red: 162, green: 207, blue: 254 }),
+    ("baby green", Color { red: 140, green: 255, blue: 158 }),
+    ("baby pink", Color { red: 255, green: 183, blue: 206 }),
+    ("baby poo", Color { red: 171, green: 144, blue: 4 }),
+    ("baby poop", Color { red: 147, green: 124, blue: 0 }),
+    ("baby poop green", Color { red: 143, green: 152, blue: 5 }),
+    ("baby puke green", Color { red: 182, green: 196, blue: 6 }),
+    ("baby purple", Color { red: 202, green: 155, blue: 247 }),
+    ("baby shit brown", Color { red: 173, green: 144, blue: 13 }),
+    ("baby shit green", Color { red: 136, green: 151, blue: 23 }),
+    ("banana", Color { red: 255, green: 255, blue: 126 }),
+    ("banana yellow", Color { red: 250, green: 254, blue: 75 }),
+    ("barbie pink", Color { red: 254, green: 70, blue: 165 }),
+    ("barf green", Color { red: 148, green: 172, blue: 2 }),
+    ("barney", Color { red: 172, green: 29, blue: 184 }),
+    ("barney purple", Color { red: 160, green: 4, blue: 152 }),
+    ("battleship grey", Color { red: 107, green: 124, blue: 133 }),
+    ("beige", Color { red: 230, green: 218, blue: 166 }),
+    ("berry", Color { red: 153, green: 15, blue: 75 }),
+    ("bile", Color { red: 181, green: 195, blue: 6 }),
+    ("black", Color { red: 0, green: 0, blue: 0 }),
+    ("bland", Color { red: 175, green: 168, blue: 139 }),
+    ("blood", Color { red: 119, green: 0, blue: 1 }),
+    ("blood orange", Color { red: 254, green: 75, blue: 3 }),
+    ("blood red", Color { red: 152, green: 0, blue: 2 }),
+    ("blue", Color { red: 3, green: 67, blue: 223 }),
+    ("blue blue", Color { red: 34, green: 66, blue: 199 }),
+    ("blue green", Color { red: 19, green: 126, blue: 109 }),
+    ("blue grey", Color { red: 96, green: 124, blue: 142 }),
+    ("blue purple", Color { red: 87, green: 41, blue: 206 }),
+    ("blue violet", Color { red: 93, green: 6, blue: 233 }),
+    ("blue with a hint of purple", Color { red: 83, green: 60, blue: 198 }),
+    ("blue/green", Color { red: 15, green: 155, blue: 142 }),
+    ("blue/grey", Color { red: 117, green: 141, blue: 163 }),
+    ("blue/purple", Color { red: 90, green: 6, blue: 239 }),
+    ("blueberry", Color { red: 70, green: 65, blue: 150 }),
+    ("bluegreen", Color { red: 1, green: 122, blue: 121 }),
+    ("bluegrey", Color { red: 133, green: 163, blue: 178 }),
+    ("bluey green", Color { red: 43, green: 177, blue: 121 }),
+    ("bluey grey", Color { red: 137, green: 160, blue: 176 }),
+    ("bluey purple", Color { red: 98, green: 65, blue: 199 }),
+    ("bluish", Color { red: 41, green: 118, blue: 187 }),
+    ("bluish green", Color { red: 16, green: 166, blue: 116 }),
+    ("bluish grey", Color { red: 116, green: 139, blue: 151 }),
+    ("bluish purple", Color { red: 112, green: 59, blue: 231 }),
+    ("blurple", Color { red: 85, green: 57, blue: 204 }),
+    ("blush", Color { red: 242, green: 158, blue: 142 }),
+    ("blush pink", Color { red: 254, green: 130, blue: 140 }),
+    ("booger", Color { red: 155, green: 181, blue: 60 }),
+    ("booger green", Color { red: 150, green: 180, blue: 3 }),
+    ("bordeaux", Color { red: 123, green: 0, blue: 44 }),
+    ("boring green", Color { red: 99, green: 179, blue: 101 }),
+    ("bottle green", Color { red: 4, green: 74, blue: 5 }),
+    ("brick", Color { red: 160, green: 54, blue: 35 }),
+    ("brick orange", Color { red: 193, green: 74, blue: 9 }),
+    ("brick red", Color { red: 143, green: 20, blue: 2 }),
+    ("bright aqua", Color { red: 11, green: 249, blue: 234 }),
+    ("bright blue", Color { red: 1, green: 101, blue: 252 }),
+    ("bright cyan", Color { red: 65, green: 253, blue: 254 }),
+    ("bright green", Color { red: 1, green: 255, blue: 7 }),
+    ("bright lavender", Color { red: 199, green: 96, blue: 255 }),
+    ("bright light blue", Color { red: 38, green: 247, blue: 253 }),
+    ("bright light green", Color { red: 45, green: 254, blue: 84 }),
+    ("bright lilac", Color { red: 201, green: 94, blue: 251 }),
+    ("bright lime", Color { red: 135, green: 253, blue: 5 }),
+    ("bright lime green", Color { red: 101, green: 254, blue: 8 }),
+    ("bright magenta", Color { red: 255, green: 8, blue: 232 }),
+    ("bright olive", Color { red: 156, green: 187, blue: 4 }),
+    ("bright orange", Color { red: 255, green: 91, blue: 0 }),
+    ("bright pink", Color { red: 254, green: 1, blue: 177 }),
+    ("bright purple", Color { red: 190, green: 3, blue: 253 }),
+    ("bright red", Color { red: 255, green: 0, blue: 13 }),
+    ("bright sea green", Color { red: 5, green: 255, blue: 166 }),
+    ("bright sky blue", Color { red: 2, green: 204, blue: 254 }),
+    ("bright teal", Color { red: 1, green: 249, blue: 198 }),
+    ("bright turquoise", Color { red: 15, green: 254, blue: 249 }),
+    ("bright violet", Color { red: 173, green: 10, blue: 253 }),
+    ("bright yellow", Color { red: 255, green: 253, blue: 1 }),
+    ("bright yellow green", Color { red: 157, green: 255, blue: 0 }),
+    ("british racing green", Color { red: 5, green: 72, blue: 13 }),
+    ("bronze", Color { red: 168, green: 121, blue: 0 }),
+    ("brown", Color { red: 101, green: 55, blue: 0 }),
+    ("brown green", Color { red: 112, green: 108, blue: 17 }),
+    ("brown grey", Color { red: 141, green: 132, blue: 104 }),
+    ("brown orange", Color { red: 185, green: 105, blue: 2 }),
+    ("brown red", Color { red: 146, green: 43, blue: 5 }),
+    ("brown yellow", Color { red: 178, green: 151, blue: 5 }),
+    ("brownish", Color { red: 156, green: 109, blue: 87 }),
+    ("brownish green", Color { red: 106, green: 110, blue: 9 }),
+    ("brownish grey", Color { red: 134, green: 119, blue: 95 }),
+    ("brownish orange", Color { red: 203, green: 119, blue: 35 }),
+    ("brownish pink", Color { red: 194, green: 126, blue: 121 }),
+    ("brownish purple", Color { red: 118, green: 66, blue: 78 }),
+    ("brownish red", Color { red: 158, green: 54, blue: 35 }),
+    ("brownish yellow", Color { red: 201, green: 176, blue: 3 }),
+    ("browny green", Color { red: 111, green: 108, blue: 10 }),
+    ("browny orange", Color { red: 202, green: 107, blue: 2 }),
+    ("bruise", Color { red: 126, green: 64, blue: 113 }),
+    ("bubble gum pink", Color { red: 255, green: 105, blue: 175 }),
+    ("bubblegum", Color { red: 255, green: 108, blue: 181 }),
+    ("bubblegum pink", Color { red: 254, green: 131, blue: 204 }),
+    ("buff", Color { red: 254, green: 246, blue: 158 }),
+    ("burgundy", Color { red: 97, green: 0, blue: 35 }),
+    ("burnt orange", Color { red: 192, green: 78, blue: 1 }),
+    ("burnt red", Color { red: 159, green: 35, blue: 5 }),
+    ("burnt siena", Color { red: 183, green: 82, blue: 3 }),
+    ("burnt sienna", Color { red: 176, green: 78, blue: 15 }),
+    ("burnt umber", Color { red: 160, green: 69, blue: 14 }),
+    ("burnt yellow", Color { red: 213, green: 171, blue: 9 }),
+    ("burple", Color { red: 104, green: 50, blue: 227 }),
+    ("butter", Color { red: 255, green: 255, blue: 129 }),
+    ("butter yellow", Color { red: 255, green: 253, blue: 116 }),
+    ("butterscotch", Color { red: 253, green: 177, blue: 71 }),
+    ("cadet blue", Color { red: 78, green: 116, blue: 150 }),
+    ("camel", Color { red: 198, green: 159, blue: 89 }),
+    ("camo", Color { red: 127, green: 143, blue: 78 }),
+    ("camo green", Color { red: 82, green: 101, blue: 37 }),
+    ("camouflage green", Color { red: 75, green: 97, blue: 19 }),
+    ("canary", Color { red: 253, green: 255, blue: 99 }),
+    ("canary yellow", Color { red: 255, green: 254, blue: 64 }),
+    ("candy pink", Color { red: 255, green: 99, blue: 233 }),
+    ("caramel", Color { red: 175, green: 111, blue: 9 }),
+    ("carmine", Color { red: 157, green: 2, blue: 22 }),
+    ("carnation", Color { red: 253, green: 121, blue: 143 }),
+    ("carnation pink", Color { red: 255, green: 127, blue: 167 }),
+    ("carolina blue", Color { red: 138, green: 184, blue: 254 }),
+    ("celadon", Color { red: 190, green: 253, blue: 183 }),
+    ("celery", Color { red: 193, green: 253, blue: 149 }),
+    ("cement", Color { red: 165, green: 163, blue: 145 }),
+    ("cerise", Color { red: 222, green: 12, blue: 98 }),
+    ("cerulean", Color { red: 4, green: 133, blue: 209 }),
+    ("cerulean blue", Color { red: 5, green: 110, blue: 238 }),
+    ("charcoal", Color { red: 52, green: 56, blue: 55 }),
+    ("charcoal grey", Color { red: 60, green: 65, blue: 66 }),
+    ("chartreuse", Color { red: 193, green: 248, blue: 10 }),
+    ("cherry", Color { red: 207, green: 2, blue: 52 }),
+    ("cherry red", Color { red: 247, green: 2, blue: 42 }),
+    ("chestnut", Color { red: 116, green: 40, blue: 2 }),
+    ("chocolate", Color { red: 61, green: 28, blue: 2 }),
+    ("chocolate brown", Color { red: 65, green: 25, blue: 0 }),
+    ("cinnamon", Color { red: 172, green: 79, blue: 6 }),
+    ("claret", Color { red: 104, green: 0, blue: 24 }),
+    ("clay", Color { red: 182, green: 106, blue: 80 }),
+    ("clay brown", Color { red: 178, green: 113, blue: 61 }),
+    ("clear blue", Color { red: 36, green: 122, blue: 253 }),
+    ("cloudy blue", Color { red: 172, green: 194, blue: 217 }),
+    ("cobalt", Color { red: 30, green: 72, blue: 143 }),
+    ("cobalt blue", Color { red: 3, green: 10, blue: 167 }),
+    ("cocoa", Color { red: 135, green: 95, blue: 66 }),
+    ("coffee", Color { red: 166, green: 129, blue: 76 }),
+    ("cool blue", Color { red: 73, green: 132, blue: 184 }),
+    ("cool green", Color { red: 51, green: 184, blue: 100 }),
+    ("cool grey", Color { red: 149, green: 163, blue: 166 }),
+    ("copper", Color { red: 182, green: 99, blue: 37 }),
+    ("coral", Color { red: 252, green: 90, blue: 80 }),
+    ("coral pink", Color { red: 255, green: 97, blue: 99 }),
+    ("cornflower", Color { red: 106, green: 121, blue: 247 }),
+    ("cornflower blue", Color { red: 81, green: 112, blue: 215 }),
+    ("cranberry", Color { red: 158, green: 0, blue: 58 }),
+    ("cream", Color { red: 255, green: 255, blue: 194 }),
+    ("creme", Color { red: 255, green: 255, blue: 182 }),
+    ("crimson", Color { red: 140, green: 0, blue: 15 }),
+    ("custard", Color { red: 255, green: 253, blue: 120 }),
+    ("cyan", Color { red: 0, green: 255, blue: 255 }),
+    ("dandelion", Color { red: 254, green: 223, blue: 8 }),
+    ("dark", Color { red: 27, green: 36, blue: 49 }),
+    ("dark aqua", Color { red: 5, green: 105, blue: 107 }),
+    ("dark aquamarine", Color { red: 1, green: 115, blue: 113 }),
+    ("dark beige", Color { red: 172, green: 147, blue: 98 }),
+    ("dark blue", Color { red: 0, green: 3, blue: 91 }),
+    ("dark blue green", Color { red: 0, green: 82, blue: 73 }),
+    ("dark blue grey", Color { red: 31, green: 59, blue: 77 }),
+    ("dark brown", Color { red: 52, green: 28, blue: 2 }),
+    ("dark coral", Color { red: 207, green: 82, blue: 78 }),
+    ("dark cream", Color { red: 255, green: 243, blue: 154 }),
+    ("dark cyan", Color { red: 10, green: 136, blue: 138 }),
+    ("dark forest green", Color { red: 0, green: 45, blue: 4 }),
+    ("dark fuchsia", Color { red: 157, green: 7, blue: 89 }),
+    ("dark gold", Color { red: 181, green: 148, blue: 16 }),
+    ("dark grass green", Color { red: 56, green: 128, blue: 4 }),
+    ("dark green", Color { red: 3, green: 53, blue: 0 }),
+    ("dark green blue", Color { red: 31, green: 99, blue: 87 }),
+    ("dark grey", Color { red: 54, green: 55, blue: 55 }),
+    ("dark grey blue", Color { red: 41, green: 70, blue: 91 }),
+    ("dark hot pink", Color { red: 217, green: 1, blue: 102 }),
+    ("dark indigo", Color { red: 31, green: 9, blue: 84 }),
+    ("dark khaki", Color { red: 155, green: 143, blue: 85 }),
+    ("dark lavender", Color { red: 133, green: 103, blue: 152 }),
+    ("dark lilac", Color { red: 156, green: 109, blue: 165 }),
+    ("dark lime", Color { red: 132, green: 183, blue: 1 }),
+    ("dark lime green", Color { red: 126, green: 189, blue: 1 }),
+    ("dark magenta", Color { red: 150, green: 0, blue: 86 }),
+    ("dark maroon", Color { red: 60, green: 0, blue: 8 }),
+    ("dark mauve", Color { red: 135, green: 76, blue: 98 }),
+    ("dark mint", Color { red: 72, green: 192, blue: 114 }),
+    ("dark mint green", Color { red: 32, green: 192, blue: 115 }),
+    ("dark mustard", Color { red: 168, green: 137, blue: 5 }),
+    ("dark navy", Color { red: 0, green: 4, blue: 53 }),
+    ("dark navy blue", Color { red: 0, green: 2, blue: 46 }),
+    ("dark olive", Color { red: 55, green: 62, blue: 2 }),
+    ("dark olive green", Color { red: 60, green: 77, blue: 3 }),
+    ("dark orange", Color { red: 198, green: 81, blue: 2 }),
+    ("dark pastel green", Color { red: 86, green: 174, blue: 87 }),
+    ("dark peach", Color { red: 222, green: 126, blue: 93 }),
+    ("dark periwinkle", Color { red: 102, green: 95, blue: 209 }),
+    ("dark pink", Color { red: 203, green: 65, blue: 107 }),
+    ("dark plum", Color { red: 63, green: 1, blue: 44 }),
+    ("dark purple", Color { red: 53, green: 6, blue: 62 }),
+    ("dark red", Color { red: 132, green: 0, blue: 0 }),
+    ("dark rose", Color { red: 181, green: 72, blue: 93 }),
+    ("dark royal blue", Color { red: 2, green: 6, blue: 111 }),
+    ("dark sage", Color { red: 89, green: 133, blue: 86 }),
+    ("dark salmon", Color { red: 200, green: 90, blue: 83 }),
+    ("dark sand", Color { red: 168, green: 143, blue: 89 }),
+    ("dark sea green", Color { red: 17, green: 135, blue: 93 }),
+    ("dark seafoam", Color { red: 31, green: 181, blue: 122 }),
+    ("dark seafoam green", Color { red: 62, green: 175, blue: 118 }),
+    ("dark sky blue", Color { red: 68, green: 142, blue: 228 }),
+    ("dark slate blue", Color { red: 33, green: 71, blue: 97 }),
+    ("dark tan", Color { red: 175, green: 136, blue: 74 }),
+    ("dark taupe", Color { red: 127, green: 104, blue: 78 }),
+    ("dark teal", Color { red: 1, green: 77, blue: 78 }),
+    ("dark turquoise", Color { red: 4, green: 92, blue: 90 }),
+    ("dark violet", Color { red: 52, green: 1, blue: 63 }),
+    ("dark yellow", Color { red: 213, green: 182, blue: 10 }),
+    ("dark yellow green", Color { red: 114, green: 143, blue: 2 }),
+    ("darkblue", Color { red: 3, green: 7, blue: 100 }),
+    ("darkgreen", Color { red: 5, green: 73, blue: 7 }),
+    ("darkish blue", Color { red: 1, green: 65, blue: 130 }),
+    ("darkish green", Color { red: 40, green: 124, blue: 55 }),
+    ("darkish pink", Color { red: 218, green: 70, blue: 125 }),
+    ("darkish purple", Color { red: 117, green: 25, blue: 115 }),
+    ("darkish red", Color { red: 169, green: 3, blue: 8 }),
+    ("deep aqua", Color { red: 8, green: 120, blue: 127 }),
+    ("deep blue", Color { red: 4, green: 2, blue: 115 }),
+    ("deep brown", Color { red: 65, green: 2, blue: 0 }),
+    ("deep green", Color { red: 2, green: 89, blue: 15 }),
+    ("deep lavender", Color { red: 141, green: 94, blue: 183 }),
+    ("deep lilac", Color { red: 150, green: 110, blue: 189 }),
+    ("deep magenta", Color { red: 160, green: 2, blue: 92 }),
+    ("deep orange", Color { red: 220, green: 77, blue: 1 }),
+    ("deep pink", Color { red: 203, green: 1, blue: 98 }),
+    ("deep purple", Color { red: 54, green: 1, blue: 63 }),
+    ("deep red", Color { red: 154, green: 2, blue: 0 }),
+    ("deep rose", Color { red: 199, green: 71, blue: 103 }),
+    ("deep sea blue", Color { red: 1, green: 84, blue: 130 }),
+    ("deep sky blue", Color { red: 13, green: 117, blue: 248 }),
+    ("deep teal", Color { red: 0, green: 85, blue: 90 }),
+    ("deep turquoise", Color { red: 1, green: 115, blue: 116 }),
+    ("deep violet", Color { red: 73, green: 6, blue: 72 }),
+    ("denim", Color { red: 59, green: 99, blue: 140 }),
+    ("denim blue", Color { red: 59, green: 91, blue: 146 }),
+    ("desert", Color { red: 204, green: 173, blue: 96 }),
+    ("diarrhea", Color { red: 159, green: 131, blue: 3 }),
+    ("dirt", Color { red: 138, green: 110, blue: 69 }),
+    ("dirt brown", Color { red: 131, green: 101, blue: 57 }),
+    ("dirty blue", Color { red: 63, green: 130, blue: 157 }),
+    ("dirty green", Color { red: 102, green: 126, blue: 44 }),
+    ("dirty orange", Color { red: 200, green: 118, blue: 6 }),
+    ("dirty pink", Color { red: 202, green: 123, blue: 128 }),
+    ("dirty purple", Color { red: 115, green: 74, blue: 101 }),
+    ("dirty yellow", Color { red: 205, green: 197, blue: 10 }),
+    ("dodger blue", Color { red: 62, green: 130, blue: 252 }),
+    ("drab", Color { red: 130, green: 131, blue: 68 }),
+    ("drab green", Color { red: 116, green: 149, blue: 81 }),
+    ("dried blood", Color { red: 75, green: 1, blue: 1 }),
+    ("duck egg blue", Color { red: 195, green: 251, blue: 244 }),
+    ("dull blue", Color { red: 73, green: 117, blue: 156 }),
+    ("dull brown", Color { red: 135, green: 110, blue: 75 }),
+    ("dull green", Color { red: 116, green: 166, blue: 98 }),
+    ("dull orange", Color { red: 216, green: 134, blue: 59 }),
+    ("dull pink", Color { red: 213, green: 134, blue: 157 }),
+    ("dull purple", Color { red: 132, green: 89, blue: 126 }),
+    ("dull red", Color { red: 187, green: 63, blue: 63 }),
+    ("dull teal", Color { red: 95, green: 158, blue: 143 }),
+    ("dull yellow", Color { red: 238, green: 220, blue: 91 }),
+    ("dusk", Color { red: 78, green: 84, blue: 129 }),
+    ("dusk blue", Color { red: 38, green: 83, blue: 141 }),
+    ("dusky blue", Color { red: 71, green: 95, blue: 148 }),
+    ("dusky pink", Color { red: 204, green: 122, blue: 139 }),
+    ("dusky purple", Color { red: 137, green: 91, blue: 123 }),
+    ("dusky rose", Color { red: 186, green: 104, blue: 115 }),
+    ("dust", Color { red: 178, green: 153, blue: 110 }),
+    ("dusty blue", Color { red: 90, green: 134, blue: 173 }),
+    ("dusty green", Color { red: 118, green: 169, blue: 115 }),
+    ("dusty lavender", Color { red: 172, green: 134, blue: 168 }),
+    ("dusty orange", Color { red: 240, green: 131, blue: 58 }),
+    ("dusty pink", Color { red: 213, green: 138, blue: 148 }),
+    ("dusty purple", Color { red: 130, green: 95, blue: 135 }),
+    ("dusty red", Color { red: 185, green: 72, blue: 78 }),
+    ("dusty rose", Color { red: 192, green: 115, blue: 122 }),
+    ("dusty teal", Color { red: 76, green: 144, blue: 133 }),
+    ("earth", Color { red: 162, green: 101, blue: 62 }),
+    ("easter green", Color { red: 140, green: 253, blue: 126 }),
+    ("easter purple", Color { red: 192, green: 113, blue: 254 }),
+    ("ecru", Color { red: 254, green: 255, blue: 202 }),
+    ("egg shell", Color { red: 255, green: 252, blue: 196 }),
+    ("eggplant", Color { red: 56, green: 8, blue: 53 }),
+    ("eggplant purple", Color { red: 67, green: 5, blue: 65 }),
+    ("eggshell", Color { red: 255, green: 255, blue: 212 }),
+    ("eggshell blue", Color { red: 196, green: 255, blue: 247 }),
+    ("electric blue", Color { red: 6, green: 82, blue: 255 }),
+    ("electric green", Color { red: 33, green: 252, blue: 13 }),
+    ("electric lime", Color { red: 168, green: 255, blue: 4 }),
+    ("electric pink", Color { red: 255, green: 4, blue: 144 }),
+    ("electric purple", Color { red: 170, green: 35, blue: 255 }),
+    ("emerald", Color { red: 1, green: 160, blue: 73 }),
+    ("emerald green", Color { red: 2, green: 143, blue: 30 }),
+    ("evergreen", Color { red: 5, green: 71, blue: 42 }),
+    ("faded blue", Color { red: 101, green: 140, blue: 187 }),
+    ("faded green", Color { red: 123, green: 178, blue: 116 }),
+    ("faded orange", Color { red: 240, green: 148, blue: 77 }),
+    ("faded pink", Color { red: 222, green: 157, blue: 172 }),
+    ("faded purple", Color { red: 145, green: 110, blue: 153 }),
+    ("faded red", Color { red: 211, green: 73, blue: 78 }),
+    ("faded yellow", Color { red: 254, green: 255, blue: 127 }),
+    ("fawn", Color { red: 207, green: 175, blue: 123 }),
+    ("fern", Color { red: 99, green: 169, blue: 80 }),
+    ("fern green", Color { red: 84, green: 141, blue: 68 }),
+    ("fire engine red", Color { red: 254, green: 0, blue: 2 }),
+    ("flat blue", Color { red: 60, green: 115, blue: 168 }),
+    ("flat green", Color { red: 105, green: 157, blue: 76 }),
+    ("fluorescent green", Color { red: 8, green: 255, blue: 8 }),
+    ("fluro green", Color { red: 10, green: 255, blue: 2 }),
+    ("foam green", Color { red: 144, green: 253, blue: 169 }),
+    ("forest", Color { red: 11, green: 85, blue: 9 }),
+    ("forest green", Color { red: 6, green: 71, blue: 12 }),
+    ("forrest green", Color { red: 21, green: 68, blue: 6 }),
+    ("french blue", Color { red: 67, green: 107, blue: 173 }),
+    ("fresh green", Color { red: 105, green: 216, blue: 79 }),
+    ("frog green", Color { red: 88, green: 188, blue: 8 }),
+    ("fuchsia", Color { red: 237, green: 13, blue: 217 }),
+    ("gold", Color { red: 219, green: 180, blue: 12 }),
+    ("golden", Color { red: 245, green: 191, blue: 3 }),
+    ("golden brown", Color { red: 178, green: 122, blue: 1 }),
+    ("golden rod", Color { red: 249, green: 188, blue: 8 }),
+    ("golden yellow", Color { red: 254, green: 198, blue: 21 }),
+    ("goldenrod", Color { red: 250, green: 194, blue: 5 }),
+    ("grape", Color { red: 108, green: 52, blue: 97 }),
+    ("grape purple", Color { red: 93, green: 20, blue: 81 }),
+    ("grapefruit", Color { red: 253, green: 89, blue: 86 }),
+    ("grass", Color { red: 92, green: 172, blue: 45 }),
+    ("grass green", Color { red: 63, green: 155, blue: 11 }),
+    ("grassy green", Color { red: 65, green: 156, blue: 3 }),
+    ("green", Color { red: 21, green: 176, blue: 26 }),
+    ("green apple", Color { red: 94, green: 220, blue: 31 }),
+    ("green blue", Color { red: 6, green: 180, blue: 139 }),
+    ("green brown", Color { red: 84, green: 78, blue: 3 }),
+    ("green grey", Color { red: 119, green: 146, blue: 111 }),
+    ("green teal", Color { red: 12, green: 181, blue: 119 }),
+    ("green yellow", Color { red: 201, green: 255, blue: 39 }),
+    ("green/blue", Color { red: 1, green: 192, blue: 141 }),
+    ("green/yellow", Color { red: 181, green: 206, blue: 8 }),
+    ("greenblue", Color { red: 35, green: 196, blue: 139 }),
+    ("greenish", Color { red: 64, green: 163, blue: 104 }),
+    ("greenish beige", Color { red: 201, green: 209, blue: 121 }),
+    ("greenish blue", Color { red: 11, green: 139, blue: 135 }),
+    ("greenish brown", Color { red: 105, green: 97, blue: 18 }),
+    ("greenish cyan", Color { red: 42, green: 254, blue: 183 }),
+    ("greenish grey", Color { red: 150, green: 174, blue: 141 }),
+    ("greenish tan", Color { red: 188, green: 203, blue: 122 }),
+    ("greenish teal", Color { red: 50, green: 191, blue: 132 }),
+    ("greenish turquoise", Color { red: 0, green: 251, blue: 176 }),
+    ("greenish yellow", Color { red: 205, green: 253, blue: 2 }),
+    ("greeny blue", Color { red: 66, green: 179, blue: 149 }),
+    ("greeny brown", Color { red: 105, green: 96, blue: 6 }),
+    ("greeny grey", Color { red: 126, green: 160, blue: 122 }),
+    ("greeny yellow", Color { red: 198, green: 248, blue: 8 }),
+    ("grey", Color { red: 146, green: 149, blue: 145 }),
+    ("grey blue", Color { red: 107, green: 139, blue: 164 }),
+    ("grey brown", Color { red: 127, green: 112, blue: 83 }),
+    ("grey green", Color { red: 120, green: 155, blue: 115 }),
+    ("grey pink", Color { red: 195, green: 144, blue: 155 }),
+    ("grey purple", Color { red: 130, green: 109, blue: 140 }),
+    ("grey teal", Color { red: 94, green: 155, blue: 138 }),
+    ("grey/blue", Color { red: 100, green: 125, blue: 142 }),
+    ("grey/green", Color { red: 134, green: 161, blue: 125 }),
+    ("greyblue", Color { red: 119, green: 161, blue: 181 }),
+    ("greyish", Color { red: 168, green: 164, blue: 149 }),
+    ("greyish blue", Color { red: 94, green: 129, blue: 157 }),
+    ("greyish brown", Color { red: 122, green: 106, blue: 79 }),
+    ("greyish green", Color { red: 130, green: 166, blue: 125 }),
+    ("greyish pink", Color { red: 200, green: 141, blue: 148 }),
+    ("greyish purple", Color { red: 136, green: 113, blue: 145 }),
+    ("greyish teal", Color { red: 113, green: 159, blue: 145 }),
+    ("gross green", Color { red: 160, green: 191, blue: 22 }),
+    ("gunmetal", Color { red: 83, green: 98, blue: 103 }),
+    ("hazel", Color { red: 142, green: 118, blue: 24 }),
+    ("heather", Color { red: 164, green: 132, blue: 172 }),
+    ("heliotrope", Color { red: 217, green: 79, blue: 245 }),
+    ("highlighter green", Color { red: 27, green: 252, blue: 6 }),
+    ("hospital green", Color { red: 155, green: 229, blue: 170 }),
+    ("hot green", Color { red: 37, green: 255, blue: 41 }),
+    ("hot magenta", Color { red: 245, green: 4, blue: 201 }),
+    ("hot pink", Color { red: 255, green: 2, blue: 141 }),
+    ("hot purple", Color { red: 203, green: 0, blue: 245 }),
+    ("hunter green", Color { red: 11, green: 64, blue: 8 }),
+    ("ice", Color { red: 214, green: 255, blue: 250 }),
+    ("ice blue", Color { red: 215, green: 255, blue: 254 }),
+    ("icky green", Color { red: 143, green: 174, blue: 34 }),
+    ("indian red", Color { red: 133, green: 14, blue: 4 }),
+    ("indigo", Color { red: 56, green: 2, blue: 130 }),
+    ("indigo blue", Color { red: 58, green: 24, blue: 177 }),
+    ("iris", Color { red: 98, green: 88, blue: 196 }),
+    ("irish green", Color { red: 1, green: 149, blue: 41 }),
+    ("ivory", Color { red: 255, green: 255, blue: 203 }),
+    ("jade", Color { red: 31, green: 167, blue: 116 }),
+    ("jade green", Color { red: 43, green: 175, blue: 106 }),
+    ("jungle green", Color { red: 4, green: 130, blue: 67 }),
+    ("kelley green", Color { red: 0, green: 147, blue: 55 }),
+    ("kelly green", Color { red: 2, green: 171, blue: 46 }),
+    ("kermit green", Color { red: 92, green: 178, blue: 0 }),
+    ("key lime", Color { red: 174, green: 255, blue: 110 }),
+    ("khaki", Color { red: 170, green: 166, blue: 98 }),
+    ("khaki green", Color { red: 114, green: 134, blue: 57 }),
+    ("kiwi", Color { red: 156, green: 239, blue: 67 }),
+    ("kiwi green", Color { red: 142, green: 229, blue: 63 }),
+    ("lavender", Color { red: 199, green: 159, blue: 239 }),
+    ("lavender blue", Color { red: 139, green: 136, blue: 248 }),
+    ("lavender pink", Color { red: 221, green: 133, blue: 215 }),
+    ("lawn green", Color { red: 77, green: 164, blue: 9 }),
+    ("leaf", Color { red: 113, green: 170, blue: 52 }),
+    ("leaf green", Color { red: 92, green: 169, blue: 4 }),
+    ("leafy green", Color { red: 81, green: 183, blue: 59 }),
+    ("leather", Color { red: 172, green: 116, blue: 52 }),
+    ("lemon", Color { red: 253, green: 255, blue: 82 }),
+    ("lemon green", Color { red: 173, green: 248, blue: 2 }),
+    ("lemon lime", Color { red: 191, green: 254, blue: 40 }),
+    ("lemon yellow", Color { red: 253, green: 255, blue: 56 }),
+    ("lichen", Color { red: 143, green: 182, blue: 123 }),
+    ("light aqua", Color { red: 140, green: 255, blue: 219 }),
+    ("light aquamarine", Color { red: 123, green: 253, blue: 199 }),
+    ("light beige", Color { red: 255, green: 254, blue: 182 }),
+    ("light blue", Color { red: 149, green: 208, blue: 252 }),
+    ("light blue green", Color { red: 126, green: 251, blue: 179 }),
+    ("light blue grey", Color { red: 183, green: 201, blue: 226 }),
+    ("light bluish green", Color { red: 118, green: 253, blue: 168 }),
+    ("light bright green", Color { red: 83, green: 254, blue: 92 }),
+    ("light brown", Color { red: 173, green: 129, blue: 80 }),
+    ("light burgundy", Color { red: 168, green: 65, blue: 91 }),
+    ("light cyan", Color { red: 172, green: 255, blue: 252 }),
+    ("light eggplant", Color { red: 137, green: 69, blue: 133 }),
+    ("light forest green", Color { red: 79, green: 145, blue: 83 }),
+    ("light gold", Color { red: 253, green: 220, blue: 92 }),
+    ("light grass green", Color { red: 154, green: 247, blue: 100 }),
+    ("light green", Color { red: 150, green: 249, blue: 123 }),
+    ("light green blue", Color { red: 86, green: 252, blue: 162 }),
+    ("light greenish blue", Color { red: 99, green: 247, blue: 180 }),
+    ("light grey", Color { red: 216, green: 220, blue: 214 }),
+    ("light grey blue", Color { red: 157, green: 188, blue: 212 }),
+    ("light grey green", Color { red: 183, green: 225, blue: 161 }),
+    ("light indigo", Color { red: 109, green: 90, blue: 207 }),
+    ("light khaki", Color { red: 230, green: 242, blue: 162 }),
+    ("light lavendar", Color { red: 239, green: 192, blue: 254 }),
+    ("light lavender", Color { red: 223, green: 197, blue: 254 }),
+    ("light light blue", Color { red: 202, green: 255, blue: 251 }),
+    ("light light green", Color { red: 200, green: 255, blue: 176 }),
+    ("light lilac", Color { red: 237, green: 200, blue: 255 }),
+    ("light lime", Color { red: 174, green: 253, blue: 108 }),
+    ("light lime green", Color { red: 185, green: 255, blue: 102 }),
+    ("light magenta", Color { red: 250, green: 95, blue: 247 }),
+    ("light maroon", Color { red: 162, green: 72, blue: 87 }),
+    ("light mauve", Color { red: 194, green: 146, blue: 161 }),
+    ("light mint", Color { red: 182, green: 255, blue: 187 }),
+    ("light mint green", Color { red: 166, green: 251, blue: 178 }),
+    ("light moss green", Color { red: 166, green: 200, blue: 117 }),
+    ("light mustard", Color { red: 247, green: 213, blue: 96 }),
+    ("light navy", Color { red: 21, green: 80, blue: 132 }),
+    ("light navy blue", Color { red: 46, green: 90, blue: 136 }),
+    ("light neon green", Color { red: 78, green: 253, blue: 84 }),
+    ("light olive", Color { red: 172, green: 191, blue: 105 }),
+    ("light olive green", Color { red: 164, green: 190, blue: 92 }),
+    ("light orange", Color { red: 253, green: 170, blue: 72 }),
+    ("light pastel green", Color { red: 178, green: 251, blue: 165 }),
+    ("light pea green", Color { red: 196, green: 254, blue: 130 }),
+    ("light peach", Color { red: 255, green: 216, blue: 177 }),
+    ("light periwinkle", Color { red: 193, green: 198, blue: 252 }),
+    ("light pink", Color { red: 255, green: 209, blue: 223 }),
+    ("light plum", Color { red: 157, green: 87, blue: 131 }),
+    ("light purple", Color { red: 191, green: 119, blue: 246 }),
+    ("light red", Color { red: 255, green: 71, blue: 76 }),
+    ("light rose", Color { red: 255, green: 197, blue: 203 }),
+    ("light royal blue", Color { red: 58, green: 46, blue: 254 }),
+    ("light sage", Color { red: 188, green: 236, blue: 172 }),
+    ("light salmon", Color { red: 254, green: 169, blue: 147 }),
+    ("light sea green", Color { red: 152, green: 246, blue: 176 }),
+    ("light seafoam", Color { red: 160, green: 254, blue: 191 }),
+    ("light seafoam green", Color { red: 167, green: 255, blue: 181 }),
+    ("light sky blue", Color { red: 198, green: 252, blue: 255 }),
+    ("light tan", Color { red: 251, green: 238, blue: 172 }),
+    ("light teal", Color { red: 144, green: 228, blue: 193 }),
+    ("light turquoise", Color { red: 126, green: 244, blue: 204 }),
+    ("light urple", Color { red: 179, green: 111, blue: 246 }),
+    ("light violet", Color { red: 214, green: 180, blue: 252 }),
+    ("light yellow", Color { red: 255, green: 254, blue: 122 }),
+    ("light yellow green", Color { red: 204, green: 253, blue: 127 }),
+    ("light yellowish green", Color { red: 194, green: 255, blue: 137 }),
+    ("lightblue", Color { red: 123, green: 200, blue: 246 }),
+    ("lighter green", Color { red: 117, green: 253, blue: 99 }),
+    ("lighter purple", Color { red: 165, green: 90, blue: 244 }),
+    ("lightgreen", Color { red: 118, green: 255, blue: 123 }),
+    ("lightish blue", Color { red: 61, green: 122, blue: 253 }),
+    ("lightish green", Color { red: 97, green: 225, blue: 96 }),
+    ("lightish purple", Color { red: 165, green: 82, blue: 230 }),
+    ("lightish red", Color { red: 254, green: 47, blue: 74 }),
+    ("lilac", Color { red: 206, green: 162, blue: 253 }),
+    ("liliac", Color { red: 196, green: 142, blue: 253 }),
+    ("lime", Color { red: 170, green: 255, blue: 50 }),
+    ("lime green", Color { red: 137, green: 254, blue: 5 }),
+    ("lime yellow", Color { red: 208, green: 254, blue: 29 }),
+    ("lipstick", Color { red: 213, green: 23, blue: 78 }),
+    ("lipstick red", Color { red: 192, green: 2, blue: 47 }),
+    ("macaroni and cheese", Color { red: 239, green: 180, blue: 53 }),
+    ("magenta", Color { red: 194, green: 0, blue: 120 }),
+    ("mahogany", Color { red: 74, green: 1, blue: 0 }),
+    ("maize", Color { red: 244, green: 208, blue: 84 }),
+    ("mango", Color { red: 255, green: 166, blue: 43 }),
+    ("manilla", Color { red: 255, green: 250, blue: 134 }),
+    ("marigold", Color { red: 252, green: 192, blue: 6 }),
+    ("marine", Color { red: 4, green: 46, blue: 96 }),
+    ("marine blue", Color { red: 1, green: 56, blue: 106 }),
+    ("maroon", Color { red: 101, green: 0, blue: 33 }),
+    ("mauve", Color { red: 174, green: 113, blue: 129 }),
+    ("medium blue", Color { red: 44, green: 111, blue: 187 }),
+    ("medium brown", Color { red: 127, green: 81, blue: 18 }),
+    ("medium green", Color { red: 57, green: 173, blue: 72 }),
+    ("medium grey", Color { red: 125, green: 127, blue: 124 }),
+    ("medium pink", Color { red: 243, green: 97, blue: 150 }),
+    ("medium purple", Color { red: 158, green: 67, blue: 162 }),
+    ("melon", Color { red: 255, green: 120, blue: 85 }),
+    ("merlot", Color { red: 115, green: 0, blue: 57 }),
+    ("metallic blue", Color { red: 79, green: 115, blue: 142 }),
+    ("mid blue", Color { red: 39, green: 106, blue: 179 }),
+    ("mid green", Color { red: 80, green: 167, blue: 71 }),
+    ("midnight", Color { red: 3, green: 1, blue: 45 }),
+    ("midnight blue", Color { red: 2, green: 0, blue: 53 }),
+    ("midnight purple", Color { red: 40, green: 1, blue: 55 }),
+    ("military green", Color { red: 102, green: 124, blue: 62 }),
+    ("milk chocolate", Color { red: 127, green: 78, blue: 30 }),
+    ("mint", Color { red: 159, green: 254, blue: 176 }),
+    ("mint green", Color { red: 143, green: 255, blue: 159 }),
+    ("minty green", Color { red: 11, green: 247, blue: 125 }),
+    ("mocha", Color { red: 157, green: 118, blue: 81 }),
+    ("moss", Color { red: 118, green: 153, blue: 88 }),
+    ("moss green", Color { red: 101, green: 139, blue: 56 }),
+    ("mossy green", Color { red: 99, green: 139, blue: 39 }),
+    ("mud", Color { red: 115, green: 92, blue: 18 }),
+    ("mud brown", Color { red: 96, green: 70, blue: 15 }),
+    ("mud green", Color { red: 96, green: 102, blue: 2 }),
+    ("muddy brown", Color { red: 136, green: 104, blue: 6 }),
+    ("muddy green", Color { red: 101, green: 116, blue: 50 }),
+    ("muddy yellow", Color { red: 191, green: 172, blue: 5 }),
+    ("mulberry", Color { red: 146, green: 10, blue: 78 }),
+    ("murky green", Color { red: 108, green: 122, blue: 14 }),
+    ("mushroom", Color { red: 186, green: 158, blue: 136 }),
+    ("mustard", Color { red: 206, green: 179, blue: 1 }),
+    ("mustard brown", Color { red: 172, green: 126, blue: 4 }),
+    ("mustard green", Color { red: 168, green: 181, blue: 4 }),
+    ("mustard yellow", Color { red: 210, green: 189, blue: 10 }),
+    ("muted blue", Color { red: 59, green: 113, blue: 159 }),
+    ("muted green", Color { red: 95, green: 160, blue: 82 }),
+    ("muted pink", Color { red: 209, green: 118, blue: 143 }),
+    ("muted purple", Color { red: 128, green: 91, blue: 135 }),
+    ("nasty green", Color { red: 112, green: 178, blue: 63 }),
+    ("navy", Color { red: 1, green: 21, blue: 62 }),
+    ("navy blue", Color { red: 0, green: 17, blue: 70 }),
+    ("navy green", Color { red: 53, green: 83, blue: 10 }),
+    ("neon blue", Color { red: 4, green: 217, blue: 255 }),
+    ("neon green", Color { red: 12, green: 255, blue: 12 }),
+    ("neon pink", Color { red: 254, green: 1, blue: 154 }),
+    ("neon purple", Color { red: 188, green: 19, blue: 254 }),
+    ("neon red", Color { red: 255, green: 7, blue: 58 }),
+    ("neon yellow", Color { red: 207, green: 255, blue: 4 }),
+    ("nice blue", Color { red: 16, green: 122, blue: 176 }),
+    ("night blue", Color { red: 4, green: 3, blue: 72 }),
+    ("ocean", Color { red: 1, green: 123, blue: 146 }),
+    ("ocean blue", Color { red: 3, green: 113, blue: 156 }),
+    ("ocean green", Color { red: 61, green: 153, blue: 115 }),
+    ("ocher", Color { red: 191, green: 155, blue: 12 }),
+    ("ochre", Color { red: 191, green: 144, blue: 5 }),
+    ("ocre", Color { red: 198, green: 156, blue: 4 }),
+    ("off blue", Color { red: 86, green: 132, blue: 174 }),
+    ("off green", Color { red: 107, green: 163, blue: 83 }),
+    ("off white", Color { red: 255, green: 255, blue: 228 }),
+    ("off yellow", Color { red: 241, green: 243, blue: 63 }),
+    ("old pink", Color { red: 199, green: 121, blue: 134 }),
+    ("old rose", Color { red: 200, green: 127, blue: 137 }),
+    ("olive", Color { red: 110, green: 117, blue: 14 }),
+    ("olive brown", Color { red: 100, green: 84, blue: 3 }),
+    ("olive drab", Color { red: 111, green: 118, blue: 50 }),
+    ("olive green", Color { red: 103, green: 122, blue: 4 }),
+    ("olive yellow", Color { red: 194, green: 183, blue: 9 }),
+    ("orange", Color { red: 249, green: 115, blue: 6 }),
+    ("orange brown", Color { red: 190, green: 100, blue: 0 }),
+    ("orange pink", Color { red: 255, green: 111, blue: 82 }),
+    ("orange red", Color { red: 253, green: 65, blue: 30 }),
+    ("orange yellow", Color { red: 255, green: 173, blue: 1 }),
+    ("orangeish", Color { red: 253, green: 141, blue: 73 }),
+    ("orangered", Color { red: 254, green: 66, blue: 15 }),
+    ("orangey brown", Color { red: 177, green: 96, blue: 2 }),
+    ("orangey red", Color { red: 250, green: 66, blue: 36 }),
+    ("orangey yellow", Color { red: 253, green: 185, blue: 21 }),
+    ("orangish", Color { red: 252, green: 130, blue: 74 }),
+    ("orangish brown", Color { red: 178, green: 95, blue: 3 }),
+    ("orangish red", Color { red: 244, green: 54, blue: 5 }),
+    ("orchid", Color { red: 200, green: 117, blue: 196 }),
+    ("pale", Color { red: 255, green: 249, blue: 208 }),
+    ("pale aqua", Color { red: 184, green: 255, blue: 235 }),
+    ("pale blue", Color { red: 208, green: 254, blue: 254 }),
+    ("pale brown", Color { red: 177, green: 145, blue: 110 }),
+    ("pale cyan", Color { red: 183, green: 255, blue: 250 }),
+    ("pale gold", Color { red: 253, green: 222, blue: 108 }),
+    ("pale green", Color { red: 199, green: 253, blue: 181 }),
+    ("pale grey", Color { red: 253, green: 253, blue: 254 }),
+    ("pale lavender", Color { red: 238, green: 207, blue: 254 }),
+    ("pale light green", Color { red: 177, green: 252, blue: 153 }),
+    ("pale lilac", Color { red: 228, green: 203, blue: 255 }),
+    ("pale lime", Color { red: 190, green: 253, blue: 115 }),
+    ("pale lime green", Color { red: 177, green: 255, blue: 101 }),
+    ("pale magenta", Color { red: 215, green: 103, blue: 173 }),
+    ("pale mauve", Color { red: 254, green: 208, blue: 252 }),
+    ("pale olive", Color { red: 185, green: 204, blue: 129 }),
+    ("pale olive green", Color { red: 177, green: 210, blue: 123 }),
+    ("pale orange", Color { red: 255, green: 167, blue: 86 }),
+    ("pale peach", Color { red: 255, green: 229, blue: 173 }),
+    ("pale pink", Color { red: 255, green: 207, blue: 220 }),
+    ("pale purple", Color { red: 183, green: 144, blue: 212 }),
+    ("pale red", Color { red: 217, green: 84, blue: 77 }),
+    ("pale rose", Color { red: 253, green: 193, blue: 197 }),
+    ("pale salmon", Color { red: 255, green: 177, blue: 154 }),
+    ("pale sky blue", Color { red: 189, green: 246, blue: 254 }),
+    ("pale teal", Color { red: 130, green: 203, blue: 178 }),
+    ("pale turquoise", Color { red: 165, green: 251, blue: 213 }),
+    ("pale violet", Color { red: 206, green: 174, blue: 250 }),
+    ("pale yellow", Color { red: 255, green: 255, blue: 132 }),
+    ("parchment", Color { red: 254, green: 252, blue: 175 }),
+    ("pastel blue", Color { red: 162, green: 191, blue: 254 }),
+    ("pastel green", Color { red: 176, green: 255, blue: 157 }),
+    ("pastel orange", Color { red: 255, green: 150, blue: 79 }),
+    ("pastel pink", Color { red: 255, green: 186, blue: 205 }),
+    ("pastel purple", Color { red: 202, green: 160, blue: 255 }),
+    ("pastel red", Color { red: 219, green: 88, blue: 86 }),
+    ("pastel yellow", Color { red: 255, green: 254, blue: 113 }),
+    ("pea", Color { red: 164, green: 191, blue: 32 }),
+    ("pea green", Color { red: 142, green: 171, blue: 18 }),
+    ("pea soup", Color { red: 146, green: 153, blue: 1 }),
+    ("pea soup green", Color { red: 148, green: 166, blue: 23 }),
+    ("peach", Color { red: 255, green: 176, blue: 124 }),
+    ("peachy pink", Color { red: 255, green: 154, blue: 138 }),
+    ("peacock blue", Color { red: 1, green: 103, blue: 149 }),
+    ("pear", Color { red: 203, green: 248, blue: 95 }),
+    ("periwinkle", Color { red: 142, green: 130, blue: 254 }),
+    ("periwinkle blue", Color { red: 143, green: 153, blue: 251 }),
+    ("perrywinkle", Color { red: 143, green: 140, blue: 231 }),
+    ("petrol", Color { red: 0, green: 95, blue: 106 }),
+    ("pig pink", Color { red: 231, green: 142, blue: 165 }),
+    ("pine", Color { red: 43, green: 93, blue: 52 }),
+    ("pine green", Color { red: 10, green: 72, blue: 30 }),
+    ("pink", Color { red: 255, green: 129, blue: 192 }),
+    ("pink purple", Color { red: 219, green: 75, blue: 218 }),
+    ("pink red", Color { red: 245, green: 5, blue: 79 }),
+    ("pink/purple", Color { red: 239, green: 29, blue: 231 }),
+    ("pinkish", Color { red: 212, green: 106, blue: 126 }),
+    ("pinkish brown", Color { red: 177, green: 114, blue: 97 }),
+    ("pinkish grey", Color { red: 200, green: 172, blue: 169 }),
+    ("pinkish orange", Color { red: 255, green: 114, blue: 76 }),
+    ("pinkish purple", Color { red: 214, green: 72, blue: 215 }),
+    ("pinkish red", Color { red: 241, green: 12, blue: 69 }),
+    ("pinkish tan", Color { red: 217, green: 155, blue: 130 }),
+    ("pinky", Color { red: 252, green: 134, blue: 170 }),
+    ("pinky purple", Color { red: 201, green: 76, blue: 190 }),
+    ("pinky red", Color { red: 252, green: 38, blue: 71 }),
+    ("piss yellow", Color { red: 221, green: 214, blue: 24 }),
+    ("pistachio", Color { red: 192, green: 250, blue: 139 }),
+    ("plum", Color { red: 88, green: 15, blue: 65 }),
+    ("plum purple", Color { red: 78, green: 5, blue: 80 }),
+    ("poison green", Color { red: 64, green: 253, blue: 20 }),
+    ("poo", Color { red: 143, green: 115, blue: 3 }),
+    ("poo brown", Color { red: 136, green: 95, blue: 1 }),
+    ("poop", Color { red: 127, green: 94, blue: 0 }),
+    ("poop brown", Color { red: 122, green: 89, blue: 1 }),
+    ("poop green", Color { red: 111, green: 124, blue: 0 }),
+    ("powder blue", Color { red: 177, green: 209, blue: 252 }),
+    ("powder pink", Color { red: 255, green: 178, blue: 208 }),
+    ("primary blue", Color { red: 8, green: 4, blue: 249 }),
+    ("prussian blue", Color { red: 0, green: 69, blue: 119 }),
+    ("puce", Color { red: 165, green: 126, blue: 82 }),
+    ("puke", Color { red: 165, green: 165, blue: 2 }),
+    ("puke brown", Color { red: 148, green: 119, blue: 6 }),
+    ("puke green", Color { red: 154, green: 174, blue: 7 }),
+    ("puke yellow", Color { red: 194, green: 190, blue: 14 }),
+    ("pumpkin", Color { red: 225, green: 119, blue: 1 }),
+    ("pumpkin orange", Color { red: 251, green: 125, blue: 7 }),
+    ("pure blue", Color { red: 2, green: 3, blue: 226 }),
+    ("purple", Color { red: 126, green: 30, blue: 156 }),
+    ("purple blue", Color { red: 99, green: 45, blue: 233 }),
+    ("purple brown", Color { red: 103, green: 58, blue: 63 }),
+    ("purple grey", Color { red: 134, green: 111, blue: 133 }),
+    ("purple pink", Color { red: 224, green: 63, blue: 216 }),
+    ("purple red", Color { red: 153, green: 1, blue: 71 }),
+    ("purple/blue", Color { red: 93, green: 33, blue: 208 }),
+    ("purple/pink", Color { red: 215, green: 37, blue: 222 }),
+    ("purpleish", Color { red: 152, green: 86, blue: 141 }),
+    ("purpleish blue", Color { red: 97, green: 64, blue: 239 }),
+    ("purpleish pink", Color { red: 223, green: 78, blue: 200 }),
+    ("purpley", Color { red: 135, green: 86, blue: 228 }),
+    ("purpley blue", Color { red: 95, green: 52, blue: 231 }),
+    ("purpley grey", Color { red: 148, green: 126, blue: 148 }),
+    ("purpley pink", Color { red: 200, green: 60, blue: 185 }),
+    ("purplish", Color { red: 148, green: 86, blue: 140 }),
+    ("purplish blue", Color { red: 96, green: 30, blue: 249 }),
+    ("purplish brown", Color { red: 107, green: 66, blue: 71 }),
+    ("purplish grey", Color { red: 122, green: 104, blue: 127 }),
+    ("purplish pink", Color { red: 206, green: 93, blue: 174 }),
+    ("purplish red", Color { red: 176, green: 5, blue: 75 }),
+    ("purply", Color { red: 152, green: 63, blue: 178 }),
+    ("purply blue", Color { red: 102, green: 26, blue: 238 }),
+    ("purply pink", Color { red: 240, green: 117, blue: 230 }),
+    ("putty", Color { red: 190, green: 174, blue: 138 }),
+    ("racing green", Color { red: 1, green: 70, blue: 0 }),
+    ("radioactive green", Color { red: 44, green: 250, blue: 31 }),
+    ("raspberry", Color { red: 176, green: 1, blue: 73 }),
+    ("raw sienna", Color { red: 154, green: 98, blue: 0 }),
+    ("raw umber", Color { red: 167, green: 94, blue: 9 }),
+    ("really light blue", Color { red: 212, green: 255, blue: 255 }),
+    ("red", Color { red: 229, green: 0, blue: 0 }),
+    ("red brown", Color { red: 139, green: 46, blue: 22 }),
+    ("red orange", Color { red: 253, green: 60, blue: 6 }),
+    ("red pink", Color { red: 250, green: 42, blue: 85 }),
+    ("red purple", Color { red: 130, green: 7, blue: 71 }),
+    ("red violet", Color { red: 158, green: 1, blue: 104 }),
+    ("red wine", Color { red: 140, green: 0, blue: 52 }),
+    ("reddish", Color { red: 196, green: 66, blue: 64 }),
+    ("reddish brown", Color { red: 127, green: 43, blue: 10 }),
+    ("reddish grey", Color { red: 153, green: 117, blue: 112 }),
+    ("reddish orange", Color { red: 248, green: 72, blue: 28 }),
+    ("reddish pink", Color { red: 254, green: 44, blue: 84 }),
+    ("reddish purple", Color { red: 145, green: 9, blue: 81 }),
+    ("reddy brown", Color { red: 110, green: 16, blue: 5 }),
+    ("rich blue", Color { red: 2, green: 27, blue: 249 }),
+    ("rich purple", Color { red: 114, green: 0, blue: 88 }),
+    ("robin egg blue", Color { red: 138, green: 241, blue: 254 }),
+    ("robin's egg", Color { red: 109, green: 237, blue: 253 }),
+    ("robin's egg blue", Color { red: 152, green: 239, blue: 249 }),
+    ("rosa", Color { red: 254, green: 134, blue: 164 }),
+    ("rose", Color { red: 207, green: 98, blue: 117 }),
+    ("rose pink", Color { red: 247, green: 135, blue: 154 }),
+    ("rose red", Color { red: 190, green: 1, blue: 60 }),
+    ("rosy pink", Color { red: 246, green: 104, blue: 142 }),
+    ("rouge", Color { red: 171, green: 18, blue: 57 }),
+    ("royal", Color { red: 12, green: 23, blue: 147 }),
+    ("royal blue", Color { red: 5, green: 4, blue: 170 }),
+    ("royal purple", Color { red: 75, green: 0, blue: 110 }),
+    ("ruby", Color { red: 202, green: 1, blue: 71 }),
+    ("russet", Color { red: 161, green: 57, blue: 5 }),
+    ("rust", Color { red: 168, green: 60, blue: 9 }),
+    ("rust brown", Color { red: 139, green: 49, blue: 3 }),
+    ("rust orange", Color { red: 196, green: 85, blue: 8 }),
+    ("rust red", Color { red: 170, green: 39, blue: 4 }),
+    ("rusty orange", Color { red: 205, green: 89, blue: 9 }),
+    ("rusty red", Color { red: 175, green: 47, blue: 13 }),
+    ("saffron", Color { red: 254, green: 178, blue: 9 }),
+    ("sage", Color { red: 135, green: 174, blue: 115 }),
+    ("sage green", Color { red: 136, green: 179, blue: 120 }),
+    ("salmon", Color { red: 255, green: 121, blue: 108 }),
+    ("salmon pink", Color { red: 254, green: 123, blue: 124 }),
+    ("sand", Color { red: 226, green: 202, blue: 118 }),
+    ("sand brown", Color { red: 203, green: 165, blue: 96 }),
+    ("sand yellow", Color { red: 252, green: 225, blue: 102 }),
+    ("sandstone", Color { red: 201, green: 174, blue: 116 }),
+    ("sandy", Color { red: 241, green: 218, blue: 122 }),
+    ("sandy brown", Color { red: 196, green: 166, blue: 97 }),
+    ("sandy yellow", Color { red: 253, green: 238, blue: 115 }),
+    ("sap green", Color { red: 92, green: 139, blue: 21 }),
+    ("sapphire", Color { red: 33, green: 56, blue: 171 }),
+    ("scarlet", Color { red: 190, green: 1, blue: 25 }),
+    ("sea", Color { red: 60, green: 153, blue: 146 }),
+    ("sea blue", Color { red: 4, green: 116, blue: 149 }),
+    ("sea green", Color { red: 83, green: 252, blue: 161 }),
+    ("seafoam", Color { red: 128, green: 249, blue: 173 }),
+    ("seafoam blue", Color { red: 120, green: 209, blue: 182 }),
+    ("seafoam green", Color { red: 122, green: 249, blue: 171 }),
+    ("seaweed", Color { red: 24, green: 209, blue: 123 }),
+    ("seaweed green", Color { red: 53, green: 173, blue: 107 }),
+    ("sepia", Color { red: 152, green: 94, blue: 43 }),
+    ("shamrock", Color { red: 1, green: 180, blue: 76 }),
+    ("shamrock green", Color { red: 2, green: 193, blue: 77 }),
+    ("shit", Color { red: 127, green: 95, blue: 0 }),
+    ("shit brown", Color { red: 123, green: 88, blue: 4 }),
+    ("shit green", Color { red: 117, green: 128, blue: 0 }),
+    ("shocking pink", Color { red: 254, green: 2, blue: 162 }),
+    ("sick green", Color { red: 157, green: 185, blue: 44 }),
+    ("sickly green", Color { red: 148, green: 178, blue: 28 }),
+    ("sickly yellow", Color { red: 208, green: 228, blue: 41 }),
+    ("sienna", Color { red: 169, green: 86, blue: 30 }),
+    ("silver", Color { red: 197, green: 201, blue: 199 }),
+    ("sky", Color { red: 130, green: 202, blue: 252 }),
+    ("sky blue", Color { red: 117, green: 187, blue: 253 }),
+    ("slate", Color { red: 81, green: 101, blue: 114 }),
+    ("slate blue", Color { red: 91, green: 124, blue: 153 }),
+    ("slate green", Color { red: 101, green: 141, blue: 109 }),
+    ("slate grey", Color { red: 89, green: 101, blue: 109 }),
+    ("slime green", Color { red: 153, green: 204, blue: 4 }),
+    ("snot", Color { red: 172, green: 187, blue: 13 }),
+    ("snot green", Color { red: 157, green: 193, blue: 0 }),
+    ("soft blue", Color { red: 100, green: 136, blue: 234 }),
+    ("soft green", Color { red: 111, green: 194, blue: 118 }),
+    ("soft pink", Color { red: 253, green: 176, blue: 192 }),
+    ("soft purple", Color { red: 166, green: 111, blue: 181 }),
+    ("spearmint", Color { red: 30, green: 248, blue: 118 }),
+    ("spring green", Color { red: 169, green: 249, blue: 113 }),
+    ("spruce", Color { red: 10, green: 95, blue: 56 }),
+    ("squash", Color { red: 242, green: 171, blue: 21 }),
+    ("steel", Color { red: 115, green: 133, blue: 149 }),
+    ("steel blue", Color { red: 90, green: 125, blue: 154 }),
+    ("steel grey", Color { red: 111, green: 130, blue: 138 }),
+    ("stone", Color { red: 173, green: 165, blue: 135 }),
+    ("stormy blue", Color { red: 80, green: 123, blue: 156 }),
+    ("straw", Color { red: 252, green: 246, blue: 121 }),
+    ("strawberry", Color { red: 251, green: 41, blue: 67 }),
+    ("strong blue", Color { red: 12, green: 6, blue: 247 }),
+    ("strong pink", Color { red: 255, green: 7, blue: 137 }),
+    ("sun yellow", Color { red: 255, green: 223, blue: 34 }),
+    ("sunflower", Color { red: 255, green: 197, blue: 18 }),
+    ("sunflower yellow", Color { red: 255, green: 218, blue: 3 }),
+    ("sunny yellow", Color { red: 255, green: 249, blue: 23 }),
+    ("sunshine yellow", Color { red: 255, green: 253, blue: 55 }),
+    ("swamp", Color { red: 105, green: 131, blue: 57 }),
+    ("swamp green", Color { red: 116, green: 133, blue: 0 }),
+    ("tan", Color { red: 209, green: 178, blue: 111 }),
+    ("tan brown", Color { red: 171, green: 126, blue: 76 }),
+    ("tan green", Color { red: 169, green: 190, blue: 112 }),
+    ("tangerine", Color { red: 255, green: 148, blue: 8 }),
+    ("taupe", Color { red: 185, green: 162, blue: 129 }),
+    ("tea", Color { red: 101, green: 171, blue: 124 }),
+    ("tea green", Color { red: 189, green: 248, blue: 163 }),
+    ("teal", Color { red: 2, green: 147, blue: 134 }),
+    ("teal blue", Color { red: 1, green: 136, blue: 159 }),
+    ("teal green", Color { red: 37, green: 163, blue: 111 }),
+    ("tealish", Color { red: 36, green: 188, blue: 168 }),
+    ("tealish green", Color { red: 12, green: 220, blue: 115 }),
+    ("terra cotta", Color { red: 201, green: 100, blue: 59 }),
+    ("terracota", Color { red: 203, green: 104, blue: 67 }),
+    ("terracotta", Color { red: 202, green: 102, blue: 65 }),
+    ("tiffany blue", Color { red: 123, green: 242, blue: 218 }),
+    ("tomato", Color { red: 239, green: 64, blue: 38 }),
+    ("tomato red", Color { red: 236, green: 45, blue: 1 }),
+    ("topaz", Color { red: 19, green: 187, blue: 175 }),
+    ("toupe", Color { red: 199, green: 172, blue: 125 }),
+    ("toxic green", Color { red: 97, green: 222, blue: 42 }),
+    ("tree green", Color { red: 42, green: 126, blue: 25 }),
+    ("true blue", Color { red: 1, green: 15, blue: 204 }),
+    ("true green", Color { red: 8, green: 148, blue: 4 }),
+    ("turquoise", Color { red: 6, green: 194, blue: 172 }),
+    ("turquoise blue", Color { red: 6, green: 177, blue: 196 }),
+    ("turquoise green", Color { red: 4, green: 244, blue: 137 }),
+    ("turtle green", Color { red: 117, green: 184, blue: 79 }),
+    ("twilight", Color { red: 78, green: 81, blue: 139 }),
+    ("twilight blue", Color { red: 10, green: 67, blue: 122 }),
+    ("ugly blue", Color { red: 49, green: 102, blue: 138 }),
+    ("ugly brown", Color { red: 125, green: 113, blue: 3 }),
+    ("ugly green", Color { red: 122, green: 151, blue: 3 }),
+    ("ugly pink", Color { red: 205, green: 117, blue: 132 }),
+    ("ugly purple", Color { red: 164, green: 66, blue: 160 }),
+    ("ugly yellow", Color { red: 208, green: 193, blue: 1 }),
+    ("ultramarine", Color { red: 32, green: 0, blue: 177 }),
+    ("ultramarine blue", Color { red: 24, green: 5, blue: 219 }),
+    ("umber", Color { red: 178, green: 100, blue: 0 }),
+    ("velvet", Color { red: 117, green: 8, blue: 81 }),
+    ("vermillion", Color { red: 244, green: 50, blue: 12 }),
+    ("very dark blue", Color { red: 0, green: 1, blue: 51 }),
+    ("very dark brown", Color { red: 29, green: 2, blue: 0 }),
+    ("very dark green", Color { red: 6, green: 46, blue: 3 }),
+    ("very dark purple", Color { red: 42, green: 1, blue: 52 }),
+    ("very light blue", Color { red: 213, green: 255, blue: 255 }),
+    ("very light brown", Color { red: 211, green: 182, blue: 131 }),
+    ("very light green", Color { red: 209, green: 255, blue: 189 }),
+    ("very light pink", Color { red: 255, green: 244, blue: 242 }),
+    ("very light purple", Color { red: 246, green: 206, blue: 252 }),
+    ("very pale blue", Color { red: 214, green: 255, blue: 254 }),
+    ("very pale green", Color { red: 207, green: 253, blue: 188 }),
+    ("vibrant blue", Color { red: 3, green: 57, blue: 248 }),
+    ("vibrant green", Color { red: 10, green: 221, blue: 8 }),
+    ("vibrant purple", Color { red: 173, green: 3, blue: 222 }),
+    ("violet", Color { red: 154, green: 14, blue: 234 }),
+    ("violet blue", Color { red: 81, green: 10, blue: 201 }),
+    ("violet pink", Color { red: 251, green: 95, blue: 252 }),
+    ("violet red", Color { red: 165, green: 0, blue: 85 }),
+    ("viridian", Color { red: 30, green: 145, blue: 103 }),
+    ("vivid blue", Color { red: 21, green: 46, blue: 255 }),
+    ("vivid green", Color { red: 47, green: 239, blue: 16 }),
+    ("vivid purple", Color { red: 153, green: 0, blue: 250 }),
+    ("vomit", Color { red: 162, green: 164, blue: 21 }),
+    ("vomit green", Color { red: 137, green: 162, blue: 3 }),
+    ("vomit yellow", Color { red: 199, green: 193, blue: 12 }),
+    ("warm blue", Color { red: 75, green: 87, blue: 219 }),
+    ("warm brown", Color { red: 150, green: 78, blue: 2 }),
+    ("warm grey", Color { red: 151, green: 138, blue: 132 }),
+    ("warm pink", Color { red: 251, green: 85, blue: 129 }),
+    ("warm purple", Color { red: 149, green: 46, blue: 143 }),
+    ("washed out green", Color { red: 188, green: 245, blue: 166 }),
+    ("water blue", Color { red: 14, green: 135, blue: 204 }),
+    ("watermelon", Color { red: 253, green: 70, blue: 89 }),
+    ("weird green", Color { red: 58, green: 229, blue: 127 }),
+    ("wheat", Color { red: 251, green: 221, blue: 126 }),
+    ("white", Color { red: 255, green: 255, blue: 255 }),
+    ("windows blue", Color { red: 55, green: 120, blue: 191 }),
+    ("wine", Color { red: 128, green: 1, blue: 63 }),
+    ("wine red", Color { red: 123, green: 3, blue: 35 }),
+    ("wintergreen", Color { red: 32, green: 249, blue: 134 }),
+    ("wisteria", Color { red: 168, green: 125, blue: 194 }),
+    ("yellow", Color { red: 255, green: 255, blue: 20 }),
+    ("yellow brown", Color { red: 183, green: 148, blue: 0 }),
+    ("yellow green", Color { red: 192, green: 251, blue: 45 }),
+    ("yellow ochre", Color { red: 203, green: 157, blue: 6 }),
+    ("yellow orange", Color { red: 252, green: 176, blue: 1 }),
+    ("yellow tan", Color { red: 255, green: 227, blue: 110 }),
+    ("yellow/green", Color { red: 200, green: 253, blue: 61 }),
+    ("yellowgreen", Color { red: 187, green: 249, blue: 15 }),
+    ("yellowish", Color { red: 250, green: 238, blue: 102 }),
+    ("yellowish brown", Color { red: 155, green: 122, blue: 1 }),
+    ("yellowish green", Color { red: 176, green: 221, blue: 22 }),
+    ("yellowish orange", Color { red: 255, green: 171, blue: 15 }),
+    ("yellowish tan", Color { red: 252, green: 252, blue: 129 }),
+    ("yellowy brown", Color { red: 174, green: 139, blue: 12 }),
+    ("yellowy green", Color { red: 191, green: 241, blue: 40 }),
+];
+
+/// Attempts to get a color from an xkcd color name. Matching is case-insensitive and leading and 
+/// trailing whitespace is ignored.
+pub fn from_xkcd_color_name(s: &str) -> Option<Color> {
+    named::find_by_name(&COLOR_NAMES, s)
+}
+
+/// Gets the name of the survey color nearest to `color`, measured by the squared distance between 
+/// their RGB values. Exact matches return their own name.
+pub fn nearest_xkcd_color_name(color: &Color) -> &'static str {
+    named::nearest(&COLOR_NAMES, color).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn parses_xkcd_color_name() {
+        assert_eq!(from_xkcd_color_name("purple"), Some(Color { red: 126, green: 30, blue: 156 }));
+        assert_eq!(
+            from_xkcd_color_name("  Navy Blue\n"),
+            Some(Color { red: 0, green: 17, blue: 70 }),
+        );
+        assert_eq!(
+            from_xkcd_color_name("Puke Green"),
+            Some(Color { red: 154, green: 174, blue: 7 }),
+        );
+        assert_eq!(from_xkcd_color_name("navyblue"), None);
+        assert_eq!(from_xkcd_color_name(""), None);
+    }
+    
+    #[test]
+    fn finds_nearest_xkcd_color_name() {
+        assert_eq!(nearest_xkcd_color_name(&Color { red: 0, green: 0, blue: 0 }), "black");
+        assert_eq!(nearest_xkcd_color_name(&Color { red: 250, green: 115, blue: 10 }), "orange");
+        
+        for (name, color) in COLOR_NAMES {
+            assert_eq!(nearest_xkcd_color_name(&color), name);
+        }
+    }
+    
+    #[test]
+    fn color_names_are_sorted() {
+        assert!(COLOR_NAMES.windows(2).all(|pair| pair[0].0 < pair[1].0));
+    }
+}
\ No newline at end of file